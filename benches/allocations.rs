@@ -0,0 +1,127 @@
+//! Counts heap allocations made per request by `Bot`, with a counting global
+//! allocator and a local keep-alive server. The server runs on another
+//! thread, only allocations of the client thread are counted.
+//!
+//! Run with `cargo bench --bench allocations`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    convert::Infallible,
+    net::SocketAddr,
+    sync::mpsc,
+};
+
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, Response, Server,
+};
+use teloxide_core::{
+    requests::{Request, Requester},
+    types::MessageId,
+    Bot,
+};
+
+const REQUESTS: usize = 1000;
+
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = Cell::new(0);
+}
+
+fn count() {
+    // `try_with` since the thread local may already be destroyed
+    let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count();
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count();
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// Starts a server on another thread, which responds with `true` to every
+/// request.
+fn serve() -> SocketAddr {
+    let (tx, rx) = mpsc::channel();
+
+    std::thread::spawn(move || {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        rt.block_on(async {
+            let make_service = make_service_fn(|_| async {
+                Ok::<_, Infallible>(service_fn(|_| async {
+                    Ok::<_, Infallible>(Response::new(Body::from(r#"{"ok":true,"result":true}"#)))
+                }))
+            });
+
+            let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_service);
+            tx.send(server.local_addr()).unwrap();
+            server.await.unwrap();
+        });
+    });
+
+    rx.recv().unwrap()
+}
+
+/// Returns the number of allocations made by `f` on this thread, divided by
+/// `REQUESTS`.
+fn per_request(f: impl FnOnce()) -> f64 {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    (ALLOCATIONS.with(Cell::get) - before) as f64 / REQUESTS as f64
+}
+
+fn main() {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let addr = serve();
+    let bot = Bot::new("TOKEN").set_api_url(format!("http://{}", addr).parse().unwrap());
+    let delete = |i: usize| bot.delete_message(0, MessageId(i as i64));
+
+    // Warm up: establish the connection
+    rt.block_on(delete(0).send()).unwrap();
+
+    let full = per_request(|| {
+        rt.block_on(async {
+            for i in 0..REQUESTS {
+                delete(i).send().await.unwrap();
+            }
+        })
+    });
+    let created = per_request(|| {
+        for i in 0..REQUESTS {
+            drop(delete(i));
+        }
+    });
+    let dropped = per_request(|| {
+        for i in 0..REQUESTS {
+            drop(delete(i).send());
+        }
+    });
+
+    println!("allocations per request ({} requests):", REQUESTS);
+    println!("  full request:                     {:.1}", full);
+    println!("  request created, not sent:        {:.1}", created);
+    println!("  `send()` dropped without polling: {:.1}", dropped);
+}
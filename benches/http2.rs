@@ -0,0 +1,110 @@
+//! Compares HTTP/1.1 with HTTP/2 (see `ClientConfig::http2_prior_knowledge`)
+//! against a local server, which responds after a delay to simulate the round
+//! trip to Telegram. Requests are sent one at a time (like long polling) and
+//! all at once (like broadcasting).
+//!
+//! The server doesn't use TLS, so the cost of TLS handshakes (which HTTP/2
+//! saves by opening fewer connections) isn't included in the timings.
+//!
+//! Run with `cargo bench --bench http2`.
+
+use std::{
+    convert::Infallible,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use futures::future::join_all;
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, Response, Server,
+};
+use teloxide_core::{
+    net::ClientConfig,
+    requests::{Request, Requester},
+    types::MessageId,
+    Bot,
+};
+
+const REQUESTS: usize = 100;
+const LATENCY: Duration = Duration::from_millis(20);
+
+/// Starts a server which accepts both HTTP/1.1 and HTTP/2 (without TLS) and
+/// counts connections.
+fn serve(connections: Arc<AtomicUsize>) -> SocketAddr {
+    let make_service = make_service_fn(move |_| {
+        connections.fetch_add(1, Ordering::Relaxed);
+
+        async {
+            Ok::<_, Infallible>(service_fn(|_| async {
+                tokio::time::sleep(LATENCY).await;
+                Ok::<_, Infallible>(Response::new(Body::from(r#"{"ok":true,"result":true}"#)))
+            }))
+        }
+    });
+
+    let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_service);
+    let addr = server.local_addr();
+    tokio::spawn(server);
+
+    addr
+}
+
+/// Sends `REQUESTS` requests, returns the time it took and the number of
+/// connections the server accepted.
+async fn run(http2: bool, concurrent: bool) -> (Duration, usize) {
+    let connections = Arc::new(AtomicUsize::new(0));
+    let addr = serve(Arc::clone(&connections));
+
+    let config = ClientConfig::new().http2_prior_knowledge(http2);
+    let bot =
+        Bot::from_config("TOKEN", config).set_api_url(format!("http://{}", addr).parse().unwrap());
+    let send = |i| bot.delete_message(0, MessageId(i as i64)).send();
+
+    let start = Instant::now();
+    if concurrent {
+        for res in join_all((0..REQUESTS).map(send)).await {
+            res.unwrap();
+        }
+    } else {
+        for i in 0..REQUESTS {
+            send(i).await.unwrap();
+        }
+    }
+
+    (start.elapsed(), connections.load(Ordering::Relaxed))
+}
+
+fn main() {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    println!(
+        "{:>10} {:>8} {:>8} {:>11}",
+        "requests", "protocol", "time", "connections"
+    );
+
+    for &concurrent in &[false, true] {
+        for &http2 in &[false, true] {
+            let (time, connections) = rt.block_on(run(http2, concurrent));
+
+            println!(
+                "{:>10} {:>8} {:>5} ms {:>11}",
+                if concurrent {
+                    "concurrent"
+                } else {
+                    "sequential"
+                },
+                if http2 { "HTTP/2" } else { "HTTP/1.1" },
+                time.as_millis(),
+                connections
+            );
+        }
+    }
+}
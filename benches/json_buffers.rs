@@ -0,0 +1,56 @@
+//! Compares serializing payloads with `serde_json::to_vec` (what
+//! `Bot::execute_json` does) against reusing a thread-local buffer.
+//!
+//! Run with `cargo bench --bench json_buffers`.
+
+use std::{cell::RefCell, time::Instant};
+
+use bytes::Bytes;
+use teloxide_core::payloads::SendMessage;
+
+const MESSAGES: usize = 100_000;
+
+thread_local! {
+    static BUF: RefCell<Vec<u8>> = RefCell::new(Vec::with_capacity(1024));
+}
+
+fn to_vec(payload: &SendMessage) -> Bytes {
+    serde_json::to_vec(payload).unwrap().into()
+}
+
+fn pooled(payload: &SendMessage) -> Bytes {
+    BUF.with(|buf| {
+        let mut buf = buf.borrow_mut();
+        buf.clear();
+        serde_json::to_writer(&mut *buf, payload).unwrap();
+        Bytes::copy_from_slice(&buf)
+    })
+}
+
+/// Returns the time in milliseconds and the total size of the serialized
+/// payloads (so that serialization isn't optimized away).
+fn run(payloads: &[SendMessage], f: fn(&SendMessage) -> Bytes) -> (u128, usize) {
+    let start = Instant::now();
+    let size = payloads.iter().map(|p| f(p).len()).sum();
+    (start.elapsed().as_millis(), size)
+}
+
+fn main() {
+    println!("{:>8} {:>10} {:>10}", "text len", "to_vec", "pooled");
+
+    for &len in &[30, 1000, 4000] {
+        let payloads: Vec<_> = (0..MESSAGES)
+            .map(|i| SendMessage::new(i as i64, "a".repeat(len)))
+            .collect();
+
+        // Warm up
+        run(&payloads, to_vec);
+        run(&payloads, pooled);
+
+        let (to_vec, size) = run(&payloads, to_vec);
+        let (pooled, pooled_size) = run(&payloads, pooled);
+        assert_eq!(size, pooled_size);
+
+        println!("{:>8} {:>7} ms {:>7} ms", len, to_vec, pooled);
+    }
+}
@@ -25,6 +25,72 @@ pub mod auto_send;
 #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "cache_me")))]
 pub mod cache_me;
 
+/// [`CircuitBreaker`] bot adaptor which fails fast when Telegram is
+/// unreachable.
+///
+/// [`CircuitBreaker`]: circuit_breaker::CircuitBreaker
+#[cfg(feature = "circuit_breaker")]
+#[cfg_attr(
+    all(docsrs, feature = "nightly"),
+    doc(cfg(feature = "circuit_breaker"))
+)]
+pub mod circuit_breaker;
+
+/// [`ErasedRequester`] bot adaptor which erases the type of a bot.
+///
+/// [`ErasedRequester`]: erased::ErasedRequester
+#[cfg(feature = "erased")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "erased")))]
+pub mod erased;
+
+/// [`Inspect`] bot adaptor which calls a closure with every successful
+/// response.
+///
+/// [`Inspect`]: inspect::Inspect
+#[cfg(feature = "inspect")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "inspect")))]
+pub mod inspect;
+
+/// [`Metrics`] bot adaptor which collects metrics of sent requests.
+///
+/// [`Metrics`]: metrics::Metrics
+#[cfg(feature = "metrics")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "metrics")))]
+pub mod metrics;
+
+/// [`Mock`] bot adaptor which records requests instead of sending them.
+///
+/// [`Mock`]: mock::Mock
+#[cfg(feature = "mock")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "mock")))]
+pub mod mock;
+
+/// [`ResolveUsernames`] bot adaptor which resolves usernames of chats to ids
+/// and caches them.
+///
+/// [`ResolveUsernames`]: resolve_usernames::ResolveUsernames
+#[cfg(feature = "resolve_usernames")]
+#[cfg_attr(
+    all(docsrs, feature = "nightly"),
+    doc(cfg(feature = "resolve_usernames"))
+)]
+pub mod resolve_usernames;
+
+/// [`Retry`] bot adaptor which automatically retries failed requests.
+///
+/// [`Retry`]: retry::Retry
+#[cfg(feature = "retry")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "retry")))]
+pub mod retry;
+
+/// [`RouteByChat`] bot adaptor which routes requests to different bots based
+/// on their chat.
+///
+/// [`RouteByChat`]: route_by_chat::RouteByChat
+#[cfg(feature = "route_by_chat")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "route_by_chat")))]
+pub mod route_by_chat;
+
 /// [`Throttle`] bot adaptor which allows automatically throttle when hitting
 /// API limits.
 ///
@@ -33,6 +99,13 @@ pub mod cache_me;
 #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "throttle")))]
 pub mod throttle;
 
+/// [`Trace`] bot adaptor which logs requests and responses.
+///
+/// [`Trace`]: trace::Trace
+#[cfg(feature = "trace")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "trace")))]
+pub mod trace;
+
 mod parse_mode;
 
 #[cfg(feature = "auto_send")]
@@ -41,8 +114,41 @@ pub use auto_send::AutoSend;
 #[cfg(feature = "cache_me")]
 #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "cache_me")))]
 pub use cache_me::CacheMe;
+#[cfg(feature = "circuit_breaker")]
+#[cfg_attr(
+    all(docsrs, feature = "nightly"),
+    doc(cfg(feature = "circuit_breaker"))
+)]
+pub use circuit_breaker::CircuitBreaker;
+#[cfg(feature = "erased")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "erased")))]
+pub use erased::ErasedRequester;
+#[cfg(feature = "inspect")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "inspect")))]
+pub use inspect::Inspect;
+#[cfg(feature = "metrics")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "metrics")))]
+pub use metrics::Metrics;
+#[cfg(feature = "mock")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "mock")))]
+pub use mock::Mock;
+#[cfg(feature = "resolve_usernames")]
+#[cfg_attr(
+    all(docsrs, feature = "nightly"),
+    doc(cfg(feature = "resolve_usernames"))
+)]
+pub use resolve_usernames::ResolveUsernames;
+#[cfg(feature = "retry")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "retry")))]
+pub use retry::Retry;
+#[cfg(feature = "route_by_chat")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "route_by_chat")))]
+pub use route_by_chat::RouteByChat;
 #[cfg(feature = "throttle")]
 #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "throttle")))]
 pub use throttle::Throttle;
+#[cfg(feature = "trace")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "trace")))]
+pub use trace::Trace;
 
 pub use parse_mode::DefaultParseMode;
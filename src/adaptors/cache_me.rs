@@ -1,4 +1,8 @@
-use std::{pin::Pin, sync::Arc};
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
 
 use futures::{
     future,
@@ -6,7 +10,6 @@ use futures::{
     task::{Context, Poll},
     Future,
 };
-use once_cell::sync::OnceCell;
 
 use crate::{
     payloads::GetMe,
@@ -18,9 +21,22 @@ use crate::{
 ///
 /// Bot's user is hardly ever changed, so sometimes it's reasonable to cache
 /// response from `get_me` method.
+///
+/// By default the response is cached forever, use [`CacheMe::new_with_ttl`]
+/// to refresh it periodically (e.g. if the bot may be renamed) or
+/// [`CacheMe::invalidate`] to force a refresh.
+///
+/// Clones of `CacheMe` share the cache.
+#[derive(Clone)]
 pub struct CacheMe<B> {
     bot: B,
-    me: Arc<OnceCell<Me>>,
+    me: Arc<Mutex<Option<CachedMe>>>,
+    ttl: Option<Duration>,
+}
+
+struct CachedMe {
+    me: Me,
+    cached_at: Instant,
 }
 
 impl<B> CacheMe<B> {
@@ -32,10 +48,27 @@ impl<B> CacheMe<B> {
     pub fn new(bot: B) -> CacheMe<B> {
         Self {
             bot,
-            me: Arc::new(OnceCell::new()),
+            me: Arc::new(Mutex::new(None)),
+            ttl: None,
         }
     }
 
+    /// Creates new cache, which expires after `ttl`.
+    ///
+    /// When the cache is expired, the next `get_me` call sends a request to
+    /// Telegram and updates the cache.
+    pub fn new_with_ttl(bot: B, ttl: Duration) -> CacheMe<B> {
+        Self {
+            ttl: Some(ttl),
+            ..Self::new(bot)
+        }
+    }
+
+    /// Returns time to live of the cache or `None` if the cache never expires.
+    pub fn ttl(&self) -> Option<Duration> {
+        self.ttl
+    }
+
     /// Allows to access inner bot
     pub fn inner(&self) -> &B {
         &self.bot
@@ -50,13 +83,70 @@ impl<B> CacheMe<B> {
     ///
     /// Returns cached response from `get_me`, if it was cached.
     ///
-    /// Note: internally this uses [`Arc::make_mut`] so this will **not**
-    /// clear cache of clones of self.
+    /// Note: this will **not** clear cache of clones of self, see also
+    /// [`CacheMe::invalidate`].
     pub fn clear(&mut self) -> Option<Me> {
-        Arc::make_mut(&mut self.me).take()
+        let old = std::mem::replace(&mut self.me, Arc::new(Mutex::new(None)));
+        let me = lock(&old).as_ref().map(|cached| cached.me.clone());
+        me
+    }
+
+    /// Invalidates the cache, so the next `get_me` call sends a request to
+    /// Telegram.
+    ///
+    /// Unlike [`CacheMe::clear`], this also invalidates the cache of clones of
+    /// self.
+    pub fn invalidate(&self) {
+        lock(&self.me).take();
+    }
+
+    /// Returns the cached response from `get_me`, if it's cached and not
+    /// expired.
+    ///
+    /// This method never sends requests, see also [`CacheMe::preload`].
+    pub fn get_cached(&self) -> Option<Me> {
+        lock(&self.me)
+            .as_ref()
+            .filter(|cached| match self.ttl {
+                Some(ttl) => cached.cached_at.elapsed() < ttl,
+                None => true,
+            })
+            .map(|cached| cached.me.clone())
+    }
+
+    /// Populates the cache, sending a `get_me` request if the response isn't
+    /// cached yet (or if it's expired).
+    ///
+    /// This is useful to make the request eagerly at startup, so that the
+    /// first "real" `get_me` call doesn't have to wait for it (and to make
+    /// [`CacheMe::get_cached`] return `Some(_)`).
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use teloxide_core::{requests::RequesterExt, Bot};
+    ///
+    /// # async {
+    /// let bot = Bot::new("TOKEN").cache_me();
+    /// let me = bot.preload().await?;
+    ///
+    /// assert_eq!(bot.get_cached(), Some(me));
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    pub fn preload(&self) -> Send<B::GetMe>
+    where
+        B: Requester,
+    {
+        self.get_me().send()
     }
 }
 
+fn lock(me: &Mutex<Option<CachedMe>>) -> MutexGuard<'_, Option<CachedMe>> {
+    // The cache is only replaced as a whole, so it can't be left in an
+    // inconsistent state and it's ok to ignore poisoning.
+    me.lock().unwrap_or_else(|err| err.into_inner())
+}
+
 macro_rules! f {
     ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
         $this.inner().$m($($arg),*)
@@ -78,8 +168,8 @@ where
     type GetMe = CachedMeRequest<B::GetMe>;
 
     fn get_me(&self) -> Self::GetMe {
-        match self.me.get() {
-            Some(me) => CachedMeRequest(Inner::Ready(me.clone()), GetMe::new()),
+        match self.get_cached() {
+            Some(me) => CachedMeRequest(Inner::Ready(me), GetMe::new()),
             None => CachedMeRequest(
                 Inner::Pending(self.bot.get_me(), Arc::clone(&self.me)),
                 GetMe::new(),
@@ -89,26 +179,30 @@ where
 
     requester_forward! {
         log_out, close, get_updates, set_webhook, delete_webhook, get_webhook_info,
-        forward_message, copy_message, send_message, send_photo, send_audio, send_document,
-        send_video, send_animation, send_voice, send_video_note, send_media_group, send_location,
+        forward_message, forward_messages, copy_message, copy_messages,
+        send_message, send_photo, send_audio, send_document,
+        send_video, send_animation, send_voice, send_video_note, send_media_group,
+        send_paid_media, send_location,
         edit_message_live_location, edit_message_live_location_inline,
         stop_message_live_location, stop_message_live_location_inline, send_venue,
         send_contact, send_poll, send_dice, send_chat_action, get_user_profile_photos,
-        get_file, kick_chat_member, unban_chat_member, restrict_chat_member,
+        get_file, kick_chat_member, ban_chat_member, unban_chat_member, restrict_chat_member,
         promote_chat_member, set_chat_administrator_custom_title, set_chat_permissions,
         export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
         revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
         set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
         leave_chat, get_chat, get_chat_administrators, get_chat_members_count,get_chat_member,
         set_chat_sticker_set, delete_chat_sticker_set, answer_callback_query,
-        set_my_commands, get_my_commands, answer_inline_query, edit_message_text,
+        set_my_commands, get_my_commands, delete_my_commands, set_chat_menu_button,
+        get_chat_menu_button, answer_inline_query, edit_message_text,
         edit_message_text_inline, edit_message_caption, edit_message_caption_inline,
         edit_message_media, edit_message_media_inline, edit_message_reply_markup,
-        edit_message_reply_markup_inline, stop_poll, delete_message, send_sticker,
+        edit_message_reply_markup_inline, stop_poll, delete_message,
+        set_message_reaction, send_sticker,
         get_sticker_set, upload_sticker_file, create_new_sticker_set,
         add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
-        set_sticker_set_thumb, send_invoice, answer_shipping_query,
-        answer_pre_checkout_query, set_passport_data_errors, send_game,
+        set_sticker_set_thumb, send_invoice, create_invoice_link, answer_shipping_query,
+        answer_pre_checkout_query, refund_star_payment, set_passport_data_errors, send_game,
         set_game_score, set_game_score_inline, get_game_high_scores, get_updates_fault_tolerant => f, fty
     }
 }
@@ -124,7 +218,7 @@ pub struct CachedMeRequest<R: Request<Payload = GetMe>>(Inner<R>, GetMe);
 
 enum Inner<R: Request<Payload = GetMe>> {
     Ready(Me),
-    Pending(R, Arc<OnceCell<Me>>),
+    Pending(R, Arc<Mutex<Option<CachedMe>>>),
 }
 
 impl<R> Request for CachedMeRequest<R>
@@ -169,9 +263,7 @@ impl<R: Request<Payload = GetMe>> HasPayload for CachedMeRequest<R> {
 type ReadyMe<Err> = Ready<Result<Me, Err>>;
 
 #[pin_project::pin_project]
-pub struct Send<R: Request<Payload = GetMe>>(
-    #[pin] future::Either<ReadyMe<R::Err>, Init<R::Send, Me>>,
-);
+pub struct Send<R: Request<Payload = GetMe>>(#[pin] future::Either<ReadyMe<R::Err>, Init<R::Send>>);
 
 impl<R: Request<Payload = GetMe>> Future for Send<R> {
     type Output = Result<Me, R::Err>;
@@ -184,7 +276,7 @@ impl<R: Request<Payload = GetMe>> Future for Send<R> {
 
 #[pin_project::pin_project]
 pub struct SendRef<R: Request<Payload = GetMe>>(
-    #[pin] future::Either<ReadyMe<R::Err>, Init<R::SendRef, Me>>,
+    #[pin] future::Either<ReadyMe<R::Err>, Init<R::SendRef>>,
 );
 
 impl<R: Request<Payload = GetMe>> Future for SendRef<R> {
@@ -197,16 +289,74 @@ impl<R: Request<Payload = GetMe>> Future for SendRef<R> {
 }
 
 #[pin_project::pin_project]
-struct Init<F, T>(#[pin] F, Arc<OnceCell<T>>);
+struct Init<F>(#[pin] F, Arc<Mutex<Option<CachedMe>>>);
 
-impl<F: Future<Output = Result<T, E>>, T: Clone, E> Future for Init<F, T> {
-    type Output = Result<T, E>;
+impl<F: Future<Output = Result<Me, E>>, E> Future for Init<F> {
+    type Output = Result<Me, E>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = self.project();
         match this.0.poll(cx) {
-            Poll::Ready(Ok(ok)) => Poll::Ready(Ok(this.1.get_or_init(|| ok).clone())),
+            Poll::Ready(Ok(me)) => {
+                *lock(this.1) = Some(CachedMe {
+                    me: me.clone(),
+                    cached_at: Instant::now(),
+                });
+                Poll::Ready(Ok(me))
+            }
             poll @ Poll::Ready(_) | poll @ Poll::Pending => poll,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn me() -> Me {
+        Me {
+            user: User {
+                id: UserId(1),
+                is_bot: true,
+                first_name: "Bot".to_owned(),
+                last_name: None,
+                username: Some("bot".to_owned()),
+                language_code: None,
+            },
+            can_join_groups: false,
+            can_read_all_group_messages: false,
+            supports_inline_queries: false,
+        }
+    }
+
+    fn cache(bot: &CacheMe<()>) {
+        *lock(&bot.me) = Some(CachedMe {
+            me: me(),
+            cached_at: Instant::now(),
+        });
+    }
+
+    #[test]
+    fn get_cached() {
+        let bot = CacheMe::new(());
+        assert_eq!(bot.get_cached(), None);
+
+        cache(&bot);
+        assert_eq!(bot.get_cached(), Some(me()));
+        assert_eq!(bot.clone().get_cached(), Some(me()));
+
+        bot.invalidate();
+        assert_eq!(bot.get_cached(), None);
+    }
+
+    #[test]
+    fn get_cached_expired() {
+        let bot = CacheMe::new_with_ttl((), Duration::from_secs(60));
+        cache(&bot);
+        assert_eq!(bot.get_cached(), Some(me()));
+
+        let bot = CacheMe::new_with_ttl((), Duration::from_secs(0));
+        cache(&bot);
+        assert_eq!(bot.get_cached(), None);
+    }
+}
@@ -0,0 +1,504 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use futures::task::{Context, Poll};
+
+use crate::{
+    requests::{HasPayload, Request, Requester},
+    types::*,
+    RequestError,
+};
+
+/// Thresholds of [`CircuitBreaker`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Thresholds {
+    /// Number of consecutive failures after which the circuit is opened.
+    pub failures: u32,
+
+    /// Time for which the circuit stays open before a request is allowed to
+    /// test the recovery.
+    pub cooldown: Duration,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            failures: 5,
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+/// State of [`CircuitBreaker`], see [`CircuitBreakerHandle::state`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum CircuitState {
+    /// Requests are sent as usual.
+    Closed,
+
+    /// Requests fail with [`RequestError::CircuitOpen`] without being sent.
+    ///
+    /// [`RequestError::CircuitOpen`]: crate::RequestError::CircuitOpen
+    Open,
+
+    /// The cooldown has passed, the next request is sent to test the
+    /// recovery, while other requests still fail fast. The circuit is closed
+    /// if the test request succeeds and opened again otherwise.
+    HalfOpen,
+}
+
+/// Failing fast when Telegram (or the network) is down.
+///
+/// After [`Thresholds::failures`] consecutive failures this bot wrapper opens
+/// the circuit: requests fail with [`RequestError::CircuitOpen`] without
+/// being sent for [`Thresholds::cooldown`]. After that a single request is
+/// sent to test the recovery (the circuit is _half-open_). If it succeeds the
+/// circuit is closed, otherwise it's opened for another cooldown.
+///
+/// Only errors which mean that Telegram couldn't be reached are counted as
+/// failures: [network errors], responses which couldn't be parsed (e.g. an
+/// error page of a proxy) and API errors with a 5xx status code. Other API
+/// errors (e.g. "message not found") mean that Telegram works fine, so they
+/// are counted as successes.
+///
+/// This adaptor complements [`Retry`]: wrap `Retry` into `CircuitBreaker`, so
+/// that all retries of a request are counted as a single failure and no
+/// retries are made while the circuit is open.
+///
+/// The current state can be checked (e.g. for health checks) through
+/// [`CircuitBreakerHandle`], see [`CircuitBreaker::handle`].
+///
+/// ## Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use teloxide_core::{
+///     adaptors::circuit_breaker::{CircuitState, Thresholds},
+///     requests::RequesterExt,
+///     Bot,
+/// };
+///
+/// let bot = Bot::new("TOKEN").retry(3).circuit_breaker(Thresholds {
+///     failures: 3,
+///     cooldown: Duration::from_secs(10),
+/// });
+/// let handle = bot.handle();
+///
+/// assert_eq!(handle.state(), CircuitState::Closed);
+/// ```
+///
+/// [`RequestError::CircuitOpen`]: crate::RequestError::CircuitOpen
+/// [network errors]: crate::RequestError::NetworkError
+/// [`Retry`]: crate::adaptors::Retry
+#[derive(Clone, Debug)]
+pub struct CircuitBreaker<B> {
+    bot: B,
+    handle: CircuitBreakerHandle,
+}
+
+impl<B> CircuitBreaker<B> {
+    /// Creates new `CircuitBreaker` with the given thresholds.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::circuit_breaker`]
+    /// instead.
+    ///
+    /// [`RequesterExt::circuit_breaker`]: crate::requests::RequesterExt::circuit_breaker
+    pub fn new(bot: B, thresholds: Thresholds) -> Self {
+        Self {
+            bot,
+            handle: CircuitBreakerHandle {
+                breaker: Arc::new(Breaker {
+                    thresholds,
+                    state: Mutex::new(State::Closed { failures: 0 }),
+                }),
+            },
+        }
+    }
+
+    /// Returns a handle to the state of the circuit.
+    ///
+    /// The handle is shared between all clones of this bot.
+    pub fn handle(&self) -> CircuitBreakerHandle {
+        self.handle.clone()
+    }
+
+    /// Allows to access the inner bot.
+    pub fn inner(&self) -> &B {
+        &self.bot
+    }
+
+    /// Unwraps the inner bot.
+    pub fn into_inner(self) -> B {
+        self.bot
+    }
+}
+
+/// A handle to the state of [`CircuitBreaker`].
+///
+/// Cloning the handle is cheap, all clones refer to the same circuit.
+#[derive(Clone, Debug)]
+pub struct CircuitBreakerHandle {
+    breaker: Arc<Breaker>,
+}
+
+impl CircuitBreakerHandle {
+    /// Returns the current state of the circuit.
+    pub fn state(&self) -> CircuitState {
+        match *self.breaker.lock() {
+            State::Closed { .. } => CircuitState::Closed,
+            State::Open { until } if Instant::now() < until => CircuitState::Open,
+            State::Open { .. } | State::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Closes the circuit, e.g. when it's known that Telegram is reachable
+    /// again.
+    pub fn reset(&self) {
+        *self.breaker.lock() = State::Closed { failures: 0 };
+    }
+}
+
+#[derive(Debug)]
+struct Breaker {
+    thresholds: Thresholds,
+    state: Mutex<State>,
+}
+
+#[derive(Debug)]
+enum State {
+    Closed { failures: u32 },
+    Open { until: Instant },
+    HalfOpen { probing: bool },
+}
+
+/// How a request was let through the circuit.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Admission {
+    Regular,
+    // The request tests the recovery of a half-open circuit
+    Probe,
+}
+
+impl Breaker {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // The state is only replaced as a whole, so it can't be left inconsistent
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Returns `None` if the request must fail fast.
+    fn admit(&self) -> Option<Admission> {
+        let mut state = self.lock();
+
+        match *state {
+            State::Closed { .. } => Some(Admission::Regular),
+            State::Open { until } if Instant::now() < until => None,
+            State::Open { .. } | State::HalfOpen { probing: false } => {
+                *state = State::HalfOpen { probing: true };
+                Some(Admission::Probe)
+            }
+            State::HalfOpen { probing: true } => None,
+        }
+    }
+
+    fn record<T>(&self, admission: Admission, res: &Result<T, RequestError>) {
+        let mut state = self.lock();
+
+        match res {
+            Err(err) if is_failure(err) => match *state {
+                State::Closed { failures } if failures + 1 < self.thresholds.failures => {
+                    *state = State::Closed {
+                        failures: failures + 1,
+                    };
+                }
+                State::Closed { .. } | State::HalfOpen { .. } => {
+                    log::warn!(
+                        "Opening the circuit for {:?} because of a failure: {}",
+                        self.thresholds.cooldown,
+                        err
+                    );
+                    *state = State::Open {
+                        until: Instant::now() + self.thresholds.cooldown,
+                    };
+                }
+                // The request was sent before the circuit was opened
+                State::Open { .. } => {}
+            },
+            // Errors which happen before sending a request (e.g. a file can't be read)
+            // don't say anything about Telegram
+            Err(RequestError::Io(_))
+            | Err(RequestError::MessageTooLong { .. })
+            | Err(RequestError::EmptyText)
+            | Err(RequestError::InvalidBotCommand { .. }) => {
+                if admission == Admission::Probe {
+                    self.release(&mut state);
+                }
+            }
+            _ => {
+                if !matches!(*state, State::Closed { .. }) {
+                    log::info!("Closing the circuit, Telegram is reachable again");
+                }
+                *state = State::Closed { failures: 0 };
+            }
+        }
+    }
+
+    /// Allows another request to test the recovery.
+    fn release(&self, state: &mut State) {
+        if let State::HalfOpen { probing: true } = *state {
+            *state = State::HalfOpen { probing: false };
+        }
+    }
+}
+
+fn is_failure(err: &RequestError) -> bool {
+    match err {
+        RequestError::NetworkError(_) | RequestError::InvalidJson { .. } => true,
+        RequestError::ApiError { status_code, .. } => status_code.is_server_error(),
+        _ => false,
+    }
+}
+
+macro_rules! f {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        CircuitBreakerRequest {
+            request: $this.inner().$m($($arg),*),
+            breaker: Arc::clone(&$this.handle.breaker),
+        }
+    };
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        CircuitBreakerRequest<B::$T>
+    };
+}
+
+impl<B> Requester for CircuitBreaker<B>
+where
+    B: Requester<Err = RequestError>,
+{
+    type Err = RequestError;
+
+    requester_forward! {
+        get_me, log_out, close, get_updates, set_webhook, delete_webhook, get_webhook_info,
+        forward_message, forward_messages, copy_message, copy_messages,
+        send_message, send_photo, send_audio, send_document,
+        send_video, send_animation, send_voice, send_video_note, send_media_group,
+        send_paid_media, send_location,
+        edit_message_live_location, edit_message_live_location_inline,
+        stop_message_live_location, stop_message_live_location_inline, send_venue,
+        send_contact, send_poll, send_dice, send_chat_action, get_user_profile_photos,
+        get_file, kick_chat_member, ban_chat_member, unban_chat_member, restrict_chat_member,
+        promote_chat_member, set_chat_administrator_custom_title, set_chat_permissions,
+        export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
+        revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
+        set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
+        leave_chat, get_chat, get_chat_administrators, get_chat_members_count,get_chat_member,
+        set_chat_sticker_set, delete_chat_sticker_set, answer_callback_query,
+        set_my_commands, get_my_commands, delete_my_commands, set_chat_menu_button,
+        get_chat_menu_button, answer_inline_query, edit_message_text,
+        edit_message_text_inline, edit_message_caption, edit_message_caption_inline,
+        edit_message_media, edit_message_media_inline, edit_message_reply_markup,
+        edit_message_reply_markup_inline, stop_poll, delete_message,
+        set_message_reaction, send_sticker,
+        get_sticker_set, upload_sticker_file, create_new_sticker_set,
+        add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
+        set_sticker_set_thumb, send_invoice, create_invoice_link, answer_shipping_query,
+        answer_pre_checkout_query, refund_star_payment, set_passport_data_errors, send_game,
+        set_game_score, set_game_score_inline, get_game_high_scores,
+        get_updates_fault_tolerant => f, fty
+    }
+}
+
+download_forward! {
+    'w
+    B
+    CircuitBreaker<B>
+    { this => this.inner() }
+}
+
+pub struct CircuitBreakerRequest<R> {
+    request: R,
+    breaker: Arc<Breaker>,
+}
+
+impl<R> HasPayload for CircuitBreakerRequest<R>
+where
+    R: HasPayload,
+{
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.request.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.request.payload_ref()
+    }
+}
+
+impl<R> Request for CircuitBreakerRequest<R>
+where
+    R: Request<Err = RequestError>,
+{
+    type Err = RequestError;
+    type Send = CircuitBreakerSend<R::Send>;
+    type SendRef = CircuitBreakerSend<R::SendRef>;
+
+    fn send(self) -> Self::Send {
+        CircuitBreakerSend::new(self.request.send(), self.breaker)
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        CircuitBreakerSend::new(self.request.send_ref(), Arc::clone(&self.breaker))
+    }
+}
+
+#[pin_project::pin_project(PinnedDrop)]
+pub struct CircuitBreakerSend<F> {
+    #[pin]
+    fut: F,
+    breaker: Arc<Breaker>,
+    // Set on the first poll, since requests are lazy. Taken when the request
+    // is finished.
+    admission: Option<Admission>,
+    polled: bool,
+}
+
+impl<F> CircuitBreakerSend<F> {
+    fn new(fut: F, breaker: Arc<Breaker>) -> Self {
+        Self {
+            fut,
+            breaker,
+            admission: None,
+            polled: false,
+        }
+    }
+}
+
+impl<F, T> Future for CircuitBreakerSend<F>
+where
+    F: Future<Output = Result<T, RequestError>>,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if !*this.polled {
+            *this.polled = true;
+            *this.admission = this.breaker.admit();
+        }
+
+        let admission = match *this.admission {
+            Some(admission) => admission,
+            None => return Poll::Ready(Err(RequestError::CircuitOpen)),
+        };
+
+        let res = futures::ready!(this.fut.poll(cx));
+        this.breaker.record(admission, &res);
+        *this.admission = None;
+
+        Poll::Ready(res)
+    }
+}
+
+#[pin_project::pinned_drop]
+impl<F> PinnedDrop for CircuitBreakerSend<F> {
+    fn drop(self: Pin<&mut Self>) {
+        // A cancelled test request doesn't say anything about the recovery
+        if let Some(Admission::Probe) = self.admission {
+            self.breaker.release(&mut self.breaker.lock());
+        }
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use futures::executor::block_on;
+
+    use crate::{
+        requests::{Request, RequesterExt},
+        ApiError, Bot,
+    };
+
+    use super::*;
+
+    #[test]
+    fn opens_and_recovers() {
+        let bot = Bot::new("TOKEN").mock();
+        let mock = bot.handle();
+        let bot = bot.circuit_breaker(Thresholds {
+            failures: 2,
+            cooldown: Duration::from_millis(50),
+        });
+        let handle = bot.handle();
+
+        let server_error = || RequestError::ApiError {
+            kind: ApiError::Unknown("Internal Server Error".to_owned()),
+            status_code: reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        mock.push_error(server_error());
+        block_on(bot.delete_message(0, MessageId(1)).send()).unwrap_err();
+        assert_eq!(handle.state(), CircuitState::Closed);
+
+        mock.push_error(server_error());
+        block_on(bot.delete_message(0, MessageId(1)).send()).unwrap_err();
+        assert_eq!(handle.state(), CircuitState::Open);
+
+        // Fails fast without waiting for the response (`Mock` responds when a
+        // request is created, so a response is still needed)
+        mock.push_response(True);
+        assert!(matches!(
+            block_on(bot.delete_message(0, MessageId(1)).send()),
+            Err(RequestError::CircuitOpen)
+        ));
+
+        std::thread::sleep(Duration::from_millis(60));
+        assert_eq!(handle.state(), CircuitState::HalfOpen);
+
+        // The test request fails, so the circuit is opened again
+        mock.push_error(server_error());
+        block_on(bot.delete_message(0, MessageId(1)).send()).unwrap_err();
+        assert_eq!(handle.state(), CircuitState::Open);
+
+        std::thread::sleep(Duration::from_millis(60));
+
+        // Other requests fail fast while the test request is in flight
+        let breaker = &handle.breaker;
+        assert_eq!(breaker.admit(), Some(Admission::Probe));
+        assert_eq!(breaker.admit(), None);
+        // ...but not after it's cancelled
+        breaker.release(&mut breaker.lock());
+
+        mock.push_response(True);
+        block_on(bot.delete_message(0, MessageId(1)).send()).unwrap();
+        assert_eq!(handle.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn client_errors() {
+        let bot = Bot::new("TOKEN").mock();
+        let mock = bot.handle();
+        let bot = bot.circuit_breaker(Thresholds {
+            failures: 1,
+            cooldown: Duration::from_secs(60),
+        });
+
+        mock.push_error(RequestError::ApiError {
+            kind: ApiError::MessageToDeleteNotFound,
+            status_code: reqwest::StatusCode::BAD_REQUEST,
+        });
+        block_on(bot.delete_message(0, MessageId(1)).send()).unwrap_err();
+        assert_eq!(bot.handle().state(), CircuitState::Closed);
+
+        mock.push_error(RequestError::RetryAfter(1));
+        block_on(bot.delete_message(0, MessageId(1)).send()).unwrap_err();
+        assert_eq!(bot.handle().state(), CircuitState::Closed);
+    }
+}
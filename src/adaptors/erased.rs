@@ -0,0 +1,2637 @@
+use std::{fmt, sync::Arc};
+
+use futures::future::BoxFuture;
+
+use crate::{
+    payloads::*,
+    requests::{HasPayload, Output, Payload, Request, Requester},
+    types::*,
+};
+
+/// [`Requester`] with erased type.
+///
+/// Stacking adaptors produces deeply nested types (e.g.
+/// `AutoSend<Throttle<Retry<CacheMe<Bot>>>>`), which get even worse when
+/// adaptors are added conditionally, since every combination is a different
+/// type. `ErasedRequester` boxes any [`Requester`] into a single type, which
+/// only depends on the error type, so it can be stored in a struct field or
+/// returned from functions without making the code generic.
+///
+/// ## Performance
+///
+/// Erasure isn't free: every method call goes through dynamic dispatch and
+/// allocates the request, and every sent request additionally allocates its
+/// future (see [`ErasedRequest`]). This is negligible in comparison with
+/// network latency, but the generic adaptors should be preferred when their
+/// types are manageable.
+///
+/// Note that `ErasedRequester` doesn't implement [`Download`], use the
+/// original [`Bot`] to download files.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{
+///     adaptors::ErasedRequester,
+///     requests::{Requester, RequesterExt},
+///     Bot, RequestError,
+/// };
+///
+/// struct App {
+///     bot: ErasedRequester<'static, RequestError>,
+/// }
+///
+/// let retry = std::env::var("RETRY").is_ok();
+/// let bot = if retry {
+///     Bot::new("TOKEN").retry(3).erase()
+/// } else {
+///     Bot::new("TOKEN").erase()
+/// };
+///
+/// let app = App { bot };
+/// let _request = app.bot.send_message(0, "Hi!");
+/// ```
+///
+/// [`Download`]: crate::net::Download
+/// [`Bot`]: crate::Bot
+pub struct ErasedRequester<'a, E> {
+    inner: Arc<dyn ErasableRequester<'a, Err = E> + Send + Sync + 'a>,
+}
+
+impl<'a, E> ErasedRequester<'a, E> {
+    /// Erases type of `requester`.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::erase`] instead.
+    ///
+    /// [`RequesterExt::erase`]: crate::requests::RequesterExt::erase
+    pub fn new<B>(requester: B) -> Self
+    where
+        B: Requester<Err = E> + Send + Sync + 'a,
+    {
+        Self {
+            inner: Arc::new(requester),
+        }
+    }
+}
+
+impl<E> Clone for ErasedRequester<'_, E> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<E> fmt::Debug for ErasedRequester<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ErasedRequester")
+    }
+}
+
+/// [`Request`] with erased type, returned by [`ErasedRequester`].
+///
+/// Futures returned by [`send`] and [`send_ref`] are boxed.
+///
+/// [`send`]: Request::send
+/// [`send_ref`]: Request::send_ref
+pub struct ErasedRequest<'a, T, E> {
+    inner: Box<dyn ErasableRequest<'a, Payload = T, Err = E> + 'a>,
+}
+
+impl<'a, T, E> ErasedRequest<'a, T, E> {
+    fn erase(request: impl ErasableRequest<'a, Payload = T, Err = E> + 'a) -> Self {
+        Self {
+            inner: Box::new(request),
+        }
+    }
+}
+
+impl<T, E> HasPayload for ErasedRequest<'_, T, E>
+where
+    T: Payload,
+{
+    type Payload = T;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.inner.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.inner.payload_ref()
+    }
+}
+
+impl<'a, T, E> Request for ErasedRequest<'a, T, E>
+where
+    T: Payload,
+    E: std::error::Error + Send,
+{
+    type Err = E;
+
+    type Send = BoxFuture<'a, Result<Output<Self>, Self::Err>>;
+
+    type SendRef = BoxFuture<'a, Result<Output<Self>, Self::Err>>;
+
+    fn send(self) -> Self::Send {
+        self.inner.send_box()
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        self.inner.send_ref()
+    }
+}
+
+/// Object-safe version of [`Request`].
+trait ErasableRequest<'a>: HasPayload {
+    type Err;
+
+    fn send_box(self: Box<Self>) -> BoxFuture<'a, Result<Output<Self>, Self::Err>>;
+
+    fn send_ref(&self) -> BoxFuture<'a, Result<Output<Self>, Self::Err>>;
+}
+
+impl<'a, R> ErasableRequest<'a> for R
+where
+    R: Request,
+    R::Send: 'a,
+    R::SendRef: 'a,
+{
+    type Err = R::Err;
+
+    fn send_box(self: Box<Self>) -> BoxFuture<'a, Result<Output<Self>, Self::Err>> {
+        Box::pin((*self).send())
+    }
+
+    fn send_ref(&self) -> BoxFuture<'a, Result<Output<Self>, Self::Err>> {
+        Box::pin(Request::send_ref(self))
+    }
+}
+
+/// Extension for erasing requests.
+trait EraseRequest<'a>: Request + Sized + 'a {
+    fn erase(self) -> ErasedRequest<'a, Self::Payload, Self::Err> {
+        ErasedRequest::erase(self)
+    }
+}
+
+impl<'a, R> EraseRequest<'a> for R where R: Request + 'a {}
+
+/// Object-safe version of [`Requester`].
+///
+/// Generic arguments are replaced with the concrete types they are converted
+/// to.
+#[allow(clippy::too_many_arguments)]
+trait ErasableRequester<'a> {
+    type Err: std::error::Error + Send;
+
+    fn get_me(&self) -> ErasedRequest<'a, GetMe, Self::Err>;
+
+    fn log_out(&self) -> ErasedRequest<'a, LogOut, Self::Err>;
+
+    fn close(&self) -> ErasedRequest<'a, Close, Self::Err>;
+
+    fn get_updates(&self) -> ErasedRequest<'a, GetUpdates, Self::Err>;
+
+    fn set_webhook(&self, url: String) -> ErasedRequest<'a, SetWebhook, Self::Err>;
+
+    fn delete_webhook(&self) -> ErasedRequest<'a, DeleteWebhook, Self::Err>;
+
+    fn get_webhook_info(&self) -> ErasedRequest<'a, GetWebhookInfo, Self::Err>;
+
+    fn forward_message(
+        &self,
+        chat_id: ChatId,
+        from_chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, ForwardMessage, Self::Err>;
+
+    fn forward_messages(
+        &self,
+        chat_id: ChatId,
+        from_chat_id: ChatId,
+        message_ids: Vec<MessageId>,
+    ) -> ErasedRequest<'a, ForwardMessages, Self::Err>;
+
+    fn copy_message(
+        &self,
+        chat_id: ChatId,
+        from_chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, CopyMessage, Self::Err>;
+
+    fn copy_messages(
+        &self,
+        chat_id: ChatId,
+        from_chat_id: ChatId,
+        message_ids: Vec<MessageId>,
+    ) -> ErasedRequest<'a, CopyMessages, Self::Err>;
+
+    fn send_message(
+        &self,
+        chat_id: ChatId,
+        text: String,
+    ) -> ErasedRequest<'a, SendMessage, Self::Err>;
+
+    fn send_photo(
+        &self,
+        chat_id: ChatId,
+        photo: InputFile,
+    ) -> ErasedRequest<'a, SendPhoto, Self::Err>;
+
+    fn send_audio(
+        &self,
+        chat_id: ChatId,
+        audio: InputFile,
+    ) -> ErasedRequest<'a, SendAudio, Self::Err>;
+
+    fn send_document(
+        &self,
+        chat_id: ChatId,
+        document: InputFile,
+    ) -> ErasedRequest<'a, SendDocument, Self::Err>;
+
+    fn send_video(
+        &self,
+        chat_id: ChatId,
+        video: InputFile,
+    ) -> ErasedRequest<'a, SendVideo, Self::Err>;
+
+    fn send_animation(
+        &self,
+        chat_id: ChatId,
+        animation: InputFile,
+    ) -> ErasedRequest<'a, SendAnimation, Self::Err>;
+
+    fn send_voice(
+        &self,
+        chat_id: ChatId,
+        voice: InputFile,
+    ) -> ErasedRequest<'a, SendVoice, Self::Err>;
+
+    fn send_video_note(
+        &self,
+        chat_id: ChatId,
+        video_note: InputFile,
+    ) -> ErasedRequest<'a, SendVideoNote, Self::Err>;
+
+    fn send_media_group(
+        &self,
+        chat_id: ChatId,
+        media: Vec<InputMedia>,
+    ) -> ErasedRequest<'a, SendMediaGroup, Self::Err>;
+
+    fn send_paid_media(
+        &self,
+        chat_id: ChatId,
+        star_count: u32,
+        media: Vec<InputPaidMedia>,
+    ) -> ErasedRequest<'a, SendPaidMedia, Self::Err>;
+
+    fn send_location(
+        &self,
+        chat_id: ChatId,
+        latitude: f64,
+        longitude: f64,
+    ) -> ErasedRequest<'a, SendLocation, Self::Err>;
+
+    fn edit_message_live_location(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+        latitude: f64,
+        longitude: f64,
+    ) -> ErasedRequest<'a, EditMessageLiveLocation, Self::Err>;
+
+    fn edit_message_live_location_inline(
+        &self,
+        inline_message_id: String,
+        latitude: f64,
+        longitude: f64,
+    ) -> ErasedRequest<'a, EditMessageLiveLocationInline, Self::Err>;
+
+    fn stop_message_live_location(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+        latitude: f64,
+        longitude: f64,
+    ) -> ErasedRequest<'a, StopMessageLiveLocation, Self::Err>;
+
+    fn stop_message_live_location_inline(
+        &self,
+        inline_message_id: String,
+        latitude: f64,
+        longitude: f64,
+    ) -> ErasedRequest<'a, StopMessageLiveLocationInline, Self::Err>;
+
+    fn send_venue(
+        &self,
+        chat_id: ChatId,
+        latitude: f64,
+        longitude: f64,
+        title: String,
+        address: String,
+    ) -> ErasedRequest<'a, SendVenue, Self::Err>;
+
+    fn send_contact(
+        &self,
+        chat_id: ChatId,
+        phone_number: String,
+        first_name: String,
+    ) -> ErasedRequest<'a, SendContact, Self::Err>;
+
+    fn send_poll(
+        &self,
+        chat_id: ChatId,
+        question: String,
+        options: Vec<String>,
+        type_: PollType,
+    ) -> ErasedRequest<'a, SendPoll, Self::Err>;
+
+    fn send_dice(&self, chat_id: ChatId) -> ErasedRequest<'a, SendDice, Self::Err>;
+
+    fn send_chat_action(
+        &self,
+        chat_id: ChatId,
+        action: ChatAction,
+    ) -> ErasedRequest<'a, SendChatAction, Self::Err>;
+
+    fn get_user_profile_photos(
+        &self,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, GetUserProfilePhotos, Self::Err>;
+
+    fn get_file(&self, file_id: String) -> ErasedRequest<'a, GetFile, Self::Err>;
+
+    fn kick_chat_member(
+        &self,
+        chat_id: ChatId,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, KickChatMember, Self::Err>;
+
+    fn ban_chat_member(
+        &self,
+        chat_id: ChatId,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, BanChatMember, Self::Err>;
+
+    fn unban_chat_member(
+        &self,
+        chat_id: ChatId,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, UnbanChatMember, Self::Err>;
+
+    fn restrict_chat_member(
+        &self,
+        chat_id: ChatId,
+        user_id: UserId,
+        permissions: ChatPermissions,
+    ) -> ErasedRequest<'a, RestrictChatMember, Self::Err>;
+
+    fn promote_chat_member(
+        &self,
+        chat_id: ChatId,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, PromoteChatMember, Self::Err>;
+
+    fn set_chat_administrator_custom_title(
+        &self,
+        chat_id: ChatId,
+        user_id: UserId,
+        custom_title: String,
+    ) -> ErasedRequest<'a, SetChatAdministratorCustomTitle, Self::Err>;
+
+    fn set_chat_permissions(
+        &self,
+        chat_id: ChatId,
+        permissions: ChatPermissions,
+    ) -> ErasedRequest<'a, SetChatPermissions, Self::Err>;
+
+    fn export_chat_invite_link(
+        &self,
+        chat_id: ChatId,
+    ) -> ErasedRequest<'a, ExportChatInviteLink, Self::Err>;
+
+    fn create_chat_invite_link(
+        &self,
+        chat_id: ChatId,
+    ) -> ErasedRequest<'a, CreateChatInviteLink, Self::Err>;
+
+    fn edit_chat_invite_link(
+        &self,
+        chat_id: ChatId,
+        invite_link: String,
+    ) -> ErasedRequest<'a, EditChatInviteLink, Self::Err>;
+
+    fn revoke_chat_invite_link(
+        &self,
+        chat_id: ChatId,
+        invite_link: String,
+    ) -> ErasedRequest<'a, RevokeChatInviteLink, Self::Err>;
+
+    fn set_chat_photo(
+        &self,
+        chat_id: ChatId,
+        photo: InputFile,
+    ) -> ErasedRequest<'a, SetChatPhoto, Self::Err>;
+
+    fn delete_chat_photo(&self, chat_id: ChatId) -> ErasedRequest<'a, DeleteChatPhoto, Self::Err>;
+
+    fn set_chat_title(
+        &self,
+        chat_id: ChatId,
+        title: String,
+    ) -> ErasedRequest<'a, SetChatTitle, Self::Err>;
+
+    fn set_chat_description(
+        &self,
+        chat_id: ChatId,
+    ) -> ErasedRequest<'a, SetChatDescription, Self::Err>;
+
+    fn pin_chat_message(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, PinChatMessage, Self::Err>;
+
+    fn unpin_chat_message(&self, chat_id: ChatId)
+        -> ErasedRequest<'a, UnpinChatMessage, Self::Err>;
+
+    fn unpin_all_chat_messages(
+        &self,
+        chat_id: ChatId,
+    ) -> ErasedRequest<'a, UnpinAllChatMessages, Self::Err>;
+
+    fn leave_chat(&self, chat_id: ChatId) -> ErasedRequest<'a, LeaveChat, Self::Err>;
+
+    fn get_chat(&self, chat_id: ChatId) -> ErasedRequest<'a, GetChat, Self::Err>;
+
+    fn get_chat_administrators(
+        &self,
+        chat_id: ChatId,
+    ) -> ErasedRequest<'a, GetChatAdministrators, Self::Err>;
+
+    fn get_chat_members_count(
+        &self,
+        chat_id: ChatId,
+    ) -> ErasedRequest<'a, GetChatMembersCount, Self::Err>;
+
+    fn get_chat_member(
+        &self,
+        chat_id: ChatId,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, GetChatMember, Self::Err>;
+
+    fn set_chat_sticker_set(
+        &self,
+        chat_id: ChatId,
+        sticker_set_name: String,
+    ) -> ErasedRequest<'a, SetChatStickerSet, Self::Err>;
+
+    fn delete_chat_sticker_set(
+        &self,
+        chat_id: ChatId,
+    ) -> ErasedRequest<'a, DeleteChatStickerSet, Self::Err>;
+
+    fn answer_callback_query(
+        &self,
+        callback_query_id: String,
+    ) -> ErasedRequest<'a, AnswerCallbackQuery, Self::Err>;
+
+    fn set_my_commands(
+        &self,
+        commands: Vec<BotCommand>,
+    ) -> ErasedRequest<'a, SetMyCommands, Self::Err>;
+
+    fn get_my_commands(&self) -> ErasedRequest<'a, GetMyCommands, Self::Err>;
+
+    fn delete_my_commands(&self) -> ErasedRequest<'a, DeleteMyCommands, Self::Err>;
+
+    fn set_chat_menu_button(&self) -> ErasedRequest<'a, SetChatMenuButton, Self::Err>;
+
+    fn get_chat_menu_button(&self) -> ErasedRequest<'a, GetChatMenuButton, Self::Err>;
+
+    fn answer_inline_query(
+        &self,
+        inline_query_id: String,
+        results: Vec<InlineQueryResult>,
+    ) -> ErasedRequest<'a, AnswerInlineQuery, Self::Err>;
+
+    fn edit_message_text(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+        text: String,
+    ) -> ErasedRequest<'a, EditMessageText, Self::Err>;
+
+    fn edit_message_text_inline(
+        &self,
+        inline_message_id: String,
+        text: String,
+    ) -> ErasedRequest<'a, EditMessageTextInline, Self::Err>;
+
+    fn edit_message_caption(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, EditMessageCaption, Self::Err>;
+
+    fn edit_message_caption_inline(
+        &self,
+        inline_message_id: String,
+    ) -> ErasedRequest<'a, EditMessageCaptionInline, Self::Err>;
+
+    fn edit_message_media(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+        media: InputMedia,
+    ) -> ErasedRequest<'a, EditMessageMedia, Self::Err>;
+
+    fn edit_message_media_inline(
+        &self,
+        inline_message_id: String,
+        media: InputMedia,
+    ) -> ErasedRequest<'a, EditMessageMediaInline, Self::Err>;
+
+    fn edit_message_reply_markup(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, EditMessageReplyMarkup, Self::Err>;
+
+    fn edit_message_reply_markup_inline(
+        &self,
+        inline_message_id: String,
+    ) -> ErasedRequest<'a, EditMessageReplyMarkupInline, Self::Err>;
+
+    fn stop_poll(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, StopPoll, Self::Err>;
+
+    fn delete_message(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, DeleteMessage, Self::Err>;
+
+    fn set_message_reaction(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, SetMessageReaction, Self::Err>;
+
+    fn send_sticker(
+        &self,
+        chat_id: ChatId,
+        sticker: InputFile,
+    ) -> ErasedRequest<'a, SendSticker, Self::Err>;
+
+    fn get_sticker_set(&self, name: String) -> ErasedRequest<'a, GetStickerSet, Self::Err>;
+
+    fn upload_sticker_file(
+        &self,
+        user_id: UserId,
+        png_sticker: InputFile,
+    ) -> ErasedRequest<'a, UploadStickerFile, Self::Err>;
+
+    fn create_new_sticker_set(
+        &self,
+        user_id: UserId,
+        name: String,
+        title: String,
+        sticker: InputSticker,
+        emojis: String,
+    ) -> ErasedRequest<'a, CreateNewStickerSet, Self::Err>;
+
+    fn add_sticker_to_set(
+        &self,
+        user_id: UserId,
+        name: String,
+        sticker: InputSticker,
+        emojis: String,
+    ) -> ErasedRequest<'a, AddStickerToSet, Self::Err>;
+
+    fn set_sticker_position_in_set(
+        &self,
+        sticker: String,
+        position: u32,
+    ) -> ErasedRequest<'a, SetStickerPositionInSet, Self::Err>;
+
+    fn delete_sticker_from_set(
+        &self,
+        sticker: String,
+    ) -> ErasedRequest<'a, DeleteStickerFromSet, Self::Err>;
+
+    fn set_sticker_set_thumb(
+        &self,
+        name: String,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, SetStickerSetThumb, Self::Err>;
+
+    fn send_invoice(
+        &self,
+        chat_id: i32,
+        title: String,
+        description: String,
+        payload: String,
+        provider_token: String,
+        start_parameter: String,
+        currency: String,
+        prices: Vec<LabeledPrice>,
+    ) -> ErasedRequest<'a, SendInvoice, Self::Err>;
+
+    fn create_invoice_link(
+        &self,
+        title: String,
+        description: String,
+        payload: String,
+        provider_token: String,
+        currency: String,
+        prices: Vec<LabeledPrice>,
+    ) -> ErasedRequest<'a, CreateInvoiceLink, Self::Err>;
+
+    fn answer_shipping_query(
+        &self,
+        shipping_query_id: String,
+        ok: bool,
+    ) -> ErasedRequest<'a, AnswerShippingQuery, Self::Err>;
+
+    fn answer_pre_checkout_query(
+        &self,
+        pre_checkout_query_id: String,
+        ok: bool,
+    ) -> ErasedRequest<'a, AnswerPreCheckoutQuery, Self::Err>;
+
+    fn refund_star_payment(
+        &self,
+        user_id: UserId,
+        telegram_payment_charge_id: String,
+    ) -> ErasedRequest<'a, RefundStarPayment, Self::Err>;
+
+    fn set_passport_data_errors(
+        &self,
+        user_id: UserId,
+        errors: Vec<PassportElementError>,
+    ) -> ErasedRequest<'a, SetPassportDataErrors, Self::Err>;
+
+    fn send_game(
+        &self,
+        chat_id: u32,
+        game_short_name: String,
+    ) -> ErasedRequest<'a, SendGame, Self::Err>;
+
+    fn set_game_score(
+        &self,
+        user_id: UserId,
+        score: u64,
+        chat_id: u32,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, SetGameScore, Self::Err>;
+
+    fn set_game_score_inline(
+        &self,
+        user_id: UserId,
+        score: u64,
+        inline_message_id: String,
+    ) -> ErasedRequest<'a, SetGameScoreInline, Self::Err>;
+
+    fn get_game_high_scores(
+        &self,
+        user_id: UserId,
+        target: TargetMessage,
+    ) -> ErasedRequest<'a, GetGameHighScores, Self::Err>;
+
+    fn get_updates_fault_tolerant(&self) -> ErasedRequest<'a, GetUpdatesFaultTolerant, Self::Err>;
+}
+
+impl<'a, B> ErasableRequester<'a> for B
+where
+    B: Requester + 'a,
+{
+    type Err = B::Err;
+
+    fn get_me(&self) -> ErasedRequest<'a, GetMe, Self::Err> {
+        Requester::get_me(self).erase()
+    }
+
+    fn log_out(&self) -> ErasedRequest<'a, LogOut, Self::Err> {
+        Requester::log_out(self).erase()
+    }
+
+    fn close(&self) -> ErasedRequest<'a, Close, Self::Err> {
+        Requester::close(self).erase()
+    }
+
+    fn get_updates(&self) -> ErasedRequest<'a, GetUpdates, Self::Err> {
+        Requester::get_updates(self).erase()
+    }
+
+    fn set_webhook(&self, url: String) -> ErasedRequest<'a, SetWebhook, Self::Err> {
+        Requester::set_webhook(self, url).erase()
+    }
+
+    fn delete_webhook(&self) -> ErasedRequest<'a, DeleteWebhook, Self::Err> {
+        Requester::delete_webhook(self).erase()
+    }
+
+    fn get_webhook_info(&self) -> ErasedRequest<'a, GetWebhookInfo, Self::Err> {
+        Requester::get_webhook_info(self).erase()
+    }
+
+    fn forward_message(
+        &self,
+        chat_id: ChatId,
+        from_chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, ForwardMessage, Self::Err> {
+        Requester::forward_message(self, chat_id, from_chat_id, message_id).erase()
+    }
+
+    fn forward_messages(
+        &self,
+        chat_id: ChatId,
+        from_chat_id: ChatId,
+        message_ids: Vec<MessageId>,
+    ) -> ErasedRequest<'a, ForwardMessages, Self::Err> {
+        Requester::forward_messages(self, chat_id, from_chat_id, message_ids).erase()
+    }
+
+    fn copy_message(
+        &self,
+        chat_id: ChatId,
+        from_chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, CopyMessage, Self::Err> {
+        Requester::copy_message(self, chat_id, from_chat_id, message_id).erase()
+    }
+
+    fn copy_messages(
+        &self,
+        chat_id: ChatId,
+        from_chat_id: ChatId,
+        message_ids: Vec<MessageId>,
+    ) -> ErasedRequest<'a, CopyMessages, Self::Err> {
+        Requester::copy_messages(self, chat_id, from_chat_id, message_ids).erase()
+    }
+
+    fn send_message(
+        &self,
+        chat_id: ChatId,
+        text: String,
+    ) -> ErasedRequest<'a, SendMessage, Self::Err> {
+        Requester::send_message(self, chat_id, text).erase()
+    }
+
+    fn send_photo(
+        &self,
+        chat_id: ChatId,
+        photo: InputFile,
+    ) -> ErasedRequest<'a, SendPhoto, Self::Err> {
+        Requester::send_photo(self, chat_id, photo).erase()
+    }
+
+    fn send_audio(
+        &self,
+        chat_id: ChatId,
+        audio: InputFile,
+    ) -> ErasedRequest<'a, SendAudio, Self::Err> {
+        Requester::send_audio(self, chat_id, audio).erase()
+    }
+
+    fn send_document(
+        &self,
+        chat_id: ChatId,
+        document: InputFile,
+    ) -> ErasedRequest<'a, SendDocument, Self::Err> {
+        Requester::send_document(self, chat_id, document).erase()
+    }
+
+    fn send_video(
+        &self,
+        chat_id: ChatId,
+        video: InputFile,
+    ) -> ErasedRequest<'a, SendVideo, Self::Err> {
+        Requester::send_video(self, chat_id, video).erase()
+    }
+
+    fn send_animation(
+        &self,
+        chat_id: ChatId,
+        animation: InputFile,
+    ) -> ErasedRequest<'a, SendAnimation, Self::Err> {
+        Requester::send_animation(self, chat_id, animation).erase()
+    }
+
+    fn send_voice(
+        &self,
+        chat_id: ChatId,
+        voice: InputFile,
+    ) -> ErasedRequest<'a, SendVoice, Self::Err> {
+        Requester::send_voice(self, chat_id, voice).erase()
+    }
+
+    fn send_video_note(
+        &self,
+        chat_id: ChatId,
+        video_note: InputFile,
+    ) -> ErasedRequest<'a, SendVideoNote, Self::Err> {
+        Requester::send_video_note(self, chat_id, video_note).erase()
+    }
+
+    fn send_media_group(
+        &self,
+        chat_id: ChatId,
+        media: Vec<InputMedia>,
+    ) -> ErasedRequest<'a, SendMediaGroup, Self::Err> {
+        Requester::send_media_group(self, chat_id, media).erase()
+    }
+
+    fn send_paid_media(
+        &self,
+        chat_id: ChatId,
+        star_count: u32,
+        media: Vec<InputPaidMedia>,
+    ) -> ErasedRequest<'a, SendPaidMedia, Self::Err> {
+        Requester::send_paid_media(self, chat_id, star_count, media).erase()
+    }
+
+    fn send_location(
+        &self,
+        chat_id: ChatId,
+        latitude: f64,
+        longitude: f64,
+    ) -> ErasedRequest<'a, SendLocation, Self::Err> {
+        Requester::send_location(self, chat_id, latitude, longitude).erase()
+    }
+
+    fn edit_message_live_location(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+        latitude: f64,
+        longitude: f64,
+    ) -> ErasedRequest<'a, EditMessageLiveLocation, Self::Err> {
+        Requester::edit_message_live_location(self, chat_id, message_id, latitude, longitude)
+            .erase()
+    }
+
+    fn edit_message_live_location_inline(
+        &self,
+        inline_message_id: String,
+        latitude: f64,
+        longitude: f64,
+    ) -> ErasedRequest<'a, EditMessageLiveLocationInline, Self::Err> {
+        Requester::edit_message_live_location_inline(self, inline_message_id, latitude, longitude)
+            .erase()
+    }
+
+    fn stop_message_live_location(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+        latitude: f64,
+        longitude: f64,
+    ) -> ErasedRequest<'a, StopMessageLiveLocation, Self::Err> {
+        Requester::stop_message_live_location(self, chat_id, message_id, latitude, longitude)
+            .erase()
+    }
+
+    fn stop_message_live_location_inline(
+        &self,
+        inline_message_id: String,
+        latitude: f64,
+        longitude: f64,
+    ) -> ErasedRequest<'a, StopMessageLiveLocationInline, Self::Err> {
+        Requester::stop_message_live_location_inline(self, inline_message_id, latitude, longitude)
+            .erase()
+    }
+
+    fn send_venue(
+        &self,
+        chat_id: ChatId,
+        latitude: f64,
+        longitude: f64,
+        title: String,
+        address: String,
+    ) -> ErasedRequest<'a, SendVenue, Self::Err> {
+        Requester::send_venue(self, chat_id, latitude, longitude, title, address).erase()
+    }
+
+    fn send_contact(
+        &self,
+        chat_id: ChatId,
+        phone_number: String,
+        first_name: String,
+    ) -> ErasedRequest<'a, SendContact, Self::Err> {
+        Requester::send_contact(self, chat_id, phone_number, first_name).erase()
+    }
+
+    fn send_poll(
+        &self,
+        chat_id: ChatId,
+        question: String,
+        options: Vec<String>,
+        type_: PollType,
+    ) -> ErasedRequest<'a, SendPoll, Self::Err> {
+        Requester::send_poll(self, chat_id, question, options, type_).erase()
+    }
+
+    fn send_dice(&self, chat_id: ChatId) -> ErasedRequest<'a, SendDice, Self::Err> {
+        Requester::send_dice(self, chat_id).erase()
+    }
+
+    fn send_chat_action(
+        &self,
+        chat_id: ChatId,
+        action: ChatAction,
+    ) -> ErasedRequest<'a, SendChatAction, Self::Err> {
+        Requester::send_chat_action(self, chat_id, action).erase()
+    }
+
+    fn get_user_profile_photos(
+        &self,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, GetUserProfilePhotos, Self::Err> {
+        Requester::get_user_profile_photos(self, user_id).erase()
+    }
+
+    fn get_file(&self, file_id: String) -> ErasedRequest<'a, GetFile, Self::Err> {
+        Requester::get_file(self, file_id).erase()
+    }
+
+    fn kick_chat_member(
+        &self,
+        chat_id: ChatId,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, KickChatMember, Self::Err> {
+        Requester::kick_chat_member(self, chat_id, user_id).erase()
+    }
+
+    fn ban_chat_member(
+        &self,
+        chat_id: ChatId,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, BanChatMember, Self::Err> {
+        Requester::ban_chat_member(self, chat_id, user_id).erase()
+    }
+
+    fn unban_chat_member(
+        &self,
+        chat_id: ChatId,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, UnbanChatMember, Self::Err> {
+        Requester::unban_chat_member(self, chat_id, user_id).erase()
+    }
+
+    fn restrict_chat_member(
+        &self,
+        chat_id: ChatId,
+        user_id: UserId,
+        permissions: ChatPermissions,
+    ) -> ErasedRequest<'a, RestrictChatMember, Self::Err> {
+        Requester::restrict_chat_member(self, chat_id, user_id, permissions).erase()
+    }
+
+    fn promote_chat_member(
+        &self,
+        chat_id: ChatId,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, PromoteChatMember, Self::Err> {
+        Requester::promote_chat_member(self, chat_id, user_id).erase()
+    }
+
+    fn set_chat_administrator_custom_title(
+        &self,
+        chat_id: ChatId,
+        user_id: UserId,
+        custom_title: String,
+    ) -> ErasedRequest<'a, SetChatAdministratorCustomTitle, Self::Err> {
+        Requester::set_chat_administrator_custom_title(self, chat_id, user_id, custom_title).erase()
+    }
+
+    fn set_chat_permissions(
+        &self,
+        chat_id: ChatId,
+        permissions: ChatPermissions,
+    ) -> ErasedRequest<'a, SetChatPermissions, Self::Err> {
+        Requester::set_chat_permissions(self, chat_id, permissions).erase()
+    }
+
+    fn export_chat_invite_link(
+        &self,
+        chat_id: ChatId,
+    ) -> ErasedRequest<'a, ExportChatInviteLink, Self::Err> {
+        Requester::export_chat_invite_link(self, chat_id).erase()
+    }
+
+    fn create_chat_invite_link(
+        &self,
+        chat_id: ChatId,
+    ) -> ErasedRequest<'a, CreateChatInviteLink, Self::Err> {
+        Requester::create_chat_invite_link(self, chat_id).erase()
+    }
+
+    fn edit_chat_invite_link(
+        &self,
+        chat_id: ChatId,
+        invite_link: String,
+    ) -> ErasedRequest<'a, EditChatInviteLink, Self::Err> {
+        Requester::edit_chat_invite_link(self, chat_id, invite_link).erase()
+    }
+
+    fn revoke_chat_invite_link(
+        &self,
+        chat_id: ChatId,
+        invite_link: String,
+    ) -> ErasedRequest<'a, RevokeChatInviteLink, Self::Err> {
+        Requester::revoke_chat_invite_link(self, chat_id, invite_link).erase()
+    }
+
+    fn set_chat_photo(
+        &self,
+        chat_id: ChatId,
+        photo: InputFile,
+    ) -> ErasedRequest<'a, SetChatPhoto, Self::Err> {
+        Requester::set_chat_photo(self, chat_id, photo).erase()
+    }
+
+    fn delete_chat_photo(&self, chat_id: ChatId) -> ErasedRequest<'a, DeleteChatPhoto, Self::Err> {
+        Requester::delete_chat_photo(self, chat_id).erase()
+    }
+
+    fn set_chat_title(
+        &self,
+        chat_id: ChatId,
+        title: String,
+    ) -> ErasedRequest<'a, SetChatTitle, Self::Err> {
+        Requester::set_chat_title(self, chat_id, title).erase()
+    }
+
+    fn set_chat_description(
+        &self,
+        chat_id: ChatId,
+    ) -> ErasedRequest<'a, SetChatDescription, Self::Err> {
+        Requester::set_chat_description(self, chat_id).erase()
+    }
+
+    fn pin_chat_message(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, PinChatMessage, Self::Err> {
+        Requester::pin_chat_message(self, chat_id, message_id).erase()
+    }
+
+    fn unpin_chat_message(
+        &self,
+        chat_id: ChatId,
+    ) -> ErasedRequest<'a, UnpinChatMessage, Self::Err> {
+        Requester::unpin_chat_message(self, chat_id).erase()
+    }
+
+    fn unpin_all_chat_messages(
+        &self,
+        chat_id: ChatId,
+    ) -> ErasedRequest<'a, UnpinAllChatMessages, Self::Err> {
+        Requester::unpin_all_chat_messages(self, chat_id).erase()
+    }
+
+    fn leave_chat(&self, chat_id: ChatId) -> ErasedRequest<'a, LeaveChat, Self::Err> {
+        Requester::leave_chat(self, chat_id).erase()
+    }
+
+    fn get_chat(&self, chat_id: ChatId) -> ErasedRequest<'a, GetChat, Self::Err> {
+        Requester::get_chat(self, chat_id).erase()
+    }
+
+    fn get_chat_administrators(
+        &self,
+        chat_id: ChatId,
+    ) -> ErasedRequest<'a, GetChatAdministrators, Self::Err> {
+        Requester::get_chat_administrators(self, chat_id).erase()
+    }
+
+    fn get_chat_members_count(
+        &self,
+        chat_id: ChatId,
+    ) -> ErasedRequest<'a, GetChatMembersCount, Self::Err> {
+        Requester::get_chat_members_count(self, chat_id).erase()
+    }
+
+    fn get_chat_member(
+        &self,
+        chat_id: ChatId,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, GetChatMember, Self::Err> {
+        Requester::get_chat_member(self, chat_id, user_id).erase()
+    }
+
+    fn set_chat_sticker_set(
+        &self,
+        chat_id: ChatId,
+        sticker_set_name: String,
+    ) -> ErasedRequest<'a, SetChatStickerSet, Self::Err> {
+        Requester::set_chat_sticker_set(self, chat_id, sticker_set_name).erase()
+    }
+
+    fn delete_chat_sticker_set(
+        &self,
+        chat_id: ChatId,
+    ) -> ErasedRequest<'a, DeleteChatStickerSet, Self::Err> {
+        Requester::delete_chat_sticker_set(self, chat_id).erase()
+    }
+
+    fn answer_callback_query(
+        &self,
+        callback_query_id: String,
+    ) -> ErasedRequest<'a, AnswerCallbackQuery, Self::Err> {
+        Requester::answer_callback_query(self, callback_query_id).erase()
+    }
+
+    fn set_my_commands(
+        &self,
+        commands: Vec<BotCommand>,
+    ) -> ErasedRequest<'a, SetMyCommands, Self::Err> {
+        Requester::set_my_commands(self, commands).erase()
+    }
+
+    fn get_my_commands(&self) -> ErasedRequest<'a, GetMyCommands, Self::Err> {
+        Requester::get_my_commands(self).erase()
+    }
+
+    fn delete_my_commands(&self) -> ErasedRequest<'a, DeleteMyCommands, Self::Err> {
+        Requester::delete_my_commands(self).erase()
+    }
+
+    fn set_chat_menu_button(&self) -> ErasedRequest<'a, SetChatMenuButton, Self::Err> {
+        Requester::set_chat_menu_button(self).erase()
+    }
+
+    fn get_chat_menu_button(&self) -> ErasedRequest<'a, GetChatMenuButton, Self::Err> {
+        Requester::get_chat_menu_button(self).erase()
+    }
+
+    fn answer_inline_query(
+        &self,
+        inline_query_id: String,
+        results: Vec<InlineQueryResult>,
+    ) -> ErasedRequest<'a, AnswerInlineQuery, Self::Err> {
+        Requester::answer_inline_query(self, inline_query_id, results).erase()
+    }
+
+    fn edit_message_text(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+        text: String,
+    ) -> ErasedRequest<'a, EditMessageText, Self::Err> {
+        Requester::edit_message_text(self, chat_id, message_id, text).erase()
+    }
+
+    fn edit_message_text_inline(
+        &self,
+        inline_message_id: String,
+        text: String,
+    ) -> ErasedRequest<'a, EditMessageTextInline, Self::Err> {
+        Requester::edit_message_text_inline(self, inline_message_id, text).erase()
+    }
+
+    fn edit_message_caption(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, EditMessageCaption, Self::Err> {
+        Requester::edit_message_caption(self, chat_id, message_id).erase()
+    }
+
+    fn edit_message_caption_inline(
+        &self,
+        inline_message_id: String,
+    ) -> ErasedRequest<'a, EditMessageCaptionInline, Self::Err> {
+        Requester::edit_message_caption_inline(self, inline_message_id).erase()
+    }
+
+    fn edit_message_media(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+        media: InputMedia,
+    ) -> ErasedRequest<'a, EditMessageMedia, Self::Err> {
+        Requester::edit_message_media(self, chat_id, message_id, media).erase()
+    }
+
+    fn edit_message_media_inline(
+        &self,
+        inline_message_id: String,
+        media: InputMedia,
+    ) -> ErasedRequest<'a, EditMessageMediaInline, Self::Err> {
+        Requester::edit_message_media_inline(self, inline_message_id, media).erase()
+    }
+
+    fn edit_message_reply_markup(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, EditMessageReplyMarkup, Self::Err> {
+        Requester::edit_message_reply_markup(self, chat_id, message_id).erase()
+    }
+
+    fn edit_message_reply_markup_inline(
+        &self,
+        inline_message_id: String,
+    ) -> ErasedRequest<'a, EditMessageReplyMarkupInline, Self::Err> {
+        Requester::edit_message_reply_markup_inline(self, inline_message_id).erase()
+    }
+
+    fn stop_poll(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, StopPoll, Self::Err> {
+        Requester::stop_poll(self, chat_id, message_id).erase()
+    }
+
+    fn delete_message(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, DeleteMessage, Self::Err> {
+        Requester::delete_message(self, chat_id, message_id).erase()
+    }
+
+    fn set_message_reaction(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, SetMessageReaction, Self::Err> {
+        Requester::set_message_reaction(self, chat_id, message_id).erase()
+    }
+
+    fn send_sticker(
+        &self,
+        chat_id: ChatId,
+        sticker: InputFile,
+    ) -> ErasedRequest<'a, SendSticker, Self::Err> {
+        Requester::send_sticker(self, chat_id, sticker).erase()
+    }
+
+    fn get_sticker_set(&self, name: String) -> ErasedRequest<'a, GetStickerSet, Self::Err> {
+        Requester::get_sticker_set(self, name).erase()
+    }
+
+    fn upload_sticker_file(
+        &self,
+        user_id: UserId,
+        png_sticker: InputFile,
+    ) -> ErasedRequest<'a, UploadStickerFile, Self::Err> {
+        Requester::upload_sticker_file(self, user_id, png_sticker).erase()
+    }
+
+    fn create_new_sticker_set(
+        &self,
+        user_id: UserId,
+        name: String,
+        title: String,
+        sticker: InputSticker,
+        emojis: String,
+    ) -> ErasedRequest<'a, CreateNewStickerSet, Self::Err> {
+        Requester::create_new_sticker_set(self, user_id, name, title, sticker, emojis).erase()
+    }
+
+    fn add_sticker_to_set(
+        &self,
+        user_id: UserId,
+        name: String,
+        sticker: InputSticker,
+        emojis: String,
+    ) -> ErasedRequest<'a, AddStickerToSet, Self::Err> {
+        Requester::add_sticker_to_set(self, user_id, name, sticker, emojis).erase()
+    }
+
+    fn set_sticker_position_in_set(
+        &self,
+        sticker: String,
+        position: u32,
+    ) -> ErasedRequest<'a, SetStickerPositionInSet, Self::Err> {
+        Requester::set_sticker_position_in_set(self, sticker, position).erase()
+    }
+
+    fn delete_sticker_from_set(
+        &self,
+        sticker: String,
+    ) -> ErasedRequest<'a, DeleteStickerFromSet, Self::Err> {
+        Requester::delete_sticker_from_set(self, sticker).erase()
+    }
+
+    fn set_sticker_set_thumb(
+        &self,
+        name: String,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, SetStickerSetThumb, Self::Err> {
+        Requester::set_sticker_set_thumb(self, name, user_id).erase()
+    }
+
+    fn send_invoice(
+        &self,
+        chat_id: i32,
+        title: String,
+        description: String,
+        payload: String,
+        provider_token: String,
+        start_parameter: String,
+        currency: String,
+        prices: Vec<LabeledPrice>,
+    ) -> ErasedRequest<'a, SendInvoice, Self::Err> {
+        Requester::send_invoice(
+            self,
+            chat_id,
+            title,
+            description,
+            payload,
+            provider_token,
+            start_parameter,
+            currency,
+            prices,
+        )
+        .erase()
+    }
+
+    fn create_invoice_link(
+        &self,
+        title: String,
+        description: String,
+        payload: String,
+        provider_token: String,
+        currency: String,
+        prices: Vec<LabeledPrice>,
+    ) -> ErasedRequest<'a, CreateInvoiceLink, Self::Err> {
+        Requester::create_invoice_link(
+            self,
+            title,
+            description,
+            payload,
+            provider_token,
+            currency,
+            prices,
+        )
+        .erase()
+    }
+
+    fn answer_shipping_query(
+        &self,
+        shipping_query_id: String,
+        ok: bool,
+    ) -> ErasedRequest<'a, AnswerShippingQuery, Self::Err> {
+        Requester::answer_shipping_query(self, shipping_query_id, ok).erase()
+    }
+
+    fn answer_pre_checkout_query(
+        &self,
+        pre_checkout_query_id: String,
+        ok: bool,
+    ) -> ErasedRequest<'a, AnswerPreCheckoutQuery, Self::Err> {
+        Requester::answer_pre_checkout_query(self, pre_checkout_query_id, ok).erase()
+    }
+
+    fn refund_star_payment(
+        &self,
+        user_id: UserId,
+        telegram_payment_charge_id: String,
+    ) -> ErasedRequest<'a, RefundStarPayment, Self::Err> {
+        Requester::refund_star_payment(self, user_id, telegram_payment_charge_id).erase()
+    }
+
+    fn set_passport_data_errors(
+        &self,
+        user_id: UserId,
+        errors: Vec<PassportElementError>,
+    ) -> ErasedRequest<'a, SetPassportDataErrors, Self::Err> {
+        Requester::set_passport_data_errors(self, user_id, errors).erase()
+    }
+
+    fn send_game(
+        &self,
+        chat_id: u32,
+        game_short_name: String,
+    ) -> ErasedRequest<'a, SendGame, Self::Err> {
+        Requester::send_game(self, chat_id, game_short_name).erase()
+    }
+
+    fn set_game_score(
+        &self,
+        user_id: UserId,
+        score: u64,
+        chat_id: u32,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, SetGameScore, Self::Err> {
+        Requester::set_game_score(self, user_id, score, chat_id, message_id).erase()
+    }
+
+    fn set_game_score_inline(
+        &self,
+        user_id: UserId,
+        score: u64,
+        inline_message_id: String,
+    ) -> ErasedRequest<'a, SetGameScoreInline, Self::Err> {
+        Requester::set_game_score_inline(self, user_id, score, inline_message_id).erase()
+    }
+
+    fn get_game_high_scores(
+        &self,
+        user_id: UserId,
+        target: TargetMessage,
+    ) -> ErasedRequest<'a, GetGameHighScores, Self::Err> {
+        Requester::get_game_high_scores(self, user_id, target).erase()
+    }
+
+    fn get_updates_fault_tolerant(&self) -> ErasedRequest<'a, GetUpdatesFaultTolerant, Self::Err> {
+        Requester::get_updates_fault_tolerant(self).erase()
+    }
+}
+
+impl<'a, Err> Requester for ErasedRequester<'a, Err>
+where
+    Err: std::error::Error + Send,
+{
+    type Err = Err;
+
+    type GetMe = ErasedRequest<'a, GetMe, Err>;
+
+    fn get_me(&self) -> Self::GetMe {
+        ErasableRequester::get_me(&*self.inner)
+    }
+
+    type LogOut = ErasedRequest<'a, LogOut, Err>;
+
+    fn log_out(&self) -> Self::LogOut {
+        ErasableRequester::log_out(&*self.inner)
+    }
+
+    type Close = ErasedRequest<'a, Close, Err>;
+
+    fn close(&self) -> Self::Close {
+        ErasableRequester::close(&*self.inner)
+    }
+
+    type GetUpdates = ErasedRequest<'a, GetUpdates, Err>;
+
+    fn get_updates(&self) -> Self::GetUpdates {
+        ErasableRequester::get_updates(&*self.inner)
+    }
+
+    type SetWebhook = ErasedRequest<'a, SetWebhook, Err>;
+
+    fn set_webhook<U>(&self, url: U) -> Self::SetWebhook
+    where
+        U: Into<String>,
+    {
+        ErasableRequester::set_webhook(&*self.inner, url.into())
+    }
+
+    type DeleteWebhook = ErasedRequest<'a, DeleteWebhook, Err>;
+
+    fn delete_webhook(&self) -> Self::DeleteWebhook {
+        ErasableRequester::delete_webhook(&*self.inner)
+    }
+
+    type GetWebhookInfo = ErasedRequest<'a, GetWebhookInfo, Err>;
+
+    fn get_webhook_info(&self) -> Self::GetWebhookInfo {
+        ErasableRequester::get_webhook_info(&*self.inner)
+    }
+
+    type ForwardMessage = ErasedRequest<'a, ForwardMessage, Err>;
+
+    fn forward_message<C, F>(
+        &self,
+        chat_id: C,
+        from_chat_id: F,
+        message_id: MessageId,
+    ) -> Self::ForwardMessage
+    where
+        C: Into<ChatId>,
+        F: Into<ChatId>,
+    {
+        ErasableRequester::forward_message(
+            &*self.inner,
+            chat_id.into(),
+            from_chat_id.into(),
+            message_id,
+        )
+    }
+
+    type ForwardMessages = ErasedRequest<'a, ForwardMessages, Err>;
+
+    fn forward_messages<C, F, M>(
+        &self,
+        chat_id: C,
+        from_chat_id: F,
+        message_ids: M,
+    ) -> Self::ForwardMessages
+    where
+        C: Into<ChatId>,
+        F: Into<ChatId>,
+        M: IntoIterator<Item = MessageId>,
+    {
+        ErasableRequester::forward_messages(
+            &*self.inner,
+            chat_id.into(),
+            from_chat_id.into(),
+            message_ids.into_iter().collect(),
+        )
+    }
+
+    type CopyMessage = ErasedRequest<'a, CopyMessage, Err>;
+
+    fn copy_message<C, F>(
+        &self,
+        chat_id: C,
+        from_chat_id: F,
+        message_id: MessageId,
+    ) -> Self::CopyMessage
+    where
+        C: Into<ChatId>,
+        F: Into<ChatId>,
+    {
+        ErasableRequester::copy_message(
+            &*self.inner,
+            chat_id.into(),
+            from_chat_id.into(),
+            message_id,
+        )
+    }
+
+    type CopyMessages = ErasedRequest<'a, CopyMessages, Err>;
+
+    fn copy_messages<C, F, M>(
+        &self,
+        chat_id: C,
+        from_chat_id: F,
+        message_ids: M,
+    ) -> Self::CopyMessages
+    where
+        C: Into<ChatId>,
+        F: Into<ChatId>,
+        M: IntoIterator<Item = MessageId>,
+    {
+        ErasableRequester::copy_messages(
+            &*self.inner,
+            chat_id.into(),
+            from_chat_id.into(),
+            message_ids.into_iter().collect(),
+        )
+    }
+
+    type SendMessage = ErasedRequest<'a, SendMessage, Err>;
+
+    fn send_message<C, T>(&self, chat_id: C, text: T) -> Self::SendMessage
+    where
+        C: Into<ChatId>,
+        T: Into<String>,
+    {
+        ErasableRequester::send_message(&*self.inner, chat_id.into(), text.into())
+    }
+
+    type SendPhoto = ErasedRequest<'a, SendPhoto, Err>;
+
+    fn send_photo<C>(&self, chat_id: C, photo: InputFile) -> Self::SendPhoto
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::send_photo(&*self.inner, chat_id.into(), photo)
+    }
+
+    type SendAudio = ErasedRequest<'a, SendAudio, Err>;
+
+    fn send_audio<C>(&self, chat_id: C, audio: InputFile) -> Self::SendAudio
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::send_audio(&*self.inner, chat_id.into(), audio)
+    }
+
+    type SendDocument = ErasedRequest<'a, SendDocument, Err>;
+
+    fn send_document<C>(&self, chat_id: C, document: InputFile) -> Self::SendDocument
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::send_document(&*self.inner, chat_id.into(), document)
+    }
+
+    type SendVideo = ErasedRequest<'a, SendVideo, Err>;
+
+    fn send_video<C>(&self, chat_id: C, video: InputFile) -> Self::SendVideo
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::send_video(&*self.inner, chat_id.into(), video)
+    }
+
+    type SendAnimation = ErasedRequest<'a, SendAnimation, Err>;
+
+    fn send_animation<C>(&self, chat_id: C, animation: InputFile) -> Self::SendAnimation
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::send_animation(&*self.inner, chat_id.into(), animation)
+    }
+
+    type SendVoice = ErasedRequest<'a, SendVoice, Err>;
+
+    fn send_voice<C>(&self, chat_id: C, voice: InputFile) -> Self::SendVoice
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::send_voice(&*self.inner, chat_id.into(), voice)
+    }
+
+    type SendVideoNote = ErasedRequest<'a, SendVideoNote, Err>;
+
+    fn send_video_note<C>(&self, chat_id: C, video_note: InputFile) -> Self::SendVideoNote
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::send_video_note(&*self.inner, chat_id.into(), video_note)
+    }
+
+    type SendMediaGroup = ErasedRequest<'a, SendMediaGroup, Err>;
+
+    fn send_media_group<C, M>(&self, chat_id: C, media: M) -> Self::SendMediaGroup
+    where
+        C: Into<ChatId>,
+        M: IntoIterator<Item = InputMedia>,
+    {
+        ErasableRequester::send_media_group(
+            &*self.inner,
+            chat_id.into(),
+            media.into_iter().collect(),
+        )
+    }
+
+    type SendPaidMedia = ErasedRequest<'a, SendPaidMedia, Err>;
+
+    fn send_paid_media<C, M>(&self, chat_id: C, star_count: u32, media: M) -> Self::SendPaidMedia
+    where
+        C: Into<ChatId>,
+        M: IntoIterator<Item = InputPaidMedia>,
+    {
+        ErasableRequester::send_paid_media(
+            &*self.inner,
+            chat_id.into(),
+            star_count,
+            media.into_iter().collect(),
+        )
+    }
+
+    type SendLocation = ErasedRequest<'a, SendLocation, Err>;
+
+    fn send_location<C>(&self, chat_id: C, latitude: f64, longitude: f64) -> Self::SendLocation
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::send_location(&*self.inner, chat_id.into(), latitude, longitude)
+    }
+
+    type EditMessageLiveLocation = ErasedRequest<'a, EditMessageLiveLocation, Err>;
+
+    fn edit_message_live_location<C>(
+        &self,
+        chat_id: C,
+        message_id: MessageId,
+        latitude: f64,
+        longitude: f64,
+    ) -> Self::EditMessageLiveLocation
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::edit_message_live_location(
+            &*self.inner,
+            chat_id.into(),
+            message_id,
+            latitude,
+            longitude,
+        )
+    }
+
+    type EditMessageLiveLocationInline = ErasedRequest<'a, EditMessageLiveLocationInline, Err>;
+
+    fn edit_message_live_location_inline<I>(
+        &self,
+        inline_message_id: I,
+        latitude: f64,
+        longitude: f64,
+    ) -> Self::EditMessageLiveLocationInline
+    where
+        I: Into<String>,
+    {
+        ErasableRequester::edit_message_live_location_inline(
+            &*self.inner,
+            inline_message_id.into(),
+            latitude,
+            longitude,
+        )
+    }
+
+    type StopMessageLiveLocation = ErasedRequest<'a, StopMessageLiveLocation, Err>;
+
+    fn stop_message_live_location<C>(
+        &self,
+        chat_id: C,
+        message_id: MessageId,
+        latitude: f64,
+        longitude: f64,
+    ) -> Self::StopMessageLiveLocation
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::stop_message_live_location(
+            &*self.inner,
+            chat_id.into(),
+            message_id,
+            latitude,
+            longitude,
+        )
+    }
+
+    type StopMessageLiveLocationInline = ErasedRequest<'a, StopMessageLiveLocationInline, Err>;
+
+    fn stop_message_live_location_inline<I>(
+        &self,
+        inline_message_id: I,
+        latitude: f64,
+        longitude: f64,
+    ) -> Self::StopMessageLiveLocationInline
+    where
+        I: Into<String>,
+    {
+        ErasableRequester::stop_message_live_location_inline(
+            &*self.inner,
+            inline_message_id.into(),
+            latitude,
+            longitude,
+        )
+    }
+
+    type SendVenue = ErasedRequest<'a, SendVenue, Err>;
+
+    fn send_venue<C, T, A>(
+        &self,
+        chat_id: C,
+        latitude: f64,
+        longitude: f64,
+        title: T,
+        address: A,
+    ) -> Self::SendVenue
+    where
+        C: Into<ChatId>,
+        T: Into<String>,
+        A: Into<String>,
+    {
+        ErasableRequester::send_venue(
+            &*self.inner,
+            chat_id.into(),
+            latitude,
+            longitude,
+            title.into(),
+            address.into(),
+        )
+    }
+
+    type SendContact = ErasedRequest<'a, SendContact, Err>;
+
+    fn send_contact<C, P, F>(&self, chat_id: C, phone_number: P, first_name: F) -> Self::SendContact
+    where
+        C: Into<ChatId>,
+        P: Into<String>,
+        F: Into<String>,
+    {
+        ErasableRequester::send_contact(
+            &*self.inner,
+            chat_id.into(),
+            phone_number.into(),
+            first_name.into(),
+        )
+    }
+
+    type SendPoll = ErasedRequest<'a, SendPoll, Err>;
+
+    fn send_poll<C, Q, O>(
+        &self,
+        chat_id: C,
+        question: Q,
+        options: O,
+        type_: PollType,
+    ) -> Self::SendPoll
+    where
+        C: Into<ChatId>,
+        Q: Into<String>,
+        O: IntoIterator<Item = String>,
+    {
+        ErasableRequester::send_poll(
+            &*self.inner,
+            chat_id.into(),
+            question.into(),
+            options.into_iter().collect(),
+            type_,
+        )
+    }
+
+    type SendDice = ErasedRequest<'a, SendDice, Err>;
+
+    fn send_dice<C>(&self, chat_id: C) -> Self::SendDice
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::send_dice(&*self.inner, chat_id.into())
+    }
+
+    type SendChatAction = ErasedRequest<'a, SendChatAction, Err>;
+
+    fn send_chat_action<C>(&self, chat_id: C, action: ChatAction) -> Self::SendChatAction
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::send_chat_action(&*self.inner, chat_id.into(), action)
+    }
+
+    type GetUserProfilePhotos = ErasedRequest<'a, GetUserProfilePhotos, Err>;
+
+    fn get_user_profile_photos(&self, user_id: UserId) -> Self::GetUserProfilePhotos {
+        ErasableRequester::get_user_profile_photos(&*self.inner, user_id)
+    }
+
+    type GetFile = ErasedRequest<'a, GetFile, Err>;
+
+    fn get_file<F>(&self, file_id: F) -> Self::GetFile
+    where
+        F: Into<String>,
+    {
+        ErasableRequester::get_file(&*self.inner, file_id.into())
+    }
+
+    type KickChatMember = ErasedRequest<'a, KickChatMember, Err>;
+
+    fn kick_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::KickChatMember
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::kick_chat_member(&*self.inner, chat_id.into(), user_id)
+    }
+
+    type BanChatMember = ErasedRequest<'a, BanChatMember, Err>;
+
+    fn ban_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::BanChatMember
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::ban_chat_member(&*self.inner, chat_id.into(), user_id)
+    }
+
+    type UnbanChatMember = ErasedRequest<'a, UnbanChatMember, Err>;
+
+    fn unban_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::UnbanChatMember
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::unban_chat_member(&*self.inner, chat_id.into(), user_id)
+    }
+
+    type RestrictChatMember = ErasedRequest<'a, RestrictChatMember, Err>;
+
+    fn restrict_chat_member<C>(
+        &self,
+        chat_id: C,
+        user_id: UserId,
+        permissions: ChatPermissions,
+    ) -> Self::RestrictChatMember
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::restrict_chat_member(&*self.inner, chat_id.into(), user_id, permissions)
+    }
+
+    type PromoteChatMember = ErasedRequest<'a, PromoteChatMember, Err>;
+
+    fn promote_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::PromoteChatMember
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::promote_chat_member(&*self.inner, chat_id.into(), user_id)
+    }
+
+    type SetChatAdministratorCustomTitle = ErasedRequest<'a, SetChatAdministratorCustomTitle, Err>;
+
+    fn set_chat_administrator_custom_title<Ch, Cu>(
+        &self,
+        chat_id: Ch,
+        user_id: UserId,
+        custom_title: Cu,
+    ) -> Self::SetChatAdministratorCustomTitle
+    where
+        Ch: Into<ChatId>,
+        Cu: Into<String>,
+    {
+        ErasableRequester::set_chat_administrator_custom_title(
+            &*self.inner,
+            chat_id.into(),
+            user_id,
+            custom_title.into(),
+        )
+    }
+
+    type SetChatPermissions = ErasedRequest<'a, SetChatPermissions, Err>;
+
+    fn set_chat_permissions<C>(
+        &self,
+        chat_id: C,
+        permissions: ChatPermissions,
+    ) -> Self::SetChatPermissions
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::set_chat_permissions(&*self.inner, chat_id.into(), permissions)
+    }
+
+    type ExportChatInviteLink = ErasedRequest<'a, ExportChatInviteLink, Err>;
+
+    fn export_chat_invite_link<C>(&self, chat_id: C) -> Self::ExportChatInviteLink
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::export_chat_invite_link(&*self.inner, chat_id.into())
+    }
+
+    type CreateChatInviteLink = ErasedRequest<'a, CreateChatInviteLink, Err>;
+
+    fn create_chat_invite_link<C>(&self, chat_id: C) -> Self::CreateChatInviteLink
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::create_chat_invite_link(&*self.inner, chat_id.into())
+    }
+
+    type EditChatInviteLink = ErasedRequest<'a, EditChatInviteLink, Err>;
+
+    fn edit_chat_invite_link<C, I>(&self, chat_id: C, invite_link: I) -> Self::EditChatInviteLink
+    where
+        C: Into<ChatId>,
+        I: Into<String>,
+    {
+        ErasableRequester::edit_chat_invite_link(&*self.inner, chat_id.into(), invite_link.into())
+    }
+
+    type RevokeChatInviteLink = ErasedRequest<'a, RevokeChatInviteLink, Err>;
+
+    fn revoke_chat_invite_link<C, I>(
+        &self,
+        chat_id: C,
+        invite_link: I,
+    ) -> Self::RevokeChatInviteLink
+    where
+        C: Into<ChatId>,
+        I: Into<String>,
+    {
+        ErasableRequester::revoke_chat_invite_link(&*self.inner, chat_id.into(), invite_link.into())
+    }
+
+    type SetChatPhoto = ErasedRequest<'a, SetChatPhoto, Err>;
+
+    fn set_chat_photo<C>(&self, chat_id: C, photo: InputFile) -> Self::SetChatPhoto
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::set_chat_photo(&*self.inner, chat_id.into(), photo)
+    }
+
+    type DeleteChatPhoto = ErasedRequest<'a, DeleteChatPhoto, Err>;
+
+    fn delete_chat_photo<C>(&self, chat_id: C) -> Self::DeleteChatPhoto
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::delete_chat_photo(&*self.inner, chat_id.into())
+    }
+
+    type SetChatTitle = ErasedRequest<'a, SetChatTitle, Err>;
+
+    fn set_chat_title<C, T>(&self, chat_id: C, title: T) -> Self::SetChatTitle
+    where
+        C: Into<ChatId>,
+        T: Into<String>,
+    {
+        ErasableRequester::set_chat_title(&*self.inner, chat_id.into(), title.into())
+    }
+
+    type SetChatDescription = ErasedRequest<'a, SetChatDescription, Err>;
+
+    fn set_chat_description<C>(&self, chat_id: C) -> Self::SetChatDescription
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::set_chat_description(&*self.inner, chat_id.into())
+    }
+
+    type PinChatMessage = ErasedRequest<'a, PinChatMessage, Err>;
+
+    fn pin_chat_message<C>(&self, chat_id: C, message_id: MessageId) -> Self::PinChatMessage
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::pin_chat_message(&*self.inner, chat_id.into(), message_id)
+    }
+
+    type UnpinChatMessage = ErasedRequest<'a, UnpinChatMessage, Err>;
+
+    fn unpin_chat_message<C>(&self, chat_id: C) -> Self::UnpinChatMessage
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::unpin_chat_message(&*self.inner, chat_id.into())
+    }
+
+    type UnpinAllChatMessages = ErasedRequest<'a, UnpinAllChatMessages, Err>;
+
+    fn unpin_all_chat_messages<C>(&self, chat_id: C) -> Self::UnpinAllChatMessages
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::unpin_all_chat_messages(&*self.inner, chat_id.into())
+    }
+
+    type LeaveChat = ErasedRequest<'a, LeaveChat, Err>;
+
+    fn leave_chat<C>(&self, chat_id: C) -> Self::LeaveChat
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::leave_chat(&*self.inner, chat_id.into())
+    }
+
+    type GetChat = ErasedRequest<'a, GetChat, Err>;
+
+    fn get_chat<C>(&self, chat_id: C) -> Self::GetChat
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::get_chat(&*self.inner, chat_id.into())
+    }
+
+    type GetChatAdministrators = ErasedRequest<'a, GetChatAdministrators, Err>;
+
+    fn get_chat_administrators<C>(&self, chat_id: C) -> Self::GetChatAdministrators
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::get_chat_administrators(&*self.inner, chat_id.into())
+    }
+
+    type GetChatMembersCount = ErasedRequest<'a, GetChatMembersCount, Err>;
+
+    fn get_chat_members_count<C>(&self, chat_id: C) -> Self::GetChatMembersCount
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::get_chat_members_count(&*self.inner, chat_id.into())
+    }
+
+    type GetChatMember = ErasedRequest<'a, GetChatMember, Err>;
+
+    fn get_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::GetChatMember
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::get_chat_member(&*self.inner, chat_id.into(), user_id)
+    }
+
+    type SetChatStickerSet = ErasedRequest<'a, SetChatStickerSet, Err>;
+
+    fn set_chat_sticker_set<C, S>(&self, chat_id: C, sticker_set_name: S) -> Self::SetChatStickerSet
+    where
+        C: Into<ChatId>,
+        S: Into<String>,
+    {
+        ErasableRequester::set_chat_sticker_set(
+            &*self.inner,
+            chat_id.into(),
+            sticker_set_name.into(),
+        )
+    }
+
+    type DeleteChatStickerSet = ErasedRequest<'a, DeleteChatStickerSet, Err>;
+
+    fn delete_chat_sticker_set<C>(&self, chat_id: C) -> Self::DeleteChatStickerSet
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::delete_chat_sticker_set(&*self.inner, chat_id.into())
+    }
+
+    type AnswerCallbackQuery = ErasedRequest<'a, AnswerCallbackQuery, Err>;
+
+    fn answer_callback_query<C>(&self, callback_query_id: C) -> Self::AnswerCallbackQuery
+    where
+        C: Into<String>,
+    {
+        ErasableRequester::answer_callback_query(&*self.inner, callback_query_id.into())
+    }
+
+    type SetMyCommands = ErasedRequest<'a, SetMyCommands, Err>;
+
+    fn set_my_commands<C>(&self, commands: C) -> Self::SetMyCommands
+    where
+        C: IntoIterator<Item = BotCommand>,
+    {
+        ErasableRequester::set_my_commands(&*self.inner, commands.into_iter().collect())
+    }
+
+    type GetMyCommands = ErasedRequest<'a, GetMyCommands, Err>;
+
+    fn get_my_commands(&self) -> Self::GetMyCommands {
+        ErasableRequester::get_my_commands(&*self.inner)
+    }
+
+    type DeleteMyCommands = ErasedRequest<'a, DeleteMyCommands, Err>;
+
+    fn delete_my_commands(&self) -> Self::DeleteMyCommands {
+        ErasableRequester::delete_my_commands(&*self.inner)
+    }
+
+    type SetChatMenuButton = ErasedRequest<'a, SetChatMenuButton, Err>;
+
+    fn set_chat_menu_button(&self) -> Self::SetChatMenuButton {
+        ErasableRequester::set_chat_menu_button(&*self.inner)
+    }
+
+    type GetChatMenuButton = ErasedRequest<'a, GetChatMenuButton, Err>;
+
+    fn get_chat_menu_button(&self) -> Self::GetChatMenuButton {
+        ErasableRequester::get_chat_menu_button(&*self.inner)
+    }
+
+    type AnswerInlineQuery = ErasedRequest<'a, AnswerInlineQuery, Err>;
+
+    fn answer_inline_query<I, R>(&self, inline_query_id: I, results: R) -> Self::AnswerInlineQuery
+    where
+        I: Into<String>,
+        R: IntoIterator<Item = InlineQueryResult>,
+    {
+        ErasableRequester::answer_inline_query(
+            &*self.inner,
+            inline_query_id.into(),
+            results.into_iter().collect(),
+        )
+    }
+
+    type EditMessageText = ErasedRequest<'a, EditMessageText, Err>;
+
+    fn edit_message_text<C, T>(
+        &self,
+        chat_id: C,
+        message_id: MessageId,
+        text: T,
+    ) -> Self::EditMessageText
+    where
+        C: Into<ChatId>,
+        T: Into<String>,
+    {
+        ErasableRequester::edit_message_text(&*self.inner, chat_id.into(), message_id, text.into())
+    }
+
+    type EditMessageTextInline = ErasedRequest<'a, EditMessageTextInline, Err>;
+
+    fn edit_message_text_inline<I, T>(
+        &self,
+        inline_message_id: I,
+        text: T,
+    ) -> Self::EditMessageTextInline
+    where
+        I: Into<String>,
+        T: Into<String>,
+    {
+        ErasableRequester::edit_message_text_inline(
+            &*self.inner,
+            inline_message_id.into(),
+            text.into(),
+        )
+    }
+
+    type EditMessageCaption = ErasedRequest<'a, EditMessageCaption, Err>;
+
+    fn edit_message_caption<C>(&self, chat_id: C, message_id: MessageId) -> Self::EditMessageCaption
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::edit_message_caption(&*self.inner, chat_id.into(), message_id)
+    }
+
+    type EditMessageCaptionInline = ErasedRequest<'a, EditMessageCaptionInline, Err>;
+
+    fn edit_message_caption_inline<I>(&self, inline_message_id: I) -> Self::EditMessageCaptionInline
+    where
+        I: Into<String>,
+    {
+        ErasableRequester::edit_message_caption_inline(&*self.inner, inline_message_id.into())
+    }
+
+    type EditMessageMedia = ErasedRequest<'a, EditMessageMedia, Err>;
+
+    fn edit_message_media<C>(
+        &self,
+        chat_id: C,
+        message_id: MessageId,
+        media: InputMedia,
+    ) -> Self::EditMessageMedia
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::edit_message_media(&*self.inner, chat_id.into(), message_id, media)
+    }
+
+    type EditMessageMediaInline = ErasedRequest<'a, EditMessageMediaInline, Err>;
+
+    fn edit_message_media_inline<I>(
+        &self,
+        inline_message_id: I,
+        media: InputMedia,
+    ) -> Self::EditMessageMediaInline
+    where
+        I: Into<String>,
+    {
+        ErasableRequester::edit_message_media_inline(&*self.inner, inline_message_id.into(), media)
+    }
+
+    type EditMessageReplyMarkup = ErasedRequest<'a, EditMessageReplyMarkup, Err>;
+
+    fn edit_message_reply_markup<C>(
+        &self,
+        chat_id: C,
+        message_id: MessageId,
+    ) -> Self::EditMessageReplyMarkup
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::edit_message_reply_markup(&*self.inner, chat_id.into(), message_id)
+    }
+
+    type EditMessageReplyMarkupInline = ErasedRequest<'a, EditMessageReplyMarkupInline, Err>;
+
+    fn edit_message_reply_markup_inline<I>(
+        &self,
+        inline_message_id: I,
+    ) -> Self::EditMessageReplyMarkupInline
+    where
+        I: Into<String>,
+    {
+        ErasableRequester::edit_message_reply_markup_inline(&*self.inner, inline_message_id.into())
+    }
+
+    type StopPoll = ErasedRequest<'a, StopPoll, Err>;
+
+    fn stop_poll<C>(&self, chat_id: C, message_id: MessageId) -> Self::StopPoll
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::stop_poll(&*self.inner, chat_id.into(), message_id)
+    }
+
+    type DeleteMessage = ErasedRequest<'a, DeleteMessage, Err>;
+
+    fn delete_message<C>(&self, chat_id: C, message_id: MessageId) -> Self::DeleteMessage
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::delete_message(&*self.inner, chat_id.into(), message_id)
+    }
+
+    type SetMessageReaction = ErasedRequest<'a, SetMessageReaction, Err>;
+
+    fn set_message_reaction<C>(&self, chat_id: C, message_id: MessageId) -> Self::SetMessageReaction
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::set_message_reaction(&*self.inner, chat_id.into(), message_id)
+    }
+
+    type SendSticker = ErasedRequest<'a, SendSticker, Err>;
+
+    fn send_sticker<C>(&self, chat_id: C, sticker: InputFile) -> Self::SendSticker
+    where
+        C: Into<ChatId>,
+    {
+        ErasableRequester::send_sticker(&*self.inner, chat_id.into(), sticker)
+    }
+
+    type GetStickerSet = ErasedRequest<'a, GetStickerSet, Err>;
+
+    fn get_sticker_set<N>(&self, name: N) -> Self::GetStickerSet
+    where
+        N: Into<String>,
+    {
+        ErasableRequester::get_sticker_set(&*self.inner, name.into())
+    }
+
+    type UploadStickerFile = ErasedRequest<'a, UploadStickerFile, Err>;
+
+    fn upload_sticker_file(
+        &self,
+        user_id: UserId,
+        png_sticker: InputFile,
+    ) -> Self::UploadStickerFile {
+        ErasableRequester::upload_sticker_file(&*self.inner, user_id, png_sticker)
+    }
+
+    type CreateNewStickerSet = ErasedRequest<'a, CreateNewStickerSet, Err>;
+
+    fn create_new_sticker_set<N, T, E>(
+        &self,
+        user_id: UserId,
+        name: N,
+        title: T,
+        sticker: InputSticker,
+        emojis: E,
+    ) -> Self::CreateNewStickerSet
+    where
+        N: Into<String>,
+        T: Into<String>,
+        E: Into<String>,
+    {
+        ErasableRequester::create_new_sticker_set(
+            &*self.inner,
+            user_id,
+            name.into(),
+            title.into(),
+            sticker,
+            emojis.into(),
+        )
+    }
+
+    type AddStickerToSet = ErasedRequest<'a, AddStickerToSet, Err>;
+
+    fn add_sticker_to_set<N, E>(
+        &self,
+        user_id: UserId,
+        name: N,
+        sticker: InputSticker,
+        emojis: E,
+    ) -> Self::AddStickerToSet
+    where
+        N: Into<String>,
+        E: Into<String>,
+    {
+        ErasableRequester::add_sticker_to_set(
+            &*self.inner,
+            user_id,
+            name.into(),
+            sticker,
+            emojis.into(),
+        )
+    }
+
+    type SetStickerPositionInSet = ErasedRequest<'a, SetStickerPositionInSet, Err>;
+
+    fn set_sticker_position_in_set<S>(
+        &self,
+        sticker: S,
+        position: u32,
+    ) -> Self::SetStickerPositionInSet
+    where
+        S: Into<String>,
+    {
+        ErasableRequester::set_sticker_position_in_set(&*self.inner, sticker.into(), position)
+    }
+
+    type DeleteStickerFromSet = ErasedRequest<'a, DeleteStickerFromSet, Err>;
+
+    fn delete_sticker_from_set<S>(&self, sticker: S) -> Self::DeleteStickerFromSet
+    where
+        S: Into<String>,
+    {
+        ErasableRequester::delete_sticker_from_set(&*self.inner, sticker.into())
+    }
+
+    type SetStickerSetThumb = ErasedRequest<'a, SetStickerSetThumb, Err>;
+
+    fn set_sticker_set_thumb<N>(&self, name: N, user_id: UserId) -> Self::SetStickerSetThumb
+    where
+        N: Into<String>,
+    {
+        ErasableRequester::set_sticker_set_thumb(&*self.inner, name.into(), user_id)
+    }
+
+    type SendInvoice = ErasedRequest<'a, SendInvoice, Err>;
+
+    fn send_invoice<T, D, Pa, P, S, C, Pri>(
+        &self,
+        chat_id: i32,
+        title: T,
+        description: D,
+        payload: Pa,
+        provider_token: P,
+        start_parameter: S,
+        currency: C,
+        prices: Pri,
+    ) -> Self::SendInvoice
+    where
+        T: Into<String>,
+        D: Into<String>,
+        Pa: Into<String>,
+        P: Into<String>,
+        S: Into<String>,
+        C: Into<String>,
+        Pri: IntoIterator<Item = LabeledPrice>,
+    {
+        ErasableRequester::send_invoice(
+            &*self.inner,
+            chat_id,
+            title.into(),
+            description.into(),
+            payload.into(),
+            provider_token.into(),
+            start_parameter.into(),
+            currency.into(),
+            prices.into_iter().collect(),
+        )
+    }
+
+    type CreateInvoiceLink = ErasedRequest<'a, CreateInvoiceLink, Err>;
+
+    fn create_invoice_link<T, D, Pa, P, C, Pri>(
+        &self,
+        title: T,
+        description: D,
+        payload: Pa,
+        provider_token: P,
+        currency: C,
+        prices: Pri,
+    ) -> Self::CreateInvoiceLink
+    where
+        T: Into<String>,
+        D: Into<String>,
+        Pa: Into<String>,
+        P: Into<String>,
+        C: Into<String>,
+        Pri: IntoIterator<Item = LabeledPrice>,
+    {
+        ErasableRequester::create_invoice_link(
+            &*self.inner,
+            title.into(),
+            description.into(),
+            payload.into(),
+            provider_token.into(),
+            currency.into(),
+            prices.into_iter().collect(),
+        )
+    }
+
+    type AnswerShippingQuery = ErasedRequest<'a, AnswerShippingQuery, Err>;
+
+    fn answer_shipping_query<S>(&self, shipping_query_id: S, ok: bool) -> Self::AnswerShippingQuery
+    where
+        S: Into<String>,
+    {
+        ErasableRequester::answer_shipping_query(&*self.inner, shipping_query_id.into(), ok)
+    }
+
+    type AnswerPreCheckoutQuery = ErasedRequest<'a, AnswerPreCheckoutQuery, Err>;
+
+    fn answer_pre_checkout_query<P>(
+        &self,
+        pre_checkout_query_id: P,
+        ok: bool,
+    ) -> Self::AnswerPreCheckoutQuery
+    where
+        P: Into<String>,
+    {
+        ErasableRequester::answer_pre_checkout_query(&*self.inner, pre_checkout_query_id.into(), ok)
+    }
+
+    type RefundStarPayment = ErasedRequest<'a, RefundStarPayment, Err>;
+
+    fn refund_star_payment<T>(
+        &self,
+        user_id: UserId,
+        telegram_payment_charge_id: T,
+    ) -> Self::RefundStarPayment
+    where
+        T: Into<String>,
+    {
+        ErasableRequester::refund_star_payment(
+            &*self.inner,
+            user_id,
+            telegram_payment_charge_id.into(),
+        )
+    }
+
+    type SetPassportDataErrors = ErasedRequest<'a, SetPassportDataErrors, Err>;
+
+    fn set_passport_data_errors<E>(&self, user_id: UserId, errors: E) -> Self::SetPassportDataErrors
+    where
+        E: IntoIterator<Item = PassportElementError>,
+    {
+        ErasableRequester::set_passport_data_errors(
+            &*self.inner,
+            user_id,
+            errors.into_iter().collect(),
+        )
+    }
+
+    type SendGame = ErasedRequest<'a, SendGame, Err>;
+
+    fn send_game<G>(&self, chat_id: u32, game_short_name: G) -> Self::SendGame
+    where
+        G: Into<String>,
+    {
+        ErasableRequester::send_game(&*self.inner, chat_id, game_short_name.into())
+    }
+
+    type SetGameScore = ErasedRequest<'a, SetGameScore, Err>;
+
+    fn set_game_score(
+        &self,
+        user_id: UserId,
+        score: u64,
+        chat_id: u32,
+        message_id: MessageId,
+    ) -> Self::SetGameScore {
+        ErasableRequester::set_game_score(&*self.inner, user_id, score, chat_id, message_id)
+    }
+
+    type SetGameScoreInline = ErasedRequest<'a, SetGameScoreInline, Err>;
+
+    fn set_game_score_inline<I>(
+        &self,
+        user_id: UserId,
+        score: u64,
+        inline_message_id: I,
+    ) -> Self::SetGameScoreInline
+    where
+        I: Into<String>,
+    {
+        ErasableRequester::set_game_score_inline(
+            &*self.inner,
+            user_id,
+            score,
+            inline_message_id.into(),
+        )
+    }
+
+    type GetGameHighScores = ErasedRequest<'a, GetGameHighScores, Err>;
+
+    fn get_game_high_scores<T>(&self, user_id: UserId, target: T) -> Self::GetGameHighScores
+    where
+        T: Into<TargetMessage>,
+    {
+        ErasableRequester::get_game_high_scores(&*self.inner, user_id, target.into())
+    }
+
+    type GetUpdatesFaultTolerant = ErasedRequest<'a, GetUpdatesFaultTolerant, Err>;
+
+    fn get_updates_fault_tolerant(&self) -> Self::GetUpdatesFaultTolerant {
+        ErasableRequester::get_updates_fault_tolerant(&*self.inner)
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use futures::executor::block_on;
+
+    use crate::{
+        payloads::ForwardMessagesSetters,
+        requests::{Request, Requester, RequesterExt},
+        types::{MessageId, True},
+        Bot,
+    };
+
+    #[test]
+    fn forwards_requests() {
+        let bot = Bot::new("TOKEN").mock();
+        let handle = bot.handle();
+        let bot = bot.erase();
+
+        handle.push_response(vec![MessageId(1), MessageId(2)]);
+        let ids = block_on(
+            bot.forward_messages(1, 2, vec![MessageId(10), MessageId(11)])
+                .disable_notification(true)
+                .send(),
+        )
+        .unwrap();
+        assert_eq!(ids, [MessageId(1), MessageId(2)]);
+
+        handle.push_response(True);
+        let request = bot.delete_message(1, MessageId(10));
+        block_on(request.send_ref()).unwrap();
+
+        let requests = handle.take_requests();
+        assert_eq!(requests[0].method, "ForwardMessages");
+        assert_eq!(
+            requests[0].payload["message_ids"],
+            serde_json::json!([10, 11])
+        );
+        assert_eq!(requests[0].payload["disable_notification"], true);
+        assert_eq!(requests[1].method, "DeleteMessage");
+    }
+}
@@ -0,0 +1,284 @@
+use std::{any::Any, fmt, future::Future, pin::Pin, sync::Arc};
+
+use futures::task::{Context, Poll};
+
+use crate::{
+    requests::{HasPayload, Output, Payload, Request, Requester},
+    types::*,
+};
+
+/// A closure called by [`Inspect`], see [`Inspect::new`].
+type Hook = dyn Fn(&'static str, &dyn Any) + Send + Sync;
+
+/// Calls a closure with every successful response.
+///
+/// Since requests return different types, the closure receives the name of the
+/// method (see [`Payload::NAME`]) and the output of the request as
+/// [`&dyn Any`], which can be downcasted to the expected type. For the common
+/// case of inspecting sent messages, there is
+/// [`RequesterExt::on_message_sent`].
+///
+/// The closure is called before the response is returned to the caller, so
+/// it should be fast (e.g. it can send the response to a channel).
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{requests::RequesterExt, types::MessageId, Bot};
+///
+/// let bot = Bot::new("TOKEN").inspect(|method, output| {
+///     if let Some(ids) = output.downcast_ref::<Vec<MessageId>>() {
+///         log::info!("{} sent {} messages", method, ids.len());
+///     }
+/// });
+/// ```
+///
+/// [`Payload::NAME`]: crate::requests::Payload::NAME
+/// [`&dyn Any`]: std::any::Any
+/// [`RequesterExt::on_message_sent`]: crate::requests::RequesterExt::on_message_sent
+#[derive(Clone)]
+pub struct Inspect<B> {
+    inner: B,
+    hook: Arc<Hook>,
+}
+
+impl<B> Inspect<B> {
+    /// Creates new `Inspect` which calls `hook` with the method name and the
+    /// output of every successful request.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::inspect`] instead.
+    ///
+    /// [`RequesterExt::inspect`]: crate::requests::RequesterExt::inspect
+    pub fn new<F>(inner: B, hook: F) -> Self
+    where
+        F: Fn(&'static str, &dyn Any) + Send + Sync + 'static,
+    {
+        Self {
+            inner,
+            hook: Arc::new(hook),
+        }
+    }
+
+    /// Allows to access the inner bot.
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Unwraps the inner bot.
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B: fmt::Debug> fmt::Debug for Inspect<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Inspect")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+macro_rules! f {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        InspectRequest {
+            inner: $this.inner().$m($($arg),*),
+            hook: Arc::clone(&$this.hook),
+        }
+    };
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        InspectRequest<B::$T>
+    };
+}
+
+impl<B> Requester for Inspect<B>
+where
+    B: Requester,
+{
+    type Err = B::Err;
+
+    requester_forward! {
+        get_me, log_out, close, get_updates, set_webhook, delete_webhook, get_webhook_info,
+        forward_message, forward_messages, copy_message, copy_messages,
+        send_message, send_photo, send_audio, send_document,
+        send_video, send_animation, send_voice, send_video_note, send_media_group,
+        send_paid_media, send_location,
+        edit_message_live_location, edit_message_live_location_inline,
+        stop_message_live_location, stop_message_live_location_inline, send_venue,
+        send_contact, send_poll, send_dice, send_chat_action, get_user_profile_photos,
+        get_file, kick_chat_member, ban_chat_member, unban_chat_member, restrict_chat_member,
+        promote_chat_member, set_chat_administrator_custom_title, set_chat_permissions,
+        export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
+        revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
+        set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
+        leave_chat, get_chat, get_chat_administrators, get_chat_members_count,get_chat_member,
+        set_chat_sticker_set, delete_chat_sticker_set, answer_callback_query,
+        set_my_commands, get_my_commands, delete_my_commands, set_chat_menu_button,
+        get_chat_menu_button, answer_inline_query, edit_message_text,
+        edit_message_text_inline, edit_message_caption, edit_message_caption_inline,
+        edit_message_media, edit_message_media_inline, edit_message_reply_markup,
+        edit_message_reply_markup_inline, stop_poll, delete_message,
+        set_message_reaction, send_sticker,
+        get_sticker_set, upload_sticker_file, create_new_sticker_set,
+        add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
+        set_sticker_set_thumb, send_invoice, create_invoice_link, answer_shipping_query,
+        answer_pre_checkout_query, refund_star_payment, set_passport_data_errors, send_game,
+        set_game_score, set_game_score_inline, get_game_high_scores,
+        get_updates_fault_tolerant => f, fty
+    }
+}
+
+download_forward! {
+    'w
+    B
+    Inspect<B>
+    { this => this.inner() }
+}
+
+pub struct InspectRequest<R> {
+    inner: R,
+    hook: Arc<Hook>,
+}
+
+impl<R> HasPayload for InspectRequest<R>
+where
+    R: HasPayload,
+{
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.inner.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.inner.payload_ref()
+    }
+}
+
+impl<R> Request for InspectRequest<R>
+where
+    R: Request,
+    Output<R>: 'static,
+{
+    type Err = R::Err;
+    type Send = InspectSend<R::Send>;
+    type SendRef = InspectSend<R::SendRef>;
+
+    fn send(self) -> Self::Send {
+        InspectSend {
+            fut: self.inner.send(),
+            method: R::Payload::NAME,
+            hook: self.hook,
+        }
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        InspectSend {
+            fut: self.inner.send_ref(),
+            method: R::Payload::NAME,
+            hook: Arc::clone(&self.hook),
+        }
+    }
+}
+
+#[pin_project::pin_project]
+pub struct InspectSend<F> {
+    #[pin]
+    fut: F,
+    method: &'static str,
+    hook: Arc<Hook>,
+}
+
+impl<F, T, E> Future for InspectSend<F>
+where
+    F: Future<Output = Result<T, E>>,
+    T: 'static,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let res = futures::ready!(this.fut.poll(cx));
+        if let Ok(output) = &res {
+            (this.hook)(this.method, output);
+        }
+
+        Poll::Ready(res)
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use std::sync::Mutex;
+
+    use futures::executor::block_on;
+
+    use crate::{
+        requests::{Request, RequesterExt},
+        Bot,
+    };
+
+    use super::*;
+
+    #[test]
+    fn inspect() {
+        let bot = Bot::new("TOKEN").mock();
+        let handle = bot.handle();
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let bot = bot.inspect({
+            let seen = Arc::clone(&seen);
+            move |method, output| {
+                let is_true = output.downcast_ref::<True>().is_some();
+                seen.lock().unwrap().push((method, is_true));
+            }
+        });
+
+        handle.push_response(True);
+        block_on(bot.delete_message(0, MessageId(1)).send()).unwrap();
+
+        handle.push_response(false);
+        block_on(bot.delete_message(0, MessageId(1)).send()).unwrap_err();
+
+        assert_eq!(*seen.lock().unwrap(), [("DeleteMessage", true)]);
+    }
+
+    #[test]
+    fn on_message_sent() {
+        let bot = Bot::new("TOKEN").mock();
+        let handle = bot.handle();
+
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let bot = bot.on_message_sent({
+            let sent = Arc::clone(&sent);
+            move |message| sent.lock().unwrap().push(message.id)
+        });
+
+        let message = |id| -> Message {
+            serde_json::from_value(serde_json::json!({
+                "message_id": id,
+                "date": 0,
+                "chat": { "id": 1, "first_name": "Name", "type": "private" },
+                "text": "text"
+            }))
+            .unwrap()
+        };
+
+        handle.push_response(message(1));
+        block_on(bot.send_message(1, "text").send()).unwrap();
+
+        handle.push_response(vec![message(2), message(3)]);
+        block_on(bot.send_media_group(1, vec![]).send()).unwrap();
+
+        handle.push_response(True);
+        block_on(bot.delete_message(1, MessageId(1)).send()).unwrap();
+
+        assert_eq!(
+            *sent.lock().unwrap(),
+            [MessageId(1), MessageId(2), MessageId(3)]
+        );
+    }
+}
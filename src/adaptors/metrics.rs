@@ -0,0 +1,491 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock,
+    },
+    time::{Duration, Instant},
+};
+
+use futures::task::{Context, Poll};
+
+use crate::{
+    requests::{HasPayload, Payload, Request, Requester},
+    types::*,
+    RequestError,
+};
+
+/// Upper bounds of the latency histogram buckets.
+const BUCKETS: [Duration; 9] = [
+    Duration::from_millis(50),
+    Duration::from_millis(100),
+    Duration::from_millis(250),
+    Duration::from_millis(500),
+    Duration::from_secs(1),
+    Duration::from_millis(2500),
+    Duration::from_secs(5),
+    Duration::from_secs(10),
+    Duration::from_secs(30),
+];
+
+/// Names of [`RequestError`] variants, errors are counted by them.
+const ERROR_KINDS: [&str; 10] = [
+    "ApiError",
+    "MigrateToChatId",
+    "RetryAfter",
+    "NetworkError",
+    "InvalidJson",
+    "Io",
+    "MessageTooLong",
+    "EmptyText",
+    "InvalidBotCommand",
+    "CircuitOpen",
+];
+
+/// Request metrics collecting.
+///
+/// This bot wrapper counts sent requests, successes & errors and records
+/// latencies of requests for every Telegram method separately. Collected
+/// metrics can be accessed through [`MetricsHandle`] (see
+/// [`Metrics::handle`]).
+///
+/// Metrics of every method are atomic counters, so concurrent requests don't
+/// wait for each other. Only the first request of a method takes an
+/// exclusive lock (to add the counters of the method), later requests take a
+/// shared one.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{
+///     requests::{Request, Requester, RequesterExt},
+///     Bot,
+/// };
+///
+/// # async {
+/// let bot = Bot::new("TOKEN").metrics();
+/// let metrics = bot.handle();
+///
+/// bot.get_me().send().await?;
+///
+/// let snapshot = metrics.snapshot();
+/// assert_eq!(snapshot["GetMe"].requests, 1);
+/// # Ok::<_, teloxide_core::RequestError>(()) };
+/// ```
+#[derive(Clone, Debug)]
+pub struct Metrics<B> {
+    bot: B,
+    handle: MetricsHandle,
+}
+
+impl<B> Metrics<B> {
+    /// Creates new `Metrics`.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::metrics`] instead.
+    ///
+    /// [`RequesterExt::metrics`]: crate::requests::RequesterExt::metrics
+    pub fn new(bot: B) -> Self {
+        Self {
+            bot,
+            handle: MetricsHandle::default(),
+        }
+    }
+
+    /// Returns a handle to the collected metrics.
+    ///
+    /// The handle is shared between all clones of this bot.
+    pub fn handle(&self) -> MetricsHandle {
+        self.handle.clone()
+    }
+
+    /// Allows to access the inner bot.
+    pub fn inner(&self) -> &B {
+        &self.bot
+    }
+
+    /// Unwraps the inner bot.
+    pub fn into_inner(self) -> B {
+        self.bot
+    }
+}
+
+/// A handle to metrics collected by [`Metrics`].
+///
+/// Cloning the handle is cheap, all clones refer to the same metrics.
+#[derive(Clone, Debug, Default)]
+pub struct MetricsHandle {
+    methods: Arc<RwLock<HashMap<&'static str, Arc<Counters>>>>,
+}
+
+impl MetricsHandle {
+    /// Returns a copy of the metrics collected so far, keyed by method names
+    /// (e.g. `"SendMessage"`).
+    ///
+    /// Methods that were never called are not present in the map.
+    pub fn snapshot(&self) -> HashMap<&'static str, MethodMetrics> {
+        // Metrics are only updated with atomic operations, the lock only
+        // protects the map, so it's ok to ignore poisoning
+        let methods = self.methods.read().unwrap_or_else(|err| err.into_inner());
+
+        methods
+            .iter()
+            .map(|(&method, counters)| (method, counters.snapshot()))
+            .collect()
+    }
+
+    /// Resets all collected metrics.
+    ///
+    /// Requests which are in flight during the reset are not counted.
+    pub fn reset(&self) {
+        self.methods
+            .write()
+            .unwrap_or_else(|err| err.into_inner())
+            .clear();
+    }
+
+    /// Counts a request of `method`, returns the counters of the method.
+    fn record_start(&self, method: &'static str) -> Arc<Counters> {
+        let counters = self.counters(method);
+        counters.requests.fetch_add(1, Ordering::Relaxed);
+        counters
+    }
+
+    fn counters(&self, method: &'static str) -> Arc<Counters> {
+        let methods = self.methods.read().unwrap_or_else(|err| err.into_inner());
+        if let Some(counters) = methods.get(method) {
+            return Arc::clone(counters);
+        }
+        drop(methods);
+
+        let mut methods = self.methods.write().unwrap_or_else(|err| err.into_inner());
+        Arc::clone(methods.entry(method).or_default())
+    }
+}
+
+/// Metrics of a single Telegram method, see [`MethodMetrics`].
+#[derive(Debug, Default)]
+struct Counters {
+    requests: AtomicU64,
+    successes: AtomicU64,
+    errors: [AtomicU64; ERROR_KINDS.len()],
+    latency_counts: [AtomicU64; BUCKETS.len() + 1],
+    latency_sum_nanos: AtomicU64,
+}
+
+impl Counters {
+    fn record_finish<T>(&self, latency: Duration, res: &Result<T, RequestError>) {
+        match res {
+            Ok(_) => self.successes.fetch_add(1, Ordering::Relaxed),
+            Err(err) => self.errors[error_kind(err)].fetch_add(1, Ordering::Relaxed),
+        };
+
+        self.latency_counts[bucket(latency)].fetch_add(1, Ordering::Relaxed);
+        // `u64` nanoseconds are enough for ~584 years
+        let nanos = std::cmp::min(latency.as_nanos(), u64::MAX as u128) as u64;
+        self.latency_sum_nanos.fetch_add(nanos, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> MethodMetrics {
+        let load = |counter: &AtomicU64| counter.load(Ordering::Relaxed);
+
+        let mut counts = [0; BUCKETS.len() + 1];
+        for (count, counter) in counts.iter_mut().zip(&self.latency_counts) {
+            *count = load(counter);
+        }
+
+        MethodMetrics {
+            requests: load(&self.requests),
+            successes: load(&self.successes),
+            errors: ERROR_KINDS
+                .iter()
+                .zip(&self.errors)
+                .map(|(&kind, counter)| (kind, load(counter)))
+                .filter(|&(_, count)| count != 0)
+                .collect(),
+            latency: LatencyHistogram {
+                counts,
+                sum: Duration::from_nanos(load(&self.latency_sum_nanos)),
+            },
+        }
+    }
+}
+
+/// Metrics of a single Telegram method.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MethodMetrics {
+    /// Number of sent requests.
+    ///
+    /// Note: this also counts requests which are still in flight or were
+    /// cancelled, so it may be bigger than `successes` + sum of `errors`.
+    pub requests: u64,
+
+    /// Number of successful requests.
+    pub successes: u64,
+
+    /// Number of failed requests, keyed by the [`RequestError`] variant name
+    /// (e.g. `"RetryAfter"`).
+    pub errors: HashMap<&'static str, u64>,
+
+    /// Latencies of completed requests.
+    pub latency: LatencyHistogram,
+}
+
+/// Histogram of request latencies.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LatencyHistogram {
+    counts: [u64; BUCKETS.len() + 1],
+    sum: Duration,
+}
+
+impl LatencyHistogram {
+    /// Returns iterator over `(upper bound, count)` pairs of the histogram
+    /// buckets.
+    ///
+    /// Counts are **not** cumulative, i.e. a latency is only counted in the
+    /// first bucket with upper bound >= the latency. The last bucket has
+    /// `None` upper bound (i.e. infinity).
+    pub fn buckets(&self) -> impl Iterator<Item = (Option<Duration>, u64)> + '_ {
+        BUCKETS
+            .iter()
+            .copied()
+            .map(Some)
+            .chain(Some(None))
+            .zip(self.counts.iter().copied())
+    }
+
+    /// Returns the number of recorded latencies.
+    pub fn count(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Returns the sum of all recorded latencies.
+    pub fn sum(&self) -> Duration {
+        self.sum
+    }
+}
+
+/// Returns the index of the histogram bucket of `latency`.
+fn bucket(latency: Duration) -> usize {
+    BUCKETS
+        .iter()
+        .position(|&bound| latency <= bound)
+        .unwrap_or(BUCKETS.len())
+}
+
+/// Returns the index of the kind of `err` in [`ERROR_KINDS`].
+fn error_kind(err: &RequestError) -> usize {
+    let kind = match err {
+        RequestError::ApiError { .. } => "ApiError",
+        RequestError::MigrateToChatId(_) => "MigrateToChatId",
+        RequestError::RetryAfter(_) => "RetryAfter",
+        RequestError::NetworkError(_) => "NetworkError",
+        RequestError::InvalidJson { .. } => "InvalidJson",
+        RequestError::Io(_) => "Io",
+        RequestError::MessageTooLong { .. } => "MessageTooLong",
+        RequestError::EmptyText => "EmptyText",
+        RequestError::InvalidBotCommand { .. } => "InvalidBotCommand",
+        RequestError::CircuitOpen => "CircuitOpen",
+    };
+
+    ERROR_KINDS
+        .iter()
+        .position(|&k| k == kind)
+        .expect("all error kinds are listed in `ERROR_KINDS`")
+}
+
+macro_rules! f {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        MetricsRequest {
+            request: $this.inner().$m($($arg),*),
+            handle: $this.handle.clone(),
+        }
+    };
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        MetricsRequest<B::$T>
+    };
+}
+
+impl<B> Requester for Metrics<B>
+where
+    B: Requester<Err = RequestError>,
+{
+    type Err = RequestError;
+
+    requester_forward! {
+        get_me, log_out, close, get_updates, set_webhook, delete_webhook, get_webhook_info,
+        forward_message, forward_messages, copy_message, copy_messages,
+        send_message, send_photo, send_audio, send_document,
+        send_video, send_animation, send_voice, send_video_note, send_media_group,
+        send_paid_media, send_location,
+        edit_message_live_location, edit_message_live_location_inline,
+        stop_message_live_location, stop_message_live_location_inline, send_venue,
+        send_contact, send_poll, send_dice, send_chat_action, get_user_profile_photos,
+        get_file, kick_chat_member, ban_chat_member, unban_chat_member, restrict_chat_member,
+        promote_chat_member, set_chat_administrator_custom_title, set_chat_permissions,
+        export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
+        revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
+        set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
+        leave_chat, get_chat, get_chat_administrators, get_chat_members_count,get_chat_member,
+        set_chat_sticker_set, delete_chat_sticker_set, answer_callback_query,
+        set_my_commands, get_my_commands, delete_my_commands, set_chat_menu_button,
+        get_chat_menu_button, answer_inline_query, edit_message_text,
+        edit_message_text_inline, edit_message_caption, edit_message_caption_inline,
+        edit_message_media, edit_message_media_inline, edit_message_reply_markup,
+        edit_message_reply_markup_inline, stop_poll, delete_message,
+        set_message_reaction, send_sticker,
+        get_sticker_set, upload_sticker_file, create_new_sticker_set,
+        add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
+        set_sticker_set_thumb, send_invoice, create_invoice_link, answer_shipping_query,
+        answer_pre_checkout_query, refund_star_payment, set_passport_data_errors, send_game,
+        set_game_score, set_game_score_inline, get_game_high_scores,
+        get_updates_fault_tolerant => f, fty
+    }
+}
+
+download_forward! {
+    'w
+    B
+    Metrics<B>
+    { this => this.inner() }
+}
+
+pub struct MetricsRequest<R> {
+    request: R,
+    handle: MetricsHandle,
+}
+
+impl<R> HasPayload for MetricsRequest<R>
+where
+    R: HasPayload,
+{
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.request.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.request.payload_ref()
+    }
+}
+
+impl<R> Request for MetricsRequest<R>
+where
+    R: Request<Err = RequestError>,
+{
+    type Err = RequestError;
+    type Send = MetricsSend<R::Send>;
+    type SendRef = MetricsSend<R::SendRef>;
+
+    fn send(self) -> Self::Send {
+        MetricsSend::new(self.request.send(), R::Payload::NAME, self.handle)
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        MetricsSend::new(
+            self.request.send_ref(),
+            R::Payload::NAME,
+            self.handle.clone(),
+        )
+    }
+}
+
+#[pin_project::pin_project]
+pub struct MetricsSend<F> {
+    #[pin]
+    fut: F,
+    method: &'static str,
+    handle: MetricsHandle,
+    // Set on the first poll, since requests are lazy.
+    started: Option<(Instant, Arc<Counters>)>,
+}
+
+impl<F> MetricsSend<F> {
+    fn new(fut: F, method: &'static str, handle: MetricsHandle) -> Self {
+        Self {
+            fut,
+            method,
+            handle,
+            started: None,
+        }
+    }
+}
+
+impl<F, T> Future for MetricsSend<F>
+where
+    F: Future<Output = Result<T, RequestError>>,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let (handle, method) = (&*this.handle, *this.method);
+        let (started, counters) = this
+            .started
+            .get_or_insert_with(|| (Instant::now(), handle.record_start(method)));
+
+        let res = futures::ready!(this.fut.poll(cx));
+        counters.record_finish(started.elapsed(), &res);
+
+        Poll::Ready(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records() {
+        let handle = MetricsHandle::default();
+
+        let send_message = handle.record_start("SendMessage");
+        handle.record_start("SendMessage");
+        handle.record_start("GetMe");
+        send_message.record_finish::<()>(Duration::from_millis(70), &Ok(()));
+        send_message
+            .record_finish::<()>(Duration::from_secs(60), &Err(RequestError::RetryAfter(10)));
+
+        let snapshot = handle.snapshot();
+
+        let send_message = &snapshot["SendMessage"];
+        assert_eq!(send_message.requests, 2);
+        assert_eq!(send_message.successes, 1);
+        assert_eq!(send_message.errors["RetryAfter"], 1);
+        assert_eq!(send_message.latency.count(), 2);
+        assert_eq!(send_message.latency.sum(), Duration::from_millis(60_070));
+
+        let buckets: Vec<_> = send_message.latency.buckets().collect();
+        assert_eq!(buckets[1], (Some(Duration::from_millis(100)), 1));
+        assert_eq!(buckets[9], (None, 1));
+
+        let get_me = &snapshot["GetMe"];
+        assert_eq!(get_me.requests, 1);
+        assert_eq!(get_me.successes, 0);
+        assert_eq!(get_me.latency.count(), 0);
+
+        assert_eq!(send_message.errors.len(), 1);
+
+        handle.reset();
+        assert!(handle.snapshot().is_empty());
+    }
+
+    #[test]
+    fn error_kinds() {
+        let err = RequestError::InvalidBotCommand {
+            command: "Start".to_owned(),
+        };
+        assert_eq!(ERROR_KINDS[error_kind(&err)], "InvalidBotCommand");
+        assert_eq!(
+            ERROR_KINDS[error_kind(&RequestError::CircuitOpen)],
+            "CircuitOpen"
+        );
+    }
+}
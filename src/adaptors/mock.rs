@@ -0,0 +1,346 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use futures::future::{ready, Ready};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    requests::{HasPayload, Output, Payload, Request, Requester, ResponseResult},
+    types::*,
+    RequestError,
+};
+
+/// A bot which records requests instead of sending them.
+///
+/// This bot wrapper is useful for testing code which sends requests: instead
+/// of sending a request, `Mock` records its method name and payload
+/// (serialized to JSON) and returns a response enqueued with
+/// [`MockHandle::push_response`] or [`MockHandle::push_error`]. Responses are
+/// returned in the order they were enqueued, regardless of the method.
+///
+/// The inner bot is only used to create requests, nothing is sent to
+/// Telegram.
+///
+/// Recorded requests and enqueued responses can be accessed through
+/// [`MockHandle`] (see [`Mock::handle`]).
+///
+/// ## Panics
+///
+/// Sending a request panics if there are no enqueued responses.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{
+///     requests::{Request, Requester, RequesterExt},
+///     types::{ChatId, MessageId, True},
+///     Bot,
+/// };
+///
+/// # async {
+/// let bot = Bot::new("TOKEN").mock();
+/// let handle = bot.handle();
+///
+/// handle.push_response(True);
+/// bot.delete_message(ChatId::Id(42), MessageId(1)).send().await?;
+///
+/// let requests = handle.take_requests();
+/// assert_eq!(requests[0].method, "DeleteMessage");
+/// assert_eq!(requests[0].payload["chat_id"], 42);
+/// # Ok::<_, teloxide_core::RequestError>(()) };
+/// ```
+#[derive(Clone, Debug)]
+pub struct Mock<B> {
+    bot: B,
+    handle: MockHandle,
+}
+
+impl<B> Mock<B> {
+    /// Creates new `Mock`.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::mock`] instead.
+    ///
+    /// [`RequesterExt::mock`]: crate::requests::RequesterExt::mock
+    pub fn new(bot: B) -> Self {
+        Self {
+            bot,
+            handle: MockHandle::default(),
+        }
+    }
+
+    /// Returns a handle to the recorded requests and enqueued responses.
+    ///
+    /// The handle is shared between all clones of this bot.
+    pub fn handle(&self) -> MockHandle {
+        self.handle.clone()
+    }
+
+    /// Allows to access the inner bot.
+    pub fn inner(&self) -> &B {
+        &self.bot
+    }
+
+    /// Unwraps the inner bot.
+    pub fn into_inner(self) -> B {
+        self.bot
+    }
+}
+
+/// A handle to requests recorded by [`Mock`] and responses which it returns.
+///
+/// Cloning the handle is cheap, all clones refer to the same requests and
+/// responses.
+#[derive(Clone, Debug, Default)]
+pub struct MockHandle {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    requests: Vec<RecordedRequest>,
+    responses: VecDeque<ResponseResult<Value>>,
+}
+
+/// A request recorded by [`Mock`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct RecordedRequest {
+    /// Name of the Telegram method (e.g. `"SendMessage"`).
+    pub method: &'static str,
+
+    /// Payload of the request, serialized to JSON.
+    pub payload: Value,
+}
+
+impl MockHandle {
+    /// Enqueues a successful response.
+    ///
+    /// `result` is deserialized into the output of the request it's returned
+    /// for, so it must be compatible with it (e.g. [`Message`] for
+    /// [`SendMessage`] or [`True`] for [`DeleteMessage`]). Otherwise the
+    /// request fails with [`RequestError::InvalidJson`].
+    ///
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `result` fails to serialize.
+    ///
+    /// [`SendMessage`]: crate::payloads::SendMessage
+    /// [`DeleteMessage`]: crate::payloads::DeleteMessage
+    pub fn push_response<T>(&self, result: T)
+    where
+        T: Serialize,
+    {
+        let result = serde_json::to_value(result).expect("responses must be serializable");
+        self.lock().responses.push_back(Ok(result));
+    }
+
+    /// Enqueues an error response.
+    pub fn push_error(&self, error: RequestError) {
+        self.lock().responses.push_back(Err(error));
+    }
+
+    /// Returns the requests recorded so far.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// Returns the requests recorded so far and clears them.
+    pub fn take_requests(&self) -> Vec<RecordedRequest> {
+        std::mem::take(&mut self.lock().requests)
+    }
+
+    /// Returns the number of enqueued responses which weren't returned yet.
+    pub fn pending_responses(&self) -> usize {
+        self.lock().responses.len()
+    }
+
+    fn respond<P>(&self, payload: &P) -> ResponseResult<P::Output>
+    where
+        P: Payload + Serialize,
+        P::Output: DeserializeOwned,
+    {
+        let mut state = self.lock();
+
+        state.requests.push(RecordedRequest {
+            method: P::NAME,
+            payload: serde_json::to_value(payload).expect("payloads are always serializable"),
+        });
+
+        match state.responses.pop_front() {
+            Some(res) => {
+                let res = res?;
+                P::Output::deserialize(&res)
+                    .map_err(|err| RequestError::invalid_json(P::NAME, res.to_string(), err))
+            }
+            None => panic!("no response enqueued for a `{}` request", P::NAME),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MockState> {
+        // The state is only updated with simple operations which can't leave it in an
+        // inconsistent state, so it's ok to ignore poisoning.
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+macro_rules! f {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        MockRequest {
+            request: $this.inner().$m($($arg),*),
+            handle: $this.handle.clone(),
+        }
+    };
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        MockRequest<B::$T>
+    };
+}
+
+impl<B> Requester for Mock<B>
+where
+    B: Requester,
+{
+    type Err = RequestError;
+
+    requester_forward! {
+        get_me, log_out, close, get_updates, set_webhook, delete_webhook, get_webhook_info,
+        forward_message, forward_messages, copy_message, copy_messages,
+        send_message, send_photo, send_audio, send_document,
+        send_video, send_animation, send_voice, send_video_note, send_media_group,
+        send_paid_media, send_location,
+        edit_message_live_location, edit_message_live_location_inline,
+        stop_message_live_location, stop_message_live_location_inline, send_venue,
+        send_contact, send_poll, send_dice, send_chat_action, get_user_profile_photos,
+        get_file, kick_chat_member, ban_chat_member, unban_chat_member, restrict_chat_member,
+        promote_chat_member, set_chat_administrator_custom_title, set_chat_permissions,
+        export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
+        revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
+        set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
+        leave_chat, get_chat, get_chat_administrators, get_chat_members_count,get_chat_member,
+        set_chat_sticker_set, delete_chat_sticker_set, answer_callback_query,
+        set_my_commands, get_my_commands, delete_my_commands, set_chat_menu_button,
+        get_chat_menu_button, answer_inline_query, edit_message_text,
+        edit_message_text_inline, edit_message_caption, edit_message_caption_inline,
+        edit_message_media, edit_message_media_inline, edit_message_reply_markup,
+        edit_message_reply_markup_inline, stop_poll, delete_message,
+        set_message_reaction, send_sticker,
+        get_sticker_set, upload_sticker_file, create_new_sticker_set,
+        add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
+        set_sticker_set_thumb, send_invoice, create_invoice_link, answer_shipping_query,
+        answer_pre_checkout_query, refund_star_payment, set_passport_data_errors, send_game,
+        set_game_score, set_game_score_inline, get_game_high_scores,
+        get_updates_fault_tolerant => f, fty
+    }
+}
+
+#[derive(Clone)]
+pub struct MockRequest<R> {
+    request: R,
+    handle: MockHandle,
+}
+
+impl<R> HasPayload for MockRequest<R>
+where
+    R: HasPayload,
+{
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.request.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.request.payload_ref()
+    }
+}
+
+impl<R> Request for MockRequest<R>
+where
+    R: HasPayload,
+    R::Payload: Serialize,
+    Output<R>: DeserializeOwned + Send,
+{
+    type Err = RequestError;
+    type Send = Ready<ResponseResult<Output<R>>>;
+    type SendRef = Ready<ResponseResult<Output<R>>>;
+
+    fn send(self) -> Self::Send {
+        self.send_ref()
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        ready(self.handle.respond(self.request.payload_ref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        payloads::SendMessageSetters,
+        requests::{Request, Requester},
+        types::{ParseMode, True},
+        ApiError, Bot,
+    };
+
+    use super::*;
+
+    #[test]
+    fn records_requests() {
+        let bot = Mock::new(Bot::new("TOKEN"));
+        let handle = bot.handle();
+
+        handle.push_response(True);
+        handle.push_error(RequestError::ApiError {
+            kind: ApiError::ChatNotFound,
+            status_code: reqwest::StatusCode::BAD_REQUEST,
+        });
+        // Not a `Chat`
+        handle.push_response(1);
+
+        let res = bot.delete_message(ChatId::Id(1), MessageId(1)).send_ref();
+        assert_eq!(futures::executor::block_on(res).unwrap(), True);
+
+        let res = bot
+            .send_message(ChatId::Id(2), "text")
+            .parse_mode(ParseMode::Html)
+            .send();
+        assert!(matches!(
+            futures::executor::block_on(res),
+            Err(RequestError::ApiError {
+                kind: ApiError::ChatNotFound,
+                ..
+            })
+        ));
+
+        let res = bot.get_chat(ChatId::Id(3)).send();
+        assert!(matches!(
+            futures::executor::block_on(res),
+            Err(RequestError::InvalidJson { .. })
+        ));
+
+        assert_eq!(handle.pending_responses(), 0);
+
+        let requests = handle.take_requests();
+        assert!(handle.requests().is_empty());
+
+        let methods: Vec<_> = requests.iter().map(|r| r.method).collect();
+        assert_eq!(methods, ["DeleteMessage", "SendMessage", "GetChat"]);
+        assert_eq!(
+            requests[1].payload,
+            serde_json::json!({ "chat_id": 2, "text": "text", "parse_mode": "HTML" })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "no response enqueued for a `GetMe` request")]
+    fn no_response() {
+        let bot = Mock::new(Bot::new("TOKEN"));
+        drop(bot.get_me().send());
+    }
+}
@@ -0,0 +1,454 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use futures::future::BoxFuture;
+
+use crate::{
+    errors::ApiError,
+    requests::{HasChatId, HasPayload, Output, Request, Requester},
+    types::*,
+    RequestError,
+};
+
+/// Resolves `@username`s of chats to numeric ids and caches them.
+///
+/// When a request is sent to a [`ChatId::ChannelUsername`], the username is
+/// resolved with [`GetChat`] (only the first time, the result is cached) and
+/// the request is sent to the numeric id instead. If the username can't be
+/// resolved, the error of `GetChat` is returned and the request isn't sent.
+///
+/// A cached id is invalidated when a request to it fails with
+/// [`ApiError::ChatNotFound`] or [`RequestError::MigrateToChatId`], so the
+/// username is resolved again by the next request.
+///
+/// Clones of `ResolveUsernames` share the cache.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use teloxide_core::{
+///     requests::{Request, Requester, RequesterExt},
+///     types::ChatId,
+///     Bot,
+/// };
+///
+/// # async {
+/// let bot = Bot::new("TOKEN").resolve_usernames();
+///
+/// // Fails early if the username is invalid
+/// let id = bot.resolve("@channel").await?;
+///
+/// // Sent to `id`
+/// let channel = ChatId::ChannelUsername("@channel".to_owned());
+/// bot.send_message(channel, "Hi!").send().await?;
+/// # Ok::<_, teloxide_core::RequestError>(()) };
+/// ```
+///
+/// [`GetChat`]: crate::payloads::GetChat
+#[derive(Debug)]
+pub struct ResolveUsernames<B> {
+    bot: Arc<B>,
+    cache: Arc<Cache>,
+}
+
+type Cache = Mutex<HashMap<String, i64>>;
+
+impl<B> ResolveUsernames<B> {
+    /// Creates new `ResolveUsernames` with an empty cache.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::resolve_usernames`]
+    /// instead.
+    ///
+    /// [`RequesterExt::resolve_usernames`]: crate::requests::RequesterExt::resolve_usernames
+    pub fn new(bot: B) -> Self {
+        Self {
+            bot: Arc::new(bot),
+            cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Allows to access the inner bot.
+    pub fn inner(&self) -> &B {
+        &self.bot
+    }
+
+    /// Returns the cached id of the chat with `username`, if any.
+    ///
+    /// This method never sends requests, see also [`ResolveUsernames::resolve`].
+    pub fn cached(&self, username: &str) -> Option<i64> {
+        lock(&self.cache).get(&key(username)).copied()
+    }
+
+    /// Removes `username` from the cache, so it's resolved again by the next
+    /// request.
+    pub fn invalidate(&self, username: &str) {
+        lock(&self.cache).remove(&key(username));
+    }
+
+    /// Clears the cache.
+    pub fn clear(&self) {
+        lock(&self.cache).clear();
+    }
+}
+
+impl<B> ResolveUsernames<B>
+where
+    B: Requester<Err = RequestError>,
+{
+    /// Returns the id of the chat with `username`, sending a [`GetChat`]
+    /// request if it isn't cached yet.
+    ///
+    /// [`GetChat`]: crate::payloads::GetChat
+    pub async fn resolve(&self, username: &str) -> Result<i64, RequestError> {
+        resolve(&*self.bot, &self.cache, username).await
+    }
+}
+
+impl<B> Clone for ResolveUsernames<B> {
+    fn clone(&self) -> Self {
+        Self {
+            bot: Arc::clone(&self.bot),
+            cache: Arc::clone(&self.cache),
+        }
+    }
+}
+
+/// Usernames are case-insensitive.
+fn key(username: &str) -> String {
+    username.to_lowercase()
+}
+
+fn lock(cache: &Cache) -> MutexGuard<'_, HashMap<String, i64>> {
+    // Entries are inserted and removed atomically, so the cache can't be left
+    // in an inconsistent state and it's ok to ignore poisoning.
+    cache.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+async fn resolve<B>(bot: &B, cache: &Cache, username: &str) -> Result<i64, RequestError>
+where
+    B: Requester<Err = RequestError>,
+{
+    if let Some(&id) = lock(cache).get(&key(username)) {
+        return Ok(id);
+    }
+
+    let chat = bot
+        .get_chat(ChatId::ChannelUsername(username.to_owned()))
+        .send()
+        .await?;
+    lock(cache).insert(key(username), chat.id);
+
+    Ok(chat.id)
+}
+
+macro_rules! f {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        ResolveRequest {
+            bot: Arc::clone(&$this.bot),
+            cache: Arc::clone(&$this.cache),
+            request: $this.inner().$m($($arg),*),
+        }
+    };
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        ResolveRequest<B, B::$T>
+    };
+}
+
+impl<B> Requester for ResolveUsernames<B>
+where
+    B: Requester<Err = RequestError> + Send + Sync + 'static,
+    B::GetUpdates: Clone + Send,
+    B::SetWebhook: Clone + Send,
+    B::DeleteWebhook: Clone + Send,
+    B::GetWebhookInfo: Clone + Send,
+    B::GetMe: Clone + Send,
+    B::LogOut: Clone + Send,
+    B::Close: Clone + Send,
+    B::SendMessage: Clone + Send,
+    B::ForwardMessage: Clone + Send,
+    B::ForwardMessages: Clone + Send,
+    B::CopyMessage: Clone + Send,
+    B::CopyMessages: Clone + Send,
+    B::SendPhoto: Clone + Send,
+    B::SendAudio: Clone + Send,
+    B::SendDocument: Clone + Send,
+    B::SendVideo: Clone + Send,
+    B::SendAnimation: Clone + Send,
+    B::SendVoice: Clone + Send,
+    B::SendVideoNote: Clone + Send,
+    B::SendMediaGroup: Clone + Send,
+    B::SendPaidMedia: Clone + Send,
+    B::SendLocation: Clone + Send,
+    B::EditMessageLiveLocation: Clone + Send,
+    B::EditMessageLiveLocationInline: Clone + Send,
+    B::StopMessageLiveLocation: Clone + Send,
+    B::StopMessageLiveLocationInline: Clone + Send,
+    B::SendVenue: Clone + Send,
+    B::SendContact: Clone + Send,
+    B::SendPoll: Clone + Send,
+    B::SendDice: Clone + Send,
+    B::SendChatAction: Clone + Send,
+    B::GetUserProfilePhotos: Clone + Send,
+    B::GetFile: Clone + Send,
+    B::KickChatMember: Clone + Send,
+    B::BanChatMember: Clone + Send,
+    B::UnbanChatMember: Clone + Send,
+    B::RestrictChatMember: Clone + Send,
+    B::PromoteChatMember: Clone + Send,
+    B::SetChatAdministratorCustomTitle: Clone + Send,
+    B::SetChatPermissions: Clone + Send,
+    B::ExportChatInviteLink: Clone + Send,
+    B::CreateChatInviteLink: Clone + Send,
+    B::EditChatInviteLink: Clone + Send,
+    B::RevokeChatInviteLink: Clone + Send,
+    B::SetChatPhoto: Clone + Send,
+    B::DeleteChatPhoto: Clone + Send,
+    B::SetChatTitle: Clone + Send,
+    B::SetChatDescription: Clone + Send,
+    B::PinChatMessage: Clone + Send,
+    B::UnpinChatMessage: Clone + Send,
+    B::UnpinAllChatMessages: Clone + Send,
+    B::LeaveChat: Clone + Send,
+    B::GetChat: Clone + Send,
+    B::GetChatAdministrators: Clone + Send,
+    B::GetChatMembersCount: Clone + Send,
+    B::GetChatMember: Clone + Send,
+    B::SetChatStickerSet: Clone + Send,
+    B::DeleteChatStickerSet: Clone + Send,
+    B::AnswerCallbackQuery: Clone + Send,
+    B::SetMyCommands: Clone + Send,
+    B::GetMyCommands: Clone + Send,
+    B::DeleteMyCommands: Clone + Send,
+    B::SetChatMenuButton: Clone + Send,
+    B::GetChatMenuButton: Clone + Send,
+    B::AnswerInlineQuery: Clone + Send,
+    B::EditMessageText: Clone + Send,
+    B::EditMessageTextInline: Clone + Send,
+    B::EditMessageCaption: Clone + Send,
+    B::EditMessageCaptionInline: Clone + Send,
+    B::EditMessageMedia: Clone + Send,
+    B::EditMessageMediaInline: Clone + Send,
+    B::EditMessageReplyMarkup: Clone + Send,
+    B::EditMessageReplyMarkupInline: Clone + Send,
+    B::StopPoll: Clone + Send,
+    B::DeleteMessage: Clone + Send,
+    B::SetMessageReaction: Clone + Send,
+    B::SendSticker: Clone + Send,
+    B::GetStickerSet: Clone + Send,
+    B::UploadStickerFile: Clone + Send,
+    B::CreateNewStickerSet: Clone + Send,
+    B::AddStickerToSet: Clone + Send,
+    B::SetStickerPositionInSet: Clone + Send,
+    B::DeleteStickerFromSet: Clone + Send,
+    B::SetStickerSetThumb: Clone + Send,
+    B::SendInvoice: Clone + Send,
+    B::CreateInvoiceLink: Clone + Send,
+    B::AnswerShippingQuery: Clone + Send,
+    B::AnswerPreCheckoutQuery: Clone + Send,
+    B::RefundStarPayment: Clone + Send,
+    B::SetPassportDataErrors: Clone + Send,
+    B::SendGame: Clone + Send,
+    B::SetGameScore: Clone + Send,
+    B::SetGameScoreInline: Clone + Send,
+    B::GetGameHighScores: Clone + Send,
+    B::GetUpdatesFaultTolerant: Clone + Send,
+{
+    type Err = RequestError;
+
+    requester_forward! {
+        get_me, log_out, close, get_updates, set_webhook, delete_webhook, get_webhook_info,
+        forward_message, forward_messages, copy_message, copy_messages,
+        send_message, send_photo, send_audio, send_document,
+        send_video, send_animation, send_voice, send_video_note, send_media_group,
+        send_paid_media, send_location,
+        edit_message_live_location, edit_message_live_location_inline,
+        stop_message_live_location, stop_message_live_location_inline, send_venue,
+        send_contact, send_poll, send_dice, send_chat_action, get_user_profile_photos,
+        get_file, kick_chat_member, ban_chat_member, unban_chat_member, restrict_chat_member,
+        promote_chat_member, set_chat_administrator_custom_title, set_chat_permissions,
+        export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
+        revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
+        set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
+        leave_chat, get_chat, get_chat_administrators, get_chat_members_count,get_chat_member,
+        set_chat_sticker_set, delete_chat_sticker_set, answer_callback_query,
+        set_my_commands, get_my_commands, delete_my_commands, set_chat_menu_button,
+        get_chat_menu_button, answer_inline_query, edit_message_text,
+        edit_message_text_inline, edit_message_caption, edit_message_caption_inline,
+        edit_message_media, edit_message_media_inline, edit_message_reply_markup,
+        edit_message_reply_markup_inline, stop_poll, delete_message,
+        set_message_reaction, send_sticker,
+        get_sticker_set, upload_sticker_file, create_new_sticker_set,
+        add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
+        set_sticker_set_thumb, send_invoice, create_invoice_link, answer_shipping_query,
+        answer_pre_checkout_query, refund_star_payment, set_passport_data_errors, send_game,
+        set_game_score, set_game_score_inline, get_game_high_scores,
+        get_updates_fault_tolerant => f, fty
+    }
+}
+
+download_forward! {
+    'w
+    B
+    ResolveUsernames<B>
+    { this => this.inner() }
+}
+
+pub struct ResolveRequest<B, R> {
+    bot: Arc<B>,
+    cache: Arc<Cache>,
+    request: R,
+}
+
+impl<B, R: Clone> Clone for ResolveRequest<B, R> {
+    fn clone(&self) -> Self {
+        Self {
+            bot: Arc::clone(&self.bot),
+            cache: Arc::clone(&self.cache),
+            request: self.request.clone(),
+        }
+    }
+}
+
+impl<B, R> ResolveRequest<B, R>
+where
+    B: Requester<Err = RequestError>,
+    R: Request<Err = RequestError>,
+    R::Payload: HasChatId,
+{
+    async fn resolve_and_send(mut self) -> Result<Output<R>, RequestError> {
+        let username = match self.request.payload_ref().chat_id() {
+            Some(ChatId::ChannelUsername(username)) => Some(username.clone()),
+            _ => None,
+        };
+
+        if let Some(username) = &username {
+            let id = resolve(&*self.bot, &self.cache, username).await?;
+            if let Some(chat_id) = self.request.payload_mut().chat_id_mut() {
+                *chat_id = ChatId::Id(id);
+            }
+        }
+
+        let res = self.request.send().await;
+        if let (Some(username), Err(err)) = (&username, &res) {
+            if chat_is_gone(err) {
+                lock(&self.cache).remove(&key(username));
+            }
+        }
+
+        res
+    }
+}
+
+fn chat_is_gone(err: &RequestError) -> bool {
+    matches!(
+        err,
+        RequestError::MigrateToChatId(_)
+            | RequestError::ApiError {
+                kind: ApiError::ChatNotFound,
+                ..
+            }
+    )
+}
+
+impl<B, R> HasPayload for ResolveRequest<B, R>
+where
+    R: HasPayload,
+{
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.request.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.request.payload_ref()
+    }
+}
+
+impl<B, R> Request for ResolveRequest<B, R>
+where
+    B: Requester<Err = RequestError> + Send + Sync + 'static,
+    R: Request<Err = RequestError> + Clone + Send + 'static,
+    R::Payload: HasChatId,
+{
+    type Err = RequestError;
+
+    type Send = BoxFuture<'static, Result<Output<R>, RequestError>>;
+
+    type SendRef = BoxFuture<'static, Result<Output<R>, RequestError>>;
+
+    fn send(self) -> Self::Send {
+        Box::pin(self.resolve_and_send())
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        Box::pin(self.clone().resolve_and_send())
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use futures::executor::block_on;
+
+    use crate::{requests::RequesterExt, Bot};
+
+    use super::*;
+
+    fn channel() -> Chat {
+        serde_json::from_value(serde_json::json!({
+            "id": -1001,
+            "type": "channel",
+            "title": "Channel",
+            "username": "channel"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn resolves_once() {
+        let bot = Bot::new("TOKEN").mock();
+        let handle = bot.handle();
+        let bot = bot.resolve_usernames();
+
+        let username = || ChatId::ChannelUsername("@Channel".to_owned());
+
+        handle.push_response(channel());
+        handle.push_response(True);
+        block_on(bot.delete_message(username(), MessageId(1)).send()).unwrap();
+
+        handle.push_response(True);
+        block_on(bot.delete_message(username(), MessageId(2)).send_ref()).unwrap();
+
+        assert_eq!(bot.cached("@channel"), Some(-1001));
+
+        let requests = handle.take_requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].method, "GetChat");
+        assert_eq!(requests[0].payload["chat_id"], "@Channel");
+        assert_eq!(requests[1].payload["chat_id"], -1001);
+        assert_eq!(requests[2].payload["chat_id"], -1001);
+    }
+
+    #[test]
+    fn invalidates() {
+        let bot = Bot::new("TOKEN").mock();
+        let handle = bot.handle();
+        let bot = bot.resolve_usernames();
+
+        handle.push_response(channel());
+        assert_eq!(block_on(bot.resolve("@channel")).unwrap(), -1001);
+
+        handle.push_error(RequestError::ApiError {
+            kind: ApiError::ChatNotFound,
+            status_code: reqwest::StatusCode::BAD_REQUEST,
+        });
+        let username = ChatId::ChannelUsername("@channel".to_owned());
+        block_on(bot.delete_message(username, MessageId(1)).send()).unwrap_err();
+        assert_eq!(bot.cached("@channel"), None);
+    }
+}
@@ -0,0 +1,458 @@
+use std::{fmt, future::Future, pin::Pin, sync::Arc, time::Duration};
+
+use futures::task::{Context, Poll};
+
+use crate::{
+    requests::{HasPayload, Output, Request, Requester},
+    types::*,
+    RequestError,
+};
+
+/// A strategy of computing delays between retries of failed network requests.
+///
+/// See also [`ExponentialBackoff`].
+pub trait Backoff {
+    /// Returns the delay before the `retry`-th retry (starting from `1`).
+    fn delay(&self, retry: u32) -> Duration;
+}
+
+impl<F> Backoff for F
+where
+    F: Fn(u32) -> Duration,
+{
+    fn delay(&self, retry: u32) -> Duration {
+        self(retry)
+    }
+}
+
+/// Exponential backoff, the delay is doubled after each retry.
+///
+/// The `n`-th delay is `min(initial * 2^(n - 1), max)`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ExponentialBackoff {
+    /// Delay before the first retry.
+    pub initial: Duration,
+
+    /// Upper bound of the delay.
+    pub max: Duration,
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self {
+            initial: Duration::from_millis(500),
+            max: Duration::from_secs(30),
+        }
+    }
+}
+
+impl Backoff for ExponentialBackoff {
+    fn delay(&self, retry: u32) -> Duration {
+        let factor = 1u32
+            .checked_shl(retry.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+
+        self.initial
+            .checked_mul(factor)
+            .map_or(self.max, |delay| delay.min(self.max))
+    }
+}
+
+/// Automatic retrying of requests which failed because of flood control (and,
+/// optionally, because of network errors).
+///
+/// When Telegram returns [`RequestError::RetryAfter(secs)`] this bot wrapper
+/// waits for `secs` seconds and then sends the request again, up to
+/// `max_attempts` times in total.
+///
+/// Network errors ([`RequestError::NetworkError`]) are not retried by default,
+/// since the request could have reached Telegram before the error happened
+/// (e.g. `send_message` may then send the message twice). To retry them
+/// anyway, use [`Retry::retry_network_errors`].
+///
+/// Note: internally requests are re-sent by [`send_ref`], so wrapped requests
+/// need to be `Clone` (all requests of [`Bot`] are).
+///
+/// [`RequestError::RetryAfter(secs)`]: crate::RequestError::RetryAfter
+/// [`send_ref`]: crate::requests::Request::send_ref
+/// [`Bot`]: crate::Bot
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{
+///     adaptors::retry::ExponentialBackoff,
+///     requests::{Request, Requester, RequesterExt},
+///     Bot,
+/// };
+///
+/// # async {
+/// let bot = Bot::new("TOKEN")
+///     .retry(5)
+///     .retry_network_errors(ExponentialBackoff::default());
+///
+/// bot.send_message(0, "I'll get through!").send().await?;
+/// # Ok::<_, teloxide_core::RequestError>(()) };
+/// ```
+#[derive(Clone)]
+pub struct Retry<B> {
+    bot: B,
+    policy: Arc<Policy>,
+}
+
+struct Policy {
+    max_attempts: u32,
+    backoff: Option<Box<dyn Backoff + Send + Sync>>,
+}
+
+impl<B> Retry<B> {
+    /// Creates new `Retry` which sends requests at most `max_attempts` times.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::retry`] instead.
+    ///
+    /// [`RequesterExt::retry`]: crate::requests::RequesterExt::retry
+    pub fn new(bot: B, max_attempts: u32) -> Self {
+        Self {
+            bot,
+            policy: Arc::new(Policy {
+                max_attempts,
+                backoff: None,
+            }),
+        }
+    }
+
+    /// Enables retrying of requests failed because of network errors, waiting
+    /// between attempts in accordance with `backoff`.
+    ///
+    /// Note: this only affects `self`, clones made before calling this method
+    /// are unaffected.
+    pub fn retry_network_errors<S>(mut self, backoff: S) -> Self
+    where
+        S: Backoff + Send + Sync + 'static,
+    {
+        self.policy = Arc::new(Policy {
+            max_attempts: self.policy.max_attempts,
+            backoff: Some(Box::new(backoff)),
+        });
+        self
+    }
+
+    /// Returns maximum number of attempts to send a request.
+    pub fn max_attempts(&self) -> u32 {
+        self.policy.max_attempts
+    }
+
+    /// Allows to access the inner bot.
+    pub fn inner(&self) -> &B {
+        &self.bot
+    }
+
+    /// Unwraps the inner bot.
+    pub fn into_inner(self) -> B {
+        self.bot
+    }
+}
+
+impl<B: fmt::Debug> fmt::Debug for Retry<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Retry")
+            .field("bot", &self.bot)
+            .field("max_attempts", &self.policy.max_attempts)
+            .field("retry_network_errors", &self.policy.backoff.is_some())
+            .finish()
+    }
+}
+
+macro_rules! f {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        RetryRequest {
+            request: Arc::new($this.inner().$m($($arg),*)),
+            policy: Arc::clone(&$this.policy),
+        }
+    };
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        RetryRequest<B::$T>
+    };
+}
+
+impl<B> Requester for Retry<B>
+where
+    B: Requester<Err = RequestError>,
+    B::GetUpdates: Clone + Send + Sync,
+    B::SetWebhook: Clone + Send + Sync,
+    B::DeleteWebhook: Clone + Send + Sync,
+    B::GetWebhookInfo: Clone + Send + Sync,
+    B::GetMe: Clone + Send + Sync,
+    B::LogOut: Clone + Send + Sync,
+    B::Close: Clone + Send + Sync,
+    B::SendMessage: Clone + Send + Sync,
+    B::ForwardMessage: Clone + Send + Sync,
+    B::ForwardMessages: Clone + Send + Sync,
+    B::CopyMessage: Clone + Send + Sync,
+    B::CopyMessages: Clone + Send + Sync,
+    B::SendPhoto: Clone + Send + Sync,
+    B::SendAudio: Clone + Send + Sync,
+    B::SendDocument: Clone + Send + Sync,
+    B::SendVideo: Clone + Send + Sync,
+    B::SendAnimation: Clone + Send + Sync,
+    B::SendVoice: Clone + Send + Sync,
+    B::SendVideoNote: Clone + Send + Sync,
+    B::SendMediaGroup: Clone + Send + Sync,
+    B::SendPaidMedia: Clone + Send + Sync,
+    B::SendLocation: Clone + Send + Sync,
+    B::EditMessageLiveLocation: Clone + Send + Sync,
+    B::EditMessageLiveLocationInline: Clone + Send + Sync,
+    B::StopMessageLiveLocation: Clone + Send + Sync,
+    B::StopMessageLiveLocationInline: Clone + Send + Sync,
+    B::SendVenue: Clone + Send + Sync,
+    B::SendContact: Clone + Send + Sync,
+    B::SendPoll: Clone + Send + Sync,
+    B::SendDice: Clone + Send + Sync,
+    B::SendChatAction: Clone + Send + Sync,
+    B::GetUserProfilePhotos: Clone + Send + Sync,
+    B::GetFile: Clone + Send + Sync,
+    B::KickChatMember: Clone + Send + Sync,
+    B::BanChatMember: Clone + Send + Sync,
+    B::UnbanChatMember: Clone + Send + Sync,
+    B::RestrictChatMember: Clone + Send + Sync,
+    B::PromoteChatMember: Clone + Send + Sync,
+    B::SetChatAdministratorCustomTitle: Clone + Send + Sync,
+    B::SetChatPermissions: Clone + Send + Sync,
+    B::ExportChatInviteLink: Clone + Send + Sync,
+    B::CreateChatInviteLink: Clone + Send + Sync,
+    B::EditChatInviteLink: Clone + Send + Sync,
+    B::RevokeChatInviteLink: Clone + Send + Sync,
+    B::SetChatPhoto: Clone + Send + Sync,
+    B::DeleteChatPhoto: Clone + Send + Sync,
+    B::SetChatTitle: Clone + Send + Sync,
+    B::SetChatDescription: Clone + Send + Sync,
+    B::PinChatMessage: Clone + Send + Sync,
+    B::UnpinChatMessage: Clone + Send + Sync,
+    B::UnpinAllChatMessages: Clone + Send + Sync,
+    B::LeaveChat: Clone + Send + Sync,
+    B::GetChat: Clone + Send + Sync,
+    B::GetChatAdministrators: Clone + Send + Sync,
+    B::GetChatMembersCount: Clone + Send + Sync,
+    B::GetChatMember: Clone + Send + Sync,
+    B::SetChatStickerSet: Clone + Send + Sync,
+    B::DeleteChatStickerSet: Clone + Send + Sync,
+    B::AnswerCallbackQuery: Clone + Send + Sync,
+    B::SetMyCommands: Clone + Send + Sync,
+    B::GetMyCommands: Clone + Send + Sync,
+    B::DeleteMyCommands: Clone + Send + Sync,
+    B::SetChatMenuButton: Clone + Send + Sync,
+    B::GetChatMenuButton: Clone + Send + Sync,
+    B::AnswerInlineQuery: Clone + Send + Sync,
+    B::EditMessageText: Clone + Send + Sync,
+    B::EditMessageTextInline: Clone + Send + Sync,
+    B::EditMessageCaption: Clone + Send + Sync,
+    B::EditMessageCaptionInline: Clone + Send + Sync,
+    B::EditMessageMedia: Clone + Send + Sync,
+    B::EditMessageMediaInline: Clone + Send + Sync,
+    B::EditMessageReplyMarkup: Clone + Send + Sync,
+    B::EditMessageReplyMarkupInline: Clone + Send + Sync,
+    B::StopPoll: Clone + Send + Sync,
+    B::DeleteMessage: Clone + Send + Sync,
+    B::SetMessageReaction: Clone + Send + Sync,
+    B::SendSticker: Clone + Send + Sync,
+    B::GetStickerSet: Clone + Send + Sync,
+    B::UploadStickerFile: Clone + Send + Sync,
+    B::CreateNewStickerSet: Clone + Send + Sync,
+    B::AddStickerToSet: Clone + Send + Sync,
+    B::SetStickerPositionInSet: Clone + Send + Sync,
+    B::DeleteStickerFromSet: Clone + Send + Sync,
+    B::SetStickerSetThumb: Clone + Send + Sync,
+    B::SendInvoice: Clone + Send + Sync,
+    B::CreateInvoiceLink: Clone + Send + Sync,
+    B::AnswerShippingQuery: Clone + Send + Sync,
+    B::AnswerPreCheckoutQuery: Clone + Send + Sync,
+    B::RefundStarPayment: Clone + Send + Sync,
+    B::SetPassportDataErrors: Clone + Send + Sync,
+    B::SendGame: Clone + Send + Sync,
+    B::SetGameScore: Clone + Send + Sync,
+    B::SetGameScoreInline: Clone + Send + Sync,
+    B::GetGameHighScores: Clone + Send + Sync,
+    B::GetUpdatesFaultTolerant: Clone + Send + Sync,
+{
+    type Err = RequestError;
+
+    requester_forward! {
+        get_me, log_out, close, get_updates, set_webhook, delete_webhook, get_webhook_info,
+        forward_message, forward_messages, copy_message, copy_messages,
+        send_message, send_photo, send_audio, send_document,
+        send_video, send_animation, send_voice, send_video_note, send_media_group,
+        send_paid_media, send_location,
+        edit_message_live_location, edit_message_live_location_inline,
+        stop_message_live_location, stop_message_live_location_inline, send_venue,
+        send_contact, send_poll, send_dice, send_chat_action, get_user_profile_photos,
+        get_file, kick_chat_member, ban_chat_member, unban_chat_member, restrict_chat_member,
+        promote_chat_member, set_chat_administrator_custom_title, set_chat_permissions,
+        export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
+        revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
+        set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
+        leave_chat, get_chat, get_chat_administrators, get_chat_members_count,get_chat_member,
+        set_chat_sticker_set, delete_chat_sticker_set, answer_callback_query,
+        set_my_commands, get_my_commands, delete_my_commands, set_chat_menu_button,
+        get_chat_menu_button, answer_inline_query, edit_message_text,
+        edit_message_text_inline, edit_message_caption, edit_message_caption_inline,
+        edit_message_media, edit_message_media_inline, edit_message_reply_markup,
+        edit_message_reply_markup_inline, stop_poll, delete_message,
+        set_message_reaction, send_sticker,
+        get_sticker_set, upload_sticker_file, create_new_sticker_set,
+        add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
+        set_sticker_set_thumb, send_invoice, create_invoice_link, answer_shipping_query,
+        answer_pre_checkout_query, refund_star_payment, set_passport_data_errors, send_game,
+        set_game_score, set_game_score_inline, get_game_high_scores,
+        get_updates_fault_tolerant => f, fty
+    }
+}
+
+download_forward! {
+    'w
+    B
+    Retry<B>
+    { this => this.inner() }
+}
+
+pub struct RetryRequest<R> {
+    // `Arc` allows to re-send the request from `send_ref` without cloning it.
+    // The request is only cloned if it's modified while being sent.
+    request: Arc<R>,
+    policy: Arc<Policy>,
+}
+
+impl<R> HasPayload for RetryRequest<R>
+where
+    R: HasPayload + Clone,
+{
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        Arc::make_mut(&mut self.request).payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.request.payload_ref()
+    }
+}
+
+impl<R> Request for RetryRequest<R>
+where
+    R: Request<Err = RequestError> + Clone + Send + Sync,
+{
+    type Err = RequestError;
+    type Send = RetrySend<R>;
+    type SendRef = RetrySend<R>;
+
+    fn send(self) -> Self::Send {
+        RetrySend::new(self.request, self.policy)
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        RetrySend::new(Arc::clone(&self.request), Arc::clone(&self.policy))
+    }
+}
+
+#[pin_project::pin_project]
+pub struct RetrySend<R: Request> {
+    request: Arc<R>,
+    policy: Arc<Policy>,
+    attempt: u32,
+    #[pin]
+    state: RetryState<R::SendRef>,
+}
+
+#[pin_project::pin_project(project = RetryStateProj)]
+enum RetryState<F> {
+    Sending(#[pin] F),
+    Waiting(#[pin] tokio::time::Sleep),
+}
+
+impl<R: Request> RetrySend<R> {
+    fn new(request: Arc<R>, policy: Arc<Policy>) -> Self {
+        let state = RetryState::Sending(request.send_ref());
+
+        Self {
+            request,
+            policy,
+            attempt: 1,
+            state,
+        }
+    }
+}
+
+impl<R> Future for RetrySend<R>
+where
+    R: Request<Err = RequestError>,
+{
+    type Output = Result<Output<R>, RequestError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match this.state.as_mut().project() {
+                RetryStateProj::Sending(fut) => {
+                    let res = futures::ready!(fut.poll(cx));
+
+                    let delay = match &res {
+                        _ if *this.attempt >= this.policy.max_attempts => None,
+                        Err(RequestError::RetryAfter(secs)) => {
+                            Some(Duration::from_secs(*secs as u64))
+                        }
+                        Err(RequestError::NetworkError(_)) => this
+                            .policy
+                            .backoff
+                            .as_ref()
+                            .map(|backoff| backoff.delay(*this.attempt)),
+                        _ => None,
+                    };
+
+                    match delay {
+                        Some(delay) => {
+                            log::warn!(
+                                "Request failed (attempt {}/{}), retrying in {:?}",
+                                this.attempt,
+                                this.policy.max_attempts,
+                                delay
+                            );
+                            *this.attempt += 1;
+                            this.state
+                                .set(RetryState::Waiting(tokio::time::sleep(delay)));
+                        }
+                        None => return Poll::Ready(res),
+                    }
+                }
+                RetryStateProj::Waiting(sleep) => {
+                    futures::ready!(sleep.poll(cx));
+                    this.state.set(RetryState::Sending(this.request.send_ref()));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Backoff, ExponentialBackoff};
+
+    #[test]
+    fn exponential_backoff() {
+        let backoff = ExponentialBackoff {
+            initial: Duration::from_secs(1),
+            max: Duration::from_secs(10),
+        };
+
+        assert_eq!(backoff.delay(1), Duration::from_secs(1));
+        assert_eq!(backoff.delay(2), Duration::from_secs(2));
+        assert_eq!(backoff.delay(3), Duration::from_secs(4));
+        assert_eq!(backoff.delay(4), Duration::from_secs(8));
+        assert_eq!(backoff.delay(5), Duration::from_secs(10));
+        assert_eq!(backoff.delay(100), Duration::from_secs(10));
+    }
+}
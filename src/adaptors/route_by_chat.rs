@@ -0,0 +1,237 @@
+use std::{fmt, sync::Arc};
+
+use crate::{requests::Requester, types::*};
+
+/// Routes requests to one of several bots based on the chat of a request.
+///
+/// `selector` is called with the chat of every request which is sent to a
+/// chat and returns the index of the bot which sends it. Requests which aren't
+/// sent to a chat (e.g. [`GetMe`] or inline variants of `edit_message_*`
+/// methods) are sent with the first bot.
+///
+/// Requests are routed when they are created (i.e. when a [`Requester`]
+/// method is called), so the chat passed to the method is used. To get the
+/// chat of an already created request use [`HasChatId`].
+///
+/// Since all bots must have the same type, bots with different adaptors should
+/// be [erased] first.
+///
+/// ## Examples
+///
+/// Trace requests to a debug chat:
+///
+/// ```
+/// use teloxide_core::{
+///     adaptors::{trace::TraceSettings, RouteByChat},
+///     requests::RequesterExt,
+///     types::ChatId,
+///     Bot,
+/// };
+///
+/// const DEBUG_CHAT: ChatId = ChatId::Id(-1001234567890);
+///
+/// let bot = Bot::new("TOKEN");
+/// let bot = RouteByChat::new(
+///     |chat_id: &ChatId| (*chat_id == DEBUG_CHAT) as usize,
+///     vec![
+///         bot.clone().erase(),
+///         bot.trace(TraceSettings::REQUESTS | TraceSettings::RESPONSES)
+///             .erase(),
+///     ],
+/// );
+/// ```
+///
+/// [`GetMe`]: crate::payloads::GetMe
+/// [`HasChatId`]: crate::requests::HasChatId
+/// [erased]: crate::adaptors::ErasedRequester
+#[derive(Clone)]
+pub struct RouteByChat<B> {
+    requesters: Vec<B>,
+    selector: Arc<dyn Fn(&ChatId) -> usize + Send + Sync>,
+}
+
+impl<B> RouteByChat<B> {
+    /// Creates new `RouteByChat`, which sends requests with
+    /// `requesters[selector(chat_id)]`.
+    ///
+    /// ## Panics
+    ///
+    /// If `requesters` is empty. Sending a request to a chat panics if
+    /// `selector` returns an index which is out of bounds.
+    pub fn new<S, I>(selector: S, requesters: I) -> Self
+    where
+        S: Fn(&ChatId) -> usize + Send + Sync + 'static,
+        I: IntoIterator<Item = B>,
+    {
+        let requesters: Vec<_> = requesters.into_iter().collect();
+        assert!(
+            !requesters.is_empty(),
+            "`RouteByChat` requires at least one requester"
+        );
+
+        Self {
+            requesters,
+            selector: Arc::new(selector),
+        }
+    }
+
+    /// Allows to access the inner bots.
+    pub fn inner(&self) -> &[B] {
+        &self.requesters
+    }
+
+    /// Unwraps the inner bots.
+    pub fn into_inner(self) -> Vec<B> {
+        self.requesters
+    }
+
+    /// Returns the bot which sends requests to `chat_id`.
+    pub fn route(&self, chat_id: &ChatId) -> &B {
+        &self.requesters[(self.selector)(chat_id)]
+    }
+
+    /// Returns the bot which sends requests that aren't sent to a chat.
+    fn fallback(&self) -> &B {
+        &self.requesters[0]
+    }
+}
+
+impl<B: fmt::Debug> fmt::Debug for RouteByChat<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RouteByChat")
+            .field("requesters", &self.requesters)
+            .finish()
+    }
+}
+
+/// Finds the `chat_id` argument of a method and routes the call by it.
+macro_rules! route {
+    ($m:ident $this:ident [$($args:ident),*]) => {
+        $this.fallback().$m($($args),*)
+    };
+    ($m:ident $this:ident [$($args:ident),*] $arg:ident : $T:tt $(, $rest:ident : $R:tt)*) => {
+        route!(@check $arg $arg $T, $m $this [$($args),*] $($rest : $R),*)
+    };
+    // `chat_id` is an integer in `send_invoice`, `send_game` and `set_game_score`
+    (@check chat_id $c:ident i32, $m:ident $this:ident [$($args:ident),*] $($_rest:tt)*) => {
+        $this.route(&ChatId::Id($c.into())).$m($($args),*)
+    };
+    (@check chat_id $c:ident u32, $m:ident $this:ident [$($args:ident),*] $($_rest:tt)*) => {
+        $this.route(&ChatId::Id($c.into())).$m($($args),*)
+    };
+    (@check chat_id $c:ident $T:tt, $m:ident $this:ident [$($args:ident),*] $($_rest:tt)*) => {{
+        let $c: ChatId = $c.into();
+        $this.route(&$c).$m($($args),*)
+    }};
+    (@check $_a:ident $_b:ident $T:tt, $m:ident $this:ident [$($args:ident),*] $($rest:tt)*) => {
+        route!($m $this [$($args),*] $($rest)*)
+    };
+}
+
+macro_rules! f {
+    ($m:ident $this:ident ($($arg:ident : $T:tt),*)) => {
+        route!($m $this [$($arg),*] $($arg : $T),*)
+    };
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        B::$T
+    };
+}
+
+impl<B> Requester for RouteByChat<B>
+where
+    B: Requester,
+{
+    type Err = B::Err;
+
+    requester_forward! {
+        get_me, log_out, close, get_updates, set_webhook, delete_webhook, get_webhook_info,
+        forward_message, forward_messages, copy_message, copy_messages,
+        send_message, send_photo, send_audio, send_document,
+        send_video, send_animation, send_voice, send_video_note, send_media_group,
+        send_paid_media, send_location,
+        edit_message_live_location, edit_message_live_location_inline,
+        stop_message_live_location, stop_message_live_location_inline, send_venue,
+        send_contact, send_poll, send_dice, send_chat_action, get_user_profile_photos,
+        get_file, kick_chat_member, ban_chat_member, unban_chat_member, restrict_chat_member,
+        promote_chat_member, set_chat_administrator_custom_title, set_chat_permissions,
+        export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
+        revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
+        set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
+        leave_chat, get_chat, get_chat_administrators, get_chat_members_count,get_chat_member,
+        set_chat_sticker_set, delete_chat_sticker_set, answer_callback_query,
+        set_my_commands, get_my_commands, delete_my_commands, set_chat_menu_button,
+        get_chat_menu_button, answer_inline_query, edit_message_text,
+        edit_message_text_inline, edit_message_caption, edit_message_caption_inline,
+        edit_message_media, edit_message_media_inline, edit_message_reply_markup,
+        edit_message_reply_markup_inline, stop_poll, delete_message,
+        set_message_reaction, send_sticker,
+        get_sticker_set, upload_sticker_file, create_new_sticker_set,
+        add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
+        set_sticker_set_thumb, send_invoice, create_invoice_link, answer_shipping_query,
+        answer_pre_checkout_query, refund_star_payment, set_passport_data_errors, send_game,
+        set_game_score, set_game_score_inline, get_game_high_scores,
+        get_updates_fault_tolerant => f, fty
+    }
+}
+
+download_forward! {
+    'w
+    B
+    RouteByChat<B>
+    { this => this.fallback() }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use futures::executor::block_on;
+
+    use crate::{
+        requests::{Request, RequesterExt},
+        Bot,
+    };
+
+    use super::*;
+
+    #[test]
+    fn route() {
+        let default = Bot::new("TOKEN").mock();
+        let debug = Bot::new("TOKEN").mock();
+        let (default_handle, debug_handle) = (default.handle(), debug.handle());
+
+        let bot = RouteByChat::new(
+            |chat_id: &ChatId| (*chat_id == ChatId::Id(42)) as usize,
+            vec![default, debug],
+        );
+
+        debug_handle.push_response(True);
+        block_on(bot.delete_message(42, MessageId(1)).send()).unwrap();
+
+        default_handle.push_response(True);
+        block_on(bot.delete_message(1, MessageId(1)).send()).unwrap();
+
+        let message: Message = serde_json::from_value(serde_json::json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": { "id": 42, "first_name": "Name", "type": "private" },
+            "text": "text"
+        }))
+        .unwrap();
+        debug_handle.push_response(message);
+        block_on(bot.set_game_score(UserId(1), 10, 42, MessageId(1)).send()).unwrap();
+
+        default_handle.push_response(True);
+        block_on(bot.answer_callback_query("id").send()).unwrap();
+
+        let default_requests = default_handle.take_requests();
+        let debug_requests = debug_handle.take_requests();
+        assert_eq!(default_requests.len(), 2);
+        assert_eq!(default_requests[0].payload["chat_id"], 1);
+        assert_eq!(default_requests[1].method, "AnswerCallbackQuery");
+        assert_eq!(debug_requests.len(), 2);
+        assert_eq!(debug_requests[0].payload["chat_id"], 42);
+        assert_eq!(debug_requests[1].method, "SetGameScore");
+    }
+}
@@ -3,6 +3,7 @@ use std::{
     future::Future,
     hash::{Hash, Hasher},
     pin::Pin,
+    sync::{Arc, Mutex},
     time::{Duration, Instant},
 };
 
@@ -65,6 +66,8 @@ use crate::{
 // limits (i.e.: map[chat] < limit), if one is found, decrease `allowed`, notify
 // the request that it can be now executed, increase counts, add record to the
 // history.
+//
+// 9. Publish a snapshot of the queue (see `Throttle::queue_snapshot`).
 
 const MINUTE: Duration = Duration::from_secs(60);
 const SECOND: Duration = Duration::from_secs(1);
@@ -77,13 +80,24 @@ const DELAY: Duration = Duration::from_millis(250);
 
 /// Telegram request limits.
 ///
-/// This struct is used in [`Throttle`].
+/// This struct is used in [`Throttle`]. The defaults are the limits documented
+/// by Telegram, but they can be overridden, e.g. to be more conservative or if
+/// you are using a self-hosted Bot API server with relaxed limits:
+///
+/// ```
+/// use teloxide_core::adaptors::throttle::Limits;
+///
+/// let limits = Limits {
+///     messages_per_sec_overall: 10,
+///     ..Limits::default()
+/// };
+/// ```
 ///
 /// Note that you may ask telegram [@BotSupport] to increase limits for your
 /// particular bot if it has a lot of users (but they may or may not do that).
 ///
 /// [@BotSupport]: https://t.me/botsupport
-#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Limits {
     /// Allowed messages in one chat per second.
     pub messages_per_sec_chat: u32,
@@ -150,6 +164,79 @@ pub struct Throttle<B> {
     bot: B,
     // `RequestLock` allows to unlock requests (allowing them to be sent).
     queue: mpsc::Sender<(ChatIdHash, RequestLock)>,
+    limits: Limits,
+    // Updated by the worker, see `Throttle::queue_snapshot`.
+    snapshot: Arc<Mutex<QueueSnapshot>>,
+}
+
+/// A snapshot of the [`Throttle`] queue.
+///
+/// Can be obtained via [`Throttle::queue_snapshot`].
+#[derive(Clone, Debug, Default)]
+pub struct QueueSnapshot {
+    per_chat: HashMap<ChatIdHash, usize>,
+    queued: usize,
+    last_sent: Option<Instant>,
+}
+
+impl QueueSnapshot {
+    fn new(queue: &[(ChatIdHash, RequestLock)], last_sent: Option<Instant>) -> Self {
+        let mut per_chat = HashMap::new();
+        for (chat, _) in queue {
+            *per_chat.entry(*chat).or_insert(0) += 1;
+        }
+
+        Self {
+            per_chat,
+            queued: queue.len(),
+            last_sent,
+        }
+    }
+
+    /// Returns the number of requests waiting in the queue.
+    pub fn queued(&self) -> usize {
+        self.queued
+    }
+
+    /// Returns the number of requests to the given chat waiting in the queue.
+    pub fn queued_in<C>(&self, chat_id: C) -> usize
+    where
+        C: Into<ChatId>,
+    {
+        let chat = ChatIdHash::from(&chat_id.into());
+        self.per_chat.get(&chat).copied().unwrap_or(0)
+    }
+
+    /// Returns the number of chats with at least one request waiting in the
+    /// queue.
+    pub fn chats(&self) -> usize {
+        self.per_chat.len()
+    }
+
+    /// Returns the biggest number of requests to a single chat waiting in the
+    /// queue.
+    pub fn max_queued_per_chat(&self) -> usize {
+        self.per_chat.values().copied().max().unwrap_or(0)
+    }
+
+    /// Returns an iterator over `(chat id, number of queued requests)` pairs.
+    ///
+    /// Note: chats which requests were sent by `@channelusername` are not
+    /// yielded (see [`queued_in`](Self::queued_in)).
+    pub fn queued_per_chat_id(&self) -> impl Iterator<Item = (i64, usize)> + '_ {
+        self.per_chat
+            .iter()
+            .filter_map(|(chat, &count)| match chat {
+                ChatIdHash::Id(id) => Some((*id, count)),
+                ChatIdHash::ChannelUsernameHash(_) => None,
+            })
+    }
+
+    /// Returns the time at which the worker has allowed the last request to be
+    /// sent, if any.
+    pub fn last_sent(&self) -> Option<Instant> {
+        self.last_sent
+    }
 }
 
 type RequestsSent = u32;
@@ -163,7 +250,11 @@ struct RequestsSentToChats {
     per_sec: HashMap<ChatIdHash, RequestsSent>,
 }
 
-async fn worker(limits: Limits, mut rx: mpsc::Receiver<(ChatIdHash, RequestLock)>) {
+async fn worker(
+    limits: Limits,
+    mut rx: mpsc::Receiver<(ChatIdHash, RequestLock)>,
+    snapshot: Arc<Mutex<QueueSnapshot>>,
+) {
     // FIXME(waffle): Make an research about data structures for this queue.
     //                Currently this is O(n) removing (n = number of elements
     //                stayed), amortized O(1) push (vec+vecrem).
@@ -246,6 +337,7 @@ async fn worker(limits: Limits, mut rx: mpsc::Receiver<(ChatIdHash, RequestLock)
 
         if allowed == 0 {
             requests_sent.per_sec.clear();
+            publish_snapshot(&snapshot, &queue, &history);
             tokio::time::sleep(DELAY).await;
             continue;
         }
@@ -258,9 +350,10 @@ async fn worker(limits: Limits, mut rx: mpsc::Receiver<(ChatIdHash, RequestLock)
 
         while let Some(entry) = queue_removing.next() {
             let chat = &entry.value().0;
-            let requests_sent_count = requests_sent.per_sec.get(chat).copied().unwrap_or(0);
-            let limits_not_exceeded = requests_sent_count < limits.messages_per_sec_chat
-                && requests_sent_count < limits.messages_per_min_chat;
+            let sent_per_sec = requests_sent.per_sec.get(chat).copied().unwrap_or(0);
+            let sent_per_min = requests_sent.per_min.get(chat).copied().unwrap_or(0);
+            let limits_not_exceeded = sent_per_sec < limits.messages_per_sec_chat
+                && sent_per_min < limits.messages_per_min_chat;
 
             if limits_not_exceeded {
                 *requests_sent.per_sec.entry(*chat).or_insert(0) += 1;
@@ -282,10 +375,23 @@ async fn worker(limits: Limits, mut rx: mpsc::Receiver<(ChatIdHash, RequestLock)
         // It's easier to just recompute last second stats, instead of keeping
         // track of it alongside with minute stats, so we just throw this away.
         requests_sent.per_sec.clear();
+        publish_snapshot(&snapshot, &queue, &history);
         tokio::time::sleep(DELAY).await;
     }
 }
 
+fn publish_snapshot(
+    snapshot: &Mutex<QueueSnapshot>,
+    queue: &[(ChatIdHash, RequestLock)],
+    history: &VecDeque<(ChatIdHash, Instant)>,
+) {
+    let new = QueueSnapshot::new(queue, history.back().map(|&(_, time)| time));
+
+    // The snapshot is always replaced as a whole, so it can't be left in an
+    // inconsistent state and it's ok to ignore poisoning.
+    *snapshot.lock().unwrap_or_else(|err| err.into_inner()) = new;
+}
+
 async fn read_from_rx<T>(rx: &mut mpsc::Receiver<T>, queue: &mut Vec<T>, rx_is_closed: &mut bool) {
     if queue.is_empty() {
         match rx.recv().await {
@@ -311,11 +417,22 @@ impl<B> Throttle<B> {
     ///
     /// Note: [`Throttle`] will only send requests if returned worker is
     /// polled/spawned/awaited.
+    ///
+    /// ## Panics
+    ///
+    /// If `limits.messages_per_sec_overall` is `0`.
     pub fn new(bot: B, limits: Limits) -> (Self, impl Future<Output = ()>) {
         let (tx, rx) = mpsc::channel(limits.messages_per_sec_overall as usize);
 
-        let worker = worker(limits, rx);
-        let this = Self { bot, queue: tx };
+        let snapshot = Arc::new(Mutex::new(QueueSnapshot::default()));
+
+        let worker = worker(limits, rx, Arc::clone(&snapshot));
+        let this = Self {
+            bot,
+            queue: tx,
+            limits,
+            snapshot,
+        };
 
         (this, worker)
     }
@@ -344,6 +461,43 @@ impl<B> Throttle<B> {
         this
     }
 
+    /// Returns the limits used by this bot.
+    pub fn limits(&self) -> Limits {
+        self.limits
+    }
+
+    /// Returns a snapshot of the requests queue.
+    ///
+    /// The snapshot is updated by the worker once per iteration (i.e. every
+    /// ~250ms), so it may be a bit outdated. Taking a snapshot never waits for
+    /// the worker.
+    ///
+    /// Note: requests which were sent, but not yet received by the worker are
+    /// not counted.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run (throttle fails to spawn task without tokio runtime)
+    /// use teloxide_core::{adaptors::throttle::Limits, requests::RequesterExt, Bot};
+    ///
+    /// let bot = Bot::new("TOKEN").throttle(Limits::default());
+    ///
+    /// /* send many requests here */
+    ///
+    /// let snapshot = bot.queue_snapshot();
+    /// println!(
+    ///     "{} requests to {} chats are waiting in the queue",
+    ///     snapshot.queued(),
+    ///     snapshot.chats()
+    /// );
+    /// ```
+    pub fn queue_snapshot(&self) -> QueueSnapshot {
+        self.snapshot
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+    }
+
     /// Allows to access inner bot
     pub fn inner(&self) -> &B {
         &self.bot
@@ -387,7 +541,9 @@ impl<B: Requester> Requester for Throttle<B>
 where
     B::SendMessage: Send,
     B::ForwardMessage: Send,
+    B::ForwardMessages: Send,
     B::CopyMessage: Send,
+    B::CopyMessages: Send,
     B::SendPhoto: Send,
     B::SendAudio: Send,
     B::SendDocument: Send,
@@ -396,6 +552,7 @@ where
     B::SendVoice: Send,
     B::SendVideoNote: Send,
     B::SendMediaGroup: Send,
+    B::SendPaidMedia: Send,
     B::SendLocation: Send,
     B::SendVenue: Send,
     B::SendContact: Send,
@@ -407,9 +564,10 @@ where
     type Err = B::Err;
 
     requester_forward! {
-        send_message, forward_message, copy_message, send_photo, send_audio,
+        send_message, forward_message, forward_messages, copy_message, copy_messages,
+        send_photo, send_audio,
         send_document, send_video, send_animation, send_voice, send_video_note,
-        send_media_group, send_location, send_venue, send_contact, send_poll,
+        send_media_group, send_paid_media, send_location, send_venue, send_contact, send_poll,
         send_dice, send_sticker,  => f, fty
     }
 
@@ -455,7 +613,7 @@ where
         get_me, log_out, close, get_updates, set_webhook, delete_webhook, get_webhook_info,
         edit_message_live_location, edit_message_live_location_inline,
         stop_message_live_location, stop_message_live_location_inline,
-        send_chat_action, get_user_profile_photos, get_file, kick_chat_member,
+        send_chat_action, get_user_profile_photos, get_file, kick_chat_member, ban_chat_member,
         unban_chat_member, restrict_chat_member, promote_chat_member,
         set_chat_administrator_custom_title, set_chat_permissions,
         export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
@@ -463,13 +621,15 @@ where
         set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
         leave_chat, get_chat, get_chat_administrators, get_chat_members_count,
         get_chat_member, set_chat_sticker_set, delete_chat_sticker_set,
-        answer_callback_query, set_my_commands, get_my_commands, answer_inline_query,
+        answer_callback_query, set_my_commands, get_my_commands, delete_my_commands,
+        set_chat_menu_button, get_chat_menu_button, answer_inline_query,
         edit_message_text, edit_message_text_inline, edit_message_caption,
         edit_message_caption_inline, edit_message_media, edit_message_media_inline,
         edit_message_reply_markup, edit_message_reply_markup_inline, stop_poll,
-        delete_message, get_sticker_set, upload_sticker_file, create_new_sticker_set,
-        add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
-        set_sticker_set_thumb, answer_shipping_query, answer_pre_checkout_query,
+        delete_message, set_message_reaction, get_sticker_set, upload_sticker_file,
+        create_new_sticker_set, add_sticker_to_set, set_sticker_position_in_set,
+        delete_sticker_from_set, set_sticker_set_thumb, create_invoice_link,
+        answer_shipping_query, answer_pre_checkout_query, refund_star_payment,
         set_passport_data_errors, send_game, set_game_score, set_game_score_inline,
         get_game_high_scores, get_updates_fault_tolerant => fid, ftyid
     }
@@ -819,3 +979,41 @@ mod chan_send {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn queue_snapshot() {
+        let now = Instant::now();
+        let queue: Vec<_> = vec![
+            ChatIdHash::Id(1),
+            ChatIdHash::Id(2),
+            ChatIdHash::Id(1),
+            ChatIdHash::from(&ChatId::ChannelUsername("@channel".to_owned())),
+        ]
+        .into_iter()
+        .map(|chat| (chat, channel().0))
+        .collect();
+
+        let snapshot = QueueSnapshot::new(&queue, Some(now));
+        assert_eq!(snapshot.queued(), 4);
+        assert_eq!(snapshot.chats(), 3);
+        assert_eq!(snapshot.max_queued_per_chat(), 2);
+        assert_eq!(snapshot.queued_in(1), 2);
+        assert_eq!(snapshot.queued_in(2), 1);
+        assert_eq!(snapshot.queued_in(3), 0);
+        assert_eq!(snapshot.queued_in("@channel".to_owned()), 1);
+        assert_eq!(snapshot.last_sent(), Some(now));
+
+        let mut ids: Vec<_> = snapshot.queued_per_chat_id().collect();
+        ids.sort_unstable();
+        assert_eq!(ids, [(1, 2), (2, 1)]);
+
+        let empty = QueueSnapshot::default();
+        assert_eq!(empty.queued(), 0);
+        assert_eq!(empty.max_queued_per_chat(), 0);
+        assert_eq!(empty.last_sent(), None);
+    }
+}
@@ -0,0 +1,296 @@
+use std::{
+    fmt::{Debug, Display},
+    future::Future,
+    pin::Pin,
+};
+
+use futures::task::{Context, Poll};
+
+use crate::{
+    net::redact_token,
+    requests::{HasPayload, Output, Payload, Request, Requester},
+    types::*,
+};
+
+bitflags::bitflags! {
+    /// [`Trace`] settings that determine what will be logged.
+    ///
+    /// The name of the method is always logged (it's the `method` field of
+    /// the `request` span), other information is logged only if the
+    /// corresponding flag is set.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::adaptors::trace::TraceSettings;
+    ///
+    /// // Log nothing but method names
+    /// let _ = TraceSettings::empty();
+    ///
+    /// // Log requests and responses
+    /// let _ = TraceSettings::REQUESTS | TraceSettings::RESPONSES;
+    ///
+    /// // Log requests and errors, but only for failed requests
+    /// let _ = TraceSettings::REQUESTS | TraceSettings::RESPONSES | TraceSettings::ERRORS_ONLY;
+    /// ```
+    pub struct TraceSettings: u8 {
+        /// Log payloads of requests.
+        const REQUESTS = 0b001;
+
+        /// Log responses (both successful results and errors).
+        const RESPONSES = 0b010;
+
+        /// Log only failed requests.
+        ///
+        /// With this flag set, nothing is logged for successful requests.
+        const ERRORS_ONLY = 0b100;
+    }
+}
+
+/// Trace requests and responses.
+///
+/// This bot wrapper logs sent requests and received responses using
+/// [`tracing`] at the `DEBUG` level. Every request is logged inside of a
+/// `request` span with the method name as the `method` field (e.g.
+/// `request{method=SendMessage}`), so it's possible to filter logs per method.
+///
+/// What exactly is logged is determined by [`TraceSettings`]. The bot token is
+/// never logged.
+///
+/// Note: payloads are logged with their [`Debug`] representation, this
+/// includes contents of files sent from memory.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{adaptors::trace::TraceSettings, requests::RequesterExt, Bot};
+///
+/// let bot = Bot::new("TOKEN").trace(TraceSettings::REQUESTS | TraceSettings::RESPONSES);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Trace<B> {
+    inner: B,
+    settings: TraceSettings,
+}
+
+impl<B> Trace<B> {
+    /// Creates new `Trace`.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::trace`] instead.
+    ///
+    /// [`RequesterExt::trace`]: crate::requests::RequesterExt::trace
+    pub fn new(inner: B, settings: TraceSettings) -> Self {
+        Self { inner, settings }
+    }
+
+    /// Allows to access the inner bot.
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Unwraps the inner bot.
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+
+    /// Returns the settings of this bot.
+    pub fn settings(&self) -> TraceSettings {
+        self.settings
+    }
+}
+
+macro_rules! f {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        TraceRequest {
+            inner: $this.inner().$m($($arg),*),
+            settings: $this.settings,
+        }
+    };
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        TraceRequest<B::$T>
+    };
+}
+
+impl<B> Requester for Trace<B>
+where
+    B: Requester,
+{
+    type Err = B::Err;
+
+    requester_forward! {
+        get_me, log_out, close, get_updates, set_webhook, delete_webhook, get_webhook_info,
+        forward_message, forward_messages, copy_message, copy_messages,
+        send_message, send_photo, send_audio, send_document,
+        send_video, send_animation, send_voice, send_video_note, send_media_group,
+        send_paid_media, send_location,
+        edit_message_live_location, edit_message_live_location_inline,
+        stop_message_live_location, stop_message_live_location_inline, send_venue,
+        send_contact, send_poll, send_dice, send_chat_action, get_user_profile_photos,
+        get_file, kick_chat_member, ban_chat_member, unban_chat_member, restrict_chat_member,
+        promote_chat_member, set_chat_administrator_custom_title, set_chat_permissions,
+        export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
+        revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
+        set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
+        leave_chat, get_chat, get_chat_administrators, get_chat_members_count,get_chat_member,
+        set_chat_sticker_set, delete_chat_sticker_set, answer_callback_query,
+        set_my_commands, get_my_commands, delete_my_commands, set_chat_menu_button,
+        get_chat_menu_button, answer_inline_query, edit_message_text,
+        edit_message_text_inline, edit_message_caption, edit_message_caption_inline,
+        edit_message_media, edit_message_media_inline, edit_message_reply_markup,
+        edit_message_reply_markup_inline, stop_poll, delete_message,
+        set_message_reaction, send_sticker,
+        get_sticker_set, upload_sticker_file, create_new_sticker_set,
+        add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
+        set_sticker_set_thumb, send_invoice, create_invoice_link, answer_shipping_query,
+        answer_pre_checkout_query, refund_star_payment, set_passport_data_errors, send_game,
+        set_game_score, set_game_score_inline, get_game_high_scores,
+        get_updates_fault_tolerant => f, fty
+    }
+}
+
+download_forward! {
+    'w
+    B
+    Trace<B>
+    { this => this.inner() }
+}
+
+pub struct TraceRequest<R> {
+    inner: R,
+    settings: TraceSettings,
+}
+
+impl<R> TraceRequest<R>
+where
+    R: Request,
+    R::Payload: Debug,
+{
+    // Logs the start of the request (if needed) and returns the span alongside
+    // with the payload which needs to be logged on error.
+    fn start(&self) -> (tracing::Span, Option<String>) {
+        let span = tracing::debug_span!("request", method = R::Payload::NAME);
+
+        // With `ERRORS_ONLY` we don't know in advance if the request needs to be
+        // logged, so we need to remember the payload.
+        let payload = if self.settings.contains(TraceSettings::REQUESTS) {
+            let payload = format!("{:?}", self.inner.payload_ref());
+
+            if self.settings.contains(TraceSettings::ERRORS_ONLY) {
+                Some(payload)
+            } else {
+                span.in_scope(|| tracing::debug!(payload = %payload, "Sending request"));
+                None
+            }
+        } else {
+            if !self.settings.contains(TraceSettings::ERRORS_ONLY) {
+                span.in_scope(|| tracing::debug!("Sending request"));
+            }
+            None
+        };
+
+        (span, payload)
+    }
+}
+
+impl<R> HasPayload for TraceRequest<R>
+where
+    R: HasPayload,
+{
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.inner.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.inner.payload_ref()
+    }
+}
+
+impl<R> Request for TraceRequest<R>
+where
+    R: Request,
+    R::Payload: Debug,
+    Output<R>: Debug,
+{
+    type Err = R::Err;
+    type Send = TraceSend<R::Send>;
+    type SendRef = TraceSend<R::SendRef>;
+
+    fn send(self) -> Self::Send {
+        let (span, payload) = self.start();
+
+        TraceSend {
+            fut: self.inner.send(),
+            span,
+            settings: self.settings,
+            payload,
+        }
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        let (span, payload) = self.start();
+
+        TraceSend {
+            fut: self.inner.send_ref(),
+            span,
+            settings: self.settings,
+            payload,
+        }
+    }
+}
+
+#[pin_project::pin_project]
+pub struct TraceSend<F> {
+    #[pin]
+    fut: F,
+    span: tracing::Span,
+    settings: TraceSettings,
+    // Payload of the request, set only with `REQUESTS | ERRORS_ONLY`.
+    payload: Option<String>,
+}
+
+impl<F, T, E> Future for TraceSend<F>
+where
+    F: Future<Output = Result<T, E>>,
+    T: Debug,
+    E: Display,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let _enter = this.span.enter();
+
+        let res = futures::ready!(this.fut.poll(cx));
+        let settings = *this.settings;
+
+        match &res {
+            Ok(output) if !settings.contains(TraceSettings::ERRORS_ONLY) => {
+                if settings.contains(TraceSettings::RESPONSES) {
+                    tracing::debug!(response = ?output, "Got response");
+                } else {
+                    tracing::debug!("Got response");
+                }
+            }
+            Ok(_) => {}
+            Err(err) => {
+                if let Some(payload) = this.payload.take() {
+                    tracing::debug!(payload = %payload, "Sent request");
+                }
+
+                if settings.contains(TraceSettings::RESPONSES) {
+                    let err = err.to_string();
+                    tracing::debug!(error = %redact_token(&err), "Got error");
+                } else {
+                    tracing::debug!("Got error");
+                }
+            }
+        }
+
+        Poll::Ready(res)
+    }
+}
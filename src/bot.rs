@@ -1,16 +1,18 @@
 use std::{future::Future, sync::Arc, time::Duration};
 
+use bytes::{Bytes, BytesMut};
+use futures::{Stream, TryFutureExt, TryStreamExt};
 use reqwest::{
-    header::{HeaderMap, CONNECTION},
+    header::{HeaderMap, CONTENT_LENGTH, CONTENT_TYPE},
     Client, ClientBuilder,
 };
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
-    bot::api_url::ApiUrl,
-    net,
-    requests::{MultipartPayload, Payload, ResponseResult},
-    serde_multipart,
+    bot::api_url::{ApiRouter, ApiUrl},
+    net::{self, ClientConfig, Download},
+    requests::{MultipartPayload, Payload, Request, Requester, ResponseResult},
+    serde_multipart, DownloadError, RequestError,
 };
 
 mod api;
@@ -19,6 +21,8 @@ mod download;
 
 pub(crate) const TELOXIDE_TOKEN: &str = "TELOXIDE_TOKEN";
 pub(crate) const TELOXIDE_PROXY: &str = "TELOXIDE_PROXY";
+pub(crate) const TELOXIDE_PROXY_USERNAME: &str = "TELOXIDE_PROXY_USERNAME";
+pub(crate) const TELOXIDE_PROXY_PASSWORD: &str = "TELOXIDE_PROXY_PASSWORD";
 
 /// A requests sender.
 ///
@@ -61,11 +65,23 @@ pub(crate) const TELOXIDE_PROXY: &str = "TELOXIDE_PROXY";
 pub struct Bot {
     token: Arc<str>,
     api_url: ApiUrl,
+    api_router: Option<ApiRouter>,
     client: Client,
+    timeout: Option<Duration>,
+    test_env: bool,
+    local_mode: bool,
+    network_retries: u32,
+    retry_delay: Duration,
+    validate_lengths: bool,
+    extra_headers: Arc<HeaderMap>,
 }
 
 /// Constructors
 impl Bot {
+    /// The name passed to the router set by [`Bot::with_api_router`] for
+    /// file downloads.
+    pub const DOWNLOAD_ROUTE: &'static str = "DownloadFile";
+
     /// Creates a new `Bot` with the specified token and the default
     /// [http-client](reqwest::Client).
     ///
@@ -79,15 +95,34 @@ impl Bot {
         Self::with_client(token, build_sound_bot())
     }
 
+    /// Creates a new `Bot` with the specified token and an
+    /// [http-client](reqwest::Client) built from `config`.
+    ///
+    /// This allows to change some settings of the default client, see
+    /// [`ClientConfig`].
+    ///
+    /// # Panics
+    ///
+    /// If it cannot create [`reqwest::Client`].
+    pub fn from_config<S>(token: S, config: ClientConfig) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::with_client(token, config.build().expect("creating reqwest::Client"))
+    }
+
     /// Creates a new `Bot` with the specified token and your
     /// [`reqwest::Client`].
     ///
     /// # Caution
     /// Your custom client might not be configured correctly to be able to work
-    /// in long time durations, see [issue 223].
+    /// in long time durations, see [issue 223]. Consider using
+    /// [`Bot::from_config`] or [`ClientConfig::builder`] (which returns a
+    /// [`ClientBuilder`] with safe settings) instead.
     ///
     /// [`reqwest::Client`]: https://docs.rs/reqwest/latest/reqwest/struct.Client.html
     /// [issue 223]: https://github.com/teloxide/teloxide/issues/223
+    /// [`ClientBuilder`]: reqwest::ClientBuilder
     pub fn with_client<S>(token: S, client: Client) -> Self
     where
         S: Into<String>,
@@ -95,7 +130,15 @@ impl Bot {
         Self {
             token: Into::<Arc<str>>::into(Into::<String>::into(token)),
             api_url: ApiUrl::Default,
+            api_router: None,
             client,
+            timeout: None,
+            test_env: false,
+            local_mode: false,
+            network_retries: 0,
+            retry_delay: Duration::from_millis(500),
+            validate_lengths: false,
+            extra_headers: Arc::new(HeaderMap::new()),
         }
     }
 
@@ -105,7 +148,8 @@ impl Bot {
     ///
     /// This function passes the value of `TELOXIDE_PROXY` into
     /// [`reqwest::Proxy::all`], if it exists, otherwise returns the default
-    /// client.
+    /// client. See [`net::client_from_env`] for how proxy credentials are
+    /// handled.
     ///
     /// # Panics
     ///  - If cannot get the `TELOXIDE_TOKEN`  environmental variable.
@@ -113,6 +157,7 @@ impl Bot {
     ///
     /// [`reqwest::Client`]: https://docs.rs/reqwest/0.10.1/reqwest/struct.Client.html
     /// [`reqwest::Proxy::all`]: https://docs.rs/reqwest/latest/reqwest/struct.Proxy.html#method.all
+    /// [`net::client_from_env`]: crate::net::client_from_env
     pub fn from_env() -> Self {
         Self::from_env_with_client(crate::net::client_from_env())
     }
@@ -176,6 +221,322 @@ impl Bot {
         self.api_url = ApiUrl::Custom(Arc::new(url));
         self
     }
+
+    /// Sets a function which picks the API url for every request.
+    ///
+    /// The `router` is called with the name of the method (e.g. `"GetFile"`,
+    /// see [`Payload::NAME`]) for every request and with
+    /// [`Bot::DOWNLOAD_ROUTE`] for every file download made through
+    /// [`Download`] methods. The returned url is used instead of the one set
+    /// by [`set_api_url`]. This allows e.g. to route file uploads and
+    /// downloads to a different [Bot API server] instance than other methods.
+    ///
+    /// Note: [`Bot::api_url`] still returns the url set by [`set_api_url`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use reqwest::Url;
+    /// use teloxide_core::Bot;
+    ///
+    /// let main = Url::parse("http://localhost:8081").unwrap();
+    /// let files = Url::parse("http://localhost:8082").unwrap();
+    ///
+    /// let bot = Bot::new("TOKEN").with_api_router(move |method| match method {
+    ///     "GetFile" | "SendDocument" | Bot::DOWNLOAD_ROUTE => files.clone(),
+    ///     _ => main.clone(),
+    /// });
+    /// ```
+    ///
+    /// ## Multi-instance behaviour
+    ///
+    /// Just like [`set_api_url`], this method only affects one bot instance,
+    /// older clones are unaffected.
+    ///
+    /// [`Payload::NAME`]: crate::requests::Payload::NAME
+    /// [`Download`]: crate::net::Download
+    /// [`set_api_url`]: Bot::set_api_url
+    /// [Bot API server]: https://github.com/tdlib/telegram-bot-api
+    pub fn with_api_router<F>(mut self, router: F) -> Self
+    where
+        F: Fn(&str) -> reqwest::Url + Send + Sync + 'static,
+    {
+        self.api_router = Some(ApiRouter(Arc::new(router)));
+        self
+    }
+
+    /// Switches the bot to the Telegram [test environment].
+    ///
+    /// In the test environment all requests are sent to
+    /// `<api url>/bot<token>/test/<method>` instead of
+    /// `<api url>/bot<token>/<method>` (the same applies to file downloads).
+    /// Note that the test environment has its own bots, so the token must be
+    /// obtained from the test [@BotFather].
+    ///
+    /// This composes with [`set_api_url`], so it's possible to use the test
+    /// environment of a self-hosted [Telegram bot API server][tbas].
+    ///
+    /// [test environment]: https://core.telegram.org/bots/webapps#using-bots-in-the-test-environment
+    /// [@BotFather]: https://t.me/BotFather
+    /// [`set_api_url`]: Bot::set_api_url
+    /// [tbas]: https://github.com/tdlib/telegram-bot-api
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::Bot;
+    ///
+    /// let bot = Bot::new("TOKEN")
+    ///     .set_api_url(reqwest::Url::parse("https://example.com/").unwrap())
+    ///     .test_env();
+    ///
+    /// assert!(bot.is_test_env());
+    /// assert_eq!(bot.api_url().as_str(), "https://example.com/");
+    /// ```
+    ///
+    /// ## Multi-instance behaviour
+    ///
+    /// Just like [`set_api_url`], this method only affects one bot instance,
+    /// older clones are unaffected.
+    pub fn test_env(mut self) -> Self {
+        self.test_env = true;
+        self
+    }
+
+    /// Switches the bot to the "local mode" of a self-hosted [Telegram bot API
+    /// server][tbas] (the one started with `--local` flag).
+    ///
+    /// In the local mode:
+    /// - [`InputFile::File`]s are not uploaded, instead they are passed to the
+    ///   server as `file://` uris, so the server reads them directly from the
+    ///   disk. This allows to bypass the upload size limit. Note that the paths
+    ///   must be **absolute** and accessible by the server.
+    /// - [`Download`] methods read files directly from the disk, since in the
+    ///   local mode [`GetFile`] returns absolute local paths (relative paths
+    ///   are still downloaded via HTTP, see [`File::is_local`]).
+    ///
+    /// Use this together with [`set_api_url`], local mode can't be used with
+    /// the official Telegram server.
+    ///
+    /// [tbas]: https://github.com/tdlib/telegram-bot-api
+    /// [`InputFile::File`]: crate::types::InputFile::File
+    /// [`Download`]: crate::net::Download
+    /// [`GetFile`]: crate::payloads::GetFile
+    /// [`File::is_local`]: crate::types::File::is_local
+    /// [`set_api_url`]: Bot::set_api_url
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::Bot;
+    ///
+    /// let bot = Bot::new("TOKEN")
+    ///     .set_api_url(reqwest::Url::parse("http://localhost:8081").unwrap())
+    ///     .local_mode();
+    ///
+    /// assert!(bot.is_local_mode());
+    /// ```
+    ///
+    /// ## Multi-instance behaviour
+    ///
+    /// Just like [`set_api_url`], this method only affects one bot instance,
+    /// older clones are unaffected.
+    pub fn local_mode(mut self) -> Self {
+        self.local_mode = true;
+        self
+    }
+
+    /// Sets a timeout for all requests made by this bot, overriding the
+    /// timeout of the [http-client](reqwest::Client).
+    ///
+    /// The timeout is applied from when a request starts connecting until the
+    /// response body has finished. This is useful for e.g. big uploads which
+    /// don't fit into the default client timeout.
+    ///
+    /// Timeouts of long-running requests (e.g. [`GetUpdates`] used for long
+    /// polling) are extended to fit their [`timeout_hint`], regardless of this
+    /// setting.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use teloxide_core::Bot;
+    ///
+    /// let bot = Bot::new("TOKEN");
+    /// let uploader = bot.clone().with_timeout(Duration::from_secs(120));
+    ///
+    /// assert_eq!(bot.timeout(), None);
+    /// assert_eq!(uploader.timeout(), Some(Duration::from_secs(120)));
+    /// ```
+    ///
+    /// ## Multi-instance behaviour
+    ///
+    /// Just like [`set_api_url`], this method only sets the timeout for one
+    /// bot instance, older clones are unaffected.
+    ///
+    /// [`set_api_url`]: Bot::set_api_url
+    /// [`GetUpdates`]: crate::payloads::GetUpdates
+    /// [`timeout_hint`]: crate::requests::Payload::timeout_hint
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Retries idempotent requests up to `retries` times if they fail with
+    /// [`RequestError::NetworkError`] (e.g. because of a DNS failure or a
+    /// connection reset).
+    ///
+    /// The bot waits `delay` before the first retry, and the delay is doubled
+    /// after every retry, up to 30 seconds (or `delay`, if it's bigger).
+    ///
+    /// Only requests with [`Payload::IDEMPOTENT`] set (e.g. [`GetMe`] or
+    /// [`GetChat`]) are retried, since retrying e.g. [`SendMessage`] may send
+    /// the same message twice. To retry all requests, see the [`Retry`]
+    /// adaptor.
+    ///
+    /// By default, requests are not retried.
+    ///
+    /// [`RequestError::NetworkError`]: crate::RequestError::NetworkError
+    /// [`Payload::IDEMPOTENT`]: crate::requests::Payload::IDEMPOTENT
+    /// [`GetMe`]: crate::payloads::GetMe
+    /// [`GetChat`]: crate::payloads::GetChat
+    /// [`SendMessage`]: crate::payloads::SendMessage
+    /// [`Retry`]: crate::adaptors::retry::Retry
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use teloxide_core::Bot;
+    ///
+    /// let bot = Bot::new("TOKEN").with_network_retries(3, Duration::from_millis(100));
+    /// assert_eq!(bot.network_retries(), 3);
+    /// ```
+    ///
+    /// ## Multi-instance behaviour
+    ///
+    /// Just like [`set_api_url`], this method only affects one bot instance,
+    /// older clones are unaffected.
+    ///
+    /// [`set_api_url`]: Bot::set_api_url
+    pub fn with_network_retries(mut self, retries: u32, delay: Duration) -> Self {
+        self.network_retries = retries;
+        self.retry_delay = delay;
+        self
+    }
+
+    /// Makes the bot check lengths of texts, captions and chat titles (and bot
+    /// commands) before sending requests.
+    ///
+    /// Telegram limits message texts to [4096], media captions to [1024] and
+    /// chat titles to [128] UTF-16 code units. With this option requests
+    /// exceeding the limits fail with [`RequestError::MessageTooLong`] without
+    /// being sent, so that e.g. users can be shown a friendly error. Empty chat
+    /// titles fail with [`RequestError::EmptyText`] and [`SetMyCommands`]
+    /// requests with invalid commands (see [`BotCommand::is_valid`]) fail
+    /// with [`RequestError::InvalidBotCommand`].
+    ///
+    /// Telegram applies the limits after parsing entities, so texts and
+    /// captions with `parse_mode` set are not checked (their markup would be
+    /// counted otherwise), Telegram checks them instead. Captions of
+    /// [`SendMediaGroup`] are checked like any other captions.
+    ///
+    /// By default, lengths are not checked.
+    ///
+    /// [4096]: crate::utils::MESSAGE_TEXT_MAX_LEN
+    /// [1024]: crate::utils::CAPTION_MAX_LEN
+    /// [128]: crate::utils::CHAT_TITLE_MAX_LEN
+    /// [`RequestError::MessageTooLong`]: crate::RequestError::MessageTooLong
+    /// [`RequestError::EmptyText`]: crate::RequestError::EmptyText
+    /// [`SetMyCommands`]: crate::payloads::SetMyCommands
+    /// [`SendMediaGroup`]: crate::payloads::SendMediaGroup
+    /// [`BotCommand::is_valid`]: crate::types::BotCommand::is_valid
+    /// [`RequestError::InvalidBotCommand`]: crate::RequestError::InvalidBotCommand
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::{
+    ///     requests::{Request, Requester},
+    ///     Bot, RequestError,
+    /// };
+    ///
+    /// # async {
+    /// let bot = Bot::new("TOKEN").validate_lengths();
+    ///
+    /// let res = bot.send_message(0, "a".repeat(5000)).send().await;
+    /// assert!(matches!(
+    ///     res,
+    ///     Err(RequestError::MessageTooLong { limit: 4096, actual: 5000 })
+    /// ));
+    /// # };
+    /// ```
+    ///
+    /// ## Multi-instance behaviour
+    ///
+    /// Just like [`set_api_url`], this method only affects one bot instance,
+    /// older clones are unaffected.
+    ///
+    /// [`set_api_url`]: Bot::set_api_url
+    pub fn validate_lengths(mut self) -> Self {
+        self.validate_lengths = true;
+        self
+    }
+
+    /// Sets headers which are added to every request to the Telegram API.
+    ///
+    /// This is useful e.g. if a self-hosted [Telegram Bot API server] is
+    /// behind a gateway which requires an API key in a header. Headers set by
+    /// previous calls are replaced.
+    ///
+    /// ## Precedence
+    ///
+    /// - The extra headers take precedence over the default headers of the
+    ///   [http-client](reqwest::Client) (e.g. `Connection: keep-alive` of the
+    ///   default client).
+    /// - `Content-Type` and `Content-Length` are always set by the bot
+    ///   according to the request body, so these headers are ignored.
+    ///
+    /// The extra headers are not sent with file downloads. Their values are
+    /// marked as [sensitive], so they are not shown in the `Debug` output of
+    /// the bot.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use reqwest::header::{HeaderMap, HeaderValue};
+    /// use teloxide_core::Bot;
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("x-api-key", HeaderValue::from_static("secret"));
+    ///
+    /// let bot = Bot::new("TOKEN").with_extra_headers(headers);
+    /// assert_eq!(bot.extra_headers()["x-api-key"], "secret");
+    /// ```
+    ///
+    /// ## Multi-instance behaviour
+    ///
+    /// Just like [`set_api_url`], this method only affects one bot instance,
+    /// older clones are unaffected.
+    ///
+    /// [Telegram Bot API server]: https://github.com/tdlib/telegram-bot-api
+    /// [sensitive]: reqwest::header::HeaderValue::set_sensitive
+    /// [`set_api_url`]: Bot::set_api_url
+    pub fn with_extra_headers(mut self, mut headers: HeaderMap) -> Self {
+        headers.remove(CONTENT_TYPE);
+        headers.remove(CONTENT_LENGTH);
+
+        // The headers may contain secrets (e.g. API keys), don't leak them via `Debug`
+        headers
+            .values_mut()
+            .for_each(|value| value.set_sensitive(true));
+
+        self.extra_headers = Arc::new(headers);
+        self
+    }
 }
 
 /// Getters
@@ -191,12 +552,183 @@ impl Bot {
     }
 
     /// Returns currently used token API url.
+    ///
+    /// Note: the `/test` segment of the [test environment] is inserted after
+    /// the token, so it's not a part of the returned url, see
+    /// [`Bot::is_test_env`].
+    ///
+    /// [test environment]: Bot::test_env
     pub fn api_url(&self) -> reqwest::Url {
         self.api_url.get()
     }
+
+    /// Returns `true` if the bot uses the Telegram [test environment].
+    ///
+    /// [test environment]: Bot::test_env
+    pub fn is_test_env(&self) -> bool {
+        self.test_env
+    }
+
+    /// Returns `true` if the bot is in the [local mode].
+    ///
+    /// [local mode]: Bot::local_mode
+    pub fn is_local_mode(&self) -> bool {
+        self.local_mode
+    }
+
+    /// Returns currently used request timeout, if it was overridden with
+    /// [`Bot::with_timeout`].
+    ///
+    /// `None` means that the timeout of the [http-client](reqwest::Client) is
+    /// used.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Returns how many times idempotent requests are retried on network
+    /// errors, see [`Bot::with_network_retries`].
+    pub fn network_retries(&self) -> u32 {
+        self.network_retries
+    }
+
+    /// Returns `true` if the bot checks lengths of texts and captions before
+    /// sending requests, see [`Bot::validate_lengths`].
+    pub fn is_validating_lengths(&self) -> bool {
+        self.validate_lengths
+    }
+
+    /// Returns headers which are added to every request, see
+    /// [`Bot::with_extra_headers`].
+    pub fn extra_headers(&self) -> &HeaderMap {
+        &self.extra_headers
+    }
 }
 
 impl Bot {
+    /// Sends a cheap request ([`GetMe`]) to establish a connection to the
+    /// Telegram API server.
+    ///
+    /// Connections are pooled by the http-client, so after this call the next
+    /// request doesn't have to wait for the TCP and TLS handshakes. This may be
+    /// useful in short-lived (e.g. serverless) environments where the latency
+    /// of the first request matters.
+    ///
+    /// Calling this method is completely optional. The connection is only
+    /// reused by clones of this bot (which share the client) and only while
+    /// it's kept alive by the client.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use teloxide_core::Bot;
+    ///
+    /// # async {
+    /// let bot = Bot::new("TOKEN");
+    /// bot.warm_up().await?;
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    ///
+    /// [`GetMe`]: crate::payloads::GetMe
+    pub fn warm_up(&self) -> impl Future<Output = ResponseResult<()>> {
+        let request = self.get_me();
+        async move { request.send().await.map(drop) }
+    }
+
+    /// Downloads a file by its id.
+    ///
+    /// This is a shortcut for calling [`GetFile`] and then
+    /// [`Download::download_file`] with the received path. The whole file is
+    /// collected in memory, to download big files see
+    /// [`Bot::download_by_file_id_stream`].
+    ///
+    /// ## Errors
+    ///
+    /// Errors of [`GetFile`] are returned as [`DownloadError::GetFile`].
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use teloxide_core::Bot;
+    ///
+    /// # async {
+    /// let bot = Bot::new("TOKEN");
+    /// let bytes = bot.download_by_file_id("*file_id*").await?;
+    /// # Ok::<_, teloxide_core::DownloadError>(()) };
+    /// ```
+    ///
+    /// [`GetFile`]: crate::payloads::GetFile
+    /// [`Download::download_file`]: crate::net::Download::download_file
+    pub fn download_by_file_id<F>(
+        &self,
+        file_id: F,
+    ) -> impl Future<Output = Result<Bytes, DownloadError>> + 'static
+    where
+        F: Into<String>,
+    {
+        let bot = self.clone();
+        let request = self.get_file(file_id);
+
+        async move {
+            let file = request.send().await.map_err(DownloadError::GetFile)?;
+
+            let mut bytes = BytesMut::with_capacity(file.file_size as usize);
+            let stream = bot.download_file_stream(&file.file_path);
+            futures::pin_mut!(stream);
+            while let Some(chunk) = stream.try_next().await? {
+                bytes.extend_from_slice(&chunk);
+            }
+
+            Ok(bytes.freeze())
+        }
+    }
+
+    /// Downloads a file by its id as [`Stream`].
+    ///
+    /// This is a shortcut for calling [`GetFile`] and then
+    /// [`Download::download_file_stream`] with the received path.
+    ///
+    /// ## Errors
+    ///
+    /// Errors of [`GetFile`] are yielded as [`DownloadError::GetFile`], after
+    /// that the stream ends.
+    ///
+    /// [`GetFile`]: crate::payloads::GetFile
+    /// [`Download::download_file_stream`]: crate::net::Download::download_file_stream
+    pub fn download_by_file_id_stream<F>(
+        &self,
+        file_id: F,
+    ) -> impl Stream<Item = Result<Bytes, DownloadError>> + 'static
+    where
+        F: Into<String>,
+    {
+        let bot = self.clone();
+
+        self.get_file(file_id)
+            .send()
+            .map_err(DownloadError::GetFile)
+            .map_ok(move |file| bot.download_file_stream(&file.file_path))
+            .try_flatten_stream()
+    }
+}
+
+impl Bot {
+    /// Returns the token as it should be placed in urls, i.e. with the `/test`
+    /// suffix in the test environment.
+    pub(crate) fn url_token(&self) -> Arc<str> {
+        match self.test_env {
+            true => format!("{}/test", self.token).into(),
+            false => Arc::clone(&self.token),
+        }
+    }
+
+    /// Returns the API url for the `method`, see [`Bot::with_api_router`].
+    pub(crate) fn api_url_for(&self, method: &str) -> reqwest::Url {
+        match &self.api_router {
+            Some(ApiRouter(router)) => router(method),
+            None => self.api_url.get(),
+        }
+    }
+
     pub(crate) fn execute_json<P>(
         &self,
         payload: &P,
@@ -205,16 +737,71 @@ impl Bot {
         P: Payload + Serialize,
         P::Output: DeserializeOwned,
     {
+        // Note: the future is `'static` because `Request::SendRef` can't borrow
+        // from the request (or the bot) without GATs, so everything it needs is
+        // moved into it. This is cheap: `Client`, the token and the headers are
+        // reference counted, so cloning them doesn't allocate. A request on a
+        // keep-alive connection makes 47 allocations in release mode (see
+        // `benches/allocations.rs`), only 3 of them before the future is
+        // polled, the rest while the request is sent and the response is
+        // parsed.
         let client = self.client.clone();
-        let token = Arc::clone(&self.token);
-        let api_url = self.api_url.clone();
+        let token = self.url_token();
+        let api_url = self.api_url_for(P::NAME);
+        let timeout = self.request_timeout(payload);
+        let retries = if P::IDEMPOTENT {
+            self.network_retries
+        } else {
+            0
+        };
+        let retry_delay = self.retry_delay;
+        let headers = Arc::clone(&self.extra_headers);
 
-        let params = serde_json::to_vec(payload)
+        let check = self.check_lengths(payload);
+        // Note: reusing serialization buffers doesn't help, the time is
+        // dominated by JSON encoding itself, not by allocations. See
+        // `benches/json_buffers.rs`, 100k `SendMessage`s in release mode:
+        //
+        // | text len | to_vec | pooled |
+        // |----------|--------|--------|
+        // | 30       | 4 ms   | 4 ms   |
+        // | 1000     | 47 ms  | 44 ms  |
+        // | 4000     | 170 ms | 173 ms |
+        let mut params = serde_json::to_vec(payload)
             // this `expect` should be ok since we don't write request those may trigger error here
             .expect("serialization of request to be infallible");
 
         // async move to capture client&token&api_url&params
-        async move { net::request_json(&client, token.as_ref(), api_url.get(), P::NAME, params).await }
+        async move {
+            check?;
+
+            let mut retry = 0;
+            loop {
+                // Only clone params if we may need them for a retry
+                let params = match retry < retries {
+                    true => params.clone(),
+                    false => std::mem::take(&mut params),
+                };
+
+                let err = match net::request_json(
+                    &client,
+                    token.as_ref(),
+                    api_url.clone(),
+                    P::NAME,
+                    params,
+                    timeout,
+                    &headers,
+                )
+                .await
+                {
+                    Err(RequestError::NetworkError(err)) if retry < retries => err,
+                    res => return res,
+                };
+
+                wait_before_retry::<P>(retry_delay, retry, &err).await;
+                retry += 1;
+            }
+        }
     }
 
     pub(crate) fn execute_multipart<P>(
@@ -225,38 +812,113 @@ impl Bot {
         P: MultipartPayload + Serialize,
         P::Output: DeserializeOwned,
     {
+        // See the note about `'static` in `execute_json`
         let client = self.client.clone();
-        let token = Arc::clone(&self.token);
-        let api_url = self.api_url.clone();
+        let token = self.url_token();
+        let api_url = self.api_url_for(P::NAME);
+        let timeout = self.request_timeout(payload);
+        let retries = if P::IDEMPOTENT {
+            self.network_retries
+        } else {
+            0
+        };
+        let retry_delay = self.retry_delay;
+        let headers = Arc::clone(&self.extra_headers);
 
-        let params = serde_multipart::to_form(payload);
+        let check = self.check_lengths(payload);
+        // `Form` can't be cloned, so we need to serialize payload for every attempt
+        let params: Vec<_> = (0..=retries)
+            .map(|_| serde_multipart::to_form(payload, self.local_mode))
+            .collect();
 
         // async move to capture client&token&api_url&params
         async move {
-            let params = params.await?;
-            net::request_multipart(&client, token.as_ref(), api_url.get(), P::NAME, params).await
+            check?;
+
+            for (retry, params) in (0..).zip(params) {
+                let params = params.await?;
+                let err = match net::request_multipart(
+                    &client,
+                    token.as_ref(),
+                    api_url.clone(),
+                    P::NAME,
+                    params,
+                    timeout,
+                    &headers,
+                )
+                .await
+                {
+                    Err(RequestError::NetworkError(err)) if retry < retries => err,
+                    res => return res,
+                };
+
+                wait_before_retry::<P>(retry_delay, retry, &err).await;
+            }
+
+            unreachable!("the last attempt always returns")
+        }
+    }
+
+    /// Returns the timeout of a request with `payload`.
+    ///
+    /// For long-running requests (see [`Payload::timeout_hint`]) this is the
+    /// hint plus [`TIMEOUT_HINT_MARGIN`], or the timeout set with
+    /// [`Bot::with_timeout`], whichever is bigger. So long polling works
+    /// regardless of the timeout of the http-client.
+    fn request_timeout<P: Payload>(&self, payload: &P) -> Option<Duration> {
+        match payload.timeout_hint() {
+            Some(hint) => Some(
+                self.timeout
+                    .unwrap_or_default()
+                    .max(hint + TIMEOUT_HINT_MARGIN),
+            ),
+            None => self.timeout,
+        }
+    }
+
+    fn check_lengths<P: Payload>(&self, payload: &P) -> Result<(), RequestError> {
+        match self.validate_lengths {
+            true => payload.check_lengths(),
+            false => Ok(()),
         }
     }
 }
 
-/// Returns a builder with safe settings.
-///
-/// By "safe settings" I mean that a client will be able to work in long time
-/// durations, see the [issue 223].
-///
-/// [issue 223]: https://github.com/teloxide/teloxide/issues/223
-pub(crate) fn sound_bot() -> ClientBuilder {
-    let mut headers = HeaderMap::new();
-    headers.insert(CONNECTION, "keep-alive".parse().unwrap());
+/// Time added to [`Payload::timeout_hint`] to get the timeout of a request, to
+/// account for connecting and transferring the response.
+const TIMEOUT_HINT_MARGIN: Duration = Duration::from_secs(10);
+
+/// Maximum delay between retries of a request, unless the initial delay is
+/// bigger.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
 
-    let connect_timeout = Duration::from_secs(5);
-    let timeout = 10;
+/// Returns the delay before the `retry`-th (starting from 0) retry of a
+/// request: `initial` doubled after every retry, capped at
+/// [`MAX_RETRY_DELAY`].
+fn retry_delay(initial: Duration, retry: u32) -> Duration {
+    let max = std::cmp::max(initial, MAX_RETRY_DELAY);
 
-    ClientBuilder::new()
-        .connect_timeout(connect_timeout)
-        .timeout(Duration::from_secs(connect_timeout.as_secs() + timeout + 2))
-        .tcp_nodelay(true)
-        .default_headers(headers)
+    initial
+        .checked_mul(2u32.saturating_pow(retry))
+        .map_or(max, |delay| std::cmp::min(delay, max))
+}
+
+/// Waits before retrying a request which failed with a network error.
+async fn wait_before_retry<P: Payload>(initial: Duration, retry: u32, err: &reqwest::Error) {
+    let delay = retry_delay(initial, retry);
+
+    log::warn!(
+        "{} request failed with a network error ({}), retrying in {:?}",
+        P::NAME,
+        err,
+        delay
+    );
+    tokio::time::sleep(delay).await;
+}
+
+/// Returns a builder with safe settings, see [`ClientConfig`].
+pub(crate) fn sound_bot() -> ClientBuilder {
+    ClientConfig::new().builder()
 }
 
 pub(crate) fn build_sound_bot() -> Client {
@@ -266,3 +928,300 @@ pub(crate) fn build_sound_bot() -> Client {
 fn get_env(env: &'static str) -> String {
     std::env::var(env).unwrap_or_else(|_| panic!("Cannot get the {} env variable", env))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Read, net::TcpListener};
+
+    use futures::StreamExt;
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    /// A `GetFile` response.
+    const FILE: &str = r#"{"ok":true,"result":{"file_id":"id","file_unique_id":"uid","file_size":4,"file_path":"photos/1.jpg"}}"#;
+
+    /// Starts a server which responds to requests with `responses` (one
+    /// connection per response). Returns its url and a handle which resolves
+    /// to heads of received requests (lowercased).
+    fn serve<S>(responses: Vec<(u16, S)>) -> (reqwest::Url, std::thread::JoinHandle<Vec<String>>)
+    where
+        S: AsRef<str> + Send + 'static,
+    {
+        let (addr, server) = crate::test_server::serve(responses);
+        let heads = std::thread::spawn(move || {
+            let requests = server.join().unwrap();
+            requests
+                .into_iter()
+                .map(|r| r.head.to_lowercase())
+                .collect()
+        });
+
+        let url = reqwest::Url::parse(&format!("http://{}", addr)).unwrap();
+        (url, heads)
+    }
+
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(fut)
+    }
+
+    #[test]
+    fn extra_headers() {
+        let (url, server) = serve(vec![(200, r#"{"ok":true,"result":true}"#)]);
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-api-key", HeaderValue::from_static("secret"));
+        headers.insert(
+            reqwest::header::CONNECTION,
+            HeaderValue::from_static("close"),
+        );
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+        headers.insert(
+            reqwest::header::USER_AGENT,
+            HeaderValue::from_static("my-bot/1.0"),
+        );
+
+        let bot = Bot::new("TOKEN")
+            .set_api_url(url)
+            .with_extra_headers(headers);
+        assert!(bot.extra_headers().get(CONTENT_TYPE).is_none());
+        assert!(!format!("{:?}", bot).contains("secret"));
+
+        block_on(bot.delete_webhook().send()).unwrap();
+
+        let request = &server.join().unwrap()[0];
+        assert!(request.contains("\r\nx-api-key: secret\r\n"));
+        assert!(request.contains("\r\nconnection: close\r\n"));
+        assert!(!request.contains("keep-alive"));
+        assert!(request.contains("\r\ncontent-type: application/json\r\n"));
+        assert!(!request.contains("text/plain"));
+        assert!(request.contains("\r\nuser-agent: my-bot/1.0\r\n"));
+        assert!(!request.contains("teloxide-core"));
+    }
+
+    #[test]
+    fn retry_delay_is_capped() {
+        let initial = Duration::from_millis(500);
+        let delays: Vec<_> = (0..8)
+            .map(|retry| retry_delay(initial, retry).as_millis())
+            .collect();
+        assert_eq!(delays, [500, 1000, 2000, 4000, 8000, 16000, 30000, 30000]);
+        assert_eq!(retry_delay(initial, 20), MAX_RETRY_DELAY);
+        assert_eq!(retry_delay(initial, u32::MAX), MAX_RETRY_DELAY);
+
+        // A bigger initial delay is not reduced
+        let initial = Duration::from_secs(60);
+        assert_eq!(retry_delay(initial, 0), initial);
+        assert_eq!(retry_delay(initial, 3), initial);
+    }
+
+    #[test]
+    fn abort() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let bot = Bot::new("TOKEN").set_api_url(reqwest::Url::parse(&url).unwrap());
+        let (request, handle) = bot.delete_webhook().send_abortable();
+
+        // Aborts the request once it's received and returns after the
+        // connection is closed
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            stream
+                .set_read_timeout(Some(std::time::Duration::from_secs(5)))
+                .unwrap();
+            crate::test_server::read_request(&stream);
+
+            handle.abort();
+            let mut buf = [0; 1024];
+            while stream.read(&mut buf).unwrap() != 0 {}
+        });
+
+        block_on(async {
+            assert!(request.await.is_err());
+            // The runtime is kept alive, so the connection isn't closed by
+            // shutting it down
+            tokio::task::spawn_blocking(move || server.join().unwrap())
+                .await
+                .unwrap();
+        });
+    }
+
+    #[test]
+    fn download_by_file_id() {
+        let (url, server) = serve(vec![(200, FILE), (200, "data")]);
+        let bot = Bot::new("TOKEN").set_api_url(url);
+
+        assert_eq!(
+            &block_on(bot.download_by_file_id("id")).unwrap()[..],
+            b"data"
+        );
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("post /bottoken/getfile "));
+        assert!(requests[1].starts_with("get /file/bottoken/photos/1.jpg "));
+
+        // The file path has expired
+        let (url, _server) = serve(vec![(200, FILE), (404, "")]);
+        let bot = Bot::new("TOKEN").set_api_url(url);
+
+        let chunks: Vec<_> = block_on(bot.download_by_file_id_stream("id").collect());
+        assert!(matches!(chunks[..], [Err(DownloadError::FileNotFound)]));
+
+        let (url, _server) = serve(vec![(
+            400,
+            r#"{"ok":false,"error_code":400,"description":"Bad Request: invalid file id"}"#,
+        )]);
+        let bot = Bot::new("TOKEN").set_api_url(url);
+
+        assert!(matches!(
+            block_on(bot.download_by_file_id("id")),
+            Err(DownloadError::GetFile(RequestError::ApiError { .. }))
+        ));
+    }
+
+    #[test]
+    fn local_mode_download() {
+        let path = std::env::temp_dir().join(format!("teloxide-file-{}", uuid::Uuid::new_v4()));
+        std::fs::write(&path, "local").unwrap();
+
+        let file = serde_json::json!({
+            "ok": true,
+            "result": {
+                "file_id": "id",
+                "file_unique_id": "uid",
+                "file_size": 5,
+                "file_path": path,
+            }
+        });
+        let (url, server) = serve(vec![(200, file.to_string()), (200, "remote".to_owned())]);
+        let bot = Bot::new("TOKEN").set_api_url(url).local_mode();
+
+        // An absolute path is read from the disk
+        assert_eq!(
+            &block_on(bot.download_by_file_id("id")).unwrap()[..],
+            b"local"
+        );
+
+        // A relative path is downloaded via HTTP
+        let chunks: Vec<_> =
+            block_on(async { bot.download_file_stream("photos/1.jpg").collect().await });
+        assert_eq!(&chunks[0].as_ref().unwrap()[..], b"remote");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("post /bottoken/getfile "));
+        assert!(requests[1].starts_with("get /file/bottoken/photos/1.jpg "));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn api_router() {
+        let (main, main_server) = serve(vec![(200, r#"{"ok":true,"result":true}"#)]);
+        let (files, files_server) = serve(vec![(200, FILE), (200, "data")]);
+
+        let bot = Bot::new("TOKEN").with_api_router(move |method| match method {
+            "GetFile" | Bot::DOWNLOAD_ROUTE => files.clone(),
+            _ => main.clone(),
+        });
+
+        block_on(bot.delete_webhook().send()).unwrap();
+        assert_eq!(
+            &block_on(bot.download_by_file_id("id")).unwrap()[..],
+            b"data"
+        );
+
+        let requests = main_server.join().unwrap();
+        assert!(requests[0].starts_with("post /bottoken/deletewebhook "));
+
+        let requests = files_server.join().unwrap();
+        assert!(requests[0].starts_with("post /bottoken/getfile "));
+        assert!(requests[1].starts_with("get /file/bottoken/photos/1.jpg "));
+    }
+    #[test]
+    fn validate_lengths() {
+        use crate::{payloads::SendPhotoSetters, types::InputFile};
+
+        let (url, server) = serve(vec![(200, r#"{"ok":true,"result":true}"#)]);
+        let bot = Bot::new("TOKEN").set_api_url(url).validate_lengths();
+
+        // Too long requests are not sent
+        let res = block_on(bot.send_message(0, "🦀".repeat(2049)).send());
+        assert!(matches!(
+            res,
+            Err(RequestError::MessageTooLong {
+                limit: 4096,
+                actual: 4098
+            })
+        ));
+        let photo = bot
+            .send_photo(0, InputFile::file_id("id"))
+            .caption("a".repeat(1025));
+        assert!(matches!(
+            block_on(photo.send()),
+            Err(RequestError::MessageTooLong {
+                limit: 1024,
+                actual: 1025
+            })
+        ));
+
+        block_on(bot.edit_message_text_inline("id", "a".repeat(4096)).send()).unwrap();
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("post /bottoken/editmessagetext "));
+    }
+
+    #[test]
+    fn long_polling_timeout() {
+        use crate::payloads::{GetMe, GetUpdates, GetUpdatesSetters};
+
+        let bot = Bot::new("TOKEN");
+        let long_poll = GetUpdates::new().timeout(30);
+
+        assert_eq!(bot.request_timeout(&GetMe::new()), None);
+        assert_eq!(
+            bot.request_timeout(&long_poll),
+            Some(Duration::from_secs(40))
+        );
+
+        let bot = bot.with_timeout(Duration::from_secs(60));
+        assert_eq!(
+            bot.request_timeout(&GetMe::new()),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            bot.request_timeout(&long_poll),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            bot.request_timeout(&GetUpdates::new().timeout(120)),
+            Some(Duration::from_secs(130))
+        );
+    }
+
+    #[test]
+    fn invalid_json() {
+        let (url, _server) = serve(vec![(200, "<html>Bad Gateway</html>")]);
+        let bot = Bot::new("TOKEN").set_api_url(url);
+
+        let err = block_on(bot.get_me().send()).unwrap_err();
+        match &err {
+            RequestError::InvalidJson { method, raw, .. } => {
+                assert_eq!(*method, "GetMe");
+                assert_eq!(&**raw, "<html>Bad Gateway</html>");
+            }
+            err => panic!("unexpected error: {:?}", err),
+        }
+        assert!(err
+            .to_string()
+            .starts_with("An error while parsing the response to `GetMe`: "));
+        assert!(err
+            .to_string()
+            .ends_with("the response: <html>Bad Gateway</html>"));
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     requests::{JsonRequest, MultipartRequest},
     types::{
         BotCommand, ChatId, ChatPermissions, InlineQueryResult, InputFile, InputMedia,
-        InputSticker, LabeledPrice,
+        InputPaidMedia, InputSticker, LabeledPrice, MessageId, UserId,
     },
     Bot,
 };
@@ -18,7 +18,7 @@ impl Requester for Bot {
         Self::GetUpdates::new(self.clone(), payloads::GetUpdates::new())
     }
 
-    type SetWebhook = JsonRequest<payloads::SetWebhook>;
+    type SetWebhook = MultipartRequest<payloads::SetWebhook>;
 
     fn set_webhook<U>(&self, url: U) -> Self::SetWebhook
     where
@@ -61,7 +61,7 @@ impl Requester for Bot {
         &self,
         chat_id: C,
         from_chat_id: F,
-        message_id: i32,
+        message_id: MessageId,
     ) -> Self::ForwardMessage
     where
         C: Into<ChatId>,
@@ -152,6 +152,19 @@ impl Requester for Bot {
         Self::SendMediaGroup::new(self.clone(), payloads::SendMediaGroup::new(chat_id, media))
     }
 
+    type SendPaidMedia = MultipartRequest<payloads::SendPaidMedia>;
+
+    fn send_paid_media<C, M>(&self, chat_id: C, star_count: u32, media: M) -> Self::SendPaidMedia
+    where
+        C: Into<ChatId>,
+        M: IntoIterator<Item = InputPaidMedia>,
+    {
+        Self::SendPaidMedia::new(
+            self.clone(),
+            payloads::SendPaidMedia::new(chat_id, star_count, media),
+        )
+    }
+
     type SendLocation = JsonRequest<payloads::SendLocation>;
 
     fn send_location<C>(&self, chat_id: C, latitude: f64, longitude: f64) -> Self::SendLocation
@@ -169,7 +182,7 @@ impl Requester for Bot {
     fn edit_message_live_location<C>(
         &self,
         chat_id: C,
-        message_id: i32,
+        message_id: MessageId,
         latitude: f64,
         longitude: f64,
     ) -> Self::EditMessageLiveLocation
@@ -204,7 +217,7 @@ impl Requester for Bot {
     fn stop_message_live_location<C>(
         &self,
         chat_id: C,
-        message_id: i32,
+        message_id: MessageId,
         latitude: f64,
         longitude: f64,
     ) -> Self::StopMessageLiveLocation
@@ -313,7 +326,7 @@ impl Requester for Bot {
 
     type GetUserProfilePhotos = JsonRequest<payloads::GetUserProfilePhotos>;
 
-    fn get_user_profile_photos(&self, user_id: i64) -> Self::GetUserProfilePhotos {
+    fn get_user_profile_photos(&self, user_id: UserId) -> Self::GetUserProfilePhotos {
         Self::GetUserProfilePhotos::new(self.clone(), payloads::GetUserProfilePhotos::new(user_id))
     }
 
@@ -328,7 +341,7 @@ impl Requester for Bot {
 
     type KickChatMember = JsonRequest<payloads::KickChatMember>;
 
-    fn kick_chat_member<C>(&self, chat_id: C, user_id: i64) -> Self::KickChatMember
+    fn kick_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::KickChatMember
     where
         C: Into<ChatId>,
     {
@@ -338,9 +351,18 @@ impl Requester for Bot {
         )
     }
 
+    type BanChatMember = JsonRequest<payloads::BanChatMember>;
+
+    fn ban_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::BanChatMember
+    where
+        C: Into<ChatId>,
+    {
+        Self::BanChatMember::new(self.clone(), payloads::BanChatMember::new(chat_id, user_id))
+    }
+
     type UnbanChatMember = JsonRequest<payloads::UnbanChatMember>;
 
-    fn unban_chat_member<C>(&self, chat_id: C, user_id: i64) -> Self::UnbanChatMember
+    fn unban_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::UnbanChatMember
     where
         C: Into<ChatId>,
     {
@@ -355,7 +377,7 @@ impl Requester for Bot {
     fn restrict_chat_member<C>(
         &self,
         chat_id: C,
-        user_id: i64,
+        user_id: UserId,
         permissions: ChatPermissions,
     ) -> Self::RestrictChatMember
     where
@@ -369,7 +391,7 @@ impl Requester for Bot {
 
     type PromoteChatMember = JsonRequest<payloads::PromoteChatMember>;
 
-    fn promote_chat_member<C>(&self, chat_id: C, user_id: i64) -> Self::PromoteChatMember
+    fn promote_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::PromoteChatMember
     where
         C: Into<ChatId>,
     {
@@ -384,7 +406,7 @@ impl Requester for Bot {
     fn set_chat_administrator_custom_title<Ch, Cu>(
         &self,
         chat_id: Ch,
-        user_id: i64,
+        user_id: UserId,
         custom_title: Cu,
     ) -> Self::SetChatAdministratorCustomTitle
     where
@@ -500,7 +522,7 @@ impl Requester for Bot {
 
     type PinChatMessage = JsonRequest<payloads::PinChatMessage>;
 
-    fn pin_chat_message<C>(&self, chat_id: C, message_id: i32) -> Self::PinChatMessage
+    fn pin_chat_message<C>(&self, chat_id: C, message_id: MessageId) -> Self::PinChatMessage
     where
         C: Into<ChatId>,
     {
@@ -560,7 +582,7 @@ impl Requester for Bot {
 
     type GetChatMember = JsonRequest<payloads::GetChatMember>;
 
-    fn get_chat_member<C>(&self, chat_id: C, user_id: i64) -> Self::GetChatMember
+    fn get_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::GetChatMember
     where
         C: Into<ChatId>,
     {
@@ -616,6 +638,24 @@ impl Requester for Bot {
         Self::GetMyCommands::new(self.clone(), payloads::GetMyCommands::new())
     }
 
+    type DeleteMyCommands = JsonRequest<payloads::DeleteMyCommands>;
+
+    fn delete_my_commands(&self) -> Self::DeleteMyCommands {
+        Self::DeleteMyCommands::new(self.clone(), payloads::DeleteMyCommands::new())
+    }
+
+    type SetChatMenuButton = JsonRequest<payloads::SetChatMenuButton>;
+
+    fn set_chat_menu_button(&self) -> Self::SetChatMenuButton {
+        Self::SetChatMenuButton::new(self.clone(), payloads::SetChatMenuButton::new())
+    }
+
+    type GetChatMenuButton = JsonRequest<payloads::GetChatMenuButton>;
+
+    fn get_chat_menu_button(&self) -> Self::GetChatMenuButton {
+        Self::GetChatMenuButton::new(self.clone(), payloads::GetChatMenuButton::new())
+    }
+
     type AnswerInlineQuery = JsonRequest<payloads::AnswerInlineQuery>;
 
     fn answer_inline_query<I, R>(&self, inline_query_id: I, results: R) -> Self::AnswerInlineQuery
@@ -631,7 +671,12 @@ impl Requester for Bot {
 
     type EditMessageText = JsonRequest<payloads::EditMessageText>;
 
-    fn edit_message_text<C, T>(&self, chat_id: C, message_id: i32, text: T) -> Self::EditMessageText
+    fn edit_message_text<C, T>(
+        &self,
+        chat_id: C,
+        message_id: MessageId,
+        text: T,
+    ) -> Self::EditMessageText
     where
         C: Into<ChatId>,
         T: Into<String>,
@@ -661,7 +706,7 @@ impl Requester for Bot {
 
     type EditMessageCaption = JsonRequest<payloads::EditMessageCaption>;
 
-    fn edit_message_caption<C>(&self, chat_id: C, message_id: i32) -> Self::EditMessageCaption
+    fn edit_message_caption<C>(&self, chat_id: C, message_id: MessageId) -> Self::EditMessageCaption
     where
         C: Into<ChatId>,
     {
@@ -688,7 +733,7 @@ impl Requester for Bot {
     fn edit_message_media<C>(
         &self,
         chat_id: C,
-        message_id: i32,
+        message_id: MessageId,
         media: InputMedia,
     ) -> Self::EditMessageMedia
     where
@@ -721,7 +766,7 @@ impl Requester for Bot {
     fn edit_message_reply_markup<C>(
         &self,
         chat_id: C,
-        message_id: i32,
+        message_id: MessageId,
     ) -> Self::EditMessageReplyMarkup
     where
         C: Into<ChatId>,
@@ -749,7 +794,7 @@ impl Requester for Bot {
 
     type StopPoll = JsonRequest<payloads::StopPoll>;
 
-    fn stop_poll<C>(&self, chat_id: C, message_id: i32) -> Self::StopPoll
+    fn stop_poll<C>(&self, chat_id: C, message_id: MessageId) -> Self::StopPoll
     where
         C: Into<ChatId>,
     {
@@ -758,7 +803,7 @@ impl Requester for Bot {
 
     type DeleteMessage = JsonRequest<payloads::DeleteMessage>;
 
-    fn delete_message<C>(&self, chat_id: C, message_id: i32) -> Self::DeleteMessage
+    fn delete_message<C>(&self, chat_id: C, message_id: MessageId) -> Self::DeleteMessage
     where
         C: Into<ChatId>,
     {
@@ -768,6 +813,18 @@ impl Requester for Bot {
         )
     }
 
+    type SetMessageReaction = JsonRequest<payloads::SetMessageReaction>;
+
+    fn set_message_reaction<C>(&self, chat_id: C, message_id: MessageId) -> Self::SetMessageReaction
+    where
+        C: Into<ChatId>,
+    {
+        Self::SetMessageReaction::new(
+            self.clone(),
+            payloads::SetMessageReaction::new(chat_id, message_id),
+        )
+    }
+
     type SendSticker = MultipartRequest<payloads::SendSticker>;
 
     fn send_sticker<C>(&self, chat_id: C, sticker: InputFile) -> Self::SendSticker
@@ -788,8 +845,11 @@ impl Requester for Bot {
 
     type UploadStickerFile = MultipartRequest<payloads::UploadStickerFile>;
 
-    fn upload_sticker_file(&self, user_id: i64, png_sticker: InputFile) -> Self::UploadStickerFile where
-    {
+    fn upload_sticker_file(
+        &self,
+        user_id: UserId,
+        png_sticker: InputFile,
+    ) -> Self::UploadStickerFile where {
         Self::UploadStickerFile::new(
             self.clone(),
             payloads::UploadStickerFile::new(user_id, png_sticker),
@@ -800,7 +860,7 @@ impl Requester for Bot {
 
     fn create_new_sticker_set<N, T, E>(
         &self,
-        user_id: i64,
+        user_id: UserId,
         name: N,
         title: T,
         sticker: InputSticker,
@@ -821,7 +881,7 @@ impl Requester for Bot {
 
     fn add_sticker_to_set<N, E>(
         &self,
-        user_id: i64,
+        user_id: UserId,
         name: N,
         sticker: InputSticker,
         emojis: E,
@@ -863,7 +923,7 @@ impl Requester for Bot {
 
     type SetStickerSetThumb = MultipartRequest<payloads::SetStickerSetThumb>;
 
-    fn set_sticker_set_thumb<N>(&self, name: N, user_id: i64) -> Self::SetStickerSetThumb
+    fn set_sticker_set_thumb<N>(&self, name: N, user_id: UserId) -> Self::SetStickerSetThumb
     where
         N: Into<String>,
     {
@@ -910,6 +970,38 @@ impl Requester for Bot {
         )
     }
 
+    type CreateInvoiceLink = JsonRequest<payloads::CreateInvoiceLink>;
+
+    fn create_invoice_link<T, D, Pa, P, C, Pri>(
+        &self,
+        title: T,
+        description: D,
+        payload: Pa,
+        provider_token: P,
+        currency: C,
+        prices: Pri,
+    ) -> Self::CreateInvoiceLink
+    where
+        T: Into<String>,
+        D: Into<String>,
+        Pa: Into<String>,
+        P: Into<String>,
+        C: Into<String>,
+        Pri: IntoIterator<Item = LabeledPrice>,
+    {
+        Self::CreateInvoiceLink::new(
+            self.clone(),
+            payloads::CreateInvoiceLink::new(
+                title,
+                description,
+                payload,
+                provider_token,
+                currency,
+                prices,
+            ),
+        )
+    }
+
     type AnswerShippingQuery = JsonRequest<payloads::AnswerShippingQuery>;
 
     fn answer_shipping_query<S>(&self, shipping_query_id: S, ok: bool) -> Self::AnswerShippingQuery
@@ -938,9 +1030,25 @@ impl Requester for Bot {
         )
     }
 
+    type RefundStarPayment = JsonRequest<payloads::RefundStarPayment>;
+
+    fn refund_star_payment<T>(
+        &self,
+        user_id: UserId,
+        telegram_payment_charge_id: T,
+    ) -> Self::RefundStarPayment
+    where
+        T: Into<String>,
+    {
+        Self::RefundStarPayment::new(
+            self.clone(),
+            payloads::RefundStarPayment::new(user_id, telegram_payment_charge_id),
+        )
+    }
+
     type SetPassportDataErrors = JsonRequest<payloads::SetPassportDataErrors>;
 
-    fn set_passport_data_errors<E>(&self, user_id: i64, errors: E) -> Self::SetPassportDataErrors
+    fn set_passport_data_errors<E>(&self, user_id: UserId, errors: E) -> Self::SetPassportDataErrors
     where
         E: IntoIterator<Item = crate::types::PassportElementError>,
     {
@@ -966,10 +1074,10 @@ impl Requester for Bot {
 
     fn set_game_score(
         &self,
-        user_id: i64,
+        user_id: UserId,
         score: u64,
         chat_id: u32,
-        message_id: i64,
+        message_id: MessageId,
     ) -> Self::SetGameScore {
         Self::SetGameScore::new(
             self.clone(),
@@ -981,7 +1089,7 @@ impl Requester for Bot {
 
     fn set_game_score_inline<I>(
         &self,
-        user_id: i64,
+        user_id: UserId,
         score: u64,
         inline_message_id: I,
     ) -> Self::SetGameScoreInline
@@ -996,7 +1104,7 @@ impl Requester for Bot {
 
     type GetGameHighScores = JsonRequest<payloads::GetGameHighScores>;
 
-    fn get_game_high_scores<T>(&self, user_id: i64, target: T) -> Self::GetGameHighScores
+    fn get_game_high_scores<T>(&self, user_id: UserId, target: T) -> Self::GetGameHighScores
     where
         T: Into<crate::types::TargetMessage>,
     {
@@ -1020,7 +1128,12 @@ impl Requester for Bot {
 
     type CopyMessage = JsonRequest<payloads::CopyMessage>;
 
-    fn copy_message<C, F>(&self, chat_id: C, from_chat_id: F, message_id: i32) -> Self::CopyMessage
+    fn copy_message<C, F>(
+        &self,
+        chat_id: C,
+        from_chat_id: F,
+        message_id: MessageId,
+    ) -> Self::CopyMessage
     where
         C: Into<ChatId>,
         F: Into<ChatId>,
@@ -1031,6 +1144,44 @@ impl Requester for Bot {
         )
     }
 
+    type ForwardMessages = JsonRequest<payloads::ForwardMessages>;
+
+    fn forward_messages<C, F, M>(
+        &self,
+        chat_id: C,
+        from_chat_id: F,
+        message_ids: M,
+    ) -> Self::ForwardMessages
+    where
+        C: Into<ChatId>,
+        F: Into<ChatId>,
+        M: IntoIterator<Item = MessageId>,
+    {
+        Self::ForwardMessages::new(
+            self.clone(),
+            payloads::ForwardMessages::new(chat_id, from_chat_id, message_ids),
+        )
+    }
+
+    type CopyMessages = JsonRequest<payloads::CopyMessages>;
+
+    fn copy_messages<C, F, M>(
+        &self,
+        chat_id: C,
+        from_chat_id: F,
+        message_ids: M,
+    ) -> Self::CopyMessages
+    where
+        C: Into<ChatId>,
+        F: Into<ChatId>,
+        M: IntoIterator<Item = MessageId>,
+    {
+        Self::CopyMessages::new(
+            self.clone(),
+            payloads::CopyMessages::new(chat_id, from_chat_id, message_ids),
+        )
+    }
+
     type UnpinAllChatMessages = JsonRequest<payloads::UnpinAllChatMessages>;
 
     fn unpin_all_chat_messages<C>(&self, chat_id: C) -> Self::UnpinAllChatMessages
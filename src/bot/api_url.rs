@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{fmt, sync::Arc};
 
 #[derive(Debug, Clone)]
 pub(crate) enum ApiUrl {
@@ -16,3 +16,16 @@ impl ApiUrl {
         }
     }
 }
+
+/// A function which picks an API url for a method, see
+/// [`Bot::with_api_router`].
+///
+/// [`Bot::with_api_router`]: crate::Bot::with_api_router
+#[derive(Clone)]
+pub(crate) struct ApiRouter(pub(crate) Arc<dyn Fn(&str) -> reqwest::Url + Send + Sync>);
+
+impl fmt::Debug for ApiRouter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ApiRouter")
+    }
+}
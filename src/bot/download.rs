@@ -1,10 +1,14 @@
-use bytes::Bytes;
-use futures::{future::BoxFuture, stream::BoxStream, FutureExt, StreamExt};
+use bytes::{Bytes, BytesMut};
+use futures::{
+    future::BoxFuture, stream::BoxStream, FutureExt, StreamExt, TryFutureExt, TryStreamExt,
+};
 use tokio::io::AsyncWrite;
+use tokio_util::codec::{BytesCodec, FramedRead};
 
 use crate::{
     bot::Bot,
     net::{self, Download},
+    types::File,
     DownloadError,
 };
 
@@ -20,21 +24,46 @@ impl<'w> Download<'w> for Bot {
         path: &str,
         destination: &'w mut (dyn AsyncWrite + Unpin + Send),
     ) -> Self::Fut {
+        if self.local_mode && File::is_local_path(path) {
+            let path = path.to_owned();
+            return async move {
+                let mut file = tokio::fs::File::open(path).await?;
+                tokio::io::copy(&mut file, destination).await?;
+                Ok(())
+            }
+            .boxed();
+        }
+
         net::download_file(
             &self.client,
-            self.api_url.get(),
-            &self.token,
+            self.api_url_for(Bot::DOWNLOAD_ROUTE),
+            &self.url_token(),
             path,
             destination,
         )
         .boxed()
     }
 
-    type StreamErr = reqwest::Error;
+    type StreamErr = DownloadError;
 
     type Stream = BoxStream<'static, Result<Bytes, Self::StreamErr>>;
 
     fn download_file_stream(&self, path: &str) -> Self::Stream {
-        net::download_file_stream(&self.client, self.api_url.get(), &self.token, path).boxed()
+        if self.local_mode && File::is_local_path(path) {
+            return tokio::fs::File::open(path.to_owned())
+                .map_ok(|file| FramedRead::new(file, BytesCodec::new()).map_ok(BytesMut::freeze))
+                .try_flatten_stream()
+                .map_err(DownloadError::Io)
+                .boxed();
+        }
+
+        net::download_file_stream(
+            &self.client,
+            self.api_url_for(Bot::DOWNLOAD_ROUTE),
+            &self.url_token(),
+            path,
+        )
+        .map_err(DownloadError::from)
+        .boxed()
     }
 }
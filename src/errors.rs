@@ -1,24 +1,94 @@
-use std::io;
+use std::{borrow::Cow, fmt, io};
 
 use derive_more::From;
 use reqwest::StatusCode;
 use serde::Deserialize;
 use thiserror::Error;
 
+use crate::net::redact_token;
+
 /// An error caused by downloading a file.
-#[derive(Debug, Error, From)]
+///
+/// The bot token is redacted from the [`Display`] and [`Debug`]
+/// representations of this error.
+///
+/// [`Display`]: std::fmt::Display
+#[derive(Error, From)]
 pub enum DownloadError {
     /// A network error while downloading a file from Telegram.
-    #[error("A network error: {0}")]
+    ///
+    /// Errors created by `teloxide-core` don't contain the URL of the request
+    /// (which contains the bot token), see [`reqwest::Error::url`].
+    #[error("A network error: {}", redact_token(&.0.to_string()))]
+    #[from(ignore)]
     NetworkError(#[source] reqwest::Error),
 
     /// An I/O error while writing a file to destination.
     #[error("An I/O error: {0}")]
     Io(#[source] std::io::Error),
+
+    /// The server ignored the `Range` header of a request made by
+    /// [`download_file_range`] and responded with the whole file.
+    ///
+    /// [`download_file_range`]: crate::net::download_file_range
+    #[error("The server doesn't support range requests")]
+    #[from(ignore)]
+    RangeNotSupported,
+
+    /// The file was not found, i.e. the server responded with `404 Not
+    /// Found`.
+    ///
+    /// This usually means that the file path has expired: paths returned by
+    /// [`GetFile`] are valid for at least 1 hour, after that [`GetFile`]
+    /// should be called again.
+    ///
+    /// [`GetFile`]: crate::payloads::GetFile
+    #[error(
+        "The file was not found, the file path may have expired (call `GetFile` to get a new one)"
+    )]
+    #[from(ignore)]
+    FileNotFound,
+
+    /// An error while requesting the file path with [`GetFile`] (e.g. in
+    /// [`Bot::download_by_file_id`]).
+    ///
+    /// [`GetFile`]: crate::payloads::GetFile
+    /// [`Bot::download_by_file_id`]: crate::Bot::download_by_file_id
+    #[error("Cannot get the file path: {0}")]
+    GetFile(#[source] RequestError),
+}
+
+impl From<reqwest::Error> for DownloadError {
+    fn from(err: reqwest::Error) -> Self {
+        match err.status() {
+            Some(StatusCode::NOT_FOUND) => DownloadError::FileNotFound,
+            _ => DownloadError::NetworkError(err.without_url()),
+        }
+    }
+}
+
+impl fmt::Debug for DownloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DownloadError::NetworkError(err) => f
+                .debug_tuple("NetworkError")
+                .field(&RedactedDebug(err))
+                .finish(),
+            DownloadError::Io(err) => f.debug_tuple("Io").field(err).finish(),
+            DownloadError::RangeNotSupported => f.write_str("RangeNotSupported"),
+            DownloadError::FileNotFound => f.write_str("FileNotFound"),
+            DownloadError::GetFile(err) => f.debug_tuple("GetFile").field(err).finish(),
+        }
+    }
 }
 
 /// An error caused by sending a request to Telegram.
-#[derive(Debug, Error)]
+///
+/// The bot token is redacted from the [`Display`] and [`Debug`]
+/// representations of this error.
+///
+/// [`Display`]: std::fmt::Display
+#[derive(Error)]
 pub enum RequestError {
     /// A Telegram API error.
     #[error("A Telegram's error #{status_code}: {kind:?}")]
@@ -32,6 +102,8 @@ pub enum RequestError {
 
     /// The group has been migrated to a supergroup with the specified
     /// identifier.
+    ///
+    /// The request can be repeated with `chat_id` set to the new identifier.
     #[error("The group has been migrated to a supergroup with ID #{0}")]
     MigrateToChatId(i64),
 
@@ -41,7 +113,10 @@ pub enum RequestError {
     RetryAfter(i32),
 
     /// Network error while sending a request to Telegram.
-    #[error("A network error: {0}")]
+    ///
+    /// Errors created by `teloxide-core` don't contain the URL of the request
+    /// (which contains the bot token), see [`reqwest::Error::url`].
+    #[error("A network error: {}", redact_token(&.0.to_string()))]
     NetworkError(#[source] reqwest::Error),
 
     /// Error while parsing a response from Telegram.
@@ -50,18 +125,201 @@ pub enum RequestError {
     /// description of the error.
     ///
     /// [open an issue]: https://github.com/teloxide/teloxide/issues/new
-    #[error("An error while parsing JSON: {0}")]
-    InvalidJson(#[source] serde_json::Error),
+    #[error(
+        "An error while parsing the response to `{method}`: {source}, the response: {}",
+        snippet(raw)
+    )]
+    InvalidJson {
+        /// The name of the method the response is for, see [`Payload::NAME`].
+        ///
+        /// [`Payload::NAME`]: crate::requests::Payload::NAME
+        method: &'static str,
+
+        /// The raw response which failed to parse.
+        ///
+        /// Note that it's truncated in the [`Display`] representation of the
+        /// error.
+        ///
+        /// [`Display`]: std::fmt::Display
+        raw: Box<str>,
+
+        /// The parsing error.
+        #[source]
+        source: serde_json::Error,
+    },
 
     /// Occurs when trying to send a file to Telegram.
     #[error("An I/O error: {0}")]
     Io(#[source] io::Error),
+
+    /// A text, a caption or a chat title is longer than Telegram allows.
+    ///
+    /// This error is returned before sending the request, only if the bot
+    /// validates lengths (see [`Bot::validate_lengths`]) and the text has no
+    /// `parse_mode`. Lengths are measured in UTF-16 code units, see
+    /// [`utf16_len`].
+    ///
+    /// [`Bot::validate_lengths`]: crate::Bot::validate_lengths
+    /// [`utf16_len`]: crate::utils::utf16_len
+    #[error("The text is too long: {actual} UTF-16 code units, while the limit is {limit}")]
+    MessageTooLong {
+        /// The maximum allowed length.
+        limit: usize,
+
+        /// The length of the text.
+        actual: usize,
+    },
+
+    /// A text which must not be empty (e.g. a chat title) is empty.
+    ///
+    /// This error is returned before sending the request, only if the bot
+    /// validates lengths (see [`Bot::validate_lengths`]).
+    ///
+    /// [`Bot::validate_lengths`]: crate::Bot::validate_lengths
+    #[error("The text is empty")]
+    EmptyText,
+
+    /// A command of [`SetMyCommands`] doesn't satisfy the requirements of
+    /// Telegram, see [`BotCommand::is_valid`].
+    ///
+    /// This error is returned before sending the request, only if the bot
+    /// validates lengths (see [`Bot::validate_lengths`]).
+    ///
+    /// [`SetMyCommands`]: crate::payloads::SetMyCommands
+    /// [`BotCommand::is_valid`]: crate::types::BotCommand::is_valid
+    /// [`Bot::validate_lengths`]: crate::Bot::validate_lengths
+    #[error("Invalid bot command `{command}`")]
+    InvalidBotCommand {
+        /// The invalid command.
+        command: String,
+    },
+
+    /// The request wasn't sent because the circuit of [`CircuitBreaker`] is
+    /// open, i.e. Telegram has been unreachable recently.
+    ///
+    /// [`CircuitBreaker`]: crate::adaptors::circuit_breaker::CircuitBreaker
+    #[error("The circuit is open, the request wasn't sent")]
+    CircuitOpen,
+}
+
+impl RequestError {
+    /// Creates [`RequestError::NetworkError`], stripping the URL (which
+    /// contains the bot token) from the error.
+    pub(crate) fn network(err: reqwest::Error) -> Self {
+        RequestError::NetworkError(err.without_url())
+    }
+
+    /// Creates [`RequestError::InvalidJson`].
+    pub(crate) fn invalid_json(
+        method: &'static str,
+        raw: impl Into<Box<str>>,
+        source: serde_json::Error,
+    ) -> Self {
+        RequestError::InvalidJson {
+            method,
+            raw: raw.into(),
+            source,
+        }
+    }
+}
+
+impl fmt::Debug for RequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequestError::ApiError { kind, status_code } => f
+                .debug_struct("ApiError")
+                .field("kind", kind)
+                .field("status_code", status_code)
+                .finish(),
+            RequestError::MigrateToChatId(id) => {
+                f.debug_tuple("MigrateToChatId").field(id).finish()
+            }
+            RequestError::RetryAfter(secs) => f.debug_tuple("RetryAfter").field(secs).finish(),
+            RequestError::NetworkError(err) => f
+                .debug_tuple("NetworkError")
+                .field(&RedactedDebug(err))
+                .finish(),
+            RequestError::InvalidJson {
+                method,
+                raw,
+                source,
+            } => f
+                .debug_struct("InvalidJson")
+                .field("method", method)
+                .field("raw", raw)
+                .field("source", source)
+                .finish(),
+            RequestError::Io(err) => f.debug_tuple("Io").field(err).finish(),
+            RequestError::MessageTooLong { limit, actual } => f
+                .debug_struct("MessageTooLong")
+                .field("limit", limit)
+                .field("actual", actual)
+                .finish(),
+            RequestError::EmptyText => f.write_str("EmptyText"),
+            RequestError::InvalidBotCommand { command } => f
+                .debug_struct("InvalidBotCommand")
+                .field("command", command)
+                .finish(),
+            RequestError::CircuitOpen => f.write_str("CircuitOpen"),
+        }
+    }
+}
+
+/// The maximum number of bytes of a raw response shown in
+/// [`RequestError::InvalidJson`]'s message.
+const SNIPPET_MAX_LEN: usize = 256;
+
+/// Truncates `raw` to at most [`SNIPPET_MAX_LEN`] bytes (on a char boundary).
+fn snippet(raw: &str) -> Cow<'_, str> {
+    if raw.len() <= SNIPPET_MAX_LEN {
+        return Cow::Borrowed(raw);
+    }
+
+    let mut end = SNIPPET_MAX_LEN;
+    while !raw.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    Cow::Owned(format!("{}... ({} bytes total)", &raw[..end], raw.len()))
+}
+
+/// Formats the inner value with [`Debug`], redacting bot tokens.
+///
+/// [`Debug`]: std::fmt::Debug
+struct RedactedDebug<'a, T>(&'a T);
+
+impl<T: fmt::Debug> fmt::Debug for RedactedDebug<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = if f.alternate() {
+            format!("{:#?}", self.0)
+        } else {
+            format!("{:?}", self.0)
+        };
+
+        f.write_str(&redact_token(&s))
+    }
 }
 
 /// A kind of an API error.
 #[derive(Debug, Deserialize, PartialEq, Hash, Eq, Clone)]
 #[serde(field_identifier)]
 pub enum ApiError {
+    /// Occurs when the bot token is invalid.
+    ///
+    /// Telegram responds with `401 Unauthorized` if the token is wrong.
+    ///
+    /// Note that a malformed token (e.g. empty or without a colon) makes
+    /// Telegram respond with `404 Not Found`, which is returned as
+    /// [`ApiError::Unknown`], since the same response is returned for unknown
+    /// methods (e.g. by an older self-hosted Bot API server).
+    ///
+    /// May happen in any method. To detect a misconfigured token early, call
+    /// e.g. [`GetMe`] at startup.
+    ///
+    /// [`GetMe`]: crate::payloads::GetMe
+    #[serde(rename = "Unauthorized")]
+    InvalidToken,
+
     /// Occurs when the bot tries to send message to user who blocked the bot.
     #[serde(rename = "Forbidden: bot was blocked by the user")]
     BotBlocked,
@@ -107,6 +365,15 @@ pub enum ApiError {
     #[serde(rename = "Bad Request: message to delete not found")]
     MessageToDeleteNotFound,
 
+    /// Occurs when bot tries to copy a message which does not exists.
+    ///
+    /// May happen in methods:
+    /// 1. [`CopyMessage`]
+    ///
+    /// [`CopyMessage`]: crate::payloads::CopyMessage
+    #[serde(rename = "Bad Request: message to copy not found")]
+    MessageToCopyNotFound,
+
     /// Occurs when bot tries to send a text message without text.
     ///
     /// May happen in methods:
@@ -341,6 +608,17 @@ pub enum ApiError {
     #[serde(rename = "Bad Request: wrong file id")]
     WrongFileId,
 
+    /// Occurs when bot tries to send a file by a wrong file id or an URL.
+    ///
+    /// May happen in methods:
+    /// 1. [`SendPhoto`]
+    /// 2. [`SendDocument`]
+    ///
+    /// [`SendPhoto`]: crate::payloads::SendPhoto
+    /// [`SendDocument`]: crate::payloads::SendDocument
+    #[serde(rename = "Bad Request: wrong file identifier/HTTP URL specified")]
+    WrongFileIdOrUrl,
+
     /// Occurs when bot tries to do some with group which was deactivated.
     #[serde(rename = "Bad Request: group is deactivated")]
     GroupDeactivated,
@@ -373,6 +651,16 @@ pub enum ApiError {
     #[serde(rename = "Bad Request: not enough rights to pin a message")]
     NotEnoughRightsToPinMessage,
 
+    /// Occurs when bot tries to send a message to a chat in which it has no
+    /// rights to send messages.
+    ///
+    /// May happen in methods:
+    /// 1. [`SendMessage`]
+    ///
+    /// [`SendMessage`]: crate::payloads::SendMessage
+    #[serde(rename = "Bad Request: have no rights to send a message")]
+    NotEnoughRightsToPostMessages,
+
     /// Occurs when bot tries to use method in group which is allowed only in a
     /// supergroup or channel.
     #[serde(rename = "Bad Request: method is available only for supergroups and channel")]
@@ -472,16 +760,32 @@ pub enum ApiError {
     /// 1. [`SendMessage`]
     ///
     /// [`SendMessage`]: crate::payloads::SendMessage
-    #[serde(rename = "Unauthorized: bot was kicked from a chat")]
+    #[serde(
+        rename = "Unauthorized: bot was kicked from a chat",
+        alias = "Forbidden: bot was kicked from the group chat"
+    )]
     BotKicked,
 
+    /// Occurs when bot tries to do something in a supergroup the bot was
+    /// kicked from.
+    ///
+    /// May happen in methods:
+    /// 1. [`SendMessage`]
+    ///
+    /// [`SendMessage`]: crate::payloads::SendMessage
+    #[serde(rename = "Forbidden: bot was kicked from the supergroup chat")]
+    BotKickedFromSupergroup,
+
     /// Occurs when bot tries to send message to deactivated user.
     ///
     /// May happen in methods:
     /// 1. [`SendMessage`]
     ///
     /// [`SendMessage`]: crate::payloads::SendMessage
-    #[serde(rename = "Unauthorized: user is deactivated")]
+    #[serde(
+        rename = "Unauthorized: user is deactivated",
+        alias = "Forbidden: user is deactivated"
+    )]
     UserDeactivated,
 
     /// Occurs when you tries to initiate conversation with a user.
@@ -490,7 +794,10 @@ pub enum ApiError {
     /// 1. [`SendMessage`]
     ///
     /// [`SendMessage`]: crate::payloads::SendMessage
-    #[serde(rename = "Unauthorized: bot can't initiate conversation with a user")]
+    #[serde(
+        rename = "Unauthorized: bot can't initiate conversation with a user",
+        alias = "Forbidden: bot can't initiate conversation with a user"
+    )]
     CantInitiateConversation,
 
     /// Occurs when you tries to send message to bot.
@@ -499,7 +806,10 @@ pub enum ApiError {
     /// 1. [`SendMessage`]
     ///
     /// [`SendMessage`]: crate::payloads::SendMessage
-    #[serde(rename = "Unauthorized: bot can't send messages to bots")]
+    #[serde(
+        rename = "Unauthorized: bot can't send messages to bots",
+        alias = "Forbidden: bot can't send messages to bots"
+    )]
     CantTalkWithBots,
 
     /// Occurs when bot tries to send button with invalid http url.
@@ -541,3 +851,305 @@ pub enum ApiError {
     /// [open an issue]: https://github.com/teloxide/teloxide/issues/new
     Unknown(String),
 }
+
+impl ApiError {
+    /// Parses an error from the `description` field of a Telegram response.
+    ///
+    /// Descriptions which are not known to `teloxide` are parsed as
+    /// [`ApiError::Unknown`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::ApiError;
+    ///
+    /// assert_eq!(
+    ///     ApiError::from_description("Forbidden: bot was blocked by the user"),
+    ///     ApiError::BotBlocked
+    /// );
+    /// assert_eq!(
+    ///     ApiError::from_description("Bad Request: something new"),
+    ///     ApiError::Unknown("Bad Request: something new".to_owned())
+    /// );
+    /// ```
+    pub fn from_description(description: &str) -> Self {
+        use serde::de::{value::StrDeserializer, IntoDeserializer};
+
+        let deserializer: StrDeserializer<'_, serde::de::value::Error> =
+            description.into_deserializer();
+
+        // Unknown descriptions are deserialized into `ApiError::Unknown`, so this
+        // can't fail.
+        Self::deserialize(deserializer)
+            .unwrap_or_else(|_| ApiError::Unknown(description.to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ApiError::{self, *},
+        DownloadError, RequestError,
+    };
+
+    const TOKEN: &str = "535362388:AAF7-g0gYncWnm5IyfZlpPRqRRv6kNAGlao";
+
+    fn network_error() -> reqwest::Error {
+        let url = format!("https://api.telegram.org/bot{}/GetMe", TOKEN);
+
+        // An invalid header value, so building the request fails
+        reqwest::Client::new()
+            .get(&url)
+            .header("x", "\n")
+            .build()
+            .unwrap_err()
+            .with_url(url.parse().unwrap())
+    }
+
+    #[test]
+    fn token_is_redacted() {
+        assert!(format!("{:?}", network_error()).contains(TOKEN));
+
+        let errors = vec![
+            format!("{}", RequestError::NetworkError(network_error())),
+            format!("{:?}", RequestError::NetworkError(network_error())),
+            format!("{:#?}", RequestError::NetworkError(network_error())),
+            format!("{}", DownloadError::NetworkError(network_error())),
+            format!("{:?}", DownloadError::NetworkError(network_error())),
+        ];
+
+        for err in errors {
+            assert!(!err.contains(TOKEN), "{}", err);
+            assert!(err.contains("/bot<REDACTED>/GetMe"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn url_is_stripped() {
+        let err = RequestError::network(network_error());
+        assert!(matches!(err, RequestError::NetworkError(err) if err.url().is_none()));
+
+        let err = DownloadError::from(network_error());
+        assert!(matches!(err, DownloadError::NetworkError(err) if err.url().is_none()));
+    }
+
+    #[test]
+    fn invalid_json_is_truncated() {
+        let raw = "ы".repeat(200);
+        let source = serde_json::from_str::<()>(&raw).unwrap_err();
+        let err = RequestError::invalid_json("GetMe", raw, source).to_string();
+
+        // 256 bytes are 128 two-byte chars
+        let shown = "ы".repeat(128);
+        assert!(err.ends_with(&format!("the response: {}... (400 bytes total)", shown)));
+    }
+
+    #[test]
+    fn api_error_from_description() {
+        let cases: &[(&str, ApiError)] = &[
+            ("Unauthorized", InvalidToken),
+            ("Forbidden: bot was blocked by the user", BotBlocked),
+            (
+                "Bad Request: message is not modified: specified new message content and reply \
+                 markup are exactly the same as a current content and reply markup of the message",
+                MessageNotModified,
+            ),
+            ("Bad Request: MESSAGE_ID_INVALID", MessageIdInvalid),
+            (
+                "Bad Request: message to forward not found",
+                MessageToForwardNotFound,
+            ),
+            (
+                "Bad Request: message to delete not found",
+                MessageToDeleteNotFound,
+            ),
+            (
+                "Bad Request: message to copy not found",
+                MessageToCopyNotFound,
+            ),
+            ("Bad Request: message text is empty", MessageTextIsEmpty),
+            ("Bad Request: message can't be edited", MessageCantBeEdited),
+            (
+                "Bad Request: message can't be deleted",
+                MessageCantBeDeleted,
+            ),
+            (
+                "Bad Request: message to edit not found",
+                MessageToEditNotFound,
+            ),
+            (
+                "Bad Request: reply message not found",
+                MessageToReplyNotFound,
+            ),
+            (
+                "Bad Request: message identifier is not specified",
+                MessageIdentifierNotSpecified,
+            ),
+            ("Bad Request: message is too long", MessageIsTooLong),
+            (
+                "Bad Request: Too much messages to send as an album",
+                ToMuchMessages,
+            ),
+            (
+                "Bad Request: poll has already been closed",
+                PollHasAlreadyClosed,
+            ),
+            (
+                "Bad Request: poll must have at least 2 option",
+                PollMustHaveMoreOptions,
+            ),
+            (
+                "Bad Request: poll can't have more than 10 options",
+                PollCantHaveMoreOptions,
+            ),
+            (
+                "Bad Request: poll options must be non-empty",
+                PollOptionsMustBeNonEmpty,
+            ),
+            (
+                "Bad Request: poll question must be non-empty",
+                PollQuestionMustBeNonEmpty,
+            ),
+            (
+                "Bad Request: poll options length must not exceed 100",
+                PollOptionsLengthTooLong,
+            ),
+            (
+                "Bad Request: poll question length must not exceed 255",
+                PollQuestionLengthTooLong,
+            ),
+            (
+                "Bad Request: message with poll to stop not found",
+                MessageWithPollNotFound,
+            ),
+            ("Bad Request: message is not a poll", MessageIsNotAPoll),
+            ("Bad Request: chat not found", ChatNotFound),
+            ("Bad Request: user not found", UserNotFound),
+            (
+                "Bad Request: chat description is not modified",
+                ChatDescriptionIsNotModified,
+            ),
+            (
+                "Bad Request: query is too old and response timeout expired or query id is \
+                 invalid",
+                InvalidQueryId,
+            ),
+            ("Bad Request: BUTTON_URL_INVALID", ButtonUrlInvalid),
+            ("Bad Request: BUTTON_DATA_INVALID", ButtonDataInvalid),
+            (
+                "Bad Request: can't parse inline keyboard button: Text buttons are unallowed in \
+                 the inline keyboard",
+                TextButtonsAreUnallowed,
+            ),
+            ("Bad Request: wrong file id", WrongFileId),
+            (
+                "Bad Request: wrong file identifier/HTTP URL specified",
+                WrongFileIdOrUrl,
+            ),
+            ("Bad Request: group is deactivated", GroupDeactivated),
+            (
+                "Bad Request: Photo should be uploaded as an InputFile",
+                PhotoAsInputFileRequired,
+            ),
+            ("Bad Request: STICKERSET_INVALID", InvalidStickersSet),
+            (
+                "Bad Request: not enough rights to pin a message",
+                NotEnoughRightsToPinMessage,
+            ),
+            (
+                "Bad Request: have no rights to send a message",
+                NotEnoughRightsToPostMessages,
+            ),
+            (
+                "Bad Request: method is available only for supergroups and channel",
+                MethodNotAvailableInPrivateChats,
+            ),
+            (
+                "Bad Request: can't demote chat creator",
+                CantDemoteChatCreator,
+            ),
+            ("Bad Request: can't restrict self", CantRestrictSelf),
+            (
+                "Bad Request: not enough rights to restrict/unrestrict chat member",
+                NotEnoughRightsToRestrict,
+            ),
+            (
+                "Bad Request: bad webhook: HTTPS url must be provided for webhook",
+                WebhookRequireHttps,
+            ),
+            (
+                "Bad Request: bad webhook: Webhook can be set up only on ports 80, 88, 443 or \
+                 8443",
+                BadWebhookPort,
+            ),
+            (
+                "Bad Request: bad webhook: Failed to resolve host: Name or service not known",
+                UnknownHost,
+            ),
+            ("Bad Request: can't parse URL", CantParseUrl),
+            ("Bad Request: can't parse entities", CantParseEntities),
+            (
+                "can't use getUpdates method while webhook is active",
+                CantGetUpdates,
+            ),
+            ("Unauthorized: bot was kicked from a chat", BotKicked),
+            ("Forbidden: bot was kicked from the group chat", BotKicked),
+            (
+                "Forbidden: bot was kicked from the supergroup chat",
+                BotKickedFromSupergroup,
+            ),
+            ("Unauthorized: user is deactivated", UserDeactivated),
+            ("Forbidden: user is deactivated", UserDeactivated),
+            (
+                "Unauthorized: bot can't initiate conversation with a user",
+                CantInitiateConversation,
+            ),
+            (
+                "Forbidden: bot can't initiate conversation with a user",
+                CantInitiateConversation,
+            ),
+            (
+                "Unauthorized: bot can't send messages to bots",
+                CantTalkWithBots,
+            ),
+            (
+                "Forbidden: bot can't send messages to bots",
+                CantTalkWithBots,
+            ),
+            ("Bad Request: wrong HTTP URL", WrongHttpUrl),
+            (
+                "Conflict: terminated by other getUpdates request; make sure that only one bot \
+                 instance is running",
+                TerminatedByOtherGetUpdates,
+            ),
+            ("Bad Request: invalid file id", FileIdInvalid),
+        ];
+
+        for (description, expected) in cases {
+            assert_eq!(
+                &ApiError::from_description(description),
+                expected,
+                "{}",
+                description
+            );
+        }
+    }
+
+    #[test]
+    fn api_error_from_unknown_description() {
+        assert_eq!(
+            ApiError::from_description("Bad Request: something went wrong"),
+            Unknown("Bad Request: something went wrong".to_owned())
+        );
+        // Unknown methods and malformed tokens can't be told apart
+        assert_eq!(
+            ApiError::from_description("Not Found"),
+            Unknown("Not Found".to_owned())
+        );
+        // Matching is exact
+        assert_eq!(
+            ApiError::from_description("bad request: chat not found"),
+            Unknown("bad request: chat not found".to_owned())
+        );
+    }
+}
@@ -43,22 +43,64 @@
 //!
 //! ## Cargo features
 //!
-//! - `native-tls` = use [`native-tls`] tls implementation (**enabled by
-//!   default**)
-//! - `rustls` — use [`rustls`] tls implementation
+//! - `rustls` — use [`rustls`] tls implementation (**enabled by default**),
+//!   which doesn't link OpenSSL, so static (musl) builds and
+//!   cross-compilation work out of the box
+//! - `native-tls` — use [`native-tls`] tls implementation (OpenSSL on Linux,
+//!   the system one on macOS and Windows)
+//! - `socks` — enables support of SOCKS5 proxies (e.g. in `TELOXIDE_PROXY`)
 //! - `auto_send` — enables [`AutoSend`] bot adaptor
 //! - `throttle` — enables [`Throttle`] bot adaptor
 //! - `cache_me` — enables [`CacheMe`] bot adaptor
+//! - `resolve_usernames` — enables [`ResolveUsernames`] bot adaptor
+//! - `retry` — enables [`Retry`] bot adaptor
+//! - `circuit_breaker` — enables [`CircuitBreaker`] bot adaptor
+//! - `erased` — enables [`ErasedRequester`] bot adaptor
+//! - `inspect` — enables [`Inspect`] bot adaptor
+//! - `metrics` — enables [`Metrics`] bot adaptor
+//! - `mock` — enables [`Mock`] bot adaptor
+//! - `route_by_chat` — enables [`RouteByChat`] bot adaptor
+//! - `trace` — enables [`Trace`] bot adaptor
+//! - `web_app` — enables [`utils::web_app`] (validation of Web App
+//!   `initData`)
+//! - `login_widget` — enables [`utils::login_widget`] (validation of Login
+//!   Widget authorization data)
+//! - `chrono` — enables [`chrono`] getters for dates (e.g.
+//!   [`Message::date_utc`])
 //! - `full` — enables all features except `nigthly`
 //! - `nightly` — enables nigthly-only features, currently:
 //!   - Removes some future boxing using `#![feature(type_alias_impl_trait)]`
 //!   - Used to built docs (`#![feature(doc_cfg, doc_spotlight)]`)
 //!
+//! The TLS backend is used by all clients created by this crate (e.g. by
+//! [`Bot::new`], [`Bot::from_env`] and [`ClientConfig`]):
+//!
+//! | Features                                     | TLS backend             |
+//! |----------------------------------------------|-------------------------|
+//! | default (`rustls`)                           | [`rustls`]              |
+//! | `native-tls` with `default-features = false` | [`native-tls`]          |
+//! | `native-tls` and `rustls`                    | [`native-tls`]          |
+//! | none (`default-features = false`)            | none, only `http` works |
+//!
 //! [`AutoSend`]: adaptors::AutoSend
 //! [`Throttle`]: adaptors::Throttle
 //! [`CacheMe`]: adaptors::CacheMe
+//! [`ResolveUsernames`]: adaptors::ResolveUsernames
+//! [`Retry`]: adaptors::Retry
+//! [`CircuitBreaker`]: adaptors::CircuitBreaker
+//! [`ErasedRequester`]: adaptors::ErasedRequester
+//! [`Inspect`]: adaptors::Inspect
+//! [`Metrics`]: adaptors::Metrics
+//! [`Mock`]: adaptors::Mock
+//! [`RouteByChat`]: adaptors::RouteByChat
+//! [`Trace`]: adaptors::Trace
+//! [`Message::date_utc`]: types::Message::date_utc
+//! [`Bot::new`]: crate::Bot::new
+//! [`Bot::from_env`]: crate::Bot::from_env
+//! [`ClientConfig`]: crate::net::ClientConfig
 //! [`native-tls`]: https://docs.rs/native-tls
 //! [`rustls`]: https://docs.rs/rustls
+//! [`chrono`]: https://docs.rs/chrono
 
 #![doc(
     // FIXME(waffle): use github
@@ -92,6 +134,7 @@ pub mod payloads;
 pub mod prelude;
 pub mod requests;
 pub mod types;
+pub mod utils;
 
 // reexported
 mod bot;
@@ -99,3 +142,6 @@ mod errors;
 
 // implementation details
 mod serde_multipart;
+
+#[cfg(test)]
+mod test_server;
@@ -133,6 +133,250 @@ macro_rules! calculated_doc {
     );
 }
 
+/// Returns the name of the Telegram method of a payload, see
+/// [`Payload::NAME`].
+///
+/// Inline variants of methods (e.g. `EditMessageTextInline`) use the same
+/// Telegram method as their chat counterparts.
+///
+/// [`Payload::NAME`]: crate::requests::Payload::NAME
+macro_rules! payload_name {
+    (EditMessageLiveLocationInline) => {
+        "EditMessageLiveLocation"
+    };
+    (StopMessageLiveLocationInline) => {
+        "StopMessageLiveLocation"
+    };
+    (EditMessageTextInline) => {
+        "EditMessageText"
+    };
+    (EditMessageCaptionInline) => {
+        "EditMessageCaption"
+    };
+    (EditMessageMediaInline) => {
+        "EditMessageMedia"
+    };
+    (EditMessageReplyMarkupInline) => {
+        "EditMessageReplyMarkup"
+    };
+    (SetGameScoreInline) => {
+        "SetGameScore"
+    };
+    ($Method:ident) => {
+        stringify!($Method)
+    };
+}
+
+/// Returns `true` for payloads of idempotent (getter-like) methods, see
+/// [`Payload::IDEMPOTENT`].
+///
+/// [`Payload::IDEMPOTENT`]: crate::requests::Payload::IDEMPOTENT
+macro_rules! is_idempotent {
+    (GetMe) => {
+        true
+    };
+    (GetUpdates) => {
+        true
+    };
+    (GetWebhookInfo) => {
+        true
+    };
+    (GetUserProfilePhotos) => {
+        true
+    };
+    (GetFile) => {
+        true
+    };
+    (GetChat) => {
+        true
+    };
+    (GetChatAdministrators) => {
+        true
+    };
+    (GetChatMembersCount) => {
+        true
+    };
+    (GetChatMember) => {
+        true
+    };
+    (GetMyCommands) => {
+        true
+    };
+    (DeleteMyCommands) => {
+        true
+    };
+    (GetChatMenuButton) => {
+        true
+    };
+    (GetStickerSet) => {
+        true
+    };
+    (GetGameHighScores) => {
+        true
+    };
+    ($Method:ident) => {
+        false
+    };
+}
+
+/// Implements [`Payload::timeout_hint`] for payloads of long-running methods.
+///
+/// [`Payload::timeout_hint`]: crate::requests::Payload::timeout_hint
+macro_rules! payload_timeout_hint {
+    (GetUpdates) => {
+        fn timeout_hint(&self) -> Option<core::time::Duration> {
+            self.timeout
+                .map(|secs| core::time::Duration::from_secs(secs.into()))
+        }
+    };
+    ($other:ident) => {};
+}
+
+/// Adds shortcut setters to setters traits of some payloads.
+macro_rules! payload_shortcut_setters {
+    (AnswerCallbackQuery) => {
+        /// Shows the notification as an alert, instead of a notification at
+        /// the top of the chat screen (sets `show_alert` to `true`).
+        ///
+        /// The text of the alert should be set with [`text`].
+        ///
+        /// [`text`]: AnswerCallbackQuerySetters::text
+        fn alert(self) -> Self {
+            self.show_alert(true)
+        }
+
+        /// Shows `text` as a notification at the top of the chat screen (sets
+        /// `text` and sets `show_alert` to `false`).
+        fn toast<T>(self, text: T) -> Self
+        where
+            T: Into<String>,
+        {
+            self.text(text).show_alert(false)
+        }
+    };
+    ($other:ident) => {};
+}
+
+/// Implements [`Payload::check_lengths`] for payloads which have texts,
+/// captions, chat titles or bot commands.
+///
+/// [`Payload::check_lengths`]: crate::requests::Payload::check_lengths
+macro_rules! payload_check_lengths {
+    (SendMessage) => {
+        payload_check_lengths!(@text);
+    };
+    (EditMessageText) => {
+        payload_check_lengths!(@text);
+    };
+    (EditMessageTextInline) => {
+        payload_check_lengths!(@text);
+    };
+    (SendPhoto) => {
+        payload_check_lengths!(@caption);
+    };
+    (SendAudio) => {
+        payload_check_lengths!(@caption);
+    };
+    (SendDocument) => {
+        payload_check_lengths!(@caption);
+    };
+    (SendVideo) => {
+        payload_check_lengths!(@caption);
+    };
+    (SendAnimation) => {
+        payload_check_lengths!(@caption);
+    };
+    (SendVoice) => {
+        payload_check_lengths!(@caption);
+    };
+    (SendPaidMedia) => {
+        payload_check_lengths!(@caption);
+    };
+    (CopyMessage) => {
+        payload_check_lengths!(@caption);
+    };
+    (EditMessageCaption) => {
+        payload_check_lengths!(@caption);
+    };
+    (EditMessageCaptionInline) => {
+        payload_check_lengths!(@caption);
+    };
+    (SendMediaGroup) => {
+        fn check_lengths(&self) -> Result<(), $crate::RequestError> {
+            self.media.iter().try_for_each(|media| {
+                let (caption, parse_mode) = media.caption();
+                $crate::utils::check_caption(caption, parse_mode)
+            })
+        }
+    };
+    (SetChatTitle) => {
+        fn check_lengths(&self) -> Result<(), $crate::RequestError> {
+            $crate::utils::check_non_empty_len(&self.title, $crate::utils::CHAT_TITLE_MAX_LEN)
+        }
+    };
+    (SetMyCommands) => {
+        fn check_lengths(&self) -> Result<(), $crate::RequestError> {
+            $crate::types::check_bot_commands(&self.commands)
+        }
+    };
+    (@text) => {
+        fn check_lengths(&self) -> Result<(), $crate::RequestError> {
+            $crate::utils::check_len_unless_parsed(
+                &self.text,
+                self.parse_mode,
+                $crate::utils::MESSAGE_TEXT_MAX_LEN,
+            )
+        }
+    };
+    (@caption) => {
+        fn check_lengths(&self) -> Result<(), $crate::RequestError> {
+            $crate::utils::check_caption(self.caption.as_deref(), self.parse_mode)
+        }
+    };
+    ($other:ident) => {};
+}
+
+/// Finds the `chat_id` field among the fields of a payload, used to implement
+/// [`HasChatId::chat_id`].
+///
+/// [`HasChatId::chat_id`]: crate::requests::HasChatId::chat_id
+macro_rules! payload_chat_id {
+    (@mut $this:tt) => {
+        None
+    };
+    (@mut $this:tt chat_id $($rest:ident)*) => {
+        $crate::requests::has_chat_id::AsChatId::as_chat_id_mut(&mut $this.chat_id)
+    };
+    (@mut $this:tt $other:ident $($rest:ident)*) => {
+        payload_chat_id!(@mut $this $($rest)*)
+    };
+    ($this:tt) => {
+        None
+    };
+    ($this:tt chat_id $($rest:ident)*) => {
+        $crate::requests::has_chat_id::AsChatId::as_chat_id(&$this.chat_id)
+    };
+    ($this:tt $other:ident $($rest:ident)*) => {
+        payload_chat_id!($this $($rest)*)
+    };
+}
+
+/// Finds the `message_thread_id` field among the optional fields of a payload,
+/// used to implement [`HasChatId::message_thread_id`].
+///
+/// [`HasChatId::message_thread_id`]: crate::requests::HasChatId::message_thread_id
+macro_rules! payload_message_thread_id {
+    ($this:tt) => {
+        None
+    };
+    ($this:tt message_thread_id $($rest:ident)*) => {
+        $this.message_thread_id
+    };
+    ($this:tt $other:ident $($rest:ident)*) => {
+        payload_message_thread_id!($this $($rest)*)
+    };
+}
+
 /// Declare payload type, implement `Payload` trait and ::new method for it,
 /// declare setters trait and implement it for all type which have payload.
 #[macro_use]
@@ -220,7 +464,27 @@ macro_rules! impl_payload {
         impl $crate::requests::Payload for $Method {
             type Output = $Ret;
 
-            const NAME: &'static str = stringify!($Method);
+            const NAME: &'static str = payload_name!($Method);
+
+            const IDEMPOTENT: bool = is_idempotent!($Method);
+
+            payload_check_lengths!($Method);
+
+            payload_timeout_hint!($Method);
+        }
+
+        impl $crate::requests::HasChatId for $Method {
+            fn chat_id(&self) -> Option<&$crate::types::ChatId> {
+                payload_chat_id!(self $($($fields)*)?)
+            }
+
+            fn chat_id_mut(&mut self) -> Option<&mut $crate::types::ChatId> {
+                payload_chat_id!(@mut self $($($fields)*)?)
+            }
+
+            fn message_thread_id(&self) -> Option<$crate::types::ThreadId> {
+                payload_message_thread_id!(self $($($opt_fields)*)?)
+            }
         }
 
         calculated_doc! {
@@ -240,6 +504,8 @@ macro_rules! impl_payload {
                         impl_payload! { @setter_opt $Method $opt_fields : $OptFTy $([$opt_conv])? }
                     )*
                 )?
+
+                payload_shortcut_setters!($Method);
             }
         }
 
@@ -267,6 +533,28 @@ macro_rules! impl_payload {
                 self
             }
         }
+
+        paste::paste! {
+            calculated_doc! {
+                #[doc = concat!(
+                    "Setter for [`",
+                    stringify!($field),
+                    "`](",
+                    stringify!($Method),
+                    "::",
+                    stringify!($field),
+                    ") field, which sets it to `value` (or unsets it, if `value` is `None`)."
+                )]
+                #[allow(clippy::wrong_self_convention)]
+                fn [<maybe_ $field>]<T>(mut self, value: ::core::option::Option<T>) -> Self
+                where
+                    T: Into<$FTy>,
+                {
+                    self.payload_mut().$field = value.map(Into::into);
+                    self
+                }
+            }
+        }
     };
     (@setter_opt $Method:ident $field:ident : $FTy:ty [collect]) => {
         calculated_doc! {
@@ -288,6 +576,28 @@ macro_rules! impl_payload {
                 self
             }
         }
+
+        paste::paste! {
+            calculated_doc! {
+                #[doc = concat!(
+                    "Setter for [`",
+                    stringify!($field),
+                    "`](",
+                    stringify!($Method),
+                    "::",
+                    stringify!($field),
+                    ") field, which sets it to `value` (or unsets it, if `value` is `None`)."
+                )]
+                #[allow(clippy::wrong_self_convention)]
+                fn [<maybe_ $field>]<T>(mut self, value: ::core::option::Option<T>) -> Self
+                where
+                    T: ::core::iter::IntoIterator<Item = <$FTy as ::core::iter::IntoIterator>::Item>,
+                {
+                    self.payload_mut().$field = value.map(|value| value.into_iter().collect());
+                    self
+                }
+            }
+        }
     };
     (@setter_opt $Method:ident $field:ident : $FTy:ty) => {
         calculated_doc! {
@@ -306,6 +616,25 @@ macro_rules! impl_payload {
                 self
             }
         }
+
+        paste::paste! {
+            calculated_doc! {
+                #[doc = concat!(
+                    "Setter for [`",
+                    stringify!($field),
+                    "`](",
+                    stringify!($Method),
+                    "::",
+                    stringify!($field),
+                    ") field, which sets it to `value` (or unsets it, if `value` is `None`)."
+                )]
+                #[allow(clippy::wrong_self_convention)]
+                fn [<maybe_ $field>](mut self, value: ::core::option::Option<$FTy>) -> Self {
+                    self.payload_mut().$field = value;
+                    self
+                }
+            }
+        }
     };
     (@setter $Method:ident $field:ident : $FTy:ty [into]) => {
         calculated_doc! {
@@ -471,19 +800,39 @@ macro_rules! requester_forward {
     (@method forward_message $body:ident $ty:ident) => {
         type ForwardMessage = $ty![ForwardMessage];
 
-        fn forward_message<C, F>(&self, chat_id: C, from_chat_id: F, message_id: i32) -> Self::ForwardMessage where C: Into<ChatId>,
+        fn forward_message<C, F>(&self, chat_id: C, from_chat_id: F, message_id: MessageId) -> Self::ForwardMessage where C: Into<ChatId>,
         F: Into<ChatId> {
             let this = self;
-            $body!(forward_message this (chat_id: C, from_chat_id: F, message_id: i32))
+            $body!(forward_message this (chat_id: C, from_chat_id: F, message_id: MessageId))
+        }
+    };
+    (@method forward_messages $body:ident $ty:ident) => {
+        type ForwardMessages = $ty![ForwardMessages];
+
+        fn forward_messages<C, F, M>(&self, chat_id: C, from_chat_id: F, message_ids: M) -> Self::ForwardMessages where C: Into<ChatId>,
+        F: Into<ChatId>,
+        M: IntoIterator<Item = MessageId> {
+            let this = self;
+            $body!(forward_messages this (chat_id: C, from_chat_id: F, message_ids: M))
         }
     };
     (@method copy_message $body:ident $ty:ident) => {
         type CopyMessage = $ty![CopyMessage];
 
-        fn copy_message<C, F>(&self, chat_id: C, from_chat_id: F, message_id: i32) -> Self::CopyMessage where C: Into<ChatId>,
+        fn copy_message<C, F>(&self, chat_id: C, from_chat_id: F, message_id: MessageId) -> Self::CopyMessage where C: Into<ChatId>,
         F: Into<ChatId> {
             let this = self;
-            $body!(copy_message this (chat_id: C, from_chat_id: F, message_id: i32))
+            $body!(copy_message this (chat_id: C, from_chat_id: F, message_id: MessageId))
+        }
+    };
+    (@method copy_messages $body:ident $ty:ident) => {
+        type CopyMessages = $ty![CopyMessages];
+
+        fn copy_messages<C, F, M>(&self, chat_id: C, from_chat_id: F, message_ids: M) -> Self::CopyMessages where C: Into<ChatId>,
+        F: Into<ChatId>,
+        M: IntoIterator<Item = MessageId> {
+            let this = self;
+            $body!(copy_messages this (chat_id: C, from_chat_id: F, message_ids: M))
         }
     };
     (@method send_photo $body:ident $ty:ident) => {
@@ -551,6 +900,15 @@ macro_rules! requester_forward {
             $body!(send_media_group this (chat_id: C, media: M))
         }
     };
+    (@method send_paid_media $body:ident $ty:ident) => {
+        type SendPaidMedia = $ty![SendPaidMedia];
+
+        fn send_paid_media<C, M>(&self, chat_id: C, star_count: u32, media: M) -> Self::SendPaidMedia where C: Into<ChatId>,
+        M: IntoIterator<Item = InputPaidMedia> {
+            let this = self;
+            $body!(send_paid_media this (chat_id: C, star_count: u32, media: M))
+        }
+    };
     (@method send_location $body:ident $ty:ident) => {
         type SendLocation = $ty![SendLocation];
 
@@ -562,9 +920,9 @@ macro_rules! requester_forward {
     (@method edit_message_live_location $body:ident $ty:ident) => {
         type EditMessageLiveLocation = $ty![EditMessageLiveLocation];
 
-        fn edit_message_live_location<C>(&self, chat_id: C, message_id: i32, latitude: f64, longitude: f64) -> Self::EditMessageLiveLocation where C: Into<ChatId> {
+        fn edit_message_live_location<C>(&self, chat_id: C, message_id: MessageId, latitude: f64, longitude: f64) -> Self::EditMessageLiveLocation where C: Into<ChatId> {
             let this = self;
-            $body!(edit_message_live_location this (chat_id: C, message_id: i32, latitude: f64, longitude: f64))
+            $body!(edit_message_live_location this (chat_id: C, message_id: MessageId, latitude: f64, longitude: f64))
         }
     };
     (@method edit_message_live_location_inline $body:ident $ty:ident) => {
@@ -578,9 +936,9 @@ macro_rules! requester_forward {
     (@method stop_message_live_location $body:ident $ty:ident) => {
         type StopMessageLiveLocation = $ty![StopMessageLiveLocation];
 
-        fn stop_message_live_location<C>(&self, chat_id: C, message_id: i32, latitude: f64, longitude: f64) -> Self::StopMessageLiveLocation where C: Into<ChatId> {
+        fn stop_message_live_location<C>(&self, chat_id: C, message_id: MessageId, latitude: f64, longitude: f64) -> Self::StopMessageLiveLocation where C: Into<ChatId> {
             let this = self;
-            $body!(stop_message_live_location this (chat_id: C, message_id: i32, latitude: f64, longitude: f64))
+            $body!(stop_message_live_location this (chat_id: C, message_id: MessageId, latitude: f64, longitude: f64))
         }
     };
     (@method stop_message_live_location_inline $body:ident $ty:ident) => {
@@ -640,9 +998,9 @@ macro_rules! requester_forward {
     (@method get_user_profile_photos $body:ident $ty:ident) => {
         type GetUserProfilePhotos = $ty![GetUserProfilePhotos];
 
-        fn get_user_profile_photos(&self, user_id: i64) -> Self::GetUserProfilePhotos {
+        fn get_user_profile_photos(&self, user_id: UserId) -> Self::GetUserProfilePhotos {
             let this = self;
-            $body!(get_user_profile_photos this (user_id: i64))
+            $body!(get_user_profile_photos this (user_id: UserId))
         }
     };
     (@method get_file $body:ident $ty:ident) => {
@@ -656,42 +1014,50 @@ macro_rules! requester_forward {
     (@method kick_chat_member $body:ident $ty:ident) => {
         type KickChatMember = $ty![KickChatMember];
 
-        fn kick_chat_member<C>(&self, chat_id: C, user_id: i64) -> Self::KickChatMember where C: Into<ChatId> {
+        fn kick_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::KickChatMember where C: Into<ChatId> {
             let this = self;
-            $body!(kick_chat_member this (chat_id: C, user_id: i64))
+            $body!(kick_chat_member this (chat_id: C, user_id: UserId))
+        }
+    };
+    (@method ban_chat_member $body:ident $ty:ident) => {
+        type BanChatMember = $ty![BanChatMember];
+
+        fn ban_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::BanChatMember where C: Into<ChatId> {
+            let this = self;
+            $body!(ban_chat_member this (chat_id: C, user_id: UserId))
         }
     };
     (@method unban_chat_member $body:ident $ty:ident) => {
         type UnbanChatMember = $ty![UnbanChatMember];
 
-        fn unban_chat_member<C>(&self, chat_id: C, user_id: i64) -> Self::UnbanChatMember where C: Into<ChatId> {
+        fn unban_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::UnbanChatMember where C: Into<ChatId> {
             let this = self;
-            $body!(unban_chat_member this (chat_id: C, user_id: i64))
+            $body!(unban_chat_member this (chat_id: C, user_id: UserId))
         }
     };
     (@method restrict_chat_member $body:ident $ty:ident) => {
         type RestrictChatMember = $ty![RestrictChatMember];
 
-        fn restrict_chat_member<C>(&self, chat_id: C, user_id: i64, permissions: ChatPermissions) -> Self::RestrictChatMember where C: Into<ChatId> {
+        fn restrict_chat_member<C>(&self, chat_id: C, user_id: UserId, permissions: ChatPermissions) -> Self::RestrictChatMember where C: Into<ChatId> {
             let this = self;
-            $body!(restrict_chat_member this (chat_id: C, user_id: i64, permissions: ChatPermissions))
+            $body!(restrict_chat_member this (chat_id: C, user_id: UserId, permissions: ChatPermissions))
         }
     };
     (@method promote_chat_member $body:ident $ty:ident) => {
         type PromoteChatMember = $ty![PromoteChatMember];
 
-        fn promote_chat_member<C>(&self, chat_id: C, user_id: i64) -> Self::PromoteChatMember where C: Into<ChatId> {
+        fn promote_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::PromoteChatMember where C: Into<ChatId> {
             let this = self;
-            $body!(promote_chat_member this (chat_id: C, user_id: i64))
+            $body!(promote_chat_member this (chat_id: C, user_id: UserId))
         }
     };
     (@method set_chat_administrator_custom_title $body:ident $ty:ident) => {
         type SetChatAdministratorCustomTitle = $ty![SetChatAdministratorCustomTitle];
 
-        fn set_chat_administrator_custom_title<Ch, Cu>(&self, chat_id: Ch, user_id: i64, custom_title: Cu) -> Self::SetChatAdministratorCustomTitle where Ch: Into<ChatId>,
+        fn set_chat_administrator_custom_title<Ch, Cu>(&self, chat_id: Ch, user_id: UserId, custom_title: Cu) -> Self::SetChatAdministratorCustomTitle where Ch: Into<ChatId>,
         Cu: Into<String> {
             let this = self;
-            $body!(set_chat_administrator_custom_title this (chat_id: Ch, user_id: i64, custom_title: Cu))
+            $body!(set_chat_administrator_custom_title this (chat_id: Ch, user_id: UserId, custom_title: Cu))
         }
     };
     (@method set_chat_permissions $body:ident $ty:ident) => {
@@ -772,9 +1138,9 @@ macro_rules! requester_forward {
     (@method pin_chat_message $body:ident $ty:ident) => {
         type PinChatMessage = $ty![PinChatMessage];
 
-        fn pin_chat_message<C>(&self, chat_id: C, message_id: i32) -> Self::PinChatMessage where C: Into<ChatId> {
+        fn pin_chat_message<C>(&self, chat_id: C, message_id: MessageId) -> Self::PinChatMessage where C: Into<ChatId> {
             let this = self;
-            $body!(pin_chat_message this (chat_id: C, message_id: i32))
+            $body!(pin_chat_message this (chat_id: C, message_id: MessageId))
         }
     };
     (@method unpin_chat_message $body:ident $ty:ident) => {
@@ -828,9 +1194,9 @@ macro_rules! requester_forward {
     (@method get_chat_member $body:ident $ty:ident) => {
         type GetChatMember = $ty![GetChatMember];
 
-        fn get_chat_member<C>(&self, chat_id: C, user_id: i64) -> Self::GetChatMember where C: Into<ChatId> {
+        fn get_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::GetChatMember where C: Into<ChatId> {
             let this = self;
-            $body!(get_chat_member this (chat_id: C, user_id: i64))
+            $body!(get_chat_member this (chat_id: C, user_id: UserId))
         }
     };
     (@method set_chat_sticker_set $body:ident $ty:ident) => {
@@ -874,6 +1240,30 @@ macro_rules! requester_forward {
             $body!(get_my_commands this ())
         }
     };
+    (@method delete_my_commands $body:ident $ty:ident) => {
+        type DeleteMyCommands = $ty![DeleteMyCommands];
+
+        fn delete_my_commands(&self) -> Self::DeleteMyCommands {
+            let this = self;
+            $body!(delete_my_commands this ())
+        }
+    };
+    (@method set_chat_menu_button $body:ident $ty:ident) => {
+        type SetChatMenuButton = $ty![SetChatMenuButton];
+
+        fn set_chat_menu_button(&self) -> Self::SetChatMenuButton {
+            let this = self;
+            $body!(set_chat_menu_button this ())
+        }
+    };
+    (@method get_chat_menu_button $body:ident $ty:ident) => {
+        type GetChatMenuButton = $ty![GetChatMenuButton];
+
+        fn get_chat_menu_button(&self) -> Self::GetChatMenuButton {
+            let this = self;
+            $body!(get_chat_menu_button this ())
+        }
+    };
     (@method answer_inline_query $body:ident $ty:ident) => {
         type AnswerInlineQuery = $ty![AnswerInlineQuery];
 
@@ -886,10 +1276,10 @@ macro_rules! requester_forward {
     (@method edit_message_text $body:ident $ty:ident) => {
         type EditMessageText = $ty![EditMessageText];
 
-        fn edit_message_text<C, T>(&self, chat_id: C, message_id: i32, text: T) -> Self::EditMessageText where C: Into<ChatId>,
+        fn edit_message_text<C, T>(&self, chat_id: C, message_id: MessageId, text: T) -> Self::EditMessageText where C: Into<ChatId>,
         T: Into<String> {
             let this = self;
-            $body!(edit_message_text this (chat_id: C, message_id: i32, text: T))
+            $body!(edit_message_text this (chat_id: C, message_id: MessageId, text: T))
         }
     };
     (@method edit_message_text_inline $body:ident $ty:ident) => {
@@ -904,9 +1294,9 @@ macro_rules! requester_forward {
     (@method edit_message_caption $body:ident $ty:ident) => {
         type EditMessageCaption = $ty![EditMessageCaption];
 
-        fn edit_message_caption<C>(&self, chat_id: C, message_id: i32) -> Self::EditMessageCaption where C: Into<ChatId> {
+        fn edit_message_caption<C>(&self, chat_id: C, message_id: MessageId) -> Self::EditMessageCaption where C: Into<ChatId> {
             let this = self;
-            $body!(edit_message_caption this (chat_id: C, message_id: i32))
+            $body!(edit_message_caption this (chat_id: C, message_id: MessageId))
         }
     };
     (@method edit_message_caption_inline $body:ident $ty:ident) => {
@@ -920,9 +1310,9 @@ macro_rules! requester_forward {
     (@method edit_message_media $body:ident $ty:ident) => {
         type EditMessageMedia = $ty![EditMessageMedia];
 
-        fn edit_message_media<C>(&self, chat_id: C, message_id: i32, media: InputMedia) -> Self::EditMessageMedia where C: Into<ChatId> {
+        fn edit_message_media<C>(&self, chat_id: C, message_id: MessageId, media: InputMedia) -> Self::EditMessageMedia where C: Into<ChatId> {
             let this = self;
-            $body!(edit_message_media this (chat_id: C, message_id: i32, media: InputMedia))
+            $body!(edit_message_media this (chat_id: C, message_id: MessageId, media: InputMedia))
         }
     };
     (@method edit_message_media_inline $body:ident $ty:ident) => {
@@ -936,9 +1326,9 @@ macro_rules! requester_forward {
     (@method edit_message_reply_markup $body:ident $ty:ident) => {
         type EditMessageReplyMarkup = $ty![EditMessageReplyMarkup];
 
-        fn edit_message_reply_markup<C>(&self, chat_id: C, message_id: i32) -> Self::EditMessageReplyMarkup where C: Into<ChatId> {
+        fn edit_message_reply_markup<C>(&self, chat_id: C, message_id: MessageId) -> Self::EditMessageReplyMarkup where C: Into<ChatId> {
             let this = self;
-            $body!(edit_message_reply_markup this (chat_id: C, message_id: i32))
+            $body!(edit_message_reply_markup this (chat_id: C, message_id: MessageId))
         }
     };
     (@method edit_message_reply_markup_inline $body:ident $ty:ident) => {
@@ -952,17 +1342,25 @@ macro_rules! requester_forward {
     (@method stop_poll $body:ident $ty:ident) => {
         type StopPoll = $ty![StopPoll];
 
-        fn stop_poll<C>(&self, chat_id: C, message_id: i32) -> Self::StopPoll where C: Into<ChatId> {
+        fn stop_poll<C>(&self, chat_id: C, message_id: MessageId) -> Self::StopPoll where C: Into<ChatId> {
             let this = self;
-            $body!(stop_poll this (chat_id: C, message_id: i32))
+            $body!(stop_poll this (chat_id: C, message_id: MessageId))
         }
     };
     (@method delete_message $body:ident $ty:ident) => {
         type DeleteMessage = $ty![DeleteMessage];
 
-        fn delete_message<C>(&self, chat_id: C, message_id: i32) -> Self::DeleteMessage where C: Into<ChatId> {
+        fn delete_message<C>(&self, chat_id: C, message_id: MessageId) -> Self::DeleteMessage where C: Into<ChatId> {
             let this = self;
-            $body!(delete_message this (chat_id: C, message_id: i32))
+            $body!(delete_message this (chat_id: C, message_id: MessageId))
+        }
+    };
+    (@method set_message_reaction $body:ident $ty:ident) => {
+        type SetMessageReaction = $ty![SetMessageReaction];
+
+        fn set_message_reaction<C>(&self, chat_id: C, message_id: MessageId) -> Self::SetMessageReaction where C: Into<ChatId> {
+            let this = self;
+            $body!(set_message_reaction this (chat_id: C, message_id: MessageId))
         }
     };
     (@method send_sticker $body:ident $ty:ident) => {
@@ -984,28 +1382,28 @@ macro_rules! requester_forward {
     (@method upload_sticker_file $body:ident $ty:ident) => {
         type UploadStickerFile = $ty![UploadStickerFile];
 
-        fn upload_sticker_file(&self, user_id: i64, png_sticker: InputFile) -> Self::UploadStickerFile {
+        fn upload_sticker_file(&self, user_id: UserId, png_sticker: InputFile) -> Self::UploadStickerFile {
             let this = self;
-            $body!(upload_sticker_file this (user_id: i64, png_sticker: InputFile))
+            $body!(upload_sticker_file this (user_id: UserId, png_sticker: InputFile))
         }
     };
     (@method create_new_sticker_set $body:ident $ty:ident) => {
         type CreateNewStickerSet = $ty![CreateNewStickerSet];
 
-        fn create_new_sticker_set<N, T, E>(&self, user_id: i64, name: N, title: T, sticker: InputSticker, emojis: E) -> Self::CreateNewStickerSet where N: Into<String>,
+        fn create_new_sticker_set<N, T, E>(&self, user_id: UserId, name: N, title: T, sticker: InputSticker, emojis: E) -> Self::CreateNewStickerSet where N: Into<String>,
         T: Into<String>,
         E: Into<String> {
             let this = self;
-            $body!(create_new_sticker_set this (user_id: i64, name: N, title: T, sticker: InputSticker, emojis: E))
+            $body!(create_new_sticker_set this (user_id: UserId, name: N, title: T, sticker: InputSticker, emojis: E))
         }
     };
     (@method add_sticker_to_set $body:ident $ty:ident) => {
         type AddStickerToSet = $ty![AddStickerToSet];
 
-        fn add_sticker_to_set<N, E>(&self, user_id: i64, name: N, sticker: InputSticker, emojis: E) -> Self::AddStickerToSet where N: Into<String>,
+        fn add_sticker_to_set<N, E>(&self, user_id: UserId, name: N, sticker: InputSticker, emojis: E) -> Self::AddStickerToSet where N: Into<String>,
         E: Into<String> {
             let this = self;
-            $body!(add_sticker_to_set this (user_id: i64, name: N, sticker: InputSticker, emojis: E))
+            $body!(add_sticker_to_set this (user_id: UserId, name: N, sticker: InputSticker, emojis: E))
         }
     };
     (@method set_sticker_position_in_set $body:ident $ty:ident) => {
@@ -1027,9 +1425,9 @@ macro_rules! requester_forward {
     (@method set_sticker_set_thumb $body:ident $ty:ident) => {
         type SetStickerSetThumb = $ty![SetStickerSetThumb];
 
-        fn set_sticker_set_thumb<N>(&self, name: N, user_id: i64) -> Self::SetStickerSetThumb where N: Into<String> {
+        fn set_sticker_set_thumb<N>(&self, name: N, user_id: UserId) -> Self::SetStickerSetThumb where N: Into<String> {
             let this = self;
-            $body!(set_sticker_set_thumb this (name: N, user_id: i64))
+            $body!(set_sticker_set_thumb this (name: N, user_id: UserId))
         }
     };
     (@method send_invoice $body:ident $ty:ident) => {
@@ -1046,6 +1444,19 @@ macro_rules! requester_forward {
             $body!(send_invoice this (chat_id: i32, title: T, description: D, payload: Pa, provider_token: P, start_parameter: S, currency: C, prices: Pri))
         }
     };
+    (@method create_invoice_link $body:ident $ty:ident) => {
+        type CreateInvoiceLink = $ty![CreateInvoiceLink];
+
+        fn create_invoice_link<T, D, Pa, P, C, Pri>(&self, title: T, description: D, payload: Pa, provider_token: P, currency: C, prices: Pri) -> Self::CreateInvoiceLink where T: Into<String>,
+        D: Into<String>,
+        Pa: Into<String>,
+        P: Into<String>,
+        C: Into<String>,
+        Pri: IntoIterator<Item = LabeledPrice> {
+            let this = self;
+            $body!(create_invoice_link this (title: T, description: D, payload: Pa, provider_token: P, currency: C, prices: Pri))
+        }
+    };
     (@method answer_shipping_query $body:ident $ty:ident) => {
         type AnswerShippingQuery = $ty![AnswerShippingQuery];
 
@@ -1062,12 +1473,20 @@ macro_rules! requester_forward {
             $body!(answer_pre_checkout_query this (pre_checkout_query_id: P, ok: bool))
         }
     };
+    (@method refund_star_payment $body:ident $ty:ident) => {
+        type RefundStarPayment = $ty![RefundStarPayment];
+
+        fn refund_star_payment<T>(&self, user_id: UserId, telegram_payment_charge_id: T) -> Self::RefundStarPayment where T: Into<String> {
+            let this = self;
+            $body!(refund_star_payment this (user_id: UserId, telegram_payment_charge_id: T))
+        }
+    };
     (@method set_passport_data_errors $body:ident $ty:ident) => {
         type SetPassportDataErrors = $ty![SetPassportDataErrors];
 
-        fn set_passport_data_errors<E>(&self, user_id: i64, errors: E) -> Self::SetPassportDataErrors where E: IntoIterator<Item = PassportElementError> {
+        fn set_passport_data_errors<E>(&self, user_id: UserId, errors: E) -> Self::SetPassportDataErrors where E: IntoIterator<Item = PassportElementError> {
             let this = self;
-            $body!(set_passport_data_errors this (user_id: i64, errors: E))
+            $body!(set_passport_data_errors this (user_id: UserId, errors: E))
         }
     };
     (@method send_game $body:ident $ty:ident) => {
@@ -1081,25 +1500,25 @@ macro_rules! requester_forward {
     (@method set_game_score $body:ident $ty:ident) => {
         type SetGameScore = $ty![SetGameScore];
 
-        fn set_game_score(&self, user_id: i64, score: u64, chat_id: u32, message_id: i64) -> Self::SetGameScore {
+        fn set_game_score(&self, user_id: UserId, score: u64, chat_id: u32, message_id: MessageId) -> Self::SetGameScore {
             let this = self;
-            $body!(set_game_score this (user_id: i64, score: u64, chat_id: u32, message_id: i64))
+            $body!(set_game_score this (user_id: UserId, score: u64, chat_id: u32, message_id: MessageId))
         }
     };
     (@method set_game_score_inline $body:ident $ty:ident) => {
         type SetGameScoreInline = $ty![SetGameScoreInline];
 
-        fn set_game_score_inline<I>(&self, user_id: i64, score: u64, inline_message_id: I) -> Self::SetGameScoreInline where I: Into<String> {
+        fn set_game_score_inline<I>(&self, user_id: UserId, score: u64, inline_message_id: I) -> Self::SetGameScoreInline where I: Into<String> {
             let this = self;
-            $body!(set_game_score_inline this (user_id: i64, score: u64, inline_message_id: I))
+            $body!(set_game_score_inline this (user_id: UserId, score: u64, inline_message_id: I))
         }
     };
     (@method get_game_high_scores $body:ident $ty:ident) => {
         type GetGameHighScores = $ty![GetGameHighScores];
 
-        fn get_game_high_scores<T>(&self, user_id: i64, target: T) -> Self::GetGameHighScores where T: Into<TargetMessage> {
+        fn get_game_high_scores<T>(&self, user_id: UserId, target: T) -> Self::GetGameHighScores where T: Into<TargetMessage> {
             let this = self;
-            $body!(get_game_high_scores this (user_id: i64, target: T))
+            $body!(get_game_high_scores this (user_id: UserId, target: T))
         }
     };
     (@method get_updates_fault_tolerant $body:ident $ty:ident) => {
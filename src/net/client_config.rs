@@ -0,0 +1,326 @@
+use std::time::Duration;
+
+use reqwest::{
+    header::{HeaderMap, HeaderValue, CONNECTION},
+    Client, ClientBuilder,
+};
+
+/// The default `User-Agent` of the http-client.
+const DEFAULT_USER_AGENT: &str = concat!("teloxide-core/", env!("CARGO_PKG_VERSION"));
+
+/// Settings of the [http-client](reqwest::Client) used by [`Bot`].
+///
+/// The defaults are "safe settings", which allow the client to work in long
+/// time durations (see the [issue 223]), so only the settings which need to
+/// be changed have to be set.
+///
+/// ## Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use teloxide_core::{net::ClientConfig, Bot};
+///
+/// let config = ClientConfig::new()
+///     .timeout(Duration::from_secs(60))
+///     .user_agent("my-bot/1.0");
+///
+/// let bot = Bot::from_config("TOKEN", config);
+/// ```
+///
+/// [`Bot`]: crate::Bot
+/// [issue 223]: https://github.com/teloxide/teloxide/issues/223
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    connect_timeout: Duration,
+    timeout: Option<Duration>,
+    tcp_nodelay: bool,
+    tcp_keepalive: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    http2_prior_knowledge: bool,
+    http2_adaptive_window: bool,
+}
+
+impl ClientConfig {
+    /// Creates a config with the default settings.
+    pub fn new() -> Self {
+        Self {
+            connect_timeout: Duration::from_secs(5),
+            timeout: Some(Duration::from_secs(17)),
+            tcp_nodelay: true,
+            tcp_keepalive: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            user_agent: Some(DEFAULT_USER_AGENT.to_owned()),
+            http2_prior_knowledge: false,
+            http2_adaptive_window: false,
+        }
+    }
+
+    /// Sets the timeout of connecting to the server.
+    ///
+    /// Default: 5 seconds.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Sets the timeout of requests, from when a request starts connecting
+    /// until the response body has finished.
+    ///
+    /// Note that [`Bot`] extends timeouts of long-running requests (e.g.
+    /// [`GetUpdates`] used for long polling) regardless of this setting, see
+    /// [`Payload::timeout_hint`].
+    ///
+    /// Default: 17 seconds.
+    ///
+    /// [`Bot`]: crate::Bot
+    /// [`GetUpdates`]: crate::payloads::GetUpdates
+    /// [`Payload::timeout_hint`]: crate::requests::Payload::timeout_hint
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Disables the timeout of requests, see [`ClientConfig::timeout`].
+    pub fn no_timeout(mut self) -> Self {
+        self.timeout = None;
+        self
+    }
+
+    /// Sets whether `TCP_NODELAY` is set on connections.
+    ///
+    /// Default: `true`.
+    pub fn tcp_nodelay(mut self, enabled: bool) -> Self {
+        self.tcp_nodelay = enabled;
+        self
+    }
+
+    /// Sets the interval of TCP keepalive probes, `None` disables them.
+    ///
+    /// Default: `None`.
+    pub fn tcp_keepalive(mut self, interval: Option<Duration>) -> Self {
+        self.tcp_keepalive = interval;
+        self
+    }
+
+    /// Sets the maximum number of idle connections kept for each host.
+    ///
+    /// Default: no limit.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Sets the time after which idle connections are closed.
+    ///
+    /// Default: the default of [`reqwest`] (90 seconds).
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the `User-Agent` header of requests.
+    ///
+    /// Note that a `User-Agent` set with [`Bot::with_extra_headers`] takes
+    /// precedence over this one.
+    ///
+    /// Default: `teloxide-core/<version>`.
+    ///
+    /// [`Bot::with_extra_headers`]: crate::Bot::with_extra_headers
+    pub fn user_agent<S>(mut self, user_agent: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Disables the `User-Agent` header, see [`ClientConfig::user_agent`].
+    pub fn no_user_agent(mut self) -> Self {
+        self.user_agent = None;
+        self
+    }
+
+    /// Sets whether only HTTP/2 is used, without negotiating the protocol.
+    ///
+    /// By default the protocol (HTTP/1.1 or HTTP/2) is negotiated with the
+    /// server via TLS ALPN, with both `rustls` and `native-tls` backends, so
+    /// this option isn't needed to use HTTP/2 with the Telegram API. It's
+    /// meant for servers which are known to accept HTTP/2 without
+    /// negotiation, e.g. a self-hosted [Bot API server] behind a proxy which
+    /// speaks HTTP/2 over plain TCP. Whether `api.telegram.org` accepts
+    /// HTTP/2 without negotiation isn't verified, if it doesn't, every request
+    /// fails.
+    ///
+    /// HTTP/2 multiplexes concurrent requests over a single connection, which
+    /// reduces the number of connections (and TLS handshakes) when many
+    /// requests are sent concurrently (e.g. when broadcasting). It doesn't
+    /// help long polling, which sends one request at a time. Measured with
+    /// `benches/http2.rs` (100 requests to a local server without TLS, which
+    /// responds in 20 ms):
+    ///
+    /// | Requests   | Protocol | Time    | Connections |
+    /// |------------|----------|---------|-------------|
+    /// | sequential | HTTP/1.1 | 2120 ms | 1           |
+    /// | sequential | HTTP/2   | 2120 ms | 1           |
+    /// | concurrent | HTTP/1.1 | 25 ms   | 100         |
+    /// | concurrent | HTTP/2   | 24 ms   | 1           |
+    ///
+    /// Default: `false`.
+    ///
+    /// [Bot API server]: https://github.com/tdlib/telegram-bot-api
+    pub fn http2_prior_knowledge(mut self, enabled: bool) -> Self {
+        self.http2_prior_knowledge = enabled;
+        self
+    }
+
+    /// Sets whether HTTP/2 flow control windows are adjusted based on
+    /// estimated bandwidth, which may improve throughput of large uploads.
+    ///
+    /// This only has an effect when HTTP/2 is used, see
+    /// [`ClientConfig::http2_prior_knowledge`].
+    ///
+    /// Default: `false`.
+    pub fn http2_adaptive_window(mut self, enabled: bool) -> Self {
+        self.http2_adaptive_window = enabled;
+        self
+    }
+
+    /// Returns a [`ClientBuilder`] with these settings, so settings which
+    /// `ClientConfig` doesn't support (e.g. a proxy) can be added.
+    ///
+    /// The TLS backend is selected by the cargo features of this crate, see
+    /// the [crate docs](crate#cargo-features).
+    pub fn builder(self) -> ClientBuilder {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONNECTION, HeaderValue::from_static("keep-alive"));
+
+        let mut builder = ClientBuilder::new()
+            .connect_timeout(self.connect_timeout)
+            .tcp_nodelay(self.tcp_nodelay)
+            .tcp_keepalive(self.tcp_keepalive)
+            .default_headers(headers);
+
+        // `native-tls` is only enabled explicitly (unlike the default `rustls`),
+        // so it wins when both backends are enabled
+        #[cfg(feature = "native-tls")]
+        {
+            builder = builder.use_native_tls();
+        }
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(user_agent) = self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        if self.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+        if self.http2_adaptive_window {
+            builder = builder.http2_adaptive_window(true);
+        }
+
+        builder
+    }
+
+    /// Builds a [`Client`] with these settings.
+    pub fn build(self) -> reqwest::Result<Client> {
+        self.builder().build()
+    }
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Read, net::TcpListener};
+
+    use super::*;
+
+    /// Sends a request with a client built from `config` and returns the head
+    /// of the request received by the server.
+    fn request_head(config: ClientConfig) -> String {
+        let (addr, server) = crate::test_server::serve_once();
+        let url = format!("http://{}/", addr);
+
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async { config.build().unwrap().get(&url).send().await })
+            .unwrap();
+
+        server.join().unwrap().head
+    }
+
+    #[test]
+    fn default_headers() {
+        let request = request_head(ClientConfig::new());
+        let user_agent = format!(
+            "user-agent: teloxide-core/{}\r\n",
+            env!("CARGO_PKG_VERSION")
+        );
+        assert!(request.contains(&user_agent), "{}", request);
+        assert!(
+            request.contains("connection: keep-alive\r\n"),
+            "{}",
+            request
+        );
+    }
+
+    #[test]
+    fn user_agent() {
+        let config = ClientConfig::new()
+            .user_agent("my-bot/1.0")
+            .pool_max_idle_per_host(1);
+        let request = request_head(config);
+        assert!(
+            request.contains("user-agent: my-bot/1.0\r\n"),
+            "{}",
+            request
+        );
+
+        let request = request_head(ClientConfig::new().no_user_agent());
+        assert!(!request.contains("user-agent"), "{}", request);
+    }
+
+    #[test]
+    fn http2_prior_knowledge() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut preface = [0; 24];
+            stream.read_exact(&mut preface).unwrap();
+            preface
+        });
+
+        let config = ClientConfig::new()
+            .http2_prior_knowledge(true)
+            .http2_adaptive_window(true)
+            .timeout(std::time::Duration::from_secs(1));
+        // The server doesn't speak HTTP/2, so the request fails
+        let _ = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async { config.build().unwrap().get(&url).send().await });
+
+        assert_eq!(&server.join().unwrap(), b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n");
+    }
+}
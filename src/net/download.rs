@@ -6,7 +6,7 @@ use futures::{
     stream::{once, unfold},
     FutureExt, Stream, StreamExt,
 };
-use reqwest::{Client, Response, Url};
+use reqwest::{header::RANGE, Client, Response, StatusCode, Url};
 use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 use crate::{errors::DownloadError, net::file_url};
@@ -84,6 +84,11 @@ pub trait Download<'w>
 /// Note: if you don't need to use a different (from you're bot) client and
 /// don't need to get *all* performance (and you don't, c'mon it's very io-bound
 /// job), then it's recommended to use [`Download::download_file`].
+///
+/// To download a file from the [test environment], pass `"<token>/test"` as
+/// `token`.
+///
+/// [test environment]: crate::Bot::test_env
 pub fn download_file<'o, D>(
     client: &Client,
     api_url: Url,
@@ -94,18 +99,56 @@ pub fn download_file<'o, D>(
 where
     D: ?Sized + AsyncWrite + Unpin,
 {
+    download_file_response(client, api_url, token, path).then(move |r| async move {
+        let mut res = r?;
+
+        while let Some(chunk) = res.chunk().await? {
+            dst.write_all(&chunk).await?;
+        }
+
+        Ok(())
+    })
+}
+
+/// Sends a request to download a file from Telegram and returns the response.
+///
+/// This is useful if you need access to the response headers (e.g.
+/// `Content-Type`, `ETag` or `Last-Modified`), for example to implement
+/// custom caching. The body can then be read with e.g. [`Response::chunk`].
+///
+/// Responses with error status codes (4xx and 5xx) are converted to
+/// [`DownloadError::NetworkError`], except for `404 Not Found`, which is
+/// converted to [`DownloadError::FileNotFound`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use reqwest::header::ETAG;
+/// use teloxide_core::{net::download_file_response, Bot};
+///
+/// # async fn run() -> Result<(), teloxide_core::DownloadError> {
+/// let bot = Bot::new("TOKEN");
+/// # let path = "";
+///
+/// let response = download_file_response(bot.client(), bot.api_url(), bot.token(), path).await?;
+/// if let Some(etag) = response.headers().get(ETAG) {
+///     println!("ETag: {:?}", etag);
+/// }
+/// let bytes = response.bytes().await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`Response::chunk`]: reqwest::Response::chunk
+pub fn download_file_response(
+    client: &Client,
+    api_url: Url,
+    token: &str,
+    path: &str,
+) -> impl Future<Output = Result<Response, DownloadError>> + 'static {
     client
         .get(file_url(api_url, token, path))
         .send()
-        .then(move |r| async move {
-            let mut res = r?.error_for_status()?;
-
-            while let Some(chunk) = res.chunk().await? {
-                dst.write_all(&chunk).await?;
-            }
-
-            Ok(())
-        })
+        .map(|r| Ok(r?.error_for_status()?))
 }
 
 /// Download a file from Telegram as [`Stream`].
@@ -124,13 +167,141 @@ pub fn download_file_stream(
         .send()
         .into_stream()
         .flat_map(|res| match res.and_then(Response::error_for_status) {
-            Ok(res) => Either::Left(unfold(res, |mut res| async {
-                match res.chunk().await {
-                    Err(err) => Some((Err(err), res)),
-                    Ok(Some(c)) => Some((Ok(c), res)),
-                    Ok(None) => None,
-                }
-            })),
-            Err(err) => Either::Right(once(ready(Err(err)))),
+            Ok(res) => Either::Left(chunks(res)),
+            // Strip the URL, since it contains the bot token
+            Err(err) => Either::Right(once(ready(Err(err.without_url())))),
         })
 }
+
+/// Download a file from Telegram as [`Stream`], alongside with the total size
+/// of the file.
+///
+/// The returned future resolves as soon as the response headers are received,
+/// so the total size (taken from the `Content-Length` header) is known before
+/// the first chunk of the file. This allows e.g. to render a progress bar
+/// while downloading big files:
+///
+/// ```no_run
+/// use futures::StreamExt;
+/// use teloxide_core::{net::download_file_with_progress, Bot};
+///
+/// # async fn run() -> Result<(), teloxide_core::DownloadError> {
+/// let bot = Bot::new("TOKEN");
+/// # let path = "";
+///
+/// let (total, stream) =
+///     download_file_with_progress(bot.client(), bot.api_url(), bot.token(), path).await?;
+/// futures::pin_mut!(stream);
+///
+/// let mut downloaded = 0;
+/// while let Some(chunk) = stream.next().await {
+///     downloaded += chunk?.len() as u64;
+///
+///     match total {
+///         Some(total) => println!("{}/{} bytes", downloaded, total),
+///         None => println!("{} bytes", downloaded),
+///     }
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// ## `Content-Length`
+///
+/// The total size is `None` if the server didn't send the `Content-Length`
+/// header (e.g. if the response uses chunked transfer encoding). In this case
+/// the end of the file can only be detected by the end of the stream.
+///
+/// ## Errors
+///
+/// Chunks are yielded as soon as they are received, the response is never
+/// buffered. If a network error happens in the middle of the download, it's
+/// yielded from the stream and the stream ends.
+pub fn download_file_with_progress(
+    client: &Client,
+    api_url: Url,
+    token: &str,
+    path: &str,
+) -> impl Future<
+    Output = Result<
+        (
+            Option<u64>,
+            impl Stream<Item = Result<Bytes, DownloadError>> + 'static,
+        ),
+        DownloadError,
+    >,
+> + 'static {
+    let response = client.get(file_url(api_url, token, path)).send();
+
+    async move {
+        let response = response.await?.error_for_status()?;
+        let total = response.content_length();
+        let stream = chunks(response).map(|chunk| chunk.map_err(DownloadError::from));
+
+        Ok((total, stream))
+    }
+}
+
+/// Download a part of a file from Telegram as [`Stream`].
+///
+/// This function sets the [`Range`] header, so only bytes from `start` to `end`
+/// (**inclusive**) are downloaded. If `end` is `None`, bytes from `start` to
+/// the end of the file are downloaded.
+///
+/// This is useful to resume interrupted downloads: if `n` bytes were already
+/// written to the destination, the rest can be downloaded with
+/// `download_file_range(.., n, None)`.
+///
+/// ## Errors
+///
+/// If the server ignores the `Range` header (i.e. responds with `200 OK`
+/// instead of `206 Partial Content`), the stream yields
+/// [`DownloadError::RangeNotSupported`] and ends, without yielding any bytes.
+///
+/// [`Range`]: https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Range
+pub fn download_file_range(
+    client: &Client,
+    api_url: Url,
+    token: &str,
+    path: &str,
+    start: u64,
+    end: Option<u64>,
+) -> impl Stream<Item = Result<Bytes, DownloadError>> + 'static {
+    let range = match end {
+        Some(end) => format!("bytes={}-{}", start, end),
+        None => format!("bytes={}-", start),
+    };
+
+    client
+        .get(file_url(api_url, token, path))
+        .header(RANGE, range)
+        .send()
+        .into_stream()
+        .flat_map(|res| {
+            let res = res
+                .and_then(Response::error_for_status)
+                .map_err(DownloadError::from)
+                .and_then(|res| match res.status() {
+                    StatusCode::PARTIAL_CONTENT => Ok(res),
+                    _ => Err(DownloadError::RangeNotSupported),
+                });
+
+            match res {
+                Ok(res) => Either::Left(chunks(res).map(|c| c.map_err(DownloadError::from))),
+                Err(err) => Either::Right(once(ready(Err(err)))),
+            }
+        })
+}
+
+/// Returns a stream of chunks of the response body.
+///
+/// The stream ends after the first error.
+fn chunks(response: Response) -> impl Stream<Item = reqwest::Result<Bytes>> + 'static {
+    unfold(Some(response), |res| async {
+        let mut res = res?;
+        match res.chunk().await {
+            Err(err) => Some((Err(err), None)),
+            Ok(Some(c)) => Some((Ok(c), Some(res))),
+            Ok(None) => None,
+        }
+    })
+}
@@ -1,7 +1,7 @@
 use std::time::Duration;
 
 use reqwest::{
-    header::{HeaderValue, CONTENT_TYPE},
+    header::{HeaderMap, HeaderValue, CONTENT_TYPE},
     Client, Response,
 };
 use serde::de::DeserializeOwned;
@@ -14,44 +14,56 @@ pub async fn request_multipart<T>(
     client: &Client,
     token: &str,
     api_url: reqwest::Url,
-    method_name: &str,
+    method_name: &'static str,
     params: reqwest::multipart::Form,
+    timeout: Option<Duration>,
+    headers: &HeaderMap,
 ) -> ResponseResult<T>
 where
     T: DeserializeOwned,
 {
-    let response = client
+    let mut request = client
         .post(crate::net::method_url(api_url, token, method_name))
-        .multipart(params)
-        .send()
-        .await
-        .map_err(RequestError::NetworkError)?;
+        .headers(headers.clone())
+        .multipart(params);
 
-    process_response(response).await
+    if let Some(timeout) = timeout {
+        request = request.timeout(timeout);
+    }
+
+    let response = request.send().await.map_err(RequestError::network)?;
+
+    process_response(method_name, response).await
 }
 
 pub async fn request_json<T>(
     client: &Client,
     token: &str,
     api_url: reqwest::Url,
-    method_name: &str,
+    method_name: &'static str,
     params: Vec<u8>,
+    timeout: Option<Duration>,
+    headers: &HeaderMap,
 ) -> ResponseResult<T>
 where
     T: DeserializeOwned,
 {
-    let response = client
+    let mut request = client
         .post(crate::net::method_url(api_url, token, method_name))
+        .headers(headers.clone())
         .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
-        .body(params)
-        .send()
-        .await
-        .map_err(RequestError::NetworkError)?;
+        .body(params);
+
+    if let Some(timeout) = timeout {
+        request = request.timeout(timeout);
+    }
+
+    let response = request.send().await.map_err(RequestError::network)?;
 
-    process_response(response).await
+    process_response(method_name, response).await
 }
 
-async fn process_response<T>(response: Response) -> ResponseResult<T>
+async fn process_response<T>(method_name: &'static str, response: Response) -> ResponseResult<T>
 where
     T: DeserializeOwned,
 {
@@ -59,9 +71,8 @@ where
         tokio::time::sleep(DELAY_ON_SERVER_ERROR).await;
     }
 
-    serde_json::from_str::<TelegramResponse<T>>(
-        &response.text().await.map_err(RequestError::NetworkError)?,
-    )
-    .map_err(RequestError::InvalidJson)?
-    .into()
+    let text = response.text().await.map_err(RequestError::network)?;
+    serde_json::from_str::<TelegramResponse<T>>(&text)
+        .map_err(|err| RequestError::invalid_json(method_name, text, err))?
+        .into()
 }
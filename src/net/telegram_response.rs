@@ -25,6 +25,7 @@ pub(crate) enum TelegramResponse<R> {
         #[serde(rename = "description")]
         error: ApiError,
         error_code: u16,
+        #[serde(rename = "parameters")]
         response_parameters: Option<ResponseParameters>,
     },
 }
@@ -85,4 +86,40 @@ mod tests {
             matches!(val, TelegramResponse::Err { error: ApiError::Unknown(s), .. } if s == "Unknown description that won't match anything")
         );
     }
+
+    #[test]
+    fn parse_migrate_to_chat_id() {
+        let s = r#"{"ok":false,"error_code":400,"description":"Bad Request: group chat was upgraded to a supergroup chat","parameters":{"migrate_to_chat_id":-1001234567890}}"#;
+        let val = serde_json::from_str::<TelegramResponse<Update>>(s).unwrap();
+
+        assert!(matches!(
+            ResponseResult::from(val),
+            Err(RequestError::MigrateToChatId(-1001234567890))
+        ));
+    }
+
+    #[test]
+    fn parse_retry_after() {
+        let s = r#"{"ok":false,"error_code":429,"description":"Too Many Requests: retry after 35","parameters":{"retry_after":35}}"#;
+        let val = serde_json::from_str::<TelegramResponse<Update>>(s).unwrap();
+
+        assert!(matches!(
+            ResponseResult::from(val),
+            Err(RequestError::RetryAfter(35))
+        ));
+    }
+
+    #[test]
+    fn parse_invalid_token() {
+        let s = r#"{"ok":false,"error_code":401,"description":"Unauthorized"}"#;
+        let val = serde_json::from_str::<TelegramResponse<Update>>(s).unwrap();
+
+        assert!(matches!(
+            ResponseResult::from(val),
+            Err(RequestError::ApiError {
+                kind: ApiError::InvalidToken,
+                status_code: StatusCode::UNAUTHORIZED,
+            })
+        ));
+    }
 }
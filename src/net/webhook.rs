@@ -0,0 +1,43 @@
+/// The name of the header which contains the secret token of a webhook.
+///
+/// If a secret token was set for the webhook, Telegram sends it in this header
+/// with every webhook request, see [`check_secret_token`].
+pub const SECRET_TOKEN_HEADER: &str = "X-Telegram-Bot-Api-Secret-Token";
+
+/// Checks that the value of the [`SECRET_TOKEN_HEADER`] header of a webhook
+/// request matches the `expected` secret token.
+///
+/// `received` is the value of the header or `None` if the request doesn't have
+/// it, in which case `false` is returned. The comparison takes the same time
+/// regardless of where the tokens differ, so it doesn't leak the token through
+/// timing (only its length).
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::net::check_secret_token;
+///
+/// // e.g. `headers.get(SECRET_TOKEN_HEADER).map(|v| v.as_bytes())`
+/// let received = Some(&b"my-secret"[..]);
+///
+/// assert!(check_secret_token("my-secret", received));
+/// assert!(!check_secret_token("my-secret", Some(b"not-my-secret")));
+/// assert!(!check_secret_token("my-secret", None));
+/// ```
+pub fn check_secret_token(expected: &str, received: Option<&[u8]>) -> bool {
+    let received = match received {
+        Some(received) => received,
+        None => return false,
+    };
+
+    let expected = expected.as_bytes();
+    if expected.len() != received.len() {
+        return false;
+    }
+
+    expected
+        .iter()
+        .zip(received)
+        .fold(0, |acc, (a, b)| acc | (a ^ b))
+        == 0
+}
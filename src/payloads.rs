@@ -1,4 +1,28 @@
 //! Request data sent to Telegram.
+//!
+//! Every optional field of a payload has two setters: one which sets the
+//! field (e.g. `reply_to_message_id`) and one which accepts an [`Option`]
+//! (e.g. `maybe_reply_to_message_id`), which is handy when the value is
+//! optional anyway:
+//!
+//! ```
+//! use teloxide_core::{
+//!     payloads::{SendMessage, SendMessageSetters},
+//!     types::{MessageId, ParseMode},
+//! };
+//!
+//! fn reply(text: &str, reply_to: Option<MessageId>) -> SendMessage {
+//!     SendMessage::new(0, text)
+//!         .parse_mode(ParseMode::Html)
+//!         .maybe_reply_to_message_id(reply_to)
+//! }
+//!
+//! assert_eq!(reply("text", None).reply_to_message_id, None);
+//! assert_eq!(
+//!     reply("text", Some(MessageId(1))).reply_to_message_id,
+//!     Some(MessageId(1))
+//! );
+//! ```
 
 /// This module re-exports all the setters traits as `_`.
 ///
@@ -20,13 +44,17 @@ mod answer_callback_query;
 mod answer_inline_query;
 mod answer_pre_checkout_query;
 mod answer_shipping_query;
+mod ban_chat_member;
 mod close;
 mod copy_message;
+mod copy_messages;
 mod create_chat_invite_link;
+mod create_invoice_link;
 mod create_new_sticker_set;
 mod delete_chat_photo;
 mod delete_chat_sticker_set;
 mod delete_message;
+mod delete_my_commands;
 mod delete_sticker_from_set;
 mod delete_webhook;
 mod edit_chat_invite_link;
@@ -42,10 +70,12 @@ mod edit_message_text;
 mod edit_message_text_inline;
 mod export_chat_invite_link;
 mod forward_message;
+mod forward_messages;
 mod get_chat;
 mod get_chat_administrators;
 mod get_chat_member;
 mod get_chat_members_count;
+mod get_chat_menu_button;
 mod get_file;
 mod get_game_high_scores;
 mod get_me;
@@ -59,6 +89,7 @@ mod leave_chat;
 mod log_out;
 mod pin_chat_message;
 mod promote_chat_member;
+mod refund_star_payment;
 mod restrict_chat_member;
 mod revoke_chat_invite_link;
 mod send_animation;
@@ -72,6 +103,7 @@ mod send_invoice;
 mod send_location;
 mod send_media_group;
 mod send_message;
+mod send_paid_media;
 mod send_photo;
 mod send_poll;
 mod send_sticker;
@@ -81,12 +113,14 @@ mod send_video_note;
 mod send_voice;
 mod set_chat_administrator_custom_title;
 mod set_chat_description;
+mod set_chat_menu_button;
 mod set_chat_permissions;
 mod set_chat_photo;
 mod set_chat_sticker_set;
 mod set_chat_title;
 mod set_game_score;
 mod set_game_score_inline;
+mod set_message_reaction;
 mod set_my_commands;
 mod set_passport_data_errors;
 mod set_sticker_position_in_set;
@@ -105,13 +139,17 @@ pub use answer_callback_query::{AnswerCallbackQuery, AnswerCallbackQuerySetters}
 pub use answer_inline_query::{AnswerInlineQuery, AnswerInlineQuerySetters};
 pub use answer_pre_checkout_query::{AnswerPreCheckoutQuery, AnswerPreCheckoutQuerySetters};
 pub use answer_shipping_query::{AnswerShippingQuery, AnswerShippingQuerySetters};
+pub use ban_chat_member::{BanChatMember, BanChatMemberSetters};
 pub use close::{Close, CloseSetters};
 pub use copy_message::{CopyMessage, CopyMessageSetters};
+pub use copy_messages::{CopyMessages, CopyMessagesSetters};
 pub use create_chat_invite_link::{CreateChatInviteLink, CreateChatInviteLinkSetters};
+pub use create_invoice_link::{CreateInvoiceLink, CreateInvoiceLinkSetters};
 pub use create_new_sticker_set::{CreateNewStickerSet, CreateNewStickerSetSetters};
 pub use delete_chat_photo::{DeleteChatPhoto, DeleteChatPhotoSetters};
 pub use delete_chat_sticker_set::{DeleteChatStickerSet, DeleteChatStickerSetSetters};
 pub use delete_message::{DeleteMessage, DeleteMessageSetters};
+pub use delete_my_commands::{DeleteMyCommands, DeleteMyCommandsSetters};
 pub use delete_sticker_from_set::{DeleteStickerFromSet, DeleteStickerFromSetSetters};
 pub use delete_webhook::{DeleteWebhook, DeleteWebhookSetters};
 pub use edit_chat_invite_link::{EditChatInviteLink, EditChatInviteLinkSetters};
@@ -131,10 +169,12 @@ pub use edit_message_text::{EditMessageText, EditMessageTextSetters};
 pub use edit_message_text_inline::{EditMessageTextInline, EditMessageTextInlineSetters};
 pub use export_chat_invite_link::{ExportChatInviteLink, ExportChatInviteLinkSetters};
 pub use forward_message::{ForwardMessage, ForwardMessageSetters};
+pub use forward_messages::{ForwardMessages, ForwardMessagesSetters};
 pub use get_chat::{GetChat, GetChatSetters};
 pub use get_chat_administrators::{GetChatAdministrators, GetChatAdministratorsSetters};
 pub use get_chat_member::{GetChatMember, GetChatMemberSetters};
 pub use get_chat_members_count::{GetChatMembersCount, GetChatMembersCountSetters};
+pub use get_chat_menu_button::{GetChatMenuButton, GetChatMenuButtonSetters};
 pub use get_file::{GetFile, GetFileSetters};
 pub use get_game_high_scores::{GetGameHighScores, GetGameHighScoresSetters};
 pub use get_me::{GetMe, GetMeSetters};
@@ -148,6 +188,7 @@ pub use leave_chat::{LeaveChat, LeaveChatSetters};
 pub use log_out::{LogOut, LogOutSetters};
 pub use pin_chat_message::{PinChatMessage, PinChatMessageSetters};
 pub use promote_chat_member::{PromoteChatMember, PromoteChatMemberSetters};
+pub use refund_star_payment::{RefundStarPayment, RefundStarPaymentSetters};
 pub use restrict_chat_member::{RestrictChatMember, RestrictChatMemberSetters};
 pub use revoke_chat_invite_link::{RevokeChatInviteLink, RevokeChatInviteLinkSetters};
 pub use send_animation::{SendAnimation, SendAnimationSetters};
@@ -161,6 +202,7 @@ pub use send_invoice::{SendInvoice, SendInvoiceSetters};
 pub use send_location::{SendLocation, SendLocationSetters};
 pub use send_media_group::{SendMediaGroup, SendMediaGroupSetters};
 pub use send_message::{SendMessage, SendMessageSetters};
+pub use send_paid_media::{SendPaidMedia, SendPaidMediaSetters};
 pub use send_photo::{SendPhoto, SendPhotoSetters};
 pub use send_poll::{SendPoll, SendPollSetters};
 pub use send_sticker::{SendSticker, SendStickerSetters};
@@ -172,12 +214,14 @@ pub use set_chat_administrator_custom_title::{
     SetChatAdministratorCustomTitle, SetChatAdministratorCustomTitleSetters,
 };
 pub use set_chat_description::{SetChatDescription, SetChatDescriptionSetters};
+pub use set_chat_menu_button::{SetChatMenuButton, SetChatMenuButtonSetters};
 pub use set_chat_permissions::{SetChatPermissions, SetChatPermissionsSetters};
 pub use set_chat_photo::{SetChatPhoto, SetChatPhotoSetters};
 pub use set_chat_sticker_set::{SetChatStickerSet, SetChatStickerSetSetters};
 pub use set_chat_title::{SetChatTitle, SetChatTitleSetters};
 pub use set_game_score::{SetGameScore, SetGameScoreSetters};
 pub use set_game_score_inline::{SetGameScoreInline, SetGameScoreInlineSetters};
+pub use set_message_reaction::{SetMessageReaction, SetMessageReactionSetters};
 pub use set_my_commands::{SetMyCommands, SetMyCommandsSetters};
 pub use set_passport_data_errors::{SetPassportDataErrors, SetPassportDataErrorsSetters};
 pub use set_sticker_position_in_set::{SetStickerPositionInSet, SetStickerPositionInSetSetters};
@@ -198,3 +242,7 @@ pub use upload_sticker_file::{UploadStickerFile, UploadStickerFileSetters};
 mod get_updates_fault_tolerant;
 
 pub use get_updates_fault_tolerant::GetUpdatesFaultTolerant;
+
+// Files of payloads are generated by `cg`, so their tests live here
+#[cfg(test)]
+mod tests;
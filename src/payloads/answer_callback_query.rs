@@ -10,7 +10,24 @@ impl_payload! {
     ///
     /// >Alternatively, the user can be redirected to the specified Game URL. For this option to work, you must first create a game for your bot via [@Botfather] and accept the terms. Otherwise, you may use links like `t.me/your_bot?start=XXXX` that open your bot with a parameter.
     ///
+    /// Note that callback queries must be answered even if there is nothing to show, otherwise the user's client shows a progress bar until the query times out. To just acknowledge a query, send the request without setting any optional fields (e.g. `bot.answer_callback_query(id).await`, with [`AutoSend`]).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::payloads::{AnswerCallbackQuery, AnswerCallbackQuerySetters};
+    ///
+    /// // Shows a notification at the top of the chat screen
+    /// let toast = AnswerCallbackQuery::new("id").toast("Done!");
+    /// assert_eq!(toast.text.as_deref(), Some("Done!"));
+    ///
+    /// // Shows an alert, which needs to be dismissed by the user
+    /// let alert = AnswerCallbackQuery::new("id").text("Access denied").alert();
+    /// assert_eq!(alert.show_alert, Some(true));
+    /// ```
+    ///
     /// [inline keyboards]: https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    /// [`AutoSend`]: crate::adaptors::AutoSend
     /// [@Botfather]: https://t.me/botfather
     #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
     pub AnswerCallbackQuery (AnswerCallbackQuerySetters) => True {
@@ -3,7 +3,7 @@
 // edit `cg` instead.
 use serde::Serialize;
 
-use crate::types::{InlineQueryResult, True};
+use crate::types::{InlineQueryResult, InlineQueryResultsButton, True};
 
 impl_payload! {
     /// Use this method to send answers to an inline query. On success, _True_ is returned. No more than **50** results per query are allowed.
@@ -22,7 +22,13 @@ impl_payload! {
             pub is_personal: bool,
             /// Pass the offset that a client should send in the next query with the same text to receive more results. Pass an empty string if there are no more results or if you don't support pagination. Offset length can't exceed 64 bytes.
             pub next_offset: String [into],
+            /// A JSON-serialized object describing a button to be shown above inline query results
+            pub button: InlineQueryResultsButton,
             /// If passed, clients will display a button with specified text that switches the user to a private chat with the bot and sends the bot a start message with the parameter switch_pm_parameter
+            ///
+            /// Deprecated by Telegram in favour of [`button`].
+            ///
+            /// [`button`]: AnswerInlineQuery::button
             pub switch_pm_text: String [into],
             /// [Deep-linking] parameter for the /start message sent to the bot when user presses the switch button. 1-64 characters, only `A-Z`, `a-z`, `0-9`, `_` and `-` are allowed.
             ///
@@ -30,6 +36,10 @@ impl_payload! {
             ///
             /// [Deep-linking]: https://core.telegram.org/bots#deep-linking
             /// [switch_inline]: https://core.telegram.org/bots/api#inlinekeyboardmarkup
+            ///
+            /// Deprecated by Telegram in favour of [`button`].
+            ///
+            /// [`button`]: AnswerInlineQuery::button
             pub switch_pm_parameter: String [into],
         }
     }
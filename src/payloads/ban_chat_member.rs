@@ -0,0 +1,29 @@
+// This file is auto generated by `cg` <https://github.com/teloxide/cg> (24572cd + local changes).
+// **DO NOT EDIT THIS FILE**,
+// edit `cg` instead.
+use serde::Serialize;
+
+use crate::types::{ChatId, True, UntilDate, UserId};
+
+impl_payload! {
+    /// Use this method to ban a user in a group, a supergroup or a channel. In the case of supergroups and channels, the user will not be able to return to the chat on their own using invite links, etc., unless [unbanned] first. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns _True_ on success.
+    ///
+    /// [unbanned]: crate::payloads::UnbanChatMember
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub BanChatMember (BanChatMemberSetters) => True {
+        required {
+            /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+            pub chat_id: ChatId [into],
+            /// Unique identifier of the target user
+            pub user_id: UserId,
+        }
+        optional {
+            /// Date when the user will be unbanned, unix time. If user is banned for more than 366 days or less than 30 seconds from the current time they are considered to be banned forever, see [`UntilDate`]. Applied for supergroups and channels only.
+            ///
+            /// [`UntilDate`]: crate::types::UntilDate
+            pub until_date: UntilDate [into],
+            /// Pass True to delete all messages from the chat for the user that is being removed. If False, the user will be able to see messages in the group that were sent before the user was removed. Always True for supergroups and channels.
+            pub revoke_messages: bool,
+        }
+    }
+}
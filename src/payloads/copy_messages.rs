@@ -0,0 +1,38 @@
+// This file is auto generated by `cg` <https://github.com/teloxide/cg> (24572cd + local changes).
+// **DO NOT EDIT THIS FILE**,
+// edit `cg` instead.
+use serde::Serialize;
+
+use crate::types::{ChatId, MessageId, ThreadId};
+
+impl_payload! {
+    /// Use this method to copy messages of any kind. If some of the specified messages can't be found or copied, they are skipped. Service messages, giveaway messages, giveaway winners messages, and invoice messages can't be copied. A quiz poll can be copied only if the value of the field _correct\_option\_id_ is known to the bot. The method is analogous to the method [`ForwardMessages`], but the copied messages don't have a link to the original message. Album grouping is kept for copied messages. On success, an array of [`MessageId`] of the sent messages is returned.
+    ///
+    /// See also: [`CopyMessage`](crate::payloads::CopyMessage)
+    ///
+    /// [`ForwardMessages`]: crate::payloads::ForwardMessages
+    /// [`MessageId`]: crate::types::MessageId
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub CopyMessages (CopyMessagesSetters) => Vec<MessageId> {
+        required {
+            /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+            pub chat_id: ChatId [into],
+            /// Unique identifier for the chat where the original messages were sent (or channel username in the format `@channelusername`)
+            pub from_chat_id: ChatId [into],
+            /// Identifiers of 1-100 messages in the chat _from\_chat\_id_ to copy. The identifiers must be specified in a strictly increasing order.
+            pub message_ids: Vec<MessageId> [collect],
+        }
+        optional {
+            /// Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
+            pub message_thread_id: ThreadId,
+            /// Sends the messages [silently]. Users will receive a notification with no sound.
+            ///
+            /// [silently]: https://telegram.org/blog/channels-2-0#silent-messages
+            pub disable_notification: bool,
+            /// Protects the contents of the sent messages from forwarding and saving
+            pub protect_content: bool,
+            /// Pass _True_ to copy the messages without their captions
+            pub remove_caption: bool,
+        }
+    }
+}
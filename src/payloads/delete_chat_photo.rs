@@ -3,12 +3,12 @@
 // edit `cg` instead.
 use serde::Serialize;
 
-use crate::types::ChatId;
+use crate::types::{ChatId, True};
 
 impl_payload! {
     /// Use this method to delete a chat photo. Photos can't be changed for private chats. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns True on success.
     #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
-    pub DeleteChatPhoto (DeleteChatPhotoSetters) => String {
+    pub DeleteChatPhoto (DeleteChatPhotoSetters) => True {
         required {
             /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
             pub chat_id: ChatId [into],
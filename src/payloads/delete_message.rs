@@ -3,7 +3,7 @@
 // edit `cg` instead.
 use serde::Serialize;
 
-use crate::types::{ChatId, True};
+use crate::types::{ChatId, MessageId, True};
 
 impl_payload! {
     /// Use this method to delete a message, including service messages, with the following limitations:
@@ -22,7 +22,7 @@ impl_payload! {
             /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`).
             pub chat_id: ChatId [into],
             /// Identifier of the message to delete
-            pub message_id: i32,
+            pub message_id: MessageId,
         }
     }
 }
@@ -0,0 +1,24 @@
+// This file is auto generated by `cg` <https://github.com/teloxide/cg> (24572cd + local changes).
+// **DO NOT EDIT THIS FILE**,
+// edit `cg` instead.
+use serde::Serialize;
+
+use crate::types::{BotCommandScope, True};
+
+impl_payload! {
+    /// Use this method to delete the list of the bot's commands for the given scope and user language. After deletion, [higher level commands] will be shown to affected users. Returns _True_ on success.
+    ///
+    /// [higher level commands]: crate::types::BotCommandScope
+    #[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Serialize)]
+    pub DeleteMyCommands (DeleteMyCommandsSetters) => True {
+
+        optional {
+            /// A JSON-serialized object, describing scope of users for which the commands are relevant. Defaults to [`BotCommandScope::Default`].
+            ///
+            /// [`BotCommandScope::Default`]: crate::types::BotCommandScope::Default
+            pub scope: BotCommandScope,
+            /// A two-letter ISO 639-1 language code. If empty, commands will be applied to all users from the given scope, for whose language there are no dedicated commands
+            pub language_code: String [into],
+        }
+    }
+}
@@ -3,7 +3,7 @@
 // edit `cg` instead.
 use serde::Serialize;
 
-use crate::types::{ChatId, InlineKeyboardMarkup, Message, MessageEntity, ParseMode};
+use crate::types::{ChatId, InlineKeyboardMarkup, Message, MessageEntity, MessageId, ParseMode};
 
 impl_payload! {
     /// Use this method to edit captions of messages. On success, the edited Message is returned.
@@ -15,9 +15,11 @@ impl_payload! {
             /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`).
             pub chat_id: ChatId [into],
             /// Identifier of the message to edit
-            pub message_id: i32,
+            pub message_id: MessageId,
         }
         optional {
+            /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+            pub business_connection_id: String [into],
             /// New caption of the message, 0-1024 characters after entities parsing
             pub caption: String [into],
             /// Mode for parsing entities in the message text. See [formatting options] for more details.
@@ -16,6 +16,8 @@ impl_payload! {
             pub inline_message_id: String [into],
         }
         optional {
+            /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+            pub business_connection_id: String [into],
             /// New caption of the message, 0-1024 characters after entities parsing
             pub caption: String [into],
             /// Mode for parsing entities in the message text. See [formatting options] for more details.
@@ -3,7 +3,7 @@
 // edit `cg` instead.
 use serde::Serialize;
 
-use crate::types::{ChatId, Message, ReplyMarkup};
+use crate::types::{ChatId, Message, MessageId, ReplyMarkup};
 
 impl_payload! {
     /// Use this method to edit live location messages. A location can be edited until its live_period expires or editing is explicitly disabled by a call to [`StopMessageLiveLocation`]. On success, the edited Message is returned.
@@ -17,13 +17,15 @@ impl_payload! {
             /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
             pub chat_id: ChatId [into],
             /// Identifier of the message to edit
-            pub message_id: i32,
+            pub message_id: MessageId,
             /// Latitude of new location
             pub latitude: f64,
             /// Longitude of new location
             pub longitude: f64,
         }
         optional {
+            /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+            pub business_connection_id: String [into],
             /// The radius of uncertainty for the location, measured in meters; 0-1500
             pub horizontal_accuracy: f64,
             /// For live locations, a direction in which the user is moving, in degrees. Must be between 1 and 360 if specified.
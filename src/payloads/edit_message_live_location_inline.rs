@@ -3,7 +3,7 @@
 // edit `cg` instead.
 use serde::Serialize;
 
-use crate::types::{Message, ReplyMarkup};
+use crate::types::{ReplyMarkup, True};
 
 impl_payload! {
     /// Use this method to edit live location messages. A location can be edited until its live_period expires or editing is explicitly disabled by a call to [`StopMessageLiveLocation`]. On success, True is returned.
@@ -12,7 +12,7 @@ impl_payload! {
     ///
     /// [`StopMessageLiveLocation`]: crate::payloads::StopMessageLiveLocation
     #[derive(Debug, PartialEq, Clone, Serialize)]
-    pub EditMessageLiveLocationInline (EditMessageLiveLocationInlineSetters) => Message {
+    pub EditMessageLiveLocationInline (EditMessageLiveLocationInlineSetters) => True {
         required {
             /// Identifier of the inline message
             pub inline_message_id: String [into],
@@ -22,6 +22,8 @@ impl_payload! {
             pub longitude: f64,
         }
         optional {
+            /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+            pub business_connection_id: String [into],
             /// The radius of uncertainty for the location, measured in meters; 0-1500
             pub horizontal_accuracy: f64,
             /// For live locations, a direction in which the user is moving, in degrees. Must be between 1 and 360 if specified.
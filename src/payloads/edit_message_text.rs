@@ -3,7 +3,9 @@
 // edit `cg` instead.
 use serde::Serialize;
 
-use crate::types::{ChatId, InlineKeyboardMarkup, Message, MessageEntity, ParseMode};
+use crate::types::{
+    ChatId, InlineKeyboardMarkup, LinkPreviewOptions, Message, MessageEntity, MessageId, ParseMode,
+};
 
 impl_payload! {
     /// Use this method to edit text and [games] messages. On success, the edited Message is returned.
@@ -17,19 +19,23 @@ impl_payload! {
             /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`).
             pub chat_id: ChatId [into],
             /// Identifier of the message to edit
-            pub message_id: i32,
+            pub message_id: MessageId,
             /// New text of the message, 1-4096 characters after entities parsing
             pub text: String [into],
         }
         optional {
+            /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+            pub business_connection_id: String [into],
             /// Mode for parsing entities in the message text. See [formatting options] for more details.
             ///
             /// [formatting options]: https://core.telegram.org/bots/api#formatting-options
             pub parse_mode: ParseMode,
             /// List of special entities that appear in message text, which can be specified instead of _parse\_mode_
             pub entities: Vec<MessageEntity> [collect],
-            /// Disables link previews for links in this message
+            /// Disables link previews for links in this message. Can't be used together with _link\_preview\_options_
             pub disable_web_page_preview: bool,
+            /// Link preview generation options for the message. Can't be used together with _disable\_web\_page\_preview_
+            pub link_preview_options: LinkPreviewOptions,
             /// A JSON-serialized object for an [inline keyboard].
             ///
             /// [inline keyboard]: https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
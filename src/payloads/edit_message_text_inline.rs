@@ -3,7 +3,7 @@
 // edit `cg` instead.
 use serde::Serialize;
 
-use crate::types::{InlineKeyboardMarkup, MessageEntity, ParseMode, True};
+use crate::types::{InlineKeyboardMarkup, LinkPreviewOptions, MessageEntity, ParseMode, True};
 
 impl_payload! {
     /// Use this method to edit text and [games] messages. On success, _True_ is returned.
@@ -20,14 +20,18 @@ impl_payload! {
             pub text: String [into],
         }
         optional {
+            /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+            pub business_connection_id: String [into],
             /// Mode for parsing entities in the message text. See [formatting options] for more details.
             ///
             /// [formatting options]: https://core.telegram.org/bots/api#formatting-options
             pub parse_mode: ParseMode,
             /// List of special entities that appear in message text, which can be specified instead of _parse\_mode_
             pub entities: Vec<MessageEntity> [collect],
-            /// Disables link previews for links in this message
+            /// Disables link previews for links in this message. Can't be used together with _link\_preview\_options_
             pub disable_web_page_preview: bool,
+            /// Link preview generation options for the message. Can't be used together with _disable\_web\_page\_preview_
+            pub link_preview_options: LinkPreviewOptions,
             /// A JSON-serialized object for an [inline keyboard].
             ///
             /// [inline keyboard]: https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
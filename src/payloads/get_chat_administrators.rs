@@ -10,7 +10,7 @@ impl_payload! {
     ///
     /// [`ChatMember`]: crate::types::ChatMember
     #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
-    pub GetChatAdministrators (GetChatAdministratorsSetters) => ChatMember {
+    pub GetChatAdministrators (GetChatAdministratorsSetters) => Vec<ChatMember> {
         required {
             /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
             pub chat_id: ChatId [into],
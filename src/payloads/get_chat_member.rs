@@ -3,7 +3,7 @@
 // edit `cg` instead.
 use serde::Serialize;
 
-use crate::types::{ChatId, ChatMember};
+use crate::types::{ChatId, ChatMember, UserId};
 
 impl_payload! {
     /// Use this method to get information about a member of a chat. Returns a [`ChatMember`] object on success.
@@ -15,7 +15,7 @@ impl_payload! {
             /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
             pub chat_id: ChatId [into],
             /// Unique identifier of the target user
-            pub user_id: i64,
+            pub user_id: UserId,
         }
     }
 }
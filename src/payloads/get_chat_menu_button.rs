@@ -0,0 +1,20 @@
+// This file is auto generated by `cg` <https://github.com/teloxide/cg> (24572cd + local changes).
+// **DO NOT EDIT THIS FILE**,
+// edit `cg` instead.
+use serde::Serialize;
+
+use crate::types::MenuButton;
+
+impl_payload! {
+    /// Use this method to get the current value of the bot's menu button in a private chat, or the default menu button. Returns [`MenuButton`] on success.
+    ///
+    /// [`MenuButton`]: crate::types::MenuButton
+    #[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Serialize)]
+    pub GetChatMenuButton (GetChatMenuButtonSetters) => MenuButton {
+
+        optional {
+            /// Unique identifier for the target private chat. If not specified, default bot's menu button will be returned
+            pub chat_id: i64,
+        }
+    }
+}
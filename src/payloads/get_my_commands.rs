@@ -3,12 +3,22 @@
 // edit `cg` instead.
 use serde::Serialize;
 
+use crate::types::{BotCommand, BotCommandScope};
+
 impl_payload! {
-    /// Use this method to get the current list of the bot's commands. Requires no parameters. Returns Array of [`BotCommand`] on success.
+    /// Use this method to get the current list of the bot's commands for the given scope and user language. Returns Array of [`BotCommand`] on success. If commands aren't set, an empty list is returned.
     ///
     /// [`BotCommand`]: crate::types::BotCommand
     #[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Serialize)]
-    pub GetMyCommands (GetMyCommandsSetters) => u32 {
+    pub GetMyCommands (GetMyCommandsSetters) => Vec<BotCommand> {
 
+        optional {
+            /// A JSON-serialized object, describing scope of users. Defaults to [`BotCommandScope::Default`].
+            ///
+            /// [`BotCommandScope::Default`]: crate::types::BotCommandScope::Default
+            pub scope: BotCommandScope,
+            /// A two-letter ISO 639-1 language code or an empty string
+            pub language_code: String [into],
+        }
     }
 }
@@ -1,9 +1,11 @@
+use std::time::Duration;
+
 use serde::Serialize;
 
 use crate::{
     payloads::GetUpdates,
-    requests::Payload,
-    types::{SemiparsedVec, Update},
+    requests::{HasChatId, Payload},
+    types::{ChatId, SemiparsedVec, ThreadId, Update},
 };
 
 /// The fault tolerant version of [`GetUpdates`].
@@ -15,4 +17,24 @@ impl Payload for GetUpdatesFaultTolerant {
     type Output = SemiparsedVec<Update>;
 
     const NAME: &'static str = GetUpdates::NAME;
+
+    const IDEMPOTENT: bool = GetUpdates::IDEMPOTENT;
+
+    fn timeout_hint(&self) -> Option<Duration> {
+        self.0.timeout_hint()
+    }
+}
+
+impl HasChatId for GetUpdatesFaultTolerant {
+    fn chat_id(&self) -> Option<&ChatId> {
+        None
+    }
+
+    fn chat_id_mut(&mut self) -> Option<&mut ChatId> {
+        None
+    }
+
+    fn message_thread_id(&self) -> Option<ThreadId> {
+        None
+    }
 }
@@ -3,7 +3,7 @@
 // edit `cg` instead.
 use serde::Serialize;
 
-use crate::types::{ChatId, ChatPermissions, True};
+use crate::types::{ChatId, ChatPermissions, True, UntilDate, UserId};
 
 impl_payload! {
     /// Use this method to restrict a user in a supergroup. The bot must be an administrator in the supergroup for this to work and must have the appropriate admin rights. Pass _True_ for all permissions to lift restrictions from a user. Returns _True_ on success.
@@ -13,13 +13,15 @@ impl_payload! {
             /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
             pub chat_id: ChatId [into],
             /// Unique identifier of the target user
-            pub user_id: i64,
+            pub user_id: UserId,
             /// A JSON-serialized object for new user permissions
             pub permissions: ChatPermissions,
         }
         optional {
-            /// Date when the user will be unbanned, unix time. If user is banned for more than 366 days or less than 30 seconds from the current time they are considered to be banned forever
-            pub until_date: u64,
+            /// Date when the user will be unbanned, unix time. If user is banned for more than 366 days or less than 30 seconds from the current time they are considered to be banned forever, see [`UntilDate`]
+            ///
+            /// [`UntilDate`]: crate::types::UntilDate
+            pub until_date: UntilDate [into],
         }
     }
 }
@@ -3,7 +3,9 @@
 // edit `cg` instead.
 use serde::Serialize;
 
-use crate::types::{ChatId, InputFile, Message, MessageEntity, ParseMode, ReplyMarkup};
+use crate::types::{
+    ChatId, InputFile, Message, MessageEntity, MessageId, ParseMode, ReplyMarkup, ThreadId,
+};
 
 impl_payload! {
     @[multipart]
@@ -21,6 +23,10 @@ impl_payload! {
             pub animation: InputFile,
         }
         optional {
+            /// Unique identifier of the business connection on behalf of which the message will be sent
+            pub business_connection_id: String [into],
+            /// Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
+            pub message_thread_id: ThreadId,
             /// Duration of the animation in seconds
             pub duration: u32,
             /// Animation width
@@ -39,12 +45,18 @@ impl_payload! {
             pub parse_mode: ParseMode,
             /// List of special entities that appear in the photo caption, which can be specified instead of _parse\_mode_
             pub caption_entities: Vec<MessageEntity> [collect],
+            /// Pass _True_ if the animation needs to be covered with a spoiler animation
+            pub has_spoiler: bool,
             /// Sends the message [silently]. Users will receive a notification with no sound.
             ///
             /// [silently]: https://telegram.org/blog/channels-2-0#silent-messages
             pub disable_notification: bool,
+            /// Protects the contents of the sent message from forwarding and saving
+            pub protect_content: bool,
+            /// Unique identifier of the message effect to be added to the message; for private chats only
+            pub message_effect_id: String [into],
             /// If the message is a reply, ID of the original message
-            pub reply_to_message_id: i32,
+            pub reply_to_message_id: MessageId,
             /// Pass _True_, if the message should be sent even if the specified replied-to message is not found
             pub allow_sending_without_reply: bool,
             /// Additional interface options. A JSON-serialized object for an [inline keyboard], [custom reply keyboard], instructions to remove reply keyboard or to force a reply from the user.
@@ -29,5 +29,9 @@ impl_payload! {
             /// [video notes]: crate::payloads::SendVideoNote
             pub action: ChatAction,
         }
+        optional {
+            /// Unique identifier of the business connection on behalf of which the action will be sent
+            pub business_connection_id: String [into],
+        }
     }
 }
@@ -0,0 +1,54 @@
+// This file is auto generated by `cg` <https://github.com/teloxide/cg> (24572cd + local changes).
+// **DO NOT EDIT THIS FILE**,
+// edit `cg` instead.
+use serde::Serialize;
+
+use crate::types::{
+    ChatId, InputPaidMedia, Message, MessageEntity, MessageId, ParseMode, ReplyMarkup, ThreadId,
+};
+
+impl_payload! {
+    /// Use this method to send paid media. On success, the sent [`Message`] is returned.
+    ///
+    /// [`Message`]: crate::types::Message
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub SendPaidMedia (SendPaidMediaSetters) => Message {
+        required {
+            /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+            pub chat_id: ChatId [into],
+            /// The number of Telegram Stars that must be paid to buy access to the media
+            pub star_count: u32,
+            /// A JSON-serialized array describing the media to be sent; up to 10 items
+            pub media: Vec<InputPaidMedia> [collect],
+        }
+        optional {
+            /// Unique identifier of the business connection on behalf of which the message will be sent
+            pub business_connection_id: String [into],
+            /// Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
+            pub message_thread_id: ThreadId,
+            /// Media caption, 0-1024 characters after entities parsing
+            pub caption: String [into],
+            /// Mode for parsing entities in the media caption. See [formatting options] for more details.
+            ///
+            /// [formatting options]: https://core.telegram.org/bots/api#formatting-options
+            pub parse_mode: ParseMode,
+            /// List of special entities that appear in the caption, which can be specified instead of _parse\_mode_
+            pub caption_entities: Vec<MessageEntity> [collect],
+            /// Sends the message [silently]. Users will receive a notification with no sound.
+            ///
+            /// [silently]: https://telegram.org/blog/channels-2-0#silent-messages
+            pub disable_notification: bool,
+            /// Protects the contents of the sent message from forwarding and saving
+            pub protect_content: bool,
+            /// If the message is a reply, ID of the original message
+            pub reply_to_message_id: MessageId,
+            /// Pass _True_, if the message should be sent even if the specified replied-to message is not found
+            pub allow_sending_without_reply: bool,
+            /// Additional interface options. A JSON-serialized object for an [inline keyboard], [custom reply keyboard], instructions to remove reply keyboard or to force a reply from the user.
+            ///
+            /// [inline keyboard]: https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+            /// [custom reply keyboard]: https://core.telegram.org/bots#keyboards
+            pub reply_markup: ReplyMarkup [into],
+        }
+    }
+}
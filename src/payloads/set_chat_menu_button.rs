@@ -0,0 +1,22 @@
+// This file is auto generated by `cg` <https://github.com/teloxide/cg> (24572cd + local changes).
+// **DO NOT EDIT THIS FILE**,
+// edit `cg` instead.
+use serde::Serialize;
+
+use crate::types::{MenuButton, True};
+
+impl_payload! {
+    /// Use this method to change the bot's menu button in a private chat, or the default menu button. Returns _True_ on success.
+    #[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Serialize)]
+    pub SetChatMenuButton (SetChatMenuButtonSetters) => True {
+
+        optional {
+            /// Unique identifier for the target private chat. If not specified, default bot's menu button will be changed
+            pub chat_id: i64,
+            /// A JSON-serialized object for the bot's new menu button. Defaults to [`MenuButton::Default`]
+            ///
+            /// [`MenuButton::Default`]: crate::types::MenuButton::Default
+            pub menu_button: MenuButton,
+        }
+    }
+}
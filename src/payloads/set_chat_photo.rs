@@ -3,13 +3,13 @@
 // edit `cg` instead.
 use serde::Serialize;
 
-use crate::types::{ChatId, InputFile};
+use crate::types::{ChatId, InputFile, True};
 
 impl_payload! {
     @[multipart]
     /// Use this method to set a new profile photo for the chat. Photos can't be changed for private chats. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns _True_ on success.
     #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
-    pub SetChatPhoto (SetChatPhotoSetters) => String {
+    pub SetChatPhoto (SetChatPhotoSetters) => True {
         required {
             /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
             pub chat_id: ChatId [into],
@@ -3,7 +3,7 @@
 // edit `cg` instead.
 use serde::Serialize;
 
-use crate::types::Message;
+use crate::types::{Message, MessageId, UserId};
 
 impl_payload! {
     /// Use this method to set the score of the specified user in a game. On success, returns the edited [`Message`]. Returns an error, if the new score is not greater than the user's current score in the chat and force is False.
@@ -15,13 +15,13 @@ impl_payload! {
     pub SetGameScore (SetGameScoreSetters) => Message {
         required {
             /// User identifier
-            pub user_id: i64,
+            pub user_id: UserId,
             /// New score
             pub score: u64,
             /// Unique identifier for the target chat
             pub chat_id: u32,
             /// Identifier of the message to edit
-            pub message_id: i64,
+            pub message_id: MessageId,
         }
         optional {
             /// Pass True, if the high score is allowed to decrease. This can be useful when fixing mistakes or banning cheaters
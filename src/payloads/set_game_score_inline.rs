@@ -3,17 +3,17 @@
 // edit `cg` instead.
 use serde::Serialize;
 
-use crate::types::Message;
+use crate::types::{True, UserId};
 
 impl_payload! {
     /// Use this method to set the score of the specified user in a game. On success, returns _True_. Returns an error, if the new score is not greater than the user's current score in the chat and force is False.
     ///
     /// See also: [`SetGameScore`](crate::payloads::SetGameScore)
     #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
-    pub SetGameScoreInline (SetGameScoreInlineSetters) => Message {
+    pub SetGameScoreInline (SetGameScoreInlineSetters) => True {
         required {
             /// User identifier
-            pub user_id: i64,
+            pub user_id: UserId,
             /// New score
             pub score: u64,
             /// Identifier of the inline message
@@ -3,15 +3,25 @@
 // edit `cg` instead.
 use serde::Serialize;
 
-use crate::types::BotCommand;
+use crate::types::{BotCommand, BotCommandScope, True};
 
 impl_payload! {
-    /// Use this method to change the list of the bot's commands. Returns _True_ on success.
+    /// Use this method to change the list of the bot's commands. See [this manual] for more details about bot commands. Returns _True_ on success.
+    ///
+    /// [this manual]: https://core.telegram.org/bots/features#commands
     #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
-    pub SetMyCommands (SetMyCommandsSetters) => u32 {
+    pub SetMyCommands (SetMyCommandsSetters) => True {
         required {
             /// A JSON-serialized list of bot commands to be set as the list of the bot's commands. At most 100 commands can be specified.
             pub commands: Vec<BotCommand> [collect],
         }
+        optional {
+            /// A JSON-serialized object, describing scope of users for which the commands are relevant. Defaults to [`BotCommandScope::Default`].
+            ///
+            /// [`BotCommandScope::Default`]: crate::types::BotCommandScope::Default
+            pub scope: BotCommandScope,
+            /// A two-letter ISO 639-1 language code. If empty, commands will be applied to all users from the given scope, for whose language there are no dedicated commands
+            pub language_code: String [into],
+        }
     }
 }
@@ -35,6 +35,12 @@ impl_payload! {
             pub allowed_updates: Vec<AllowedUpdate> [collect],
             /// Pass _True_ to drop all pending updates
             pub drop_pending_updates: bool,
+            /// A secret token to be sent in a header “X-Telegram-Bot-Api-Secret-Token” in every webhook request, 1-256 characters. Only characters `A-Z`, `a-z`, `0-9`, `_` and `-` are allowed. The header is useful to ensure that the request comes from a webhook set by you.
+            ///
+            /// The header can be checked with [`check_secret_token`].
+            ///
+            /// [`check_secret_token`]: crate::net::check_secret_token
+            pub secret_token: String [into],
         }
     }
 }
@@ -5,31 +5,35 @@
 #[doc(no_inline)]
 pub use crate::payloads::{
     AddStickerToSetSetters as _, AnswerCallbackQuerySetters as _, AnswerInlineQuerySetters as _,
-    AnswerPreCheckoutQuerySetters as _, AnswerShippingQuerySetters as _, CloseSetters as _,
-    CopyMessageSetters as _, CreateChatInviteLinkSetters as _, CreateNewStickerSetSetters as _,
-    DeleteChatPhotoSetters as _, DeleteChatStickerSetSetters as _, DeleteMessageSetters as _,
-    DeleteStickerFromSetSetters as _, DeleteWebhookSetters as _, EditChatInviteLinkSetters as _,
+    AnswerPreCheckoutQuerySetters as _, AnswerShippingQuerySetters as _, BanChatMemberSetters as _,
+    CloseSetters as _, CopyMessageSetters as _, CopyMessagesSetters as _,
+    CreateChatInviteLinkSetters as _, CreateInvoiceLinkSetters as _,
+    CreateNewStickerSetSetters as _, DeleteChatPhotoSetters as _, DeleteChatStickerSetSetters as _,
+    DeleteMessageSetters as _, DeleteMyCommandsSetters as _, DeleteStickerFromSetSetters as _,
+    DeleteWebhookSetters as _, EditChatInviteLinkSetters as _,
     EditMessageCaptionInlineSetters as _, EditMessageCaptionSetters as _,
     EditMessageLiveLocationInlineSetters as _, EditMessageLiveLocationSetters as _,
     EditMessageMediaInlineSetters as _, EditMessageMediaSetters as _,
     EditMessageReplyMarkupInlineSetters as _, EditMessageReplyMarkupSetters as _,
     EditMessageTextInlineSetters as _, EditMessageTextSetters as _,
-    ExportChatInviteLinkSetters as _, ForwardMessageSetters as _,
+    ExportChatInviteLinkSetters as _, ForwardMessageSetters as _, ForwardMessagesSetters as _,
     GetChatAdministratorsSetters as _, GetChatMemberSetters as _, GetChatMembersCountSetters as _,
-    GetChatSetters as _, GetFileSetters as _, GetGameHighScoresSetters as _, GetMeSetters as _,
-    GetMyCommandsSetters as _, GetStickerSetSetters as _, GetUpdatesSetters as _,
-    GetUserProfilePhotosSetters as _, GetWebhookInfoSetters as _, KickChatMemberSetters as _,
-    LeaveChatSetters as _, LogOutSetters as _, PinChatMessageSetters as _,
-    PromoteChatMemberSetters as _, RestrictChatMemberSetters as _,
+    GetChatMenuButtonSetters as _, GetChatSetters as _, GetFileSetters as _,
+    GetGameHighScoresSetters as _, GetMeSetters as _, GetMyCommandsSetters as _,
+    GetStickerSetSetters as _, GetUpdatesSetters as _, GetUserProfilePhotosSetters as _,
+    GetWebhookInfoSetters as _, KickChatMemberSetters as _, LeaveChatSetters as _,
+    LogOutSetters as _, PinChatMessageSetters as _, PromoteChatMemberSetters as _,
+    RefundStarPaymentSetters as _, RestrictChatMemberSetters as _,
     RevokeChatInviteLinkSetters as _, SendAnimationSetters as _, SendAudioSetters as _,
     SendChatActionSetters as _, SendContactSetters as _, SendDiceSetters as _,
     SendDocumentSetters as _, SendGameSetters as _, SendInvoiceSetters as _,
     SendLocationSetters as _, SendMediaGroupSetters as _, SendMessageSetters as _,
-    SendPhotoSetters as _, SendPollSetters as _, SendStickerSetters as _, SendVenueSetters as _,
-    SendVideoNoteSetters as _, SendVideoSetters as _, SendVoiceSetters as _,
-    SetChatAdministratorCustomTitleSetters as _, SetChatDescriptionSetters as _,
-    SetChatPermissionsSetters as _, SetChatPhotoSetters as _, SetChatStickerSetSetters as _,
-    SetChatTitleSetters as _, SetGameScoreInlineSetters as _, SetGameScoreSetters as _,
+    SendPaidMediaSetters as _, SendPhotoSetters as _, SendPollSetters as _,
+    SendStickerSetters as _, SendVenueSetters as _, SendVideoNoteSetters as _,
+    SendVideoSetters as _, SendVoiceSetters as _, SetChatAdministratorCustomTitleSetters as _,
+    SetChatDescriptionSetters as _, SetChatMenuButtonSetters as _, SetChatPermissionsSetters as _,
+    SetChatPhotoSetters as _, SetChatStickerSetSetters as _, SetChatTitleSetters as _,
+    SetGameScoreInlineSetters as _, SetGameScoreSetters as _, SetMessageReactionSetters as _,
     SetMyCommandsSetters as _, SetPassportDataErrorsSetters as _,
     SetStickerPositionInSetSetters as _, SetStickerSetThumbSetters as _, SetWebhookSetters as _,
     StopMessageLiveLocationInlineSetters as _, StopMessageLiveLocationSetters as _,
@@ -3,7 +3,7 @@
 // edit `cg` instead.
 use serde::Serialize;
 
-use crate::types::{Message, ReplyMarkup};
+use crate::types::{ReplyMarkup, True};
 
 impl_payload! {
     /// Use this method to edit live location messages. A location can be edited until its live_period expires or editing is explicitly disabled by a call to [`StopMessageLiveLocation`]. On success, True is returned.
@@ -12,7 +12,7 @@ impl_payload! {
     ///
     /// [`StopMessageLiveLocation`]: crate::payloads::StopMessageLiveLocation
     #[derive(Debug, PartialEq, Clone, Serialize)]
-    pub StopMessageLiveLocationInline (StopMessageLiveLocationInlineSetters) => Message {
+    pub StopMessageLiveLocationInline (StopMessageLiveLocationInlineSetters) => True {
         required {
             /// Identifier of the inline message
             pub inline_message_id: String [into],
@@ -22,6 +22,8 @@ impl_payload! {
             pub longitude: f64,
         }
         optional {
+            /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+            pub business_connection_id: String [into],
             /// Additional interface options. A JSON-serialized object for an [inline keyboard], [custom reply keyboard], instructions to remove reply keyboard or to force a reply from the user.
             ///
             /// [inline keyboard]: https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
@@ -0,0 +1,173 @@
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    payloads::*,
+    requests::Payload,
+    types::{
+        ChatId, InlineQueryResult, InlineQueryResultArticle, InlineQueryResultsButton, InputFile,
+        InputMessageContent, InputMessageContentText, LabeledPrice, MessageId, True, UntilDate,
+        UserId,
+    },
+};
+
+/// Returns the name of the method, the payload serialized to JSON and the
+/// `expected` JSON.
+fn case<P>(payload: P, expected: Value) -> (&'static str, Value, Value)
+where
+    P: Payload + Serialize,
+{
+    (P::NAME, serde_json::to_value(&payload).unwrap(), expected)
+}
+
+/// Deserializes an output of the method `P` from `json`.
+fn output<P>(json: &str) -> P::Output
+where
+    P: Payload,
+    P::Output: DeserializeOwned,
+{
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn serialize() {
+    let article = InlineQueryResultArticle::new(
+        "1",
+        "Title",
+        InputMessageContent::Text(InputMessageContentText::new("text")),
+    );
+
+    let cases = vec![
+        case(
+            AnswerInlineQuery::new("query", vec![InlineQueryResult::Article(article)])
+                .cache_time(0)
+                .is_personal(true)
+                .next_offset("10")
+                .button(InlineQueryResultsButton::start_parameter(
+                    "Settings", "settings",
+                )),
+            json!({
+                "inline_query_id": "query",
+                "results": [{
+                    "type": "article",
+                    "id": "1",
+                    "title": "Title",
+                    "input_message_content": { "message_text": "text" },
+                }],
+                "cache_time": 0,
+                "is_personal": true,
+                "next_offset": "10",
+                "button": { "text": "Settings", "start_parameter": "settings" },
+            }),
+        ),
+        case(
+            BanChatMember::new(-1001, UserId(42))
+                .until_date(1650000000)
+                .revoke_messages(true),
+            json!({
+                "chat_id": -1001,
+                "user_id": 42,
+                "until_date": 1650000000,
+                "revoke_messages": true,
+            }),
+        ),
+        case(
+            BanChatMember::new(-1001, UserId(42)).until_date(UntilDate::forever()),
+            json!({ "chat_id": -1001, "user_id": 42, "until_date": 0 }),
+        ),
+        case(
+            CopyMessages::new(1, 2, (10..13).map(MessageId))
+                .disable_notification(true)
+                .remove_caption(true),
+            json!({
+                "chat_id": 1,
+                "from_chat_id": 2,
+                "message_ids": [10, 11, 12],
+                "disable_notification": true,
+                "remove_caption": true,
+            }),
+        ),
+        case(
+            CreateInvoiceLink::new(
+                "title",
+                "description",
+                "payload",
+                "token",
+                "USD",
+                vec![LabeledPrice::new("price", 1000)],
+            )
+            .max_tip_amount(500)
+            .suggested_tip_amounts(vec![100, 200])
+            .need_email(true),
+            json!({
+                "title": "title",
+                "description": "description",
+                "payload": "payload",
+                "provider_token": "token",
+                "currency": "USD",
+                "prices": [{ "label": "price", "amount": 1000 }],
+                "max_tip_amount": 500,
+                "suggested_tip_amounts": [100, 200],
+                "need_email": true,
+            }),
+        ),
+        case(
+            ForwardMessages::new(
+                1,
+                ChatId::ChannelUsername("@channel".to_owned()),
+                vec![MessageId(10), MessageId(11)],
+            )
+            .protect_content(true),
+            json!({
+                "chat_id": 1,
+                "from_chat_id": "@channel",
+                "message_ids": [10, 11],
+                "protect_content": true,
+            }),
+        ),
+        // Unset permissions are omitted, so they are not changed
+        case(
+            PromoteChatMember::new(-1001, UserId(42))
+                .can_delete_messages(true)
+                .can_pin_messages(false),
+            json!({
+                "chat_id": -1001,
+                "user_id": 42,
+                "can_delete_messages": true,
+                "can_pin_messages": false,
+            }),
+        ),
+    ];
+
+    for (method, actual, expected) in cases {
+        assert_eq!(actual, expected, "{}", method);
+    }
+}
+
+#[test]
+fn deserialize_output() {
+    assert_eq!(
+        output::<ForwardMessages>(r#"[{"message_id":20},{"message_id":21}]"#),
+        [MessageId(20), MessageId(21)]
+    );
+    assert_eq!(
+        output::<CreateInvoiceLink>(r#""https://t.me/$abc""#),
+        "https://t.me/$abc"
+    );
+    assert_eq!(output::<SetChatTitle>("true"), True);
+    assert_eq!(output::<SetChatPhoto>("true"), True);
+    assert_eq!(output::<UnpinAllChatMessages>("true"), True);
+}
+
+#[test]
+fn unset_flags_are_omitted() {
+    let payload = SendPhoto::new(1, InputFile::file_id("id"));
+    let json = serde_json::to_value(&payload).unwrap();
+    assert!(json.get("has_spoiler").is_none());
+    assert!(json.get("protect_content").is_none());
+
+    let payload = payload.has_spoiler(true).protect_content(true);
+    let json = serde_json::to_value(&payload).unwrap();
+    assert_eq!(json["has_spoiler"], true);
+    assert_eq!(json["protect_content"], true);
+}
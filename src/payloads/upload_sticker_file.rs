@@ -3,7 +3,7 @@
 // edit `cg` instead.
 use serde::Serialize;
 
-use crate::types::{File, InputFile};
+use crate::types::{File, InputFile, UserId};
 
 impl_payload! {
     @[multipart]
@@ -12,7 +12,7 @@ impl_payload! {
     pub UploadStickerFile (UploadStickerFileSetters) => File {
         required {
             /// User identifier of sticker file owner
-            pub user_id: i64,
+            pub user_id: UserId,
             /// PNG image with the sticker, must be up to 512 kilobytes in size, dimensions must not exceed 512px, and either width or height must be exactly 512px. [More info on Sending Files »]
             ///
             /// [More info on Sending Files »]: crate::types::InputFile
@@ -1,9 +1,20 @@
 //! Telegram API requests.
 
 pub use self::{
-    has_payload::HasPayload, json::JsonRequest, multipart::MultipartRequest,
-    multipart_payload::MultipartPayload, payload::Payload, request::Request, requester::Requester,
+    action_guard::ActionGuard,
+    has_chat_id::HasChatId,
+    has_payload::HasPayload,
+    json::JsonRequest,
+    multipart::MultipartRequest,
+    multipart_payload::MultipartPayload,
+    offset_store::{FileOffsetStore, InMemoryOffsetStore, OffsetStore},
+    payload::Payload,
+    payload_size::PayloadSize,
+    request::Request,
+    requester::Requester,
     requester_ext::RequesterExt,
+    to_curl::ToCurl,
+    updates_stream::{ShutdownToken, UpdatesStream},
 };
 
 /// A type that is returned after making a request to Telegram.
@@ -12,12 +23,18 @@ pub type ResponseResult<T> = Result<T, crate::RequestError>;
 /// An output type of [`Payload`] in [`HasPayload`].
 pub type Output<T> = <<T as HasPayload>::Payload as Payload>::Output;
 
+mod action_guard;
+pub(crate) mod has_chat_id;
 mod has_payload;
 mod json;
 mod multipart;
 pub(crate) mod multipart_payload;
+mod offset_store;
 mod payload;
+mod payload_size;
 mod request;
 mod requester;
 mod requester_ext;
+mod to_curl;
+mod updates_stream;
 mod utils;
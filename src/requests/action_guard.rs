@@ -0,0 +1,151 @@
+use std::time::Duration;
+
+use futures::future::{abortable, AbortHandle};
+
+use crate::{
+    requests::{Request, Requester},
+    types::{ChatAction, ChatId},
+};
+
+/// Delay between two [`SendChatAction`] requests.
+///
+/// Telegram shows the action for 5 seconds (or less, if a message is sent), so
+/// the action is re-sent a bit earlier to avoid flickering.
+///
+/// [`SendChatAction`]: crate::payloads::SendChatAction
+const REPEAT_DELAY: Duration = Duration::from_secs(4);
+
+/// A guard which repeatedly sends a chat action until dropped.
+///
+/// Create it with [`RequesterExt::chat_action_repeating`].
+///
+/// [`RequesterExt::chat_action_repeating`]: crate::requests::RequesterExt::chat_action_repeating
+#[must_use = "the chat action is stopped as soon as the guard is dropped"]
+#[derive(Debug)]
+pub struct ActionGuard {
+    handle: AbortHandle,
+}
+
+impl ActionGuard {
+    /// Spawns a task which sends `action` to `chat_id` every 4 seconds.
+    ///
+    /// Panics if called outside of a tokio runtime.
+    pub(crate) fn spawn<R>(bot: R, chat_id: ChatId, action: ChatAction) -> Self
+    where
+        R: Requester + Send + 'static,
+    {
+        let (task, handle) = abortable(async move {
+            loop {
+                if let Err(err) = bot.send_chat_action(chat_id.clone(), action).send().await {
+                    log::warn!("Error while sending chat action: {}", err);
+                }
+
+                tokio::time::sleep(REPEAT_DELAY).await;
+            }
+        });
+
+        tokio::spawn(task);
+        Self { handle }
+    }
+
+    /// Stops sending the chat action.
+    ///
+    /// This is the same as dropping the guard.
+    pub fn stop(self) {}
+}
+
+impl Drop for ActionGuard {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::requests::RequesterExt;
+
+    #[test]
+    #[should_panic]
+    fn panics_outside_of_runtime() {
+        let _guard = crate::Bot::new("TOKEN").chat_action_repeating(42, ChatAction::Typing);
+    }
+
+    /// Runs `test` with a paused clock and a mock bot which has enough
+    /// responses enqueued.
+    #[cfg(feature = "mock")]
+    fn with_mock<F, Fut>(test: F)
+    where
+        F: FnOnce(crate::adaptors::Mock<crate::Bot>) -> Fut,
+        Fut: std::future::Future<Output = ()>,
+    {
+        let bot = crate::Bot::new("TOKEN").mock();
+        for _ in 0..10 {
+            bot.handle().push_response(crate::types::True);
+        }
+
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        rt.block_on(async {
+            tokio::time::pause();
+            test(bot).await
+        });
+    }
+
+    /// Advances the paused clock and lets the spawned task run.
+    #[cfg(feature = "mock")]
+    async fn advance(millis: u64) {
+        tokio::time::advance(Duration::from_millis(millis)).await;
+        for _ in 0..10 {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn repeats_until_dropped() {
+        with_mock(|bot| async move {
+            let handle = bot.handle();
+            let guard = bot.chat_action_repeating(42, ChatAction::Typing);
+
+            advance(0).await;
+            let requests = handle.take_requests();
+            assert_eq!(requests.len(), 1);
+            assert_eq!(requests[0].method, "SendChatAction");
+            assert_eq!(requests[0].payload["chat_id"], 42);
+            assert_eq!(requests[0].payload["action"], "typing");
+
+            // Timers have millisecond resolution, so check a bit after 4 seconds
+            advance(3_900).await;
+            assert_eq!(handle.take_requests().len(), 0);
+            advance(200).await;
+            assert_eq!(handle.take_requests().len(), 1);
+            advance(4_100).await;
+            assert_eq!(handle.take_requests().len(), 1);
+
+            drop(guard);
+            advance(8_000).await;
+            assert_eq!(handle.take_requests().len(), 0);
+        });
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn stop() {
+        with_mock(|bot| async move {
+            let handle = bot.handle();
+            let guard = bot.chat_action_repeating(42, ChatAction::UploadDocument);
+
+            advance(0).await;
+            advance(4_100).await;
+            assert_eq!(handle.take_requests().len(), 2);
+
+            guard.stop();
+            advance(8_000).await;
+            assert_eq!(handle.take_requests().len(), 0);
+        });
+    }
+}
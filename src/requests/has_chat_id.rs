@@ -0,0 +1,115 @@
+use crate::types::{ChatId, ThreadId};
+
+/// Payloads of requests which may be sent to a specific chat.
+///
+/// This is implemented for all payloads and allows to get the chat of a
+/// request without knowing its type, e.g. `request.payload_ref().chat_id()`.
+///
+/// Note that requests which identify a chat by an integer id (like
+/// [`SendInvoice`] and [`SendGame`]) and inline variants of requests return
+/// `None`.
+///
+/// [`SendInvoice`]: crate::payloads::SendInvoice
+/// [`SendGame`]: crate::payloads::SendGame
+pub trait HasChatId {
+    /// Returns the chat to which the request is sent, or `None` if the request
+    /// isn't sent to a chat.
+    fn chat_id(&self) -> Option<&ChatId>;
+
+    /// Returns a mutable reference to the chat to which the request is sent,
+    /// or `None` if the request isn't sent to a chat.
+    fn chat_id_mut(&mut self) -> Option<&mut ChatId>;
+
+    /// Returns the message thread (topic) to which the request is sent, or
+    /// `None` if it isn't set or the request doesn't support threads.
+    fn message_thread_id(&self) -> Option<ThreadId>;
+}
+
+/// Converts the `chat_id` field of a payload, used by [`HasChatId`]
+/// implementations.
+pub(crate) trait AsChatId {
+    fn as_chat_id(&self) -> Option<&ChatId>;
+
+    fn as_chat_id_mut(&mut self) -> Option<&mut ChatId>;
+}
+
+impl AsChatId for ChatId {
+    fn as_chat_id(&self) -> Option<&ChatId> {
+        Some(self)
+    }
+
+    fn as_chat_id_mut(&mut self) -> Option<&mut ChatId> {
+        Some(self)
+    }
+}
+
+impl AsChatId for i32 {
+    fn as_chat_id(&self) -> Option<&ChatId> {
+        None
+    }
+
+    fn as_chat_id_mut(&mut self) -> Option<&mut ChatId> {
+        None
+    }
+}
+
+impl AsChatId for u32 {
+    fn as_chat_id(&self) -> Option<&ChatId> {
+        None
+    }
+
+    fn as_chat_id_mut(&mut self) -> Option<&mut ChatId> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        payloads::SendMessageSetters,
+        requests::{HasPayload, Requester},
+        types::ThreadId,
+        Bot,
+    };
+
+    use super::*;
+
+    fn chat_of<R>(request: R) -> Option<ChatId>
+    where
+        R: HasPayload,
+        R::Payload: HasChatId,
+    {
+        request.payload_ref().chat_id().cloned()
+    }
+
+    #[test]
+    fn chat_id() {
+        let bot = Bot::new("TOKEN");
+        assert_eq!(chat_of(bot.send_message(1, "text")), Some(ChatId::Id(1)));
+        assert_eq!(
+            chat_of(bot.leave_chat(ChatId::ChannelUsername("@channel".to_owned()))),
+            Some(ChatId::ChannelUsername("@channel".to_owned()))
+        );
+    }
+
+    #[test]
+    fn no_chat_id() {
+        let bot = Bot::new("TOKEN");
+        assert_eq!(chat_of(bot.get_me()), None);
+        assert_eq!(chat_of(bot.answer_callback_query("id")), None);
+        assert_eq!(chat_of(bot.edit_message_text_inline("id", "text")), None);
+        assert_eq!(chat_of(bot.send_game(1, "game")), None);
+    }
+
+    #[test]
+    fn message_thread_id() {
+        let bot = Bot::new("TOKEN");
+        let request = bot.send_message(1, "text");
+        assert_eq!(request.payload_ref().message_thread_id(), None);
+
+        let request = request.message_thread_id(ThreadId(7));
+        assert_eq!(request.payload_ref().message_thread_id(), Some(ThreadId(7)));
+
+        assert_eq!(bot.get_me().payload_ref().message_thread_id(), None);
+    }
+}
@@ -12,6 +12,9 @@ pub(crate) mod sealed {
 impl sealed::Sealed for payloads::SendMediaGroup {}
 impl MultipartPayload for payloads::SendMediaGroup {}
 
+impl sealed::Sealed for payloads::SendPaidMedia {}
+impl MultipartPayload for payloads::SendPaidMedia {}
+
 impl sealed::Sealed for payloads::EditMessageMedia {}
 impl MultipartPayload for payloads::EditMessageMedia {}
 
@@ -0,0 +1,244 @@
+use std::{
+    fs, io,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+/// A storage of the [`GetUpdates`] offset, which allows to resume receiving
+/// updates after a restart.
+///
+/// See [`UpdatesStream::offset_store`] for more.
+///
+/// [`GetUpdates`]: crate::payloads::GetUpdates
+/// [`UpdatesStream::offset_store`]: crate::requests::UpdatesStream::offset_store
+pub trait OffsetStore {
+    /// Loads the stored offset, if any.
+    fn load(&self) -> Option<i32>;
+
+    /// Stores the `offset`.
+    ///
+    /// This is called from [`UpdatesStream`]'s `poll_next`, i.e. on the
+    /// executor, so it must not block (e.g. on file I/O). Slow stores should
+    /// move the work elsewhere, like [`FileOffsetStore`] does.
+    ///
+    /// [`UpdatesStream`]: crate::requests::UpdatesStream
+    fn store(&self, offset: i32);
+}
+
+/// An [`OffsetStore`] which keeps the offset in memory.
+///
+/// This is useful to resume receiving updates with a new [`UpdatesStream`]
+/// (e.g. after an error terminated the previous one) in the same process.
+/// Cloning the store is cheap, all clones refer to the same offset.
+///
+/// [`UpdatesStream`]: crate::requests::UpdatesStream
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryOffsetStore {
+    offset: Arc<Mutex<Option<i32>>>,
+}
+
+impl InMemoryOffsetStore {
+    /// Creates new store without an offset.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the stored offset, if any.
+    pub fn get(&self) -> Option<i32> {
+        self.load()
+    }
+}
+
+impl OffsetStore for InMemoryOffsetStore {
+    fn load(&self) -> Option<i32> {
+        *self.offset.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn store(&self, offset: i32) {
+        *self.offset.lock().unwrap_or_else(|err| err.into_inner()) = Some(offset);
+    }
+}
+
+/// An [`OffsetStore`] which keeps the offset in a file.
+///
+/// The offset is stored as a decimal number. The file is replaced atomically
+/// (the offset is written into a temporary file next to it, which is then
+/// renamed), so it's never left half-written.
+///
+/// Inside of a tokio runtime, [`store`] doesn't block: the file is written on
+/// the blocking thread pool (see [`tokio::task::spawn_blocking`]). Writes are
+/// never reordered, and if offsets are stored faster than they are written,
+/// only the latest one is written. Outside of a runtime the file is written
+/// immediately.
+///
+/// Errors are logged and otherwise ignored: if the file can't be read (or
+/// doesn't contain a valid offset), [`load`] returns `None`.
+///
+/// [`store`]: OffsetStore::store
+/// [`load`]: OffsetStore::load
+#[derive(Clone, Debug)]
+pub struct FileOffsetStore {
+    inner: Arc<FileInner>,
+}
+
+#[derive(Debug)]
+struct FileInner {
+    path: PathBuf,
+    pending: Mutex<Pending>,
+}
+
+#[derive(Debug, Default)]
+struct Pending {
+    /// The offset which is yet to be written.
+    offset: Option<i32>,
+    /// Whether a writer is running, it writes offsets until there are none.
+    writing: bool,
+}
+
+impl FileOffsetStore {
+    /// Creates new store which keeps the offset in the file at `path`.
+    ///
+    /// The file is created on the first [`store`], if it doesn't exist.
+    ///
+    /// [`store`]: OffsetStore::store
+    pub fn new<P>(path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self {
+            inner: Arc::new(FileInner {
+                path: path.into(),
+                pending: Mutex::default(),
+            }),
+        }
+    }
+}
+
+impl FileInner {
+    fn pending(&self) -> std::sync::MutexGuard<'_, Pending> {
+        // `Pending` is only updated with simple assignments, so it's ok to ignore
+        // poisoning
+        self.pending.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Writes pending offsets until there are none.
+    fn write_pending(&self) {
+        loop {
+            let offset = {
+                let mut pending = self.pending();
+                match pending.offset.take() {
+                    Some(offset) => offset,
+                    None => {
+                        pending.writing = false;
+                        return;
+                    }
+                }
+            };
+
+            if let Err(err) = self.write(offset) {
+                log::error!("Cannot write offset to {}: {}", self.path.display(), err);
+            }
+        }
+    }
+
+    fn write(&self, offset: i32) -> io::Result<()> {
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+
+        fs::write(&tmp, offset.to_string())?;
+        fs::rename(&tmp, &self.path)
+    }
+}
+
+impl OffsetStore for FileOffsetStore {
+    fn load(&self) -> Option<i32> {
+        let path = &self.inner.path;
+
+        match fs::read_to_string(path) {
+            Ok(s) => match s.trim().parse() {
+                Ok(offset) => Some(offset),
+                Err(err) => {
+                    log::error!("Invalid offset in {}: {}", path.display(), err);
+                    None
+                }
+            },
+            Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+            Err(err) => {
+                log::error!("Cannot read offset from {}: {}", path.display(), err);
+                None
+            }
+        }
+    }
+
+    fn store(&self, offset: i32) {
+        {
+            let mut pending = self.inner.pending();
+            pending.offset = Some(offset);
+            if pending.writing {
+                // The running writer will write the new offset
+                return;
+            }
+            pending.writing = true;
+        }
+
+        let inner = Arc::clone(&self.inner);
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn_blocking(move || inner.write_pending());
+            }
+            Err(_) => inner.write_pending(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_memory() {
+        let store = InMemoryOffsetStore::new();
+        assert_eq!(store.load(), None);
+
+        store.clone().store(42);
+        assert_eq!(store.get(), Some(42));
+    }
+
+    #[test]
+    fn file() {
+        let path = std::env::temp_dir().join(format!("teloxide-offset-{}", uuid::Uuid::new_v4()));
+        let store = FileOffsetStore::new(&path);
+        assert_eq!(store.load(), None);
+
+        store.store(42);
+        store.store(43);
+        assert_eq!(FileOffsetStore::new(&path).load(), Some(43));
+
+        fs::write(&path, "not an offset").unwrap();
+        assert_eq!(store.load(), None);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn file_in_runtime() {
+        let path = std::env::temp_dir().join(format!("teloxide-offset-{}", uuid::Uuid::new_v4()));
+        let store = FileOffsetStore::new(&path);
+
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        rt.block_on(async {
+            for offset in 1..=100 {
+                store.store(offset);
+            }
+        });
+        // Waits for the blocking thread pool
+        drop(rt);
+
+        assert_eq!(store.load(), Some(100));
+        assert!(!store.inner.pending().writing);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,3 +1,7 @@
+use std::time::Duration;
+
+use crate::RequestError;
+
 /// Payload of a request.
 ///
 /// Simply speaking, structures implementing this trait represent arguments of
@@ -21,4 +25,40 @@ pub trait Payload {
     /// It is case insensitive, though must not include underscores. (e.g.
     /// `GetMe`, `GETME`, `getme`, `getMe` are ok, but `get_me` is not ok).
     const NAME: &'static str;
+
+    /// `true` if the Telegram method is idempotent, i.e. sending the same
+    /// request multiple times has the same effect as sending it once.
+    ///
+    /// Only idempotent requests are retried on network errors (see
+    /// [`Bot::with_network_retries`]), since e.g. retrying `SendMessage` may
+    /// send the same message twice.
+    ///
+    /// [`Bot::with_network_retries`]: crate::Bot::with_network_retries
+    const IDEMPOTENT: bool = false;
+
+    /// Returns the time the Telegram method may take to respond, if it's a
+    /// long-running method (e.g. [`GetUpdates`] with the `timeout` set for
+    /// long polling).
+    ///
+    /// [`Bot`] adds this to the timeout of the request, so long-running
+    /// requests aren't cut off by the timeout of the http-client.
+    ///
+    /// [`GetUpdates`]: crate::payloads::GetUpdates
+    /// [`Bot`]: crate::Bot
+    fn timeout_hint(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Checks that texts, captions and chat titles of the payload are not
+    /// longer (or, for chat titles, shorter) than Telegram allows, and that
+    /// bot commands are valid.
+    ///
+    /// This is called right before sending a request, if the bot validates
+    /// lengths (see [`Bot::validate_lengths`]).
+    ///
+    /// [`Bot::validate_lengths`]: crate::Bot::validate_lengths
+    #[doc(hidden)]
+    fn check_lengths(&self) -> Result<(), RequestError> {
+        Ok(())
+    }
 }
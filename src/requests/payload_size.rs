@@ -0,0 +1,99 @@
+use serde::Serialize;
+
+use crate::{
+    requests::{MultipartPayload, Payload},
+    serde_multipart,
+};
+
+/// Computes sizes of request bodies, so that payloads can be validated before
+/// sending them.
+///
+/// This trait is implemented for all payloads.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{payloads::SendMessage, requests::PayloadSize};
+///
+/// let payload = SendMessage::new(42, "Hi!");
+/// assert_eq!(payload.serialized_size(), r#"{"chat_id":42,"text":"Hi!"}"#.len());
+/// ```
+pub trait PayloadSize: Payload + Serialize {
+    /// Returns the size (in bytes) of the JSON body, which is sent by
+    /// [`JsonRequest`].
+    ///
+    /// [`JsonRequest`]: crate::requests::JsonRequest
+    fn serialized_size(&self) -> usize {
+        serde_json::to_vec(self)
+            .expect("serialization of request to be infallible")
+            .len()
+    }
+
+    /// Returns an estimate of the size (in bytes) of the `multipart/form-data`
+    /// body, which is sent by [`MultipartRequest`], including the sizes of
+    /// all files which are uploaded.
+    ///
+    /// `local_mode` should be the same as [`Bot::is_local_mode`] of the bot
+    /// which will send the request (in the local mode [`InputFile::File`]s
+    /// aren't uploaded).
+    ///
+    /// Returns `None` if the size of some file is unknown, i.e. if it's an
+    /// [`InputFile::read`] without a length or an [`InputFile::File`] whose
+    /// metadata can't be read.
+    ///
+    /// [`MultipartRequest`]: crate::requests::MultipartRequest
+    /// [`Bot::is_local_mode`]: crate::Bot::is_local_mode
+    /// [`InputFile::File`]: crate::types::InputFile::File
+    /// [`InputFile::read`]: crate::types::InputFile::read
+    fn multipart_size(&self, local_mode: bool) -> Option<u64>
+    where
+        Self: MultipartPayload,
+    {
+        serde_multipart::form_size(self, local_mode)
+            .expect("serialization of request to be infallible")
+    }
+}
+
+impl<P> PayloadSize for P where P: Payload + Serialize {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        payloads::{GetMe, SendDocument, SendPhoto},
+        types::InputFile,
+    };
+
+    use super::*;
+
+    /// Returns the `Content-Length` which `reqwest` computes for the form
+    /// `payload` is serialized into.
+    fn content_length<P: Serialize>(payload: &P) -> u64 {
+        let form = futures::executor::block_on(serde_multipart::to_form(payload, false)).unwrap();
+        let request = reqwest::Client::new()
+            .post("http://localhost")
+            .multipart(form)
+            .build()
+            .unwrap();
+
+        request.headers()[reqwest::header::CONTENT_LENGTH]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(GetMe::new().serialized_size(), 2);
+    }
+
+    #[test]
+    fn multipart() {
+        let photo = SendPhoto::new(0, InputFile::memory("photo.png", &b"photo"[..]));
+        assert_eq!(photo.multipart_size(false), Some(content_length(&photo)));
+
+        let file = SendDocument::new(0, InputFile::file("/definitely/not/a/file"));
+        assert_eq!(file.multipart_size(false), None);
+        assert!(file.multipart_size(true).is_some());
+    }
+}
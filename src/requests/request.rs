@@ -1,5 +1,7 @@
 use std::future::Future;
 
+use futures::future::{AbortHandle, Abortable};
+
 use crate::requests::{HasPayload, Output};
 
 /// A ready-to-send Telegram request.
@@ -84,4 +86,54 @@ pub trait Request: HasPayload {
     /// # };
     /// ```
     fn send_ref(&self) -> Self::SendRef;
+
+    /// Send this request, returning a handle which allows to cancel it.
+    ///
+    /// When [`AbortHandle::abort`] is called, the returned future resolves to
+    /// `Err(Aborted)` (without waiting for the response), even if it's being
+    /// polled by another task. The request itself is cancelled when the
+    /// future is dropped, which usually happens right after it resolves
+    /// (e.g. when it's awaited or spawned as a task). A request which is
+    /// being sent is never resumed: the connection it used is closed, not
+    /// returned to the connection pool.
+    ///
+    /// If a multipart request is aborted while its files are being uploaded,
+    /// the upload is interrupted, so the server doesn't receive the request
+    /// and it doesn't have any effect. However, a request which has already
+    /// been received by the server may still be executed (e.g. the message
+    /// may be sent), aborting only discards the response.
+    ///
+    /// Note that dropping the future (e.g. with `select!` or a timeout)
+    /// cancels the request as well, the handle is only needed to cancel it
+    /// from elsewhere.
+    ///
+    /// ## Examples
+    /// ```
+    /// # async {
+    /// use teloxide_core::{
+    ///     prelude::*,
+    ///     requests::Request,
+    ///     types::{ChatId, InputFile},
+    ///     Bot,
+    /// };
+    ///
+    /// let bot = Bot::new("TOKEN");
+    /// let (upload, handle) = bot
+    ///     .send_document(ChatId::Id(0), InputFile::file("video.mp4"))
+    ///     .send_abortable();
+    ///
+    /// // e.g. when the user has navigated away
+    /// handle.abort();
+    /// assert!(upload.await.is_err());
+    /// # };
+    /// ```
+    ///
+    /// [`AbortHandle::abort`]: futures::future::AbortHandle::abort
+    fn send_abortable(self) -> (Abortable<Self::Send>, AbortHandle)
+    where
+        Self: Sized,
+    {
+        let (handle, registration) = AbortHandle::new_pair();
+        (Abortable::new(self.send(), registration), handle)
+    }
 }
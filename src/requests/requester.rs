@@ -6,13 +6,18 @@ use crate::{
     requests::Request,
     types::{
         BotCommand, ChatAction, ChatId, ChatPermissions, InlineQueryResult, InputFile, InputMedia,
-        InputSticker, LabeledPrice, PassportElementError, PollType, TargetMessage,
+        InputPaidMedia, InputSticker, LabeledPrice, MessageId, PassportElementError, PollType,
+        TargetMessage, UserId,
     },
 };
 
 /// Methods for building requests.
 ///
-/// This trait is implemented by all bots & bot adaptors.
+/// This trait is implemented by all bots & bot adaptors. It's also implemented
+/// for references and smart pointers to them (`&R`, `&mut R`, `Box<R>`,
+/// `Arc<R>` and `Rc<R>`), which forward to the inner requester and return
+/// the same request types, so e.g. `&Bot` or `Arc<Bot>` can be passed to
+/// generic code without cloning the bot.
 ///
 /// ## Examples
 ///
@@ -46,6 +51,14 @@ use crate::{
 /// {
 ///     bot.send_message(chat, "hi").send().await.expect("error")
 /// }
+///
+/// # async {
+/// let bot = Bot::new("TOKEN");
+/// send_hi(&bot, 0).await;
+///
+/// let bot = std::sync::Arc::new(bot);
+/// send_hi(bot.clone(), 0).await;
+/// # };
 /// ```
 #[cfg_attr(all(docsrs, feature = "nightly"), doc(spotlight))]
 pub trait Requester {
@@ -108,20 +121,53 @@ pub trait Requester {
         &self,
         chat_id: C,
         from_chat_id: F,
-        message_id: i32,
+        message_id: MessageId,
     ) -> Self::ForwardMessage
     where
         C: Into<ChatId>,
         F: Into<ChatId>;
 
+    type ForwardMessages: Request<Payload = ForwardMessages, Err = Self::Err>;
+
+    /// For Telegram documentation see [`ForwardMessages`].
+    fn forward_messages<C, F, M>(
+        &self,
+        chat_id: C,
+        from_chat_id: F,
+        message_ids: M,
+    ) -> Self::ForwardMessages
+    where
+        C: Into<ChatId>,
+        F: Into<ChatId>,
+        M: IntoIterator<Item = MessageId>;
+
     type CopyMessage: Request<Payload = CopyMessage, Err = Self::Err>;
 
     /// For Telegram documentation see [`CopyMessage`].
-    fn copy_message<C, F>(&self, chat_id: C, from_chat_id: F, message_id: i32) -> Self::CopyMessage
+    fn copy_message<C, F>(
+        &self,
+        chat_id: C,
+        from_chat_id: F,
+        message_id: MessageId,
+    ) -> Self::CopyMessage
     where
         C: Into<ChatId>,
         F: Into<ChatId>;
 
+    type CopyMessages: Request<Payload = CopyMessages, Err = Self::Err>;
+
+    /// For Telegram documentation see [`CopyMessages`].
+    fn copy_messages<C, F, M>(
+        &self,
+        chat_id: C,
+        from_chat_id: F,
+        message_ids: M,
+    ) -> Self::CopyMessages
+    where
+        C: Into<ChatId>,
+        F: Into<ChatId>,
+        M: IntoIterator<Item = MessageId>;
+
     type SendPhoto: Request<Payload = SendPhoto, Err = Self::Err>;
 
     /// For Telegram documentation see [`SendPhoto`].
@@ -179,6 +225,14 @@ pub trait Requester {
         C: Into<ChatId>,
         M: IntoIterator<Item = InputMedia>;
 
+    type SendPaidMedia: Request<Payload = SendPaidMedia, Err = Self::Err>;
+
+    /// For Telegram documentation see [`SendPaidMedia`].
+    fn send_paid_media<C, M>(&self, chat_id: C, star_count: u32, media: M) -> Self::SendPaidMedia
+    where
+        C: Into<ChatId>,
+        M: IntoIterator<Item = InputPaidMedia>;
+
     type SendLocation: Request<Payload = SendLocation, Err = Self::Err>;
 
     /// For Telegram documentation see [`SendLocation`].
@@ -192,7 +246,7 @@ pub trait Requester {
     fn edit_message_live_location<C>(
         &self,
         chat_id: C,
-        message_id: i32,
+        message_id: MessageId,
         latitude: f64,
         longitude: f64,
     ) -> Self::EditMessageLiveLocation
@@ -220,7 +274,7 @@ pub trait Requester {
     fn stop_message_live_location<C>(
         &self,
         chat_id: C,
-        message_id: i32,
+        message_id: MessageId,
         latitude: f64,
         longitude: f64,
     ) -> Self::StopMessageLiveLocation
@@ -304,7 +358,7 @@ pub trait Requester {
     type GetUserProfilePhotos: Request<Payload = GetUserProfilePhotos, Err = Self::Err>;
 
     /// For Telegram documentation see [`GetUserProfilePhotos`].
-    fn get_user_profile_photos(&self, user_id: i64) -> Self::GetUserProfilePhotos;
+    fn get_user_profile_photos(&self, user_id: UserId) -> Self::GetUserProfilePhotos;
 
     type GetFile: Request<Payload = GetFile, Err = Self::Err>;
 
@@ -316,14 +370,21 @@ pub trait Requester {
     type KickChatMember: Request<Payload = KickChatMember, Err = Self::Err>;
 
     /// For Telegram documentation see [`KickChatMember`].
-    fn kick_chat_member<C>(&self, chat_id: C, user_id: i64) -> Self::KickChatMember
+    fn kick_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::KickChatMember
+    where
+        C: Into<ChatId>;
+
+    type BanChatMember: Request<Payload = BanChatMember, Err = Self::Err>;
+
+    /// For Telegram documentation see [`BanChatMember`].
+    fn ban_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::BanChatMember
     where
         C: Into<ChatId>;
 
     type UnbanChatMember: Request<Payload = UnbanChatMember, Err = Self::Err>;
 
     /// For Telegram documentation see [`UnbanChatMember`].
-    fn unban_chat_member<C>(&self, chat_id: C, user_id: i64) -> Self::UnbanChatMember
+    fn unban_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::UnbanChatMember
     where
         C: Into<ChatId>;
 
@@ -333,7 +394,7 @@ pub trait Requester {
     fn restrict_chat_member<C>(
         &self,
         chat_id: C,
-        user_id: i64,
+        user_id: UserId,
         permissions: ChatPermissions,
     ) -> Self::RestrictChatMember
     where
@@ -342,7 +403,7 @@ pub trait Requester {
     type PromoteChatMember: Request<Payload = PromoteChatMember, Err = Self::Err>;
 
     /// For Telegram documentation see [`PromoteChatMember`].
-    fn promote_chat_member<C>(&self, chat_id: C, user_id: i64) -> Self::PromoteChatMember
+    fn promote_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::PromoteChatMember
     where
         C: Into<ChatId>;
 
@@ -355,7 +416,7 @@ pub trait Requester {
     fn set_chat_administrator_custom_title<Ch, Cu>(
         &self,
         chat_id: Ch,
-        user_id: i64,
+        user_id: UserId,
         custom_title: Cu,
     ) -> Self::SetChatAdministratorCustomTitle
     where
@@ -439,7 +500,7 @@ pub trait Requester {
     type PinChatMessage: Request<Payload = PinChatMessage, Err = Self::Err>;
 
     /// For Telegram documentation see [`PinChatMessage`].
-    fn pin_chat_message<C>(&self, chat_id: C, message_id: i32) -> Self::PinChatMessage
+    fn pin_chat_message<C>(&self, chat_id: C, message_id: MessageId) -> Self::PinChatMessage
     where
         C: Into<ChatId>;
 
@@ -488,7 +549,7 @@ pub trait Requester {
     type GetChatMember: Request<Payload = GetChatMember, Err = Self::Err>;
 
     /// For Telegram documentation see [`GetChatMember`].
-    fn get_chat_member<C>(&self, chat_id: C, user_id: i64) -> Self::GetChatMember
+    fn get_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::GetChatMember
     where
         C: Into<ChatId>;
 
@@ -530,6 +591,21 @@ pub trait Requester {
     /// For Telegram documentation see [`GetMyCommands`].
     fn get_my_commands(&self) -> Self::GetMyCommands;
 
+    type DeleteMyCommands: Request<Payload = DeleteMyCommands, Err = Self::Err>;
+
+    /// For Telegram documentation see [`DeleteMyCommands`].
+    fn delete_my_commands(&self) -> Self::DeleteMyCommands;
+
+    type SetChatMenuButton: Request<Payload = SetChatMenuButton, Err = Self::Err>;
+
+    /// For Telegram documentation see [`SetChatMenuButton`].
+    fn set_chat_menu_button(&self) -> Self::SetChatMenuButton;
+
+    type GetChatMenuButton: Request<Payload = GetChatMenuButton, Err = Self::Err>;
+
+    /// For Telegram documentation see [`GetChatMenuButton`].
+    fn get_chat_menu_button(&self) -> Self::GetChatMenuButton;
+
     type AnswerInlineQuery: Request<Payload = AnswerInlineQuery, Err = Self::Err>;
 
     /// For Telegram documentation see [`AnswerInlineQuery`].
@@ -544,7 +620,7 @@ pub trait Requester {
     fn edit_message_text<C, T>(
         &self,
         chat_id: C,
-        message_id: i32,
+        message_id: MessageId,
         text: T,
     ) -> Self::EditMessageText
     where
@@ -566,7 +642,11 @@ pub trait Requester {
     type EditMessageCaption: Request<Payload = EditMessageCaption, Err = Self::Err>;
 
     /// For Telegram documentation see [`EditMessageCaption`].
-    fn edit_message_caption<C>(&self, chat_id: C, message_id: i32) -> Self::EditMessageCaption
+    fn edit_message_caption<C>(
+        &self,
+        chat_id: C,
+        message_id: MessageId,
+    ) -> Self::EditMessageCaption
     where
         C: Into<ChatId>;
 
@@ -586,7 +666,7 @@ pub trait Requester {
     fn edit_message_media<C>(
         &self,
         chat_id: C,
-        message_id: i32,
+        message_id: MessageId,
         media: InputMedia,
     ) -> Self::EditMessageMedia
     where
@@ -609,7 +689,7 @@ pub trait Requester {
     fn edit_message_reply_markup<C>(
         &self,
         chat_id: C,
-        message_id: i32,
+        message_id: MessageId,
     ) -> Self::EditMessageReplyMarkup
     where
         C: Into<ChatId>;
@@ -630,14 +710,25 @@ pub trait Requester {
     type StopPoll: Request<Payload = StopPoll, Err = Self::Err>;
 
     /// For Telegram documentation see [`StopPoll`].
-    fn stop_poll<C>(&self, chat_id: C, message_id: i32) -> Self::StopPoll
+    fn stop_poll<C>(&self, chat_id: C, message_id: MessageId) -> Self::StopPoll
     where
         C: Into<ChatId>;
 
     type DeleteMessage: Request<Payload = DeleteMessage, Err = Self::Err>;
 
     /// For Telegram documentation see [`DeleteMessage`].
-    fn delete_message<C>(&self, chat_id: C, message_id: i32) -> Self::DeleteMessage
+    fn delete_message<C>(&self, chat_id: C, message_id: MessageId) -> Self::DeleteMessage
+    where
+        C: Into<ChatId>;
+
+    type SetMessageReaction: Request<Payload = SetMessageReaction, Err = Self::Err>;
+
+    /// For Telegram documentation see [`SetMessageReaction`].
+    fn set_message_reaction<C>(
+        &self,
+        chat_id: C,
+        message_id: MessageId,
+    ) -> Self::SetMessageReaction
     where
         C: Into<ChatId>;
 
@@ -658,14 +749,18 @@ pub trait Requester {
     type UploadStickerFile: Request<Payload = UploadStickerFile, Err = Self::Err>;
 
     /// For Telegram documentation see [`UploadStickerFile`].
-    fn upload_sticker_file(&self, user_id: i64, png_sticker: InputFile) -> Self::UploadStickerFile;
+    fn upload_sticker_file(
+        &self,
+        user_id: UserId,
+        png_sticker: InputFile,
+    ) -> Self::UploadStickerFile;
 
     type CreateNewStickerSet: Request<Payload = CreateNewStickerSet, Err = Self::Err>;
 
     /// For Telegram documentation see [`CreateNewStickerSet`].
     fn create_new_sticker_set<N, T, E>(
         &self,
-        user_id: i64,
+        user_id: UserId,
         name: N,
         title: T,
         sticker: InputSticker,
@@ -681,7 +776,7 @@ pub trait Requester {
     /// For Telegram documentation see [`AddStickerToSet`].
     fn add_sticker_to_set<N, E>(
         &self,
-        user_id: i64,
+        user_id: UserId,
         name: N,
         sticker: InputSticker,
         emojis: E,
@@ -711,7 +806,7 @@ pub trait Requester {
     type SetStickerSetThumb: Request<Payload = SetStickerSetThumb, Err = Self::Err>;
 
     /// For Telegram documentation see [`SetStickerSetThumb`].
-    fn set_sticker_set_thumb<N>(&self, name: N, user_id: i64) -> Self::SetStickerSetThumb
+    fn set_sticker_set_thumb<N>(&self, name: N, user_id: UserId) -> Self::SetStickerSetThumb
     where
         N: Into<String>;
 
@@ -738,6 +833,26 @@ pub trait Requester {
         C: Into<String>,
         Pri: IntoIterator<Item = LabeledPrice>;
 
+    type CreateInvoiceLink: Request<Payload = CreateInvoiceLink, Err = Self::Err>;
+
+    /// For Telegram documentation see [`CreateInvoiceLink`].
+    fn create_invoice_link<T, D, Pa, P, C, Pri>(
+        &self,
+        title: T,
+        description: D,
+        payload: Pa,
+        provider_token: P,
+        currency: C,
+        prices: Pri,
+    ) -> Self::CreateInvoiceLink
+    where
+        T: Into<String>,
+        D: Into<String>,
+        Pa: Into<String>,
+        P: Into<String>,
+        C: Into<String>,
+        Pri: IntoIterator<Item = LabeledPrice>;
+
     type AnswerShippingQuery: Request<Payload = AnswerShippingQuery, Err = Self::Err>;
 
     /// For Telegram documentation see [`AnswerShippingQuery`].
@@ -756,10 +871,25 @@ pub trait Requester {
     where
         P: Into<String>;
 
+    type RefundStarPayment: Request<Payload = RefundStarPayment, Err = Self::Err>;
+
+    /// For Telegram documentation see [`RefundStarPayment`].
+    fn refund_star_payment<T>(
+        &self,
+        user_id: UserId,
+        telegram_payment_charge_id: T,
+    ) -> Self::RefundStarPayment
+    where
+        T: Into<String>;
+
     type SetPassportDataErrors: Request<Payload = SetPassportDataErrors, Err = Self::Err>;
 
     /// For Telegram documentation see [`SetPassportDataErrors`].
-    fn set_passport_data_errors<E>(&self, user_id: i64, errors: E) -> Self::SetPassportDataErrors
+    fn set_passport_data_errors<E>(
+        &self,
+        user_id: UserId,
+        errors: E,
+    ) -> Self::SetPassportDataErrors
     where
         E: IntoIterator<Item = PassportElementError>;
 
@@ -775,10 +905,10 @@ pub trait Requester {
     /// For Telegram documentation see [`SetGameScore`].
     fn set_game_score(
         &self,
-        user_id: i64,
+        user_id: UserId,
         score: u64,
         chat_id: u32,
-        message_id: i64,
+        message_id: MessageId,
     ) -> Self::SetGameScore;
 
     type SetGameScoreInline: Request<Payload = SetGameScoreInline, Err = Self::Err>;
@@ -786,7 +916,7 @@ pub trait Requester {
     /// For Telegram documentation see [`SetGameScoreInline`].
     fn set_game_score_inline<I>(
         &self,
-        user_id: i64,
+        user_id: UserId,
         score: u64,
         inline_message_id: I,
     ) -> Self::SetGameScoreInline
@@ -796,7 +926,7 @@ pub trait Requester {
     type GetGameHighScores: Request<Payload = GetGameHighScores, Err = Self::Err>;
 
     /// For Telegram documentation see [`GetGameHighScores`].
-    fn get_game_high_scores<T>(&self, user_id: i64, target: T) -> Self::GetGameHighScores
+    fn get_game_high_scores<T>(&self, user_id: UserId, target: T) -> Self::GetGameHighScores
     where
         T: Into<TargetMessage>;
 
@@ -822,26 +952,30 @@ macro_rules! forward_all {
     () => {
         requester_forward! {
             get_me, log_out, close, get_updates, set_webhook, delete_webhook, get_webhook_info,
-            forward_message, copy_message, send_message, send_photo, send_audio, send_document,
-            send_video, send_animation, send_voice, send_video_note, send_media_group, send_location,
+            forward_message, forward_messages, copy_message, copy_messages,
+            send_message, send_photo, send_audio, send_document,
+            send_video, send_animation, send_voice, send_video_note, send_media_group,
+            send_paid_media, send_location,
             edit_message_live_location, edit_message_live_location_inline,
             stop_message_live_location, stop_message_live_location_inline, send_venue,
             send_contact, send_poll, send_dice, send_chat_action, get_user_profile_photos,
-            get_file, kick_chat_member, unban_chat_member, restrict_chat_member,
+            get_file, kick_chat_member, ban_chat_member, unban_chat_member, restrict_chat_member,
             promote_chat_member, set_chat_administrator_custom_title, set_chat_permissions,
             export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
             revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
             set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
             leave_chat, get_chat, get_chat_administrators, get_chat_members_count,get_chat_member,
             set_chat_sticker_set, delete_chat_sticker_set, answer_callback_query,
-            set_my_commands, get_my_commands, answer_inline_query, edit_message_text,
+            set_my_commands, get_my_commands, delete_my_commands, set_chat_menu_button,
+            get_chat_menu_button, answer_inline_query, edit_message_text,
             edit_message_text_inline, edit_message_caption, edit_message_caption_inline,
             edit_message_media, edit_message_media_inline, edit_message_reply_markup,
-            edit_message_reply_markup_inline, stop_poll, delete_message, send_sticker,
+            edit_message_reply_markup_inline, stop_poll, delete_message,
+            set_message_reaction, send_sticker,
             get_sticker_set, upload_sticker_file, create_new_sticker_set,
             add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
-            set_sticker_set_thumb, send_invoice, answer_shipping_query,
-            answer_pre_checkout_query, set_passport_data_errors, send_game,
+            set_sticker_set_thumb, send_invoice, create_invoice_link, answer_shipping_query,
+            answer_pre_checkout_query, refund_star_payment, set_passport_data_errors, send_game,
             set_game_score, set_game_score_inline, get_game_high_scores,
             get_updates_fault_tolerant => fwd_deref, fty
         }
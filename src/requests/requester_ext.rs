@@ -1,4 +1,20 @@
-use crate::{adaptors::DefaultParseMode, requests::Requester, types::ParseMode};
+use futures::{
+    future::BoxFuture,
+    stream::{self, BoxStream},
+    FutureExt, StreamExt, TryFutureExt, TryStreamExt,
+};
+
+use crate::{
+    adaptors::DefaultParseMode,
+    payloads::{DeleteWebhookSetters, GetUserProfilePhotosSetters, SendMessageSetters},
+    requests::{ActionGuard, Output, Request, Requester, UpdatesStream},
+    types::{
+        ChatAction, ChatAdministratorRights, ChatAdministrators, ChatId, Message, ParseMode,
+        PhotoSize, UserId,
+    },
+    utils::{split_text, MESSAGE_TEXT_MAX_LEN},
+    RequestError,
+};
 
 #[cfg(feature = "cache_me")]
 use crate::adaptors::CacheMe;
@@ -9,6 +25,30 @@ use crate::adaptors::AutoSend;
 #[cfg(feature = "throttle")]
 use crate::adaptors::throttle::{Limits, Throttle};
 
+#[cfg(feature = "resolve_usernames")]
+use crate::adaptors::ResolveUsernames;
+
+#[cfg(feature = "retry")]
+use crate::adaptors::Retry;
+
+#[cfg(feature = "circuit_breaker")]
+use crate::adaptors::circuit_breaker::{CircuitBreaker, Thresholds};
+
+#[cfg(feature = "erased")]
+use crate::adaptors::ErasedRequester;
+
+#[cfg(feature = "inspect")]
+use crate::adaptors::Inspect;
+
+#[cfg(feature = "metrics")]
+use crate::adaptors::Metrics;
+
+#[cfg(feature = "mock")]
+use crate::adaptors::Mock;
+
+#[cfg(feature = "trace")]
+use crate::adaptors::trace::{Trace, TraceSettings};
+
 /// Extensions methods for [`Requester`].
 pub trait RequesterExt: Requester {
     /// Add `get_me` caching ability, see [`CacheMe`] for more.
@@ -48,6 +88,130 @@ pub trait RequesterExt: Requester {
         Throttle::new_spawn(self, limits)
     }
 
+    /// Resolve usernames of chats to ids and cache them, see
+    /// [`ResolveUsernames`] for more.
+    #[cfg(feature = "resolve_usernames")]
+    #[cfg_attr(
+        all(docsrs, feature = "nightly"),
+        doc(cfg(feature = "resolve_usernames"))
+    )]
+    fn resolve_usernames(self) -> ResolveUsernames<Self>
+    where
+        Self: Sized,
+    {
+        ResolveUsernames::new(self)
+    }
+
+    /// Retry requests failed because of flood control, see [`Retry`] for
+    /// more.
+    #[cfg(feature = "retry")]
+    #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "retry")))]
+    fn retry(self, max_attempts: u32) -> Retry<Self>
+    where
+        Self: Sized,
+    {
+        Retry::new(self, max_attempts)
+    }
+
+    /// Fail fast when Telegram is unreachable, see [`CircuitBreaker`] for
+    /// more.
+    #[cfg(feature = "circuit_breaker")]
+    #[cfg_attr(
+        all(docsrs, feature = "nightly"),
+        doc(cfg(feature = "circuit_breaker"))
+    )]
+    fn circuit_breaker(self, thresholds: Thresholds) -> CircuitBreaker<Self>
+    where
+        Self: Sized,
+    {
+        CircuitBreaker::new(self, thresholds)
+    }
+
+    /// Collect metrics of sent requests, see [`Metrics`] for more.
+    #[cfg(feature = "metrics")]
+    #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "metrics")))]
+    fn metrics(self) -> Metrics<Self>
+    where
+        Self: Sized,
+    {
+        Metrics::new(self)
+    }
+
+    /// Erases the type of the bot, see [`ErasedRequester`] for more.
+    #[cfg(feature = "erased")]
+    #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "erased")))]
+    fn erase<'a>(self) -> ErasedRequester<'a, Self::Err>
+    where
+        Self: Sized + Send + Sync + 'a,
+    {
+        ErasedRequester::new(self)
+    }
+
+    /// Calls `f` with the method name and the output of every successful
+    /// request, see [`Inspect`] for more.
+    #[cfg(feature = "inspect")]
+    #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "inspect")))]
+    fn inspect<F>(self, f: F) -> Inspect<Self>
+    where
+        Self: Sized,
+        F: Fn(&'static str, &dyn std::any::Any) + Send + Sync + 'static,
+    {
+        Inspect::new(self, f)
+    }
+
+    /// Calls `f` with every message returned by a successful request.
+    ///
+    /// This includes messages sent by `send_*` methods (including every
+    /// message of [`SendMediaGroup`]) and messages edited by `edit_message_*`
+    /// methods. This is a shortcut for [`RequesterExt::inspect`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::{requests::RequesterExt, Bot};
+    ///
+    /// let bot = Bot::new("TOKEN").on_message_sent(|message| {
+    ///     log::info!("sent message {:?} to {}", message.id, message.chat.id);
+    /// });
+    /// ```
+    ///
+    /// [`SendMediaGroup`]: crate::payloads::SendMediaGroup
+    #[cfg(feature = "inspect")]
+    #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "inspect")))]
+    fn on_message_sent<F>(self, f: F) -> Inspect<Self>
+    where
+        Self: Sized,
+        F: Fn(&Message) + Send + Sync + 'static,
+    {
+        Inspect::new(self, move |_, output| {
+            if let Some(message) = output.downcast_ref::<Message>() {
+                f(message);
+            } else if let Some(messages) = output.downcast_ref::<Vec<Message>>() {
+                messages.iter().for_each(&f);
+            }
+        })
+    }
+
+    /// Record requests instead of sending them, see [`Mock`] for more.
+    #[cfg(feature = "mock")]
+    #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "mock")))]
+    fn mock(self) -> Mock<Self>
+    where
+        Self: Sized,
+    {
+        Mock::new(self)
+    }
+
+    /// Log requests and responses, see [`Trace`] for more.
+    #[cfg(feature = "trace")]
+    #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "trace")))]
+    fn trace(self, settings: TraceSettings) -> Trace<Self>
+    where
+        Self: Sized,
+    {
+        Trace::new(self, settings)
+    }
+
     /// Specifies default [`ParseMode`], which will be used during all calls to:
     ///
     ///  - [`send_message`]
@@ -82,6 +246,391 @@ pub trait RequesterExt: Requester {
     {
         DefaultParseMode::new(self, parse_mode)
     }
+
+    /// Returns a stream of updates received via long polling, see
+    /// [`UpdatesStream`] for more.
+    fn updates_stream(self) -> UpdatesStream<Self>
+    where
+        Self: Sized + Requester<Err = RequestError>,
+    {
+        UpdatesStream::new(self)
+    }
+
+    /// Repeatedly sends `action` to `chat_id` until the returned guard is
+    /// dropped.
+    ///
+    /// Telegram shows a chat action (e.g. "typing...") only for 5 seconds, so
+    /// to show it during a long operation it needs to be re-sent. This method
+    /// spawns a task (with `tokio::spawn`) which sends the action every 4
+    /// seconds. Errors are logged and otherwise ignored.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if called outside of a [tokio] runtime. The runtime must also
+    /// have the time driver enabled (e.g. with [`enable_time`]), otherwise the
+    /// spawned task panics.
+    ///
+    /// [tokio]: https://tokio.rs
+    /// [`enable_time`]: tokio::runtime::Builder::enable_time
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use teloxide_core::{requests::RequesterExt, types::ChatAction, Bot};
+    ///
+    /// # async {
+    /// let bot = Bot::new("TOKEN");
+    /// # let chat_id = 0;
+    ///
+    /// let guard = bot.chat_action_repeating(chat_id, ChatAction::Typing);
+    /// /* do some long work here */
+    /// drop(guard);
+    /// # };
+    /// ```
+    fn chat_action_repeating<C>(&self, chat_id: C, action: ChatAction) -> ActionGuard
+    where
+        Self: Clone + Send + 'static,
+        C: Into<ChatId>,
+    {
+        ActionGuard::spawn(self.clone(), chat_id.into(), action)
+    }
+
+    /// Sends a request built by `message_builder` to every chat from
+    /// `chat_ids`, with at most `concurrency` requests in flight at the same
+    /// time.
+    ///
+    /// Returns the result for every chat, so failed requests can be retried.
+    /// Results are returned in the order in which requests complete, which is
+    /// not necessarily the order of `chat_ids`.
+    ///
+    /// Note that this method doesn't limit the rate of requests by itself, the
+    /// `concurrency` only limits the number of simultaneous requests. To not
+    /// hit Telegram limits, call this method on a bot wrapped in [`Throttle`]:
+    /// requests are then delayed by the throttle (and are counted towards
+    /// `concurrency` while waiting in its queue).
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `concurrency` is 0.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use teloxide_core::{
+    ///     requests::{Requester, RequesterExt},
+    ///     Bot,
+    /// };
+    ///
+    /// # async {
+    /// // Consider wrapping the bot in `Throttle` to respect rate limits
+    /// let bot = Bot::new("TOKEN");
+    /// # let chat_ids = vec![0, 1, 2];
+    ///
+    /// let results = bot
+    ///     .broadcast(
+    ///         chat_ids,
+    ///         |bot, chat_id| bot.send_message(chat_id, "Hello!"),
+    ///         16,
+    ///     )
+    ///     .await;
+    ///
+    /// for (chat_id, res) in results {
+    ///     if let Err(err) = res {
+    ///         println!("Failed to send a message to {:?}: {}", chat_id, err);
+    ///     }
+    /// }
+    /// # };
+    /// ```
+    ///
+    /// [`Throttle`]: crate::adaptors::Throttle
+    #[allow(clippy::type_complexity)]
+    fn broadcast<'a, I, F, R>(
+        &'a self,
+        chat_ids: I,
+        mut message_builder: F,
+        concurrency: usize,
+    ) -> BoxFuture<'a, Vec<(ChatId, Result<Output<R>, R::Err>)>>
+    where
+        Self: Sync,
+        I: IntoIterator,
+        I::Item: Into<ChatId>,
+        I::IntoIter: Send + 'a,
+        F: FnMut(&Self, ChatId) -> R + Send + 'a,
+        R: Request + 'a,
+        R::Err: 'a,
+        Output<R>: Send + 'a,
+    {
+        assert!(concurrency > 0, "`concurrency` must be greater than 0");
+
+        stream::iter(chat_ids)
+            .map(move |chat_id| {
+                let chat_id = chat_id.into();
+                message_builder(self, chat_id.clone())
+                    .send()
+                    .map(move |res| (chat_id, res))
+            })
+            .buffer_unordered(concurrency)
+            .collect()
+            .boxed()
+    }
+
+    /// Sends `text` to `chat_id`, split into as many messages as needed to
+    /// fit into the [limit] of a message text.
+    ///
+    /// The text is split with [`split_text`], so if `parse_mode` is set,
+    /// formatting entities are not broken between messages. `parse_mode` is
+    /// set for every message (note that [`DefaultParseMode`] can't be taken
+    /// into account, so the parse mode should be passed explicitly).
+    ///
+    /// Messages are sent one by one, in order. Returns all sent messages, or
+    /// the first error (messages sent before the error are not deleted).
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use teloxide_core::{requests::RequesterExt, types::ParseMode, Bot};
+    ///
+    /// # async {
+    /// let bot = Bot::new("TOKEN");
+    /// # let chat_id = 0;
+    /// # let long_text = String::new();
+    ///
+    /// let messages = bot
+    ///     .send_long_message(chat_id, long_text, Some(ParseMode::Html))
+    ///     .await?;
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    ///
+    /// [limit]: crate::utils::MESSAGE_TEXT_MAX_LEN
+    /// [`split_text`]: crate::utils::split_text
+    fn send_long_message<C, T>(
+        &self,
+        chat_id: C,
+        text: T,
+        parse_mode: Option<ParseMode>,
+    ) -> BoxFuture<'_, Result<Vec<Message>, Self::Err>>
+    where
+        Self: Sync,
+        C: Into<ChatId>,
+        T: Into<String>,
+    {
+        let chat_id = chat_id.into();
+        let parts = split_text(&text.into(), MESSAGE_TEXT_MAX_LEN, parse_mode);
+
+        async move {
+            let mut messages = Vec::with_capacity(parts.len());
+            for part in parts {
+                let request = self
+                    .send_message(chat_id.clone(), part)
+                    .maybe_parse_mode(parse_mode);
+                messages.push(request.send().await?);
+            }
+
+            Ok(messages)
+        }
+        .boxed()
+    }
+
+    /// Returns a stream of all profile photos of the user `user_id`.
+    ///
+    /// Every item is one photo, in all available sizes. Photos are requested
+    /// with [`GetUserProfilePhotos`] in pages of 100, advancing the offset
+    /// until there are no more photos.
+    ///
+    /// The stream ends when [`UserProfilePhotos::total_count`] (of the latest
+    /// page, since it may change between pages, e.g. if the user adds a photo)
+    /// photos were received, or when an empty page is returned. If a request
+    /// fails, the error is yielded and the stream ends.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use teloxide_core::{requests::RequesterExt, types::UserId, Bot};
+    ///
+    /// # async {
+    /// let bot = Bot::new("TOKEN");
+    ///
+    /// let photos: Vec<_> = bot.user_profile_photos_stream(UserId(42)).try_collect().await?;
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    ///
+    /// [`GetUserProfilePhotos`]: crate::payloads::GetUserProfilePhotos
+    /// [`UserProfilePhotos::total_count`]: crate::types::UserProfilePhotos::total_count
+    fn user_profile_photos_stream(
+        &self,
+        user_id: UserId,
+    ) -> BoxStream<'_, Result<Vec<PhotoSize>, Self::Err>>
+    where
+        Self: Sync,
+    {
+        /// The maximum number of photos which can be requested at once.
+        const PAGE: u8 = 100;
+
+        stream::unfold(Some(0), move |offset| async move {
+            let offset = offset?;
+            let page = self
+                .get_user_profile_photos(user_id)
+                .offset(offset)
+                .limit(PAGE)
+                .send()
+                .await;
+
+            let page = match page {
+                Ok(page) => page,
+                Err(err) => return Some((Err(err), None)),
+            };
+            if page.photos.is_empty() {
+                return None;
+            }
+
+            // `total_count` of the latest page is used, so photos added while
+            // iterating are taken into account
+            let next = offset + page.photos.len() as u32;
+            let next = match next < page.total_count {
+                true => Some(next),
+                false => None,
+            };
+
+            Some((Ok(page.photos), next))
+        })
+        .map_ok(|photos| stream::iter(photos.into_iter().map(Ok)))
+        .try_flatten()
+        .boxed()
+    }
+
+    /// Returns administrators of the chat `chat_id`, partitioned into the
+    /// creator and other administrators (see [`ChatAdministrators`]).
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use teloxide_core::{requests::RequesterExt, types::UserId, Bot};
+    ///
+    /// # async {
+    /// let bot = Bot::new("TOKEN");
+    /// # let chat_id = 0;
+    ///
+    /// let admins = bot.chat_administrators(chat_id).await?;
+    /// if let Some(rights) = admins.rights(UserId(42)) {
+    ///     println!("can delete messages: {}", rights.can_delete_messages);
+    /// }
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    fn chat_administrators<C>(
+        &self,
+        chat_id: C,
+    ) -> BoxFuture<'_, Result<ChatAdministrators, Self::Err>>
+    where
+        C: Into<ChatId>,
+    {
+        self.get_chat_administrators(chat_id)
+            .send()
+            .map_ok(ChatAdministrators::from)
+            .boxed()
+    }
+
+    /// Returns the rights of the bot in the chat `chat_id`, or `None` if the
+    /// bot is not an administrator there.
+    ///
+    /// This requests the id of the bot with [`GetMe`], so it's recommended to
+    /// use this on a bot wrapped in [`CacheMe`]. See also [`bot_is_admin`].
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use teloxide_core::{requests::RequesterExt, Bot};
+    ///
+    /// # async {
+    /// let bot = Bot::new("TOKEN").cache_me();
+    /// # let chat_id = 0;
+    ///
+    /// let rights = bot.bot_administrator_rights(chat_id).await?;
+    /// if rights.map_or(false, |r| r.can_delete_messages) {
+    ///     /* delete spam */
+    /// }
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    ///
+    /// [`GetMe`]: crate::payloads::GetMe
+    /// [`CacheMe`]: crate::adaptors::CacheMe
+    /// [`bot_is_admin`]: RequesterExt::bot_is_admin
+    fn bot_administrator_rights<C>(
+        &self,
+        chat_id: C,
+    ) -> BoxFuture<'_, Result<Option<ChatAdministratorRights>, Self::Err>>
+    where
+        Self: Sync,
+        C: Into<ChatId>,
+    {
+        let chat_id = chat_id.into();
+
+        async move {
+            let me = self.get_me().send().await?;
+            let member = self.get_chat_member(chat_id, me.user.id).send().await?;
+
+            Ok(member.kind.administrator_rights())
+        }
+        .boxed()
+    }
+
+    /// Returns `true` if the bot is an administrator (or the creator) of the
+    /// chat `chat_id`.
+    ///
+    /// See [`bot_administrator_rights`] for more.
+    ///
+    /// [`bot_administrator_rights`]: RequesterExt::bot_administrator_rights
+    fn bot_is_admin<C>(&self, chat_id: C) -> BoxFuture<'_, Result<bool, Self::Err>>
+    where
+        Self: Sync,
+        C: Into<ChatId>,
+    {
+        self.bot_administrator_rights(chat_id)
+            .map_ok(|rights| rights.is_some())
+            .boxed()
+    }
+    /// Drops all updates which are waiting to be received by the bot.
+    ///
+    /// This is useful when switching a bot from webhooks to polling or when
+    /// restarting it after a downtime, to not process a stale backlog.
+    ///
+    /// This calls [`DeleteWebhook`] with [`drop_pending_updates`] set, so note
+    /// that **the webhook is removed**, if there is one. This is what you want
+    /// before starting to poll updates with [`GetUpdates`]. To drop the backlog
+    /// of a bot which uses webhooks, pass [`SetWebhook::drop_pending_updates`]
+    /// when (re)setting the webhook instead.
+    ///
+    /// Another way to drop updates is to call [`GetUpdates`] with the offset
+    /// `-1` and then confirm the last returned update (by requesting updates
+    /// with an offset higher than its id). This doesn't require removing the
+    /// webhook (but works only when there is no webhook, since [`GetUpdates`]
+    /// can't be used together with webhooks) and needs two requests, so
+    /// updates which arrive in between are dropped too.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use teloxide_core::{requests::RequesterExt, Bot};
+    ///
+    /// # async {
+    /// let bot = Bot::new("TOKEN");
+    ///
+    /// bot.drop_pending_updates().await?;
+    /// let updates = bot.updates_stream();
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    ///
+    /// [`DeleteWebhook`]: crate::payloads::DeleteWebhook
+    /// [`drop_pending_updates`]: crate::payloads::DeleteWebhook::drop_pending_updates
+    /// [`GetUpdates`]: crate::payloads::GetUpdates
+    /// [`SetWebhook::drop_pending_updates`]: crate::payloads::SetWebhook::drop_pending_updates
+    fn drop_pending_updates(&self) -> BoxFuture<'_, Result<(), Self::Err>> {
+        self.delete_webhook()
+            .drop_pending_updates(true)
+            .send()
+            .map_ok(drop)
+            .boxed()
+    }
 }
 
 impl<T> RequesterExt for T
@@ -90,3 +639,71 @@ where
 {
     /* use default impls */
 }
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use futures::{executor::block_on, TryStreamExt};
+
+    use crate::{
+        types::{PhotoSize, True, UserProfilePhotos},
+        Bot,
+    };
+
+    use super::*;
+
+    fn photos(total_count: u32, n: usize) -> UserProfilePhotos {
+        let photo = PhotoSize {
+            file_id: "id".to_owned(),
+            file_unique_id: "uid".to_owned(),
+            width: 1,
+            height: 1,
+            file_size: None,
+        };
+
+        UserProfilePhotos {
+            total_count,
+            photos: vec![vec![photo]; n],
+        }
+    }
+
+    #[test]
+    fn user_profile_photos_stream() {
+        let bot = Bot::new("TOKEN").mock();
+        let handle = bot.handle();
+
+        // A photo is added after the first page was requested
+        handle.push_response(photos(150, 100));
+        handle.push_response(photos(151, 51));
+
+        let all: Vec<_> =
+            block_on(bot.user_profile_photos_stream(UserId(1)).try_collect()).unwrap();
+        assert_eq!(all.len(), 151);
+
+        let requests = handle.take_requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].payload["offset"], 0);
+        assert_eq!(requests[1].payload["offset"], 100);
+
+        // An empty page ends the stream, even if `total_count` says otherwise
+        handle.push_response(photos(10, 5));
+        handle.push_response(photos(10, 0));
+
+        let all: Vec<_> =
+            block_on(bot.user_profile_photos_stream(UserId(1)).try_collect()).unwrap();
+        assert_eq!(all.len(), 5);
+        assert_eq!(handle.pending_responses(), 0);
+    }
+
+    #[test]
+    fn drop_pending_updates() {
+        let bot = Bot::new("TOKEN").mock();
+        let handle = bot.handle();
+
+        handle.push_response(True);
+        block_on(bot.drop_pending_updates()).unwrap();
+
+        let requests = handle.take_requests();
+        assert_eq!(requests[0].method, "DeleteWebhook");
+        assert_eq!(requests[0].payload["drop_pending_updates"], true);
+    }
+}
@@ -0,0 +1,111 @@
+use reqwest::Url;
+use serde::Serialize;
+
+use crate::{net, requests::Payload};
+
+/// Name of the environmental variable which is used instead of the bot token
+/// in commands produced by [`ToCurl::to_curl`].
+const TOKEN_VAR: &str = "BOT_TOKEN";
+
+/// Converts payloads to `curl` commands, which is useful for debugging.
+///
+/// This trait is implemented for all payloads.
+///
+/// The payload is always sent as JSON (the same way [`JsonRequest`] sends it),
+/// so files which need to be uploaded (e.g. [`InputFile::file`]) can't be
+/// reproduced by the command.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{payloads::SendMessage, requests::ToCurl, Bot};
+///
+/// let bot = Bot::new("TOKEN");
+/// let payload = SendMessage::new(42, "Hi!");
+///
+/// assert_eq!(
+///     payload.to_curl(bot.api_url()),
+///     "curl -X POST \"https://api.telegram.org/bot$BOT_TOKEN/SendMessage\" \
+///      -H 'Content-Type: application/json' -d '{\"chat_id\":42,\"text\":\"Hi!\"}'",
+/// );
+/// ```
+///
+/// [`JsonRequest`]: crate::requests::JsonRequest
+/// [`InputFile::file`]: crate::types::InputFile::file
+pub trait ToCurl: Payload + Serialize {
+    /// Returns a `curl` command which sends this payload to the Bot API
+    /// server at `api_url`.
+    ///
+    /// The bot token is not included in the command, the `BOT_TOKEN`
+    /// environmental variable is used instead. To include the token, use
+    /// [`to_curl_with_token`].
+    ///
+    /// [`to_curl_with_token`]: ToCurl::to_curl_with_token
+    fn to_curl(&self, api_url: Url) -> String {
+        let url = net::method_url(api_url, &format!("${}", TOKEN_VAR), Self::NAME);
+
+        // Double quotes, so that the shell expands the variable
+        curl(&format!("\"{}\"", url), self)
+    }
+
+    /// Returns a `curl` command which sends this payload to the Bot API
+    /// server at `api_url`, using `token`.
+    ///
+    /// Note: the returned command contains the bot token, so be careful when
+    /// sharing it.
+    fn to_curl_with_token(&self, api_url: Url, token: &str) -> String {
+        let url = net::method_url(api_url, token, Self::NAME);
+        curl(&quote(url.as_str()), self)
+    }
+}
+
+impl<P> ToCurl for P where P: Payload + Serialize {}
+
+fn curl<P: Serialize + ?Sized>(url: &str, payload: &P) -> String {
+    let body = serde_json::to_string(payload).expect("serialization of request to be infallible");
+
+    format!(
+        "curl -X POST {} -H 'Content-Type: application/json' -d {}",
+        url,
+        quote(&body)
+    )
+}
+
+/// Quotes `s` for a POSIX shell.
+fn quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', r#"'\''"#))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        net::TELEGRAM_API_URL,
+        payloads::{GetMe, SendMessage},
+    };
+
+    use super::*;
+
+    fn api_url() -> Url {
+        Url::parse(TELEGRAM_API_URL).unwrap()
+    }
+
+    #[test]
+    fn token() {
+        assert_eq!(
+            GetMe::new().to_curl(api_url()),
+            "curl -X POST \"https://api.telegram.org/bot$BOT_TOKEN/GetMe\" \
+             -H 'Content-Type: application/json' -d '{}'"
+        );
+        assert_eq!(
+            GetMe::new().to_curl_with_token(api_url(), "1234:TOKEN"),
+            "curl -X POST 'https://api.telegram.org/bot1234:TOKEN/GetMe' \
+             -H 'Content-Type: application/json' -d '{}'"
+        );
+    }
+
+    #[test]
+    fn quoting() {
+        let curl = SendMessage::new(1, "it's $HOME").to_curl(api_url());
+        assert!(curl.ends_with(r#"-d '{"chat_id":1,"text":"it'\''s $HOME"}'"#));
+    }
+}
@@ -0,0 +1,626 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::{ready, task::AtomicWaker, Future, Stream};
+
+use crate::{
+    payloads::GetUpdatesFaultTolerant,
+    requests::{HasPayload, OffsetStore, Payload, Request, Requester},
+    types::{AllowedUpdate, SemiparsedVec, Update},
+    ApiError, RequestError,
+};
+
+/// Default timeout of long polling, it fits into the timeout of the default
+/// [http-client](reqwest::Client).
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Delay between the first error and the next [`GetUpdates`] request, it's
+/// doubled on every consecutive error up to [`MAX_DELAY_ON_ERROR`].
+///
+/// [`GetUpdates`]: crate::payloads::GetUpdates
+const DELAY_ON_ERROR: Duration = Duration::from_secs(1);
+
+/// Maximum delay between an error and the next [`GetUpdates`] request.
+///
+/// [`GetUpdates`]: crate::payloads::GetUpdates
+const MAX_DELAY_ON_ERROR: Duration = Duration::from_secs(30);
+
+/// A stream of updates received via long polling.
+///
+/// This stream repeatedly calls [`GetUpdates`], managing the offset, so every
+/// update is yielded only once. Create it with
+/// [`RequesterExt::updates_stream`].
+///
+/// ## Errors
+///
+/// Errors are yielded from the stream, but they don't terminate it, the next
+/// [`GetUpdates`] request is sent after a delay: the time specified by
+/// Telegram in case of [`RequestError::RetryAfter`], otherwise a second,
+/// doubled on every consecutive error up to 30 seconds. This way e.g. a
+/// `409 Conflict` (a webhook is set or another [`GetUpdates`] request is
+/// running) doesn't make the stream flood Telegram with requests.
+///
+/// Updates which failed to parse are yielded as [`RequestError::InvalidJson`],
+/// they are still confirmed, so they won't be received again.
+///
+/// The only errors which terminate the stream are errors caused by an invalid
+/// token (i.e. [`ApiError::InvalidToken`], Telegram responding with `401
+/// Unauthorized`), since no further request can succeed.
+///
+/// ## Shutdown
+///
+/// Telegram considers updates confirmed only when [`GetUpdates`] is called
+/// with an offset higher than their ids, i.e. updates are confirmed by the
+/// *next* request. So if the stream is just dropped, the last received
+/// updates will be delivered again on the next start.
+///
+/// To prevent this, call [`ShutdownToken::shutdown`] (the token can be
+/// obtained via [`UpdatesStream::shutdown_token`]) and keep polling the stream
+/// until it ends. After shutdown is requested, the stream:
+/// 1. Cancels the in-flight [`GetUpdates`] request, if any (updates received
+///    by it are not confirmed, so they will be delivered on the next start).
+/// 2. Yields already received updates.
+/// 3. Sends a final [`GetUpdates`] request with `timeout = 0` and `limit = 1`
+///    to confirm all yielded updates.
+/// 4. Ends.
+///
+/// Note that this gives *at-least-once* delivery, not *exactly-once*: if the
+/// final request fails (the error is yielded before the stream ends) or the
+/// process is killed before the updates are confirmed, updates may be
+/// delivered again. Also, updates are confirmed as soon as they are yielded
+/// from the stream, not when you've finished processing them.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use futures::StreamExt;
+/// use teloxide_core::{requests::RequesterExt, types::AllowedUpdate, Bot};
+///
+/// # async {
+/// let updates = Bot::new("TOKEN")
+///     .updates_stream()
+///     .timeout(Duration::from_secs(5))
+///     .allowed_updates(vec![AllowedUpdate::Message]);
+///
+/// // Stop receiving updates after a minute
+/// let shutdown = updates.shutdown_token();
+/// tokio::spawn(async move {
+///     tokio::time::sleep(Duration::from_secs(60)).await;
+///     shutdown.shutdown();
+/// });
+///
+/// futures::pin_mut!(updates);
+/// while let Some(update) = updates.next().await {
+///     match update {
+///         Ok(update) => println!("{:?}", update),
+///         Err(err) => log::error!("Error while receiving updates: {}", err),
+///     }
+/// }
+/// # };
+/// ```
+///
+/// [`GetUpdates`]: crate::payloads::GetUpdates
+/// [`RequesterExt::updates_stream`]: crate::requests::RequesterExt::updates_stream
+#[pin_project::pin_project]
+pub struct UpdatesStream<R>
+where
+    R: Requester,
+{
+    bot: R,
+    offset: Option<i32>,
+    limit: Option<u8>,
+    timeout: Duration,
+    allowed_updates: Option<Vec<AllowedUpdate>>,
+    offset_store: Option<Box<dyn OffsetStore + Send>>,
+    /// The last offset passed to the `offset_store`.
+    stored_offset: Option<i32>,
+    buffer: VecDeque<Result<Update, RequestError>>,
+    /// The number of consecutive failed requests.
+    errors: u32,
+    shutdown: ShutdownToken,
+    #[pin]
+    state: State<<R::GetUpdatesFaultTolerant as Request>::Send>,
+}
+
+#[pin_project::pin_project(project = StateProj)]
+enum State<F> {
+    Idle,
+    Receiving(#[pin] F),
+    Waiting(#[pin] tokio::time::Sleep),
+    /// Sending the final request which confirms received updates.
+    Confirming(#[pin] F),
+    Done,
+}
+
+impl<F> State<F> {
+    fn is_running(&self) -> bool {
+        matches!(self, Self::Idle | Self::Receiving(_) | Self::Waiting(_))
+    }
+}
+
+/// A handle which allows to gracefully shutdown an [`UpdatesStream`].
+///
+/// See [`UpdatesStream`]'s documentation for more.
+#[derive(Clone, Debug, Default)]
+pub struct ShutdownToken {
+    inner: Arc<ShutdownState>,
+}
+
+#[derive(Debug, Default)]
+struct ShutdownState {
+    requested: AtomicBool,
+    waker: AtomicWaker,
+}
+
+impl ShutdownToken {
+    /// Requests shutdown of the stream.
+    ///
+    /// The stream will confirm received updates and end, see
+    /// [`UpdatesStream`]'s documentation for more.
+    pub fn shutdown(&self) {
+        self.inner.requested.store(true, Ordering::Release);
+        self.inner.waker.wake();
+    }
+
+    /// Returns `true` if shutdown was requested.
+    pub fn is_shutdown_requested(&self) -> bool {
+        self.inner.requested.load(Ordering::Acquire)
+    }
+}
+
+impl<R> UpdatesStream<R>
+where
+    R: Requester,
+{
+    /// Creates new `UpdatesStream`.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::updates_stream`] instead.
+    ///
+    /// [`RequesterExt::updates_stream`]: crate::requests::RequesterExt::updates_stream
+    pub fn new(bot: R) -> Self {
+        Self {
+            bot,
+            offset: None,
+            limit: None,
+            timeout: DEFAULT_TIMEOUT,
+            allowed_updates: None,
+            offset_store: None,
+            stored_offset: None,
+            buffer: VecDeque::new(),
+            errors: 0,
+            shutdown: ShutdownToken::default(),
+            state: State::Idle,
+        }
+    }
+
+    /// Sets the timeout of long polling.
+    ///
+    /// The timeout is rounded down to seconds. [`Bot`] extends timeouts of
+    /// [`GetUpdates`] requests accordingly, so the timeout of the http-client
+    /// doesn't need to be bigger than this value (other [`Requester`]s may
+    /// need that though).
+    ///
+    /// Default: 10 seconds.
+    ///
+    /// [`Bot`]: crate::Bot
+    /// [`GetUpdates`]: crate::payloads::GetUpdates
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the maximum number of updates received by one [`GetUpdates`]
+    /// request (1-100).
+    ///
+    /// [`GetUpdates`]: crate::payloads::GetUpdates
+    pub fn limit(mut self, limit: u8) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the list of update types you want your bot to receive.
+    ///
+    /// If not set, the previous setting is used.
+    pub fn allowed_updates<I>(mut self, allowed_updates: I) -> Self
+    where
+        I: IntoIterator<Item = AllowedUpdate>,
+    {
+        self.allowed_updates = Some(allowed_updates.into_iter().collect());
+        self
+    }
+
+    /// Sets the offset of the first request, see [`GetUpdates::offset`].
+    ///
+    /// By default, updates starting with the earliest unconfirmed update are
+    /// received.
+    ///
+    /// [`GetUpdates::offset`]: crate::payloads::GetUpdates::offset
+    pub fn offset(mut self, offset: i32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the store used to persist the offset, e.g. across restarts.
+    ///
+    /// The offset is loaded from the `store` immediately and, if there is one,
+    /// it overrides the offset set by [`offset`]. After that, the stream
+    /// passes the offset to the `store` every time it's confirmed: when all
+    /// updates received by a [`GetUpdates`] request were yielded and the next
+    /// request is about to be sent, and on [shutdown].
+    ///
+    /// This means that after a restart, updates are received starting from
+    /// the first update of the last unconfirmed batch, so no update is
+    /// skipped, but the last batch may be received again.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use teloxide_core::{
+    ///     requests::{FileOffsetStore, RequesterExt},
+    ///     Bot,
+    /// };
+    ///
+    /// let updates = Bot::new("TOKEN")
+    ///     .updates_stream()
+    ///     .offset_store(FileOffsetStore::new("offset.txt"));
+    /// ```
+    ///
+    /// [`offset`]: UpdatesStream::offset
+    /// [`GetUpdates`]: crate::payloads::GetUpdates
+    /// [shutdown]: UpdatesStream#shutdown
+    pub fn offset_store<S>(mut self, store: S) -> Self
+    where
+        S: OffsetStore + Send + 'static,
+    {
+        if let Some(offset) = store.load() {
+            self.offset = Some(offset);
+            self.stored_offset = Some(offset);
+        }
+
+        self.offset_store = Some(Box::new(store));
+        self
+    }
+
+    /// Returns offset which will be used for the next [`GetUpdates`] request,
+    /// i.e. the id of the last received update + 1.
+    ///
+    /// [`GetUpdates`]: crate::payloads::GetUpdates
+    pub fn current_offset(&self) -> Option<i32> {
+        self.offset
+    }
+
+    /// Returns a token which can be used to gracefully shutdown this stream.
+    pub fn shutdown_token(&self) -> ShutdownToken {
+        self.shutdown.clone()
+    }
+
+    /// Requests shutdown of this stream, see [`ShutdownToken::shutdown`].
+    pub fn shutdown(&self) {
+        self.shutdown.shutdown();
+    }
+
+    /// Allows to access the inner bot.
+    pub fn inner(&self) -> &R {
+        &self.bot
+    }
+}
+
+impl<R> Stream for UpdatesStream<R>
+where
+    R: Requester<Err = RequestError>,
+{
+    type Item = Result<Update, RequestError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        this.shutdown.inner.waker.register(cx.waker());
+
+        loop {
+            if let Some(item) = this.buffer.pop_front() {
+                return Poll::Ready(Some(item));
+            }
+
+            if this.state.is_running() && this.shutdown.is_shutdown_requested() {
+                match *this.offset {
+                    // Nothing was received, so there is nothing to confirm
+                    None => this.state.set(State::Done),
+                    Some(offset) => {
+                        let mut req = this.bot.get_updates_fault_tolerant();
+                        let GetUpdatesFaultTolerant(payload) = req.payload_mut();
+                        payload.offset = Some(offset);
+                        payload.limit = Some(1);
+                        payload.timeout = Some(0);
+
+                        this.state.set(State::Confirming(req.send()));
+                    }
+                }
+            }
+
+            match this.state.as_mut().project() {
+                StateProj::Idle => {
+                    // All received updates were yielded, so they are confirmed by this request
+                    store_offset(this.offset_store, this.stored_offset, *this.offset);
+
+                    let mut req = this.bot.get_updates_fault_tolerant();
+                    let GetUpdatesFaultTolerant(payload) = req.payload_mut();
+                    payload.offset = *this.offset;
+                    payload.limit = *this.limit;
+                    payload.timeout = Some(this.timeout.as_secs() as u32);
+                    payload.allowed_updates = this.allowed_updates.clone();
+
+                    this.state.set(State::Receiving(req.send()));
+                }
+                StateProj::Receiving(fut) => match ready!(fut.poll(cx)) {
+                    Ok(SemiparsedVec(updates)) => {
+                        for update in updates {
+                            this.buffer
+                                .push_back(parse_update(update, &mut *this.offset));
+                        }
+                        *this.errors = 0;
+                        this.state.set(State::Idle);
+                    }
+                    Err(err) => {
+                        let next = match &err {
+                            err if is_fatal(err) => State::Done,
+                            err => {
+                                let delay = delay_on_error(err, *this.errors);
+                                *this.errors = this.errors.saturating_add(1);
+                                State::Waiting(tokio::time::sleep(delay))
+                            }
+                        };
+                        this.state.set(next);
+
+                        return Poll::Ready(Some(Err(err)));
+                    }
+                },
+                StateProj::Waiting(sleep) => {
+                    ready!(sleep.poll(cx));
+                    this.state.set(State::Idle);
+                }
+                StateProj::Confirming(fut) => {
+                    // Updates received by this request are not confirmed, so they are just
+                    // ignored. They will be received on the next start.
+                    let res = ready!(fut.poll(cx));
+                    this.state.set(State::Done);
+
+                    match res {
+                        Ok(_) => store_offset(this.offset_store, this.stored_offset, *this.offset),
+                        Err(err) => return Poll::Ready(Some(Err(err))),
+                    }
+                }
+                StateProj::Done => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+/// Passes the `offset` to the `store`, if it has changed since the last call.
+fn store_offset(
+    store: &Option<Box<dyn OffsetStore + Send>>,
+    stored: &mut Option<i32>,
+    offset: Option<i32>,
+) {
+    if let (Some(store), Some(offset)) = (store, offset) {
+        if *stored != Some(offset) {
+            store.store(offset);
+            *stored = Some(offset);
+        }
+    }
+}
+
+/// Advances the offset past the `update` and converts parsing errors to
+/// [`RequestError::InvalidJson`].
+fn parse_update(
+    update: Result<Update, (serde_json::Value, serde_json::Error)>,
+    offset: &mut Option<i32>,
+) -> Result<Update, RequestError> {
+    match update {
+        Ok(update) => {
+            *offset = Some(update.id + 1);
+            Ok(update)
+        }
+        Err((value, err)) => {
+            log::error!("Cannot parse an update: {:?}, error: {:?}", value, err);
+
+            if let Some(id) = value["update_id"].as_i64() {
+                *offset = Some(id as i32 + 1);
+            }
+
+            Err(RequestError::invalid_json(
+                GetUpdatesFaultTolerant::NAME,
+                value.to_string(),
+                err,
+            ))
+        }
+    }
+}
+
+/// Returns the delay before the next request after `err`, which follows
+/// `errors` consecutive errors.
+fn delay_on_error(err: &RequestError, errors: u32) -> Duration {
+    match err {
+        RequestError::RetryAfter(secs) => Duration::from_secs(*secs as u64),
+        _ => {
+            // 2^5 seconds already exceed the maximum
+            let delay = DELAY_ON_ERROR * 2u32.pow(errors.min(5));
+            std::cmp::min(delay, MAX_DELAY_ON_ERROR)
+        }
+    }
+}
+
+/// Returns `true` if the error means that the token is invalid.
+fn is_fatal(err: &RequestError) -> bool {
+    matches!(
+        err,
+        RequestError::ApiError {
+            kind: ApiError::InvalidToken,
+            ..
+        }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{requests::RequesterExt, types::UpdateKind};
+
+    use super::*;
+
+    #[test]
+    fn only_invalid_token_is_fatal() {
+        use reqwest::StatusCode;
+
+        assert!(is_fatal(&RequestError::ApiError {
+            kind: ApiError::InvalidToken,
+            status_code: StatusCode::UNAUTHORIZED,
+        }));
+        // E.g. an unknown method of an older self-hosted Bot API server
+        assert!(!is_fatal(&RequestError::ApiError {
+            kind: ApiError::Unknown("Not Found".to_owned()),
+            status_code: StatusCode::NOT_FOUND,
+        }));
+    }
+
+    #[test]
+    fn offset_advances_past_unparsed_updates() {
+        let mut offset = None;
+
+        let value = serde_json::json!({ "update_id": 42, "message": {} });
+        let err = serde_json::from_value::<Update>(value.clone()).unwrap_err();
+
+        assert!(matches!(
+            parse_update(Err((value, err)), &mut offset),
+            Err(RequestError::InvalidJson { .. })
+        ));
+        assert_eq!(offset, Some(43));
+    }
+
+    #[test]
+    fn unknown_update_kinds_are_not_errors() {
+        let mut offset = None;
+
+        let value = serde_json::json!({ "update_id": 42, "future_update": { "field": 1 } });
+        let update = serde_json::from_value::<Update>(value).map_err(|err| (json!(null), err));
+
+        let update = parse_update(update, &mut offset).unwrap();
+        assert_eq!(
+            update.kind,
+            UpdateKind::Unknown(json!({ "future_update": { "field": 1 } }))
+        );
+        assert_eq!(offset, Some(43));
+    }
+
+    #[test]
+    fn shutdown_without_updates() {
+        use futures::StreamExt;
+
+        let updates = crate::Bot::new("TOKEN").updates_stream();
+        updates.shutdown();
+
+        // Nothing was received, so the stream ends without sending requests
+        futures::pin_mut!(updates);
+        assert!(futures::executor::block_on(updates.next()).is_none());
+    }
+
+    #[test]
+    fn delay_on_error_is_capped() {
+        let err = || RequestError::Io(std::io::ErrorKind::Other.into());
+
+        let delays: Vec<_> = (0..8)
+            .map(|n| delay_on_error(&err(), n).as_secs())
+            .collect();
+        assert_eq!(delays, [1, 2, 4, 8, 16, 30, 30, 30]);
+        assert_eq!(delay_on_error(&err(), u32::MAX), MAX_DELAY_ON_ERROR);
+
+        let retry_after = RequestError::RetryAfter(100);
+        assert_eq!(delay_on_error(&retry_after, 0), Duration::from_secs(100));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn waits_after_api_error() {
+        use futures::StreamExt;
+        use reqwest::StatusCode;
+
+        let bot = crate::Bot::new("TOKEN").mock();
+        let handle = bot.handle();
+
+        handle.push_error(RequestError::ApiError {
+            kind: ApiError::TerminatedByOtherGetUpdates,
+            status_code: StatusCode::CONFLICT,
+        });
+        handle.push_response(json!([]));
+
+        let updates = bot.updates_stream();
+        futures::pin_mut!(updates);
+
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async {
+            assert!(matches!(
+                updates.next().await,
+                Some(Err(RequestError::ApiError { .. }))
+            ));
+
+            // The stream waits instead of immediately sending the next request
+            assert!(futures::poll!(updates.next()).is_pending());
+        });
+
+        assert_eq!(handle.take_requests().len(), 1);
+        assert_eq!(handle.pending_responses(), 1);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn offset_store() {
+        use futures::{executor::block_on, StreamExt};
+
+        use crate::requests::InMemoryOffsetStore;
+
+        let bot = crate::Bot::new("TOKEN").mock();
+        let handle = bot.handle();
+
+        let store = InMemoryOffsetStore::new();
+        store.store(10);
+
+        let updates = bot.updates_stream().offset_store(store.clone());
+        assert_eq!(updates.current_offset(), Some(10));
+        futures::pin_mut!(updates);
+
+        handle.push_response(json!([{ "update_id": 10, "a": {} }, { "update_id": 11, "b": {} }]));
+        assert_eq!(block_on(updates.next()).unwrap().unwrap().id, 10);
+        assert_eq!(block_on(updates.next()).unwrap().unwrap().id, 11);
+        // The updates are not confirmed yet
+        assert_eq!(store.get(), Some(10));
+
+        handle.push_response(json!([{ "update_id": 12, "c": {} }]));
+        assert_eq!(block_on(updates.next()).unwrap().unwrap().id, 12);
+        assert_eq!(store.get(), Some(12));
+
+        updates.shutdown();
+        handle.push_response(json!([]));
+        assert!(block_on(updates.next()).is_none());
+        assert_eq!(store.get(), Some(13));
+
+        let offsets: Vec<_> = handle
+            .take_requests()
+            .into_iter()
+            .map(|r| r.payload["offset"].clone())
+            .collect();
+        assert_eq!(offsets, [10, 12, 13]);
+    }
+}
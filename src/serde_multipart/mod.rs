@@ -18,14 +18,33 @@ use std::future::Future;
 use reqwest::multipart::Form;
 use serde::Serialize;
 
-use serializers::MultipartTopLvlSerializer;
+use crate::types::with_streams;
+
+use serializers::{Multipart, MultipartTopLvlSerializer};
 
 pub(crate) use serializers::Error;
 
 /// Serializes given value into [`Form`]
 ///
+/// If `local` is `true`, [`InputFile::File`]s are sent as `file://` uris
+/// instead of being uploaded (see [`Bot::local_mode`]).
+///
 /// [`Form`]:  reqwest::multipart::Form
-pub(crate) fn to_form<T: ?Sized + Serialize>(val: &T) -> impl Future<Output = Result<Form, Error>> {
-    let fut = val.serialize(MultipartTopLvlSerializer {});
+/// [`InputFile::File`]: crate::types::InputFile::File
+/// [`Bot::local_mode`]: crate::Bot::local_mode
+pub(crate) fn to_form<T: ?Sized + Serialize>(
+    val: &T,
+    local: bool,
+) -> impl Future<Output = Result<Form, Error>> {
+    let fut = with_streams(|| val.serialize(MultipartTopLvlSerializer { local }))
+        .map(Multipart::into_form);
     async { Ok(fut?.await?) }
 }
+
+/// Returns an estimate of the size of the body of the [`Form`] which
+/// [`to_form`] would create, or `None` if the size of some file is unknown.
+///
+/// [`Form`]:  reqwest::multipart::Form
+pub(crate) fn form_size<T: ?Sized + Serialize>(val: &T, local: bool) -> Result<Option<u64>, Error> {
+    Ok(with_streams(|| val.serialize(MultipartTopLvlSerializer { local }))?.size())
+}
@@ -68,10 +68,16 @@ impl From<Error> for RequestError {
     }
 }
 
-pub(crate) struct MultipartTopLvlSerializer {}
+pub(crate) struct MultipartTopLvlSerializer {
+    /// Send [`InputFile::File`]s as `file://` uris instead of uploading them
+    /// (see [`Bot::local_mode`]).
+    ///
+    /// [`Bot::local_mode`]: crate::Bot::local_mode
+    pub(crate) local: bool,
+}
 
 impl Serializer for MultipartTopLvlSerializer {
-    type Ok = <MultipartSerializer as SerializeStruct>::Ok;
+    type Ok = Multipart;
     type Error = Error;
     type SerializeSeq = Impossible<Self::Ok, Self::Error>;
     type SerializeTuple = Impossible<Self::Ok, Self::Error>;
@@ -220,6 +226,7 @@ impl Serializer for MultipartTopLvlSerializer {
             parts: vec![],
             files: vec![],
             key: None,
+            local: self.local,
         })
     }
 
@@ -228,7 +235,7 @@ impl Serializer for MultipartTopLvlSerializer {
         _: &'static str,
         _: usize,
     ) -> Result<Self::SerializeStruct, Self::Error> {
-        Ok(MultipartSerializer::new())
+        Ok(MultipartSerializer::new(self.local))
     }
 
     fn serialize_struct_variant(
@@ -243,21 +250,23 @@ impl Serializer for MultipartTopLvlSerializer {
 }
 
 pub(crate) struct MultipartSerializer {
-    parts: Vec<(&'static str, Part)>, // TODO: Array vecs
+    parts: Vec<(String, Vec<u8>)>, // TODO: Array vecs
     files: Vec<(String, InputFile)>,
+    local: bool,
 }
 
 impl MultipartSerializer {
-    fn new() -> Self {
+    fn new(local: bool) -> Self {
         Self {
             parts: Vec::new(),
             files: vec![],
+            local,
         }
     }
 }
 
 impl SerializeStruct for MultipartSerializer {
-    type Ok = BoxFuture<'static, io::Result<Form>>; // impl Future<Output = io::Result<Form>>
+    type Ok = Multipart;
     type Error = Error;
 
     fn serialize_field<T: ?Sized>(
@@ -268,45 +277,30 @@ impl SerializeStruct for MultipartSerializer {
     where
         T: Serialize,
     {
-        let (part, file) = value.serialize(PartSerializer {})?;
-        self.parts.push((key, part));
+        let (part, file) = value.serialize(PartSerializer { local: self.local })?;
+        self.parts.push((key.to_owned(), part));
         self.files.extend(file);
 
         Ok(())
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        let form = self
-            .parts
-            .into_iter()
-            .fold(Form::new(), |acc, (key, value)| acc.part(key, value));
-
-        if self.files.is_empty() {
-            //Ok(Either::Left(ready(Ok(form))))
-            Ok(Box::pin(ready(Ok(form))))
-        } else {
-            let fut = self
-                .files
-                .into_iter()
-                .map(|(k, f)| f.into_part().map(move |p| (k, p)))
-                .collect::<FuturesUnordered<_>>()
-                .map(Ok)
-                .try_fold(form, |acc, (k, p)| async { Ok(acc.part(k, p?)) });
-
-            //Ok(Either::Right(fut))
-            Ok(Box::pin(fut))
-        }
+        Ok(Multipart {
+            parts: self.parts,
+            files: self.files,
+        })
     }
 }
 
 pub(crate) struct MultipartMapSerializer {
-    parts: Vec<(String, Part)>, // TODO: Array vecs
+    parts: Vec<(String, Vec<u8>)>, // TODO: Array vecs
     files: Vec<(String, InputFile)>,
     key: Option<String>,
+    local: bool,
 }
 
 impl SerializeMap for MultipartMapSerializer {
-    type Ok = <MultipartSerializer as SerializeStruct>::Ok;
+    type Ok = Multipart;
     type Error = Error;
 
     fn serialize_key<T: ?Sized>(&mut self, key: &T) -> Result<(), Self::Error>
@@ -323,7 +317,7 @@ impl SerializeMap for MultipartMapSerializer {
     {
         let key = self.key.take().unwrap();
 
-        let (part, file) = value.serialize(PartSerializer {})?;
+        let (part, file) = value.serialize(PartSerializer { local: self.local })?;
         self.parts.push((key, part));
         self.files.extend(file);
 
@@ -331,14 +325,32 @@ impl SerializeMap for MultipartMapSerializer {
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Multipart {
+            parts: self.parts,
+            files: self.files,
+        })
+    }
+}
+
+/// A serialized multipart request: text parts and files which are yet to be
+/// read.
+pub(crate) struct Multipart {
+    parts: Vec<(String, Vec<u8>)>,
+    files: Vec<(String, InputFile)>,
+}
+
+impl Multipart {
+    /// Reads the files and builds the [`Form`].
+    pub(crate) fn into_form(self) -> BoxFuture<'static, io::Result<Form>> {
         let form = self
             .parts
             .into_iter()
-            .fold(Form::new(), |acc, (key, value)| acc.part(key, value));
+            .fold(Form::new(), |acc, (key, value)| {
+                acc.part(key, Part::bytes(value))
+            });
 
         if self.files.is_empty() {
-            //Ok(Either::Left(ready(Ok(form))))
-            Ok(Box::pin(ready(Ok(form))))
+            Box::pin(ready(Ok(form)))
         } else {
             let fut = self
                 .files
@@ -348,16 +360,69 @@ impl SerializeMap for MultipartMapSerializer {
                 .map(Ok)
                 .try_fold(form, |acc, (k, p)| async { Ok(acc.part(k, p?)) });
 
-            //Ok(Either::Right(fut))
-            Ok(Box::pin(fut))
+            Box::pin(fut)
+        }
+    }
+
+    /// Returns the size of the body of the form, or `None` if the size of some
+    /// file is unknown.
+    ///
+    /// This is an estimate: names of parts and files which need escaping are
+    /// assumed to be sent as-is.
+    pub(crate) fn size(&self) -> Option<u64> {
+        let boundary = Form::new().boundary().len() as u64;
+
+        let mut size = 0;
+        for (name, value) in &self.parts {
+            size += part_size(boundary, name, None, None, value.len() as u64);
+        }
+        for (name, file) in &self.files {
+            let (len, file_name, mime) = file.part_meta()?;
+            size += part_size(boundary, name, file_name.as_deref(), mime.as_ref(), len);
+        }
+
+        // The closing boundary (`--<boundary>--\r\n`)
+        if !self.parts.is_empty() || !self.files.is_empty() {
+            size += 2 + boundary + 4;
         }
+
+        Some(size)
     }
 }
 
-struct PartSerializer {}
+/// Returns the size of a part, mimicking the format `reqwest` uses:
+///
+/// ```text
+/// --<boundary>\r\n
+/// Content-Disposition: form-data; name="<name>"; filename="<file_name>"\r\n
+/// Content-Type: <mime>\r\n
+/// \r\n
+/// <value>\r\n
+/// ```
+fn part_size(
+    boundary: u64,
+    name: &str,
+    file_name: Option<&str>,
+    mime: Option<&mime::Mime>,
+    len: u64,
+) -> u64 {
+    let mut header = "Content-Disposition: form-data; name=\"\"".len() + name.len();
+    if let Some(file_name) = file_name {
+        header += "; filename=\"\"".len() + file_name.len();
+    }
+    if let Some(mime) = mime {
+        header += "\r\nContent-Type: ".len() + mime.as_ref().len();
+    }
+
+    2 + boundary + 2 + header as u64 + 4 + len + 2
+}
+
+struct PartSerializer {
+    local: bool,
+}
 
 impl Serializer for PartSerializer {
-    type Ok = (Part, Vec<(String, InputFile)>);
+    type Ok = (Vec<u8>, Vec<(String, InputFile)>);
     type Error = Error;
     type SerializeSeq = InnerPartSerializer;
     type SerializeTuple = Impossible<Self::Ok, Self::Error>;
@@ -368,39 +433,39 @@ impl Serializer for PartSerializer {
     type SerializeStructVariant = PartFromFile;
 
     fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
-        Ok((Part::text(v.to_string()), Vec::new()))
+        Ok((v.to_string().into_bytes(), Vec::new()))
     }
 
-    fn serialize_i8(self, _: i8) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        Ok((v.to_string().into_bytes(), Vec::new()))
     }
 
-    fn serialize_i16(self, _: i16) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        Ok((v.to_string().into_bytes(), Vec::new()))
     }
 
     fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
-        Ok((Part::text(v.to_string()), Vec::new()))
+        Ok((v.to_string().into_bytes(), Vec::new()))
     }
 
     fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
-        Ok((Part::text(v.to_string()), Vec::new()))
+        Ok((v.to_string().into_bytes(), Vec::new()))
     }
 
-    fn serialize_u8(self, _: u8) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        Ok((v.to_string().into_bytes(), Vec::new()))
     }
 
-    fn serialize_u16(self, _: u16) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        Ok((v.to_string().into_bytes(), Vec::new()))
     }
 
-    fn serialize_u32(self, _: u32) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        Ok((v.to_string().into_bytes(), Vec::new()))
     }
 
-    fn serialize_u64(self, _: u64) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        Ok((v.to_string().into_bytes(), Vec::new()))
     }
 
     fn serialize_f32(self, _: f32) -> Result<Self::Ok, Self::Error> {
@@ -416,7 +481,7 @@ impl Serializer for PartSerializer {
     }
 
     fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
-        Ok((Part::text(v.to_owned()), Vec::new()))
+        Ok((v.as_bytes().to_owned(), Vec::new()))
     }
 
     fn serialize_bytes(self, _: &[u8]) -> Result<Self::Ok, Self::Error> {
@@ -448,7 +513,7 @@ impl Serializer for PartSerializer {
         _: u32,
         variant_name: &'static str,
     ) -> Result<Self::Ok, Self::Error> {
-        Ok((Part::text(variant_name), Vec::new()))
+        Ok((variant_name.as_bytes().to_owned(), Vec::new()))
     }
 
     fn serialize_newtype_struct<T: ?Sized>(
@@ -479,20 +544,15 @@ impl Serializer for PartSerializer {
             value,
         )?;
 
-        match file {
-            f @ InputFile::Memory { .. } | f @ InputFile::File(_) => {
-                let uuid = uuid::Uuid::new_v4().to_string();
-                let part = Part::text(format!("attach://{}", uuid));
-                Ok((part, vec![(uuid, f)]))
-            }
-            InputFile::FileId(s) | InputFile::Url(s) => Ok((Part::text(s), Vec::new())),
-        }
+        let (s, file) = file_reference(file, self.local);
+        Ok((s.into_bytes(), file.into_iter().collect()))
     }
 
     fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
         Ok(Self::SerializeSeq {
             array_json_parts: vec![],
             files: vec![],
+            local: self.local,
         })
     }
 
@@ -533,6 +593,7 @@ impl Serializer for PartSerializer {
             ser, // TODO: capcity
             serde_json::ser::State::First,
             Vec::new(),
+            self.local,
         ))
     }
 
@@ -550,16 +611,32 @@ impl Serializer for PartSerializer {
                 variant,
                 len,
             )?,
+            local: self.local,
         })
     }
 }
 
+/// Returns a string which should be sent in place of the `file` (i.e. a file
+/// id, an url, an `attach://` reference or, in the local mode, a `file://`
+/// uri) and the file which should be attached to the request, if any.
+fn file_reference(file: InputFile, local: bool) -> (String, Option<(String, InputFile)>) {
+    match file {
+        InputFile::File(path) if local => (format!("file://{}", path.display()), None),
+        f @ InputFile::Memory { .. } | f @ InputFile::File(_) | f @ InputFile::Read(_) => {
+            let uuid = uuid::Uuid::new_v4().to_string();
+            (format!("attach://{}", uuid), Some((uuid, f)))
+        }
+        InputFile::FileId(s) | InputFile::Url(s) => (s, None),
+    }
+}
+
 struct PartFromFile {
     inner: InputFileUnserializer,
+    local: bool,
 }
 
 impl SerializeStructVariant for PartFromFile {
-    type Ok = (Part, Vec<(String, InputFile)>);
+    type Ok = (Vec<u8>, Vec<(String, InputFile)>);
     type Error = Error;
 
     fn serialize_field<T: ?Sized>(
@@ -578,26 +655,19 @@ impl SerializeStructVariant for PartFromFile {
     fn end(self) -> Result<Self::Ok, Self::Error> {
         let file = self.inner.end()?;
 
-        // TODO: to method
-        match file {
-            f @ InputFile::Memory { .. } | f @ InputFile::File(_) => {
-                let uuid = uuid::Uuid::new_v4().to_string();
-                let part = Part::text(format!("attach://{}", uuid));
-
-                Ok((part, vec![(uuid, f)]))
-            }
-            InputFile::FileId(s) | InputFile::Url(s) => Ok((Part::text(s), vec![])),
-        }
+        let (s, file) = file_reference(file, self.local);
+        Ok((s.into_bytes(), file.into_iter().collect()))
     }
 }
 
 struct InnerPartSerializer {
     array_json_parts: Vec<serde_json::Value>, // using value is such a workaround :|
     files: Vec<(String, InputFile)>,
+    local: bool,
 }
 
 impl SerializeSeq for InnerPartSerializer {
-    type Ok = (Part, Vec<(String, InputFile)>);
+    type Ok = (Vec<u8>, Vec<(String, InputFile)>);
     type Error = Error;
 
     fn serialize_element<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
@@ -607,17 +677,15 @@ impl SerializeSeq for InnerPartSerializer {
         // NOTE: this is probably highly inefficient (especially for ::Memory),
         //       but at least it works
         let mut value = serde_json::to_value(value)?;
-        let file: InputFile = serde_json::from_value(value["media"].take())?;
 
-        match file {
-            f @ InputFile::Memory { .. } | f @ InputFile::File(_) => {
-                let uuid = uuid::Uuid::new_v4().to_string();
-                value["media"] = serde_json::Value::String(format!("attach://{}", uuid));
-                self.files.push((uuid, f));
-            }
-            InputFile::FileId(s) | InputFile::Url(s) => {
-                value["media"] = serde_json::Value::String(s);
-            }
+        // Only elements of media arrays (e.g. `InputMedia`) may contain files,
+        // other arrays (e.g. `allowed_updates`) are serialized as-is
+        if let Some(media) = value.get_mut("media") {
+            let file: InputFile = serde_json::from_value(media.take())?;
+
+            let (s, file) = file_reference(file, self.local);
+            *media = serde_json::Value::String(s);
+            self.files.extend(file);
         }
 
         self.array_json_parts.push(value);
@@ -627,7 +695,7 @@ impl SerializeSeq for InnerPartSerializer {
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
         let s = serde_json::to_string(&self.array_json_parts)?;
-        Ok((Part::text(s), self.files))
+        Ok((s.into_bytes(), self.files))
     }
 }
 
@@ -635,10 +703,11 @@ struct PartSerializerStruct(
     serde_json::Serializer<Vec<u8>>,
     serde_json::ser::State,
     Vec<(String, InputFile)>,
+    bool,
 );
 
 impl SerializeStruct for PartSerializerStruct {
-    type Ok = (Part, Vec<(String, InputFile)>);
+    type Ok = (Vec<u8>, Vec<(String, InputFile)>);
     type Error = Error;
 
     fn serialize_field<T: ?Sized>(
@@ -663,21 +732,11 @@ impl SerializeStruct for PartSerializerStruct {
         if key == "media" {
             let file = value.serialize(InputFileUnserializer::NotMem)?;
 
-            match file {
-                f @ InputFile::Memory { .. } | f @ InputFile::File(_) => {
-                    let uuid = uuid::Uuid::new_v4().to_string();
-                    let attach = format!("attach://{}", uuid);
-
-                    SerializeStruct::serialize_field(&mut ser, key, attach.as_str())?;
-                    self.1 = get_state(ser);
+            let (s, file) = file_reference(file, self.3);
+            SerializeStruct::serialize_field(&mut ser, key, &s)?;
+            self.1 = get_state(ser);
 
-                    self.2.push((uuid, f));
-                }
-                InputFile::FileId(s) | InputFile::Url(s) => {
-                    SerializeStruct::serialize_field(&mut ser, key, &s)?;
-                    self.1 = get_state(ser)
-                }
-            }
+            self.2.extend(file);
         } else {
             SerializeStruct::serialize_field(&mut ser, key, value)?;
             self.1 = get_state(ser);
@@ -699,7 +758,7 @@ impl SerializeStruct for PartSerializerStruct {
         SerializeStruct::end(ser)?;
 
         let json = self.0.into_inner();
-        Ok((Part::bytes(json), self.2))
+        Ok((json, self.2))
     }
 }
 
@@ -713,3 +772,380 @@ fn get_state(
         _ => unreachable!(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the body of the `form`.
+    ///
+    /// `Form` doesn't allow to read its body, so it's sent to a local server.
+    fn form_body(form: Form) -> String {
+        let (addr, server) = crate::test_server::serve_once();
+        let url = format!("http://{}", addr);
+
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(reqwest::Client::new().post(&url).multipart(form).send())
+            .unwrap();
+
+        server.join().unwrap().text()
+    }
+
+    /// Returns the text of the part named `name`.
+    fn part<'a>(body: &'a str, name: &str) -> &'a str {
+        body.split(&format!("name=\"{}\"\r\n\r\n", name))
+            .nth(1)
+            .and_then(|rest| rest.split("\r\n").next())
+            .unwrap()
+    }
+
+    #[test]
+    fn file_reference_local() {
+        let (s, file) = file_reference(InputFile::file("/tmp/photo.png"), true);
+        assert_eq!(s, "file:///tmp/photo.png");
+        assert_eq!(file, None);
+
+        let (s, file) = file_reference(InputFile::file("/tmp/photo.png"), false);
+        let (uuid, file) = file.unwrap();
+        assert_eq!(s, format!("attach://{}", uuid));
+        assert_eq!(file, InputFile::file("/tmp/photo.png"));
+
+        let (s, file) = file_reference(InputFile::file_id("file_id"), true);
+        assert_eq!(s, "file_id");
+        assert_eq!(file, None);
+    }
+
+    #[test]
+    fn set_webhook_certificate() {
+        use crate::{
+            payloads::{setters::*, SetWebhook},
+            types::AllowedUpdate,
+        };
+
+        let payload = SetWebhook::new("https://example.com/webhook")
+            .certificate(InputFile::memory(
+                "cert.pem",
+                &b"-----BEGIN CERTIFICATE-----"[..],
+            ))
+            .max_connections(10)
+            .allowed_updates(vec![AllowedUpdate::Message])
+            .drop_pending_updates(true)
+            .secret_token("secret");
+
+        let form =
+            futures::executor::block_on(crate::serde_multipart::to_form(&payload, false)).unwrap();
+
+        // `Form` doesn't allow to inspect its parts, but its `Debug` output
+        // contains their names
+        let debug = format!("{:?}", form);
+        assert!(debug.contains("\"certificate\""));
+        assert!(debug.contains("\"url\""));
+        assert!(debug.contains("\"max_connections\""));
+        assert!(debug.contains("\"allowed_updates\""));
+        assert!(debug.contains("\"secret_token\""));
+    }
+
+    #[test]
+    fn edit_message_media() {
+        use crate::{
+            payloads::EditMessageMedia,
+            types::{InputMedia, InputMediaPhoto, MessageId},
+        };
+
+        let payload = EditMessageMedia::new(
+            0,
+            MessageId(1),
+            InputMedia::Photo(InputMediaPhoto::new(InputFile::memory(
+                "photo.png",
+                &b"<photo>"[..],
+            ))),
+        );
+
+        let form =
+            futures::executor::block_on(crate::serde_multipart::to_form(&payload, false)).unwrap();
+        let request = form_body(form);
+
+        let media: serde_json::Value = serde_json::from_str(part(&request, "media")).unwrap();
+        assert_eq!(media["type"], "photo");
+
+        let uuid = media["media"]
+            .as_str()
+            .unwrap()
+            .strip_prefix("attach://")
+            .unwrap();
+        assert!(request.contains(&format!("name=\"{}\"; filename=\"photo.png\"", uuid)));
+        assert!(request.contains("\r\n\r\n<photo>\r\n"));
+    }
+
+    #[test]
+    fn media_group_attach() {
+        use crate::{
+            payloads::SendMediaGroup,
+            types::{InputMedia, InputMediaPhoto},
+        };
+
+        let photo = |name: &'static str, data: &'static [u8]| {
+            InputMedia::Photo(InputMediaPhoto::new(InputFile::memory(name, data)))
+        };
+        let payload = SendMediaGroup::new(
+            0,
+            vec![photo("1.png", b"<photo 1>"), photo("2.png", b"<photo 2>")],
+        );
+
+        let form =
+            futures::executor::block_on(crate::serde_multipart::to_form(&payload, false)).unwrap();
+        let request = form_body(form);
+
+        let media: Vec<serde_json::Value> = serde_json::from_str(part(&request, "media")).unwrap();
+        assert_eq!(media.len(), 2);
+
+        let names: Vec<_> = media
+            .iter()
+            .map(|m| {
+                m["media"]
+                    .as_str()
+                    .unwrap()
+                    .strip_prefix("attach://")
+                    .unwrap()
+            })
+            .collect();
+        assert_ne!(names[0], names[1]);
+
+        for (name, (file_name, data)) in names
+            .into_iter()
+            .zip(vec![("1.png", "<photo 1>"), ("2.png", "<photo 2>")])
+        {
+            let header = format!("name=\"{}\"; filename=\"{}\"", name, file_name);
+            assert_eq!(request.matches(&header).count(), 1);
+
+            let content = request.split(&header).nth(1).unwrap();
+            assert_eq!(
+                content
+                    .split("\r\n\r\n")
+                    .nth(1)
+                    .unwrap()
+                    .split("\r\n")
+                    .next(),
+                Some(data)
+            );
+        }
+    }
+
+    #[test]
+    fn paid_media_attach() {
+        use crate::{
+            payloads::SendPaidMedia,
+            types::{InputPaidMedia, InputPaidMediaPhoto, InputPaidMediaVideo},
+        };
+
+        let payload = SendPaidMedia::new(
+            0,
+            10,
+            vec![
+                InputPaidMedia::Photo(InputPaidMediaPhoto::new(InputFile::memory(
+                    "photo.png",
+                    &b"<photo>"[..],
+                ))),
+                InputPaidMedia::Video(InputPaidMediaVideo::new(InputFile::file_id("id"))),
+            ],
+        );
+
+        let form =
+            futures::executor::block_on(crate::serde_multipart::to_form(&payload, false)).unwrap();
+        let request = form_body(form);
+
+        assert_eq!(part(&request, "star_count"), "10");
+
+        let media: Vec<serde_json::Value> = serde_json::from_str(part(&request, "media")).unwrap();
+        assert_eq!(media[0]["type"], "photo");
+        assert_eq!(media[1]["type"], "video");
+        assert_eq!(media[1]["media"], "id");
+
+        let uuid = media[0]["media"]
+            .as_str()
+            .unwrap()
+            .strip_prefix("attach://")
+            .unwrap();
+        assert!(request.contains(&format!("name=\"{}\"; filename=\"photo.png\"", uuid)));
+        assert!(request.contains("\r\n\r\n<photo>\r\n"));
+    }
+
+    #[test]
+    fn message_effect_id() {
+        use crate::payloads::{SendMessage, SendMessageSetters, SendPhoto, SendPhotoSetters};
+
+        let json = serde_json::to_value(SendMessage::new(0, "text")).unwrap();
+        assert_eq!(json, serde_json::json!({ "chat_id": 0, "text": "text" }));
+
+        let json = serde_json::to_value(
+            SendMessage::new(0, "text").message_effect_id("5104841245755180586"),
+        )
+        .unwrap();
+        assert_eq!(json["message_effect_id"], "5104841245755180586");
+
+        let photo = || SendPhoto::new(0, InputFile::file_id("id"));
+
+        let form =
+            futures::executor::block_on(crate::serde_multipart::to_form(&photo(), false)).unwrap();
+        assert!(!form_body(form).contains("message_effect_id"));
+
+        let payload = photo().message_effect_id("5104841245755180586");
+        let form =
+            futures::executor::block_on(crate::serde_multipart::to_form(&payload, false)).unwrap();
+        assert_eq!(
+            part(&form_body(form), "message_effect_id"),
+            "5104841245755180586"
+        );
+    }
+
+    #[test]
+    fn set_chat_photo() {
+        use crate::{payloads::SetChatPhoto, types::ChatId};
+
+        let payload = SetChatPhoto::new(-100, InputFile::memory("photo.jpg", &b"<photo>"[..]));
+        let form =
+            futures::executor::block_on(crate::serde_multipart::to_form(&payload, false)).unwrap();
+        let request = form_body(form);
+
+        assert_eq!(part(&request, "chat_id"), "-100");
+
+        let uuid = part(&request, "photo").strip_prefix("attach://").unwrap();
+        assert!(request.contains(&format!("name=\"{}\"; filename=\"photo.jpg\"", uuid)));
+        assert!(request.contains("\r\n\r\n<photo>\r\n"));
+
+        let payload = SetChatPhoto::new(
+            ChatId::ChannelUsername("@channel".to_owned()),
+            InputFile::file("/tmp/photo.jpg"),
+        );
+        let form =
+            futures::executor::block_on(crate::serde_multipart::to_form(&payload, true)).unwrap();
+        let request = form_body(form);
+
+        assert_eq!(part(&request, "chat_id"), "@channel");
+        assert_eq!(part(&request, "photo"), "file:///tmp/photo.jpg");
+    }
+
+    #[test]
+    fn sticker_files() {
+        use crate::{
+            payloads::{CreateNewStickerSet, UploadStickerFile},
+            types::{InputSticker, UserId},
+        };
+
+        let payload = UploadStickerFile::new(
+            UserId(1),
+            InputFile::memory("sticker.png", &b"<png sticker>"[..]),
+        );
+        let form =
+            futures::executor::block_on(crate::serde_multipart::to_form(&payload, false)).unwrap();
+        let request = form_body(form);
+
+        assert_eq!(part(&request, "user_id"), "1");
+        let uuid = part(&request, "png_sticker")
+            .strip_prefix("attach://")
+            .unwrap();
+        assert!(request.contains(&format!("name=\"{}\"; filename=\"sticker.png\"", uuid)));
+        assert!(request.contains("\r\n\r\n<png sticker>\r\n"));
+
+        let payload = CreateNewStickerSet::new(
+            UserId(1),
+            "pack_by_bot",
+            "Pack",
+            InputSticker::Png(InputFile::memory("sticker.png", &b"<png sticker>"[..])),
+            "🦀",
+        );
+        let form =
+            futures::executor::block_on(crate::serde_multipart::to_form(&payload, false)).unwrap();
+        let request = form_body(form);
+
+        assert_eq!(part(&request, "name"), "pack_by_bot");
+        assert_eq!(part(&request, "emojis"), "🦀");
+        let uuid = part(&request, "png_sticker")
+            .strip_prefix("attach://")
+            .unwrap();
+        assert!(request.contains(&format!("name=\"{}\"; filename=\"sticker.png\"", uuid)));
+        assert!(request.contains("\r\n\r\n<png sticker>\r\n"));
+        assert!(!request.contains("tgs_sticker"));
+
+        // Files that already exist on the Telegram servers are sent as text
+        let payload = CreateNewStickerSet::new(
+            UserId(1),
+            "pack_by_bot",
+            "Pack",
+            InputSticker::Tgs(InputFile::file_id("id")),
+            "🦀",
+        );
+        let form =
+            futures::executor::block_on(crate::serde_multipart::to_form(&payload, false)).unwrap();
+        assert_eq!(part(&form_body(form), "tgs_sticker"), "id");
+    }
+
+    #[test]
+    fn stream_upload() {
+        use crate::{
+            payloads::{SendDocument, SendMediaGroup},
+            types::{InputMedia, InputMediaDocument},
+        };
+
+        let file = |name: &'static str, data: &'static str| {
+            let chunks = vec![Ok::<_, io::Error>(bytes::Bytes::from(data))];
+            InputFile::read(name, futures::stream::iter(chunks))
+        };
+
+        let payload = SendDocument::new(0, file("doc.txt", "<doc>"));
+        let form =
+            futures::executor::block_on(crate::serde_multipart::to_form(&payload, false)).unwrap();
+        let request = form_body(form);
+
+        let uuid = part(&request, "document")
+            .strip_prefix("attach://")
+            .unwrap();
+        assert!(request.contains(&format!("name=\"{}\"; filename=\"doc.txt\"", uuid)));
+        assert!(request.contains("\r\n\r\n<doc>\r\n"));
+
+        let payload = SendMediaGroup::new(
+            0,
+            vec![InputMedia::Document(InputMediaDocument::new(file(
+                "1.txt", "<doc 1>",
+            )))],
+        );
+        let form =
+            futures::executor::block_on(crate::serde_multipart::to_form(&payload, false)).unwrap();
+        let request = form_body(form);
+
+        assert!(request.contains("filename=\"1.txt\""));
+        assert!(request.contains("\r\n\r\n<doc 1>\r\n"));
+    }
+
+    #[test]
+    fn stream() {
+        use crate::types::{with_streams, InputFileStream};
+
+        let chunks = vec![Ok::<_, io::Error>(bytes::Bytes::from("data"))];
+        let file = InputFile::read_with_len("file.txt", futures::stream::iter(chunks), 4);
+
+        let files = with_streams(|| {
+            let (_, files) = file.serialize(PartSerializer { local: true }).unwrap();
+
+            let json = serde_json::to_value(&file).unwrap();
+            assert_eq!(serde_json::from_value::<InputFile>(json).unwrap(), file);
+
+            files
+        });
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].1, file);
+
+        // Outside of `with_streams` streams are serialized as their file names and
+        // can't be deserialized
+        let json = serde_json::to_value(&file).unwrap();
+        assert_eq!(json, serde_json::json!({ "Read": "file.txt" }));
+        assert!(serde_json::from_value::<InputFile>(json).is_err());
+        assert_eq!(InputFileStream::from_index("0"), None);
+
+        assert!(futures::executor::block_on(file.clone().into_part()).is_ok());
+        assert!(futures::executor::block_on(file.into_part()).is_err());
+    }
+}
@@ -93,9 +93,18 @@ fn test() {
     let value = InputFile::Memory {
         file_name: String::from("name"),
         data: Cow::Owned(vec![1, 2, 3]),
+        mime: None,
     };
     assert_eq!(value.serialize(InputFileUnserializer::memory()), Ok(value));
 
+    let value = InputFile::memory("name", vec![1, 2, 3]).mime(mime::IMAGE_PNG);
+    assert_eq!(
+        value.serialize(InputFileUnserializer::memory()),
+        Ok(value.clone())
+    );
+    let json = serde_json::to_value(&value).unwrap();
+    assert_eq!(serde_json::from_value::<InputFile>(json).unwrap(), value);
+
     let value = InputFile::File("a/b/c".into());
     assert_eq!(value.serialize(InputFileUnserializer::NotMem), Ok(value));
 }
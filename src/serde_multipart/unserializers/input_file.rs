@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use mime::Mime;
 use serde::{
     ser::{Impossible, SerializeStructVariant},
     Serialize, Serializer,
@@ -9,13 +10,14 @@ use crate::{
     serde_multipart::unserializers::{
         bytes::BytesUnserializer, string::StringUnserializer, UnserializerError,
     },
-    types::InputFile,
+    types::{InputFile, InputFileStream},
 };
 
 pub(crate) enum InputFileUnserializer {
     Memory {
         file_name: String,
         data: Cow<'static, [u8]>,
+        mime: Option<Mime>,
     },
     NotMem,
 }
@@ -25,6 +27,7 @@ impl InputFileUnserializer {
         Self::Memory {
             file_name: String::new(),
             data: Cow::Borrowed(&[]),
+            mime: None,
         }
     }
 }
@@ -64,9 +67,12 @@ impl Serializer for InputFileUnserializer {
             "File" => Ok(InputFile::File(value.serialize(StringUnserializer)?.into())),
             "Url" => Ok(InputFile::Url(value.serialize(StringUnserializer)?)),
             "FileId" => Ok(InputFile::FileId(value.serialize(StringUnserializer)?)),
+            "Read" => InputFileStream::from_index(&value.serialize(StringUnserializer)?)
+                .map(InputFile::Read)
+                .ok_or_else(|| UnserializerError::Custom("unknown stream".to_owned())),
             name => Err(UnserializerError::UnexpectedVariant {
                 name,
-                expected: &["File", "Url", "FileId"], // TODO
+                expected: &["File", "Url", "FileId", "Read"], // TODO
             }),
         }
     }
@@ -92,8 +98,8 @@ impl Serializer for InputFileUnserializer {
             });
         }
 
-        if len != 2 {
-            return Err(UnserializerError::WrongLen { len, expected: 2 });
+        if len != 3 {
+            return Err(UnserializerError::WrongLen { len, expected: 3 });
         }
 
         Ok(self)
@@ -148,12 +154,20 @@ impl SerializeStructVariant for InputFileUnserializer {
     where
         T: Serialize,
     {
-        let (file_name, data) = match self {
-            Self::Memory { file_name, data } => (file_name, data),
+        let (file_name, data, mime) = match self {
+            Self::Memory {
+                file_name,
+                data,
+                mime,
+            } => (file_name, data, mime),
             Self::NotMem => {
                 *self = Self::memory();
                 match self {
-                    Self::Memory { file_name, data } => (file_name, data),
+                    Self::Memory {
+                        file_name,
+                        data,
+                        mime,
+                    } => (file_name, data, mime),
                     Self::NotMem => unreachable!(),
                 }
             }
@@ -162,10 +176,22 @@ impl SerializeStructVariant for InputFileUnserializer {
         match key {
             "file_name" => *file_name = value.serialize(StringUnserializer)?,
             "data" => *data = Cow::Owned(value.serialize(BytesUnserializer::default())?),
+            // `Option<Mime>` is serialized as an optional string
+            "mime" => {
+                *mime = match serde_json::to_value(value) {
+                    Ok(serde_json::Value::String(s)) => {
+                        Some(s.parse().map_err(|err: mime::FromStrError| {
+                            UnserializerError::Custom(err.to_string())
+                        })?)
+                    }
+                    Ok(_) => None,
+                    Err(err) => return Err(UnserializerError::Custom(err.to_string())),
+                }
+            }
             name => {
                 return Err(UnserializerError::UnexpectedField {
                     name,
-                    expected: &["file_name", "data"], // TODO
+                    expected: &["file_name", "data", "mime"], // TODO
                 });
             }
         }
@@ -175,7 +201,15 @@ impl SerializeStructVariant for InputFileUnserializer {
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
         match self {
-            Self::Memory { file_name, data } => Ok(InputFile::Memory { file_name, data }),
+            Self::Memory {
+                file_name,
+                data,
+                mime,
+            } => Ok(InputFile::Memory {
+                file_name,
+                data,
+                mime,
+            }),
             Self::NotMem => unreachable!("struct without fields?"),
         }
     }
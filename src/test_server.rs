@@ -0,0 +1,180 @@
+//! A minimal HTTP/1.1 server for tests which need to inspect requests sent by
+//! the http-client.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    thread::JoinHandle,
+};
+
+/// A request received by the server.
+pub(crate) struct Request {
+    /// The request line and headers, including the final empty line.
+    pub(crate) head: String,
+    /// The body, decoded if it was sent with `transfer-encoding: chunked`.
+    pub(crate) body: Vec<u8>,
+}
+
+impl Request {
+    /// Returns the head followed by the body, lossily converted to a string.
+    pub(crate) fn text(&self) -> String {
+        format!("{}{}", self.head, String::from_utf8_lossy(&self.body))
+    }
+}
+
+/// Starts a server which accepts one connection per response from
+/// `responses`, reads a request from it and responds with the given status
+/// and body. Returns the address of the server and a handle which resolves
+/// to the received requests.
+pub(crate) fn serve<S>(responses: Vec<(u16, S)>) -> (SocketAddr, JoinHandle<Vec<Request>>)
+where
+    S: AsRef<str> + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let server = std::thread::spawn(move || {
+        responses
+            .into_iter()
+            .map(|(status, body)| {
+                let (mut stream, _) = listener.accept().unwrap();
+                let request = read_request(&stream);
+
+                let body = body.as_ref();
+                let response = format!(
+                    "HTTP/1.1 {} X\r\nconnection: close\r\ncontent-length: {}\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                // The client may have already given up, that's up to the test to check
+                let _ = stream.write_all(response.as_bytes());
+
+                request
+            })
+            .collect()
+    });
+
+    (addr, server)
+}
+
+/// Same as [`serve`], but accepts a single request and responds with an empty
+/// `200 OK`.
+pub(crate) fn serve_once() -> (SocketAddr, JoinHandle<Request>) {
+    let (addr, server) = serve(vec![(200, "")]);
+    let server = std::thread::spawn(move || server.join().unwrap().pop().unwrap());
+
+    (addr, server)
+}
+
+/// Reads a request from `stream`.
+///
+/// Reading stops at EOF, so if the peer closes the connection early, a
+/// truncated request is returned.
+pub(crate) fn read_request(stream: &TcpStream) -> Request {
+    let mut reader = BufReader::new(stream);
+
+    let mut head = String::new();
+    loop {
+        let len = head.len();
+        if reader.read_line(&mut head).unwrap() == 0 || head[len..] == *"\r\n" {
+            break;
+        }
+    }
+
+    let lowercase = head.to_lowercase();
+    let header = |name: &str| {
+        lowercase
+            .lines()
+            .find_map(|line| line.strip_prefix(name)?.strip_prefix(':'))
+            .map(str::trim)
+    };
+
+    let mut body = Vec::new();
+    if header("transfer-encoding") == Some("chunked") {
+        loop {
+            let mut size = String::new();
+            if reader.read_line(&mut size).unwrap() == 0 {
+                break;
+            }
+
+            let size = size.trim().split(';').next().unwrap();
+            let size = usize::from_str_radix(size, 16).unwrap();
+            // The last chunk is followed by an empty line
+            let mut chunk = vec![0; size + 2];
+            if size == 0 || reader.read_exact(&mut chunk).is_err() {
+                break;
+            }
+
+            body.extend_from_slice(&chunk[..size]);
+        }
+    } else if let Some(len) = header("content-length") {
+        let len = len.parse().unwrap();
+        reader.take(len).read_to_end(&mut body).unwrap();
+    }
+
+    Request { head, body }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sends `request` to a server and returns what the server received.
+    fn roundtrip(request: &'static [u8], close: bool) -> Request {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let client = std::thread::spawn(move || {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            stream.write_all(request).unwrap();
+            if !close {
+                // Keep the connection open until the server is done
+                let _ = stream.read(&mut [0]);
+            }
+        });
+
+        let (stream, _) = listener.accept().unwrap();
+        let request = read_request(&stream);
+        drop(stream);
+        client.join().unwrap();
+
+        request
+    }
+
+    #[test]
+    fn bodies() {
+        let request = roundtrip(b"POST / HTTP/1.1\r\nContent-Length: 4\r\n\r\nbody", false);
+        assert_eq!(request.head, "POST / HTTP/1.1\r\nContent-Length: 4\r\n\r\n");
+        assert_eq!(request.body, b"body");
+
+        let request = roundtrip(
+            b"POST / HTTP/1.1\r\ntransfer-encoding: chunked\r\n\r\n2\r\nbo\r\n2;x=y\r\ndy\r\n0\r\n\r\n",
+            false,
+        );
+        assert_eq!(request.body, b"body");
+        assert_eq!(
+            request.text(),
+            "POST / HTTP/1.1\r\ntransfer-encoding: chunked\r\n\r\nbody"
+        );
+
+        let request = roundtrip(b"GET / HTTP/1.1\r\nhost: x\r\n\r\n", false);
+        assert_eq!(request.head, "GET / HTTP/1.1\r\nhost: x\r\n\r\n");
+        assert!(request.body.is_empty());
+    }
+
+    #[test]
+    fn eof() {
+        let request = roundtrip(b"GET / HTTP/1.1\r\nhost", true);
+        assert_eq!(request.head, "GET / HTTP/1.1\r\nhost");
+
+        let request = roundtrip(b"POST / HTTP/1.1\r\ncontent-length: 10\r\n\r\nbody", true);
+        assert_eq!(request.body, b"body");
+
+        let request = roundtrip(
+            b"POST / HTTP/1.1\r\ntransfer-encoding: chunked\r\n\r\n4\r\nbody\r\n8\r\nbo",
+            true,
+        );
+        assert_eq!(request.body, b"body");
+    }
+}
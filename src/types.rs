@@ -4,10 +4,13 @@ pub use allowed_update::*;
 pub use animation::*;
 pub use audio::*;
 pub use bot_command::*;
+pub use bot_command_scope::*;
+pub use business_connection::*;
 pub use callback_game::*;
 pub use callback_query::*;
 pub use chat::*;
 pub use chat_action::*;
+pub use chat_administrator_rights::*;
 pub use chat_id::*;
 pub use chat_invite_link::*;
 pub use chat_location::*;
@@ -50,23 +53,29 @@ pub use inline_query_result_photo::*;
 pub use inline_query_result_venue::*;
 pub use inline_query_result_video::*;
 pub use inline_query_result_voice::*;
+pub use inline_query_results_button::*;
 pub use input_file::*;
 pub use input_media::*;
 pub use input_message_content::*;
+pub use input_paid_media::*;
 pub use input_sticker::*;
 pub use invoice::*;
 pub use keyboard_button::*;
 pub use keyboard_button_poll_type::*;
 pub use label_price::*;
+pub use link_preview_options::*;
 pub use location::*;
 pub use login_url::*;
 pub use mask_position::*;
 pub use me::*;
+pub use menu_button::*;
 pub use message::*;
 pub use message_auto_delete_timer_changed::*;
 pub use message_entity::*;
 pub use message_id::*;
+pub use message_reaction_updated::*;
 pub use order_info::*;
+pub use paid_media::*;
 pub use parse_mode::*;
 pub use passport_data::*;
 pub use passport_element_error::*;
@@ -77,6 +86,7 @@ pub use poll_answer::*;
 pub use poll_type::*;
 pub use pre_checkout_query::*;
 pub use proximity_alert_triggered::*;
+pub use reaction_type::*;
 pub use reply_keyboard_markup::*;
 pub use reply_keyboard_remove::*;
 pub use reply_markup::*;
@@ -88,10 +98,12 @@ pub use sticker::*;
 pub use sticker_set::*;
 pub use successful_payment::*;
 pub use target_message::*;
+pub use thread_id::*;
 pub use unit_false::*;
 pub use unit_true::*;
 pub use update::*;
 pub use user::*;
+pub use user_id::*;
 pub use user_profile_photos::*;
 pub use venue::*;
 pub use video::*;
@@ -100,16 +112,21 @@ pub use voice::*;
 pub use voice_chat_ended::*;
 pub use voice_chat_participants_invited::*;
 pub use voice_chat_started::*;
+pub use web_app_data::*;
+pub use web_app_info::*;
 pub use webhook_info::*;
 
 mod allowed_update;
 mod animation;
 mod audio;
 mod bot_command;
+mod bot_command_scope;
+mod business_connection;
 mod callback_game;
 mod callback_query;
 mod chat;
 mod chat_action;
+mod chat_administrator_rights;
 mod chat_id;
 mod chat_invite_link;
 mod chat_location;
@@ -131,20 +148,25 @@ mod inline_keyboard_markup;
 mod input_file;
 mod input_media;
 mod input_message_content;
+mod input_paid_media;
 mod input_sticker;
 mod invoice;
 mod keyboard_button;
 mod keyboard_button_poll_type;
 mod label_price;
+mod link_preview_options;
 mod location;
 mod login_url;
 mod mask_position;
 mod me;
+mod menu_button;
 mod message;
 mod message_auto_delete_timer_changed;
 mod message_entity;
 mod message_id;
+mod message_reaction_updated;
 mod order_info;
+mod paid_media;
 mod parse_mode;
 mod photo_size;
 mod poll;
@@ -152,6 +174,7 @@ mod poll_answer;
 mod poll_type;
 mod pre_checkout_query;
 mod proximity_alert_triggered;
+mod reaction_type;
 mod reply_keyboard_markup;
 mod reply_keyboard_remove;
 mod reply_markup;
@@ -163,10 +186,12 @@ mod sticker;
 mod sticker_set;
 mod successful_payment;
 mod target_message;
+mod thread_id;
 mod unit_false;
 mod unit_true;
 mod update;
 mod user;
+mod user_id;
 mod user_profile_photos;
 mod venue;
 mod video;
@@ -175,6 +200,8 @@ mod voice;
 mod voice_chat_ended;
 mod voice_chat_participants_invited;
 mod voice_chat_started;
+mod web_app_data;
+mod web_app_info;
 mod webhook_info;
 
 mod inline_query;
@@ -199,6 +226,7 @@ mod inline_query_result_photo;
 mod inline_query_result_venue;
 mod inline_query_result_video;
 mod inline_query_result_voice;
+mod inline_query_results_button;
 
 mod encrypted_credentials;
 mod encrypted_passport_element;
@@ -206,10 +234,18 @@ mod passport_data;
 mod passport_element_error;
 mod passport_file;
 
-pub use non_telegram_types::{country_code::*, currency::*, semiparsed_vec::*};
+#[cfg(feature = "chrono")]
+pub(crate) use non_telegram_types::until_date::from_unix_time;
+pub use non_telegram_types::{
+    chat_administrators::*, country_code::*, currency::*, edit_result::*, semiparsed_vec::*,
+    until_date::UntilDate,
+};
 mod non_telegram_types {
+    pub(super) mod chat_administrators;
     pub(super) mod country_code;
     pub(super) mod currency;
+    pub(super) mod edit_result;
     pub(crate) mod mime;
     pub(super) mod semiparsed_vec;
+    pub(super) mod until_date;
 }
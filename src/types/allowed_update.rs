@@ -1,5 +1,27 @@
+use std::convert::TryFrom;
+
 use serde::{Deserialize, Serialize};
 
+use crate::types::UpdateKind;
+
+/// A type of updates to receive, used in `allowed_updates` of [`GetUpdates`]
+/// and [`SetWebhook`].
+///
+/// Every variant corresponds to a variant of [`UpdateKind`], the mapping is
+/// available through the `TryFrom` impls (which fail with
+/// [`UnknownUpdateKind`] only for [`UpdateKind::Unknown`]):
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// use teloxide_core::types::{AllowedUpdate, Update};
+///
+/// # let update: Update = serde_json::from_str(r#"{"update_id":1,"poll_answer":{"poll_id":"1","user":{"id":1,"is_bot":false,"first_name":"Name"},"option_ids":[0]}}"#).unwrap();
+/// assert_eq!(AllowedUpdate::try_from(&update.kind), Ok(AllowedUpdate::PollAnswer));
+/// ```
+///
+/// [`GetUpdates`]: crate::payloads::GetUpdates
+/// [`SetWebhook`]: crate::payloads::SetWebhook
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AllowedUpdate {
@@ -16,4 +38,84 @@ pub enum AllowedUpdate {
     PollAnswer,
     MyChatMember,
     ChatMember,
+    MessageReaction,
+    BusinessConnection,
+    BusinessMessage,
+    EditedBusinessMessage,
+    DeletedBusinessMessages,
+}
+
+/// The error returned when converting [`UpdateKind::Unknown`] into an
+/// [`AllowedUpdate`], since updates of unknown kinds can't be requested.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+#[error("the update kind is unknown to teloxide-core")]
+pub struct UnknownUpdateKind;
+
+impl TryFrom<&UpdateKind> for AllowedUpdate {
+    type Error = UnknownUpdateKind;
+
+    fn try_from(kind: &UpdateKind) -> Result<Self, Self::Error> {
+        Ok(match kind {
+            UpdateKind::Message(_) => Self::Message,
+            UpdateKind::EditedMessage(_) => Self::EditedMessage,
+            UpdateKind::ChannelPost(_) => Self::ChannelPost,
+            UpdateKind::EditedChannelPost(_) => Self::EditedChannelPost,
+            UpdateKind::InlineQuery(_) => Self::InlineQuery,
+            UpdateKind::ChosenInlineResult(_) => Self::ChosenInlineResult,
+            UpdateKind::CallbackQuery(_) => Self::CallbackQuery,
+            UpdateKind::ShippingQuery(_) => Self::ShippingQuery,
+            UpdateKind::PreCheckoutQuery(_) => Self::PreCheckoutQuery,
+            UpdateKind::Poll(_) => Self::Poll,
+            UpdateKind::PollAnswer(_) => Self::PollAnswer,
+            UpdateKind::MyChatMember(_) => Self::MyChatMember,
+            UpdateKind::ChatMember(_) => Self::ChatMember,
+            UpdateKind::MessageReaction(_) => Self::MessageReaction,
+            UpdateKind::BusinessConnection(_) => Self::BusinessConnection,
+            UpdateKind::BusinessMessage(_) => Self::BusinessMessage,
+            UpdateKind::EditedBusinessMessage(_) => Self::EditedBusinessMessage,
+            UpdateKind::DeletedBusinessMessages(_) => Self::DeletedBusinessMessages,
+            UpdateKind::Unknown(_) => return Err(UnknownUpdateKind),
+        })
+    }
+}
+
+impl TryFrom<UpdateKind> for AllowedUpdate {
+    type Error = UnknownUpdateKind;
+
+    fn try_from(kind: UpdateKind) -> Result<Self, Self::Error> {
+        Self::try_from(&kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        let updates = vec![
+            AllowedUpdate::Message,
+            AllowedUpdate::CallbackQuery,
+            AllowedUpdate::MyChatMember,
+        ];
+
+        assert_eq!(
+            serde_json::to_string(&updates).unwrap(),
+            r#"["message","callback_query","my_chat_member"]"#
+        );
+    }
+
+    #[test]
+    fn from_update_kind() {
+        let kind: UpdateKind =
+            serde_json::from_str(r#"{"edited_channel_post":{"message_id":1,"date":0,"chat":{"id":-1,"type":"channel"},"text":"text"}}"#)
+                .unwrap();
+        assert_eq!(
+            AllowedUpdate::try_from(kind),
+            Ok(AllowedUpdate::EditedChannelPost)
+        );
+
+        let kind: UpdateKind = serde_json::from_str(r#"{"new_kind":{}}"#).unwrap();
+        assert_eq!(AllowedUpdate::try_from(kind), Err(UnknownUpdateKind));
+    }
 }
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::RequestError;
+
 /// This object represents a bot command.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#botcommand).
@@ -10,11 +12,20 @@ pub struct BotCommand {
     /// Can contain only lowercase English letters, digits and underscores.
     pub command: String,
 
-    /// Description of the command, 3-256 characters.
+    /// Description of the command, 1-256 characters.
     pub description: String,
 }
 
 impl BotCommand {
+    /// Maximum length of [`BotCommand::command`].
+    pub const COMMAND_MAX_LEN: usize = 32;
+
+    /// Minimum length of [`BotCommand::description`].
+    pub const DESCRIPTION_MIN_LEN: usize = 1;
+
+    /// Maximum length of [`BotCommand::description`].
+    pub const DESCRIPTION_MAX_LEN: usize = 256;
+
     pub fn new<S1, S2>(command: S1, description: S2) -> Self
     where
         S1: Into<String>,
@@ -41,4 +52,78 @@ impl BotCommand {
         self.description = val.into();
         self
     }
+
+    /// Returns `true` if the command satisfies the requirements of Telegram,
+    /// i.e. [`command`] is 1-32 characters long and consists of lowercase
+    /// English letters, digits and underscores, and [`description`] is 1-256
+    /// characters long.
+    ///
+    /// Telegram rejects [`SetMyCommands`] requests with invalid commands, the
+    /// bot can check them before sending (see [`Bot::validate_lengths`]).
+    ///
+    /// [`Bot::validate_lengths`]: crate::Bot::validate_lengths
+    /// [`command`]: BotCommand::command
+    /// [`description`]: BotCommand::description
+    /// [`SetMyCommands`]: crate::payloads::SetMyCommands
+    pub fn is_valid(&self) -> bool {
+        let command_len = self.command.chars().count();
+        let description_len = self.description.chars().count();
+
+        (1..=Self::COMMAND_MAX_LEN).contains(&command_len)
+            && self
+                .command
+                .chars()
+                .all(|c| matches!(c, 'a'..='z' | '0'..='9' | '_'))
+            && (Self::DESCRIPTION_MIN_LEN..=Self::DESCRIPTION_MAX_LEN).contains(&description_len)
+    }
+}
+
+/// Returns [`RequestError::InvalidBotCommand`] for the first invalid command,
+/// see [`BotCommand::is_valid`].
+pub(crate) fn check_bot_commands(commands: &[BotCommand]) -> Result<(), RequestError> {
+    match commands.iter().find(|c| !c.is_valid()) {
+        Some(command) => Err(RequestError::InvalidBotCommand {
+            command: command.command.clone(),
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_valid() {
+        assert!(BotCommand::new("start", "Start the bot").is_valid());
+        assert!(BotCommand::new("set_lang_2", "Set language").is_valid());
+        assert!(BotCommand::new("a".repeat(32), "abc").is_valid());
+        assert!(BotCommand::new("start", "g").is_valid());
+        assert!(BotCommand::new("start", "go").is_valid());
+
+        assert!(!BotCommand::new("", "Start the bot").is_valid());
+        assert!(!BotCommand::new("a".repeat(33), "Start the bot").is_valid());
+        assert!(!BotCommand::new("Start", "Start the bot").is_valid());
+        assert!(!BotCommand::new("/start", "Start the bot").is_valid());
+        assert!(!BotCommand::new("start", "").is_valid());
+        assert!(!BotCommand::new("start", "a".repeat(257)).is_valid());
+    }
+
+    #[test]
+    fn check_lengths() {
+        use crate::{payloads::SetMyCommands, requests::Payload};
+
+        assert!(SetMyCommands::new(vec![BotCommand::new("start", "go")])
+            .check_lengths()
+            .is_ok());
+
+        let commands = vec![
+            BotCommand::new("help", "Help"),
+            BotCommand::new("Start", "Start the bot"),
+        ];
+        match SetMyCommands::new(commands).check_lengths() {
+            Err(RequestError::InvalidBotCommand { command }) => assert_eq!(command, "Start"),
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
 }
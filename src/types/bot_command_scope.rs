@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ChatId, UserId};
+
+/// This object represents the scope to which bot commands are applied.
+///
+/// When a user opens the command menu, the commands of the most narrow scope
+/// which has commands are shown, in this order:
+///
+/// - [`ChatMember`] of the chat and the user
+/// - [`ChatAdministrators`] of the chat (if the user is an administrator)
+/// - [`Chat`]
+/// - [`AllChatAdministrators`] (if the user is an administrator) or
+///   [`AllPrivateChats`]/[`AllGroupChats`]
+/// - [`Default`]
+///
+/// [The official docs](https://core.telegram.org/bots/api#botcommandscope).
+///
+/// [`ChatMember`]: BotCommandScope::ChatMember
+/// [`ChatAdministrators`]: BotCommandScope::ChatAdministrators
+/// [`Chat`]: BotCommandScope::Chat
+/// [`AllChatAdministrators`]: BotCommandScope::AllChatAdministrators
+/// [`AllPrivateChats`]: BotCommandScope::AllPrivateChats
+/// [`AllGroupChats`]: BotCommandScope::AllGroupChats
+/// [`Default`]: BotCommandScope::Default
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+pub enum BotCommandScope {
+    /// Commands used when no commands of a narrower scope are set.
+    Default,
+
+    /// All private chats.
+    AllPrivateChats,
+
+    /// All group and supergroup chats.
+    AllGroupChats,
+
+    /// All administrators of group and supergroup chats.
+    AllChatAdministrators,
+
+    /// A specific chat.
+    Chat {
+        /// Unique identifier for the target chat or username of the target
+        /// supergroup (in the format `@supergroupusername`).
+        chat_id: ChatId,
+    },
+
+    /// All administrators of a specific group or supergroup chat.
+    ChatAdministrators {
+        /// Unique identifier for the target chat or username of the target
+        /// supergroup (in the format `@supergroupusername`).
+        chat_id: ChatId,
+    },
+
+    /// A specific member of a group or supergroup chat.
+    ChatMember {
+        /// Unique identifier for the target chat or username of the target
+        /// supergroup (in the format `@supergroupusername`).
+        chat_id: ChatId,
+
+        /// Unique identifier of the target user.
+        user_id: UserId,
+    },
+}
+
+impl Default for BotCommandScope {
+    fn default() -> Self {
+        Self::Default
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_string(&BotCommandScope::AllChatAdministrators).unwrap(),
+            r#"{"type":"all_chat_administrators"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&BotCommandScope::ChatMember {
+                chat_id: ChatId::Id(-1001),
+                user_id: UserId(42),
+            })
+            .unwrap(),
+            r#"{"type":"chat_member","chat_id":-1001,"user_id":42}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let scope: BotCommandScope =
+            serde_json::from_str(r#"{"type":"chat_administrators","chat_id":"@group"}"#).unwrap();
+        assert_eq!(
+            scope,
+            BotCommandScope::ChatAdministrators {
+                chat_id: ChatId::ChannelUsername("@group".to_owned())
+            }
+        );
+    }
+}
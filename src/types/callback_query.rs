@@ -52,6 +52,7 @@ pub struct CallbackQuery {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::UserId;
 
     #[test]
     fn deserialize() {
@@ -70,7 +71,7 @@ mod tests {
         let expected = CallbackQuery {
             id: "id".to_string(),
             from: User {
-                id: 12345,
+                id: UserId(12345),
                 is_bot: false,
                 first_name: "firstName".to_string(),
                 last_name: None,
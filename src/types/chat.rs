@@ -73,6 +73,7 @@ pub struct ChatPrivate {
     /// `private`.
     #[serde(rename = "type")]
     #[serde(deserialize_with = "assert_private_field")]
+    #[serde(serialize_with = "serialize_private_field")]
     pub type_: (),
 
     /// A username, for private chats, supergroups and channels if
@@ -195,6 +196,13 @@ where
     des.deserialize_str(PrivateChatKindVisitor)
 }
 
+fn serialize_private_field<S>(_: &(), ser: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    ser.serialize_str("private")
+}
+
 impl Chat {
     pub fn is_private(&self) -> bool {
         matches!(self.kind, ChatKind::Private(_))
@@ -232,6 +240,102 @@ impl Chat {
     }
 }
 
+/// Getters for fields of the chat kinds.
+///
+/// Most of them are returned only in [`GetChat`], so they are `None` for
+/// chats from other places (e.g. [`Message::chat`]).
+///
+/// [`GetChat`]: crate::payloads::GetChat
+/// [`Message::chat`]: crate::types::Message::chat
+impl Chat {
+    /// A title, for supergroups, channels and group chats.
+    pub fn title(&self) -> Option<&str> {
+        match &self.kind {
+            ChatKind::Public(this) => this.title.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// A username, for private chats, supergroups and channels if available.
+    pub fn username(&self) -> Option<&str> {
+        match &self.kind {
+            ChatKind::Public(this) => match &this.kind {
+                PublicChatKind::Channel(PublicChatChannel { username, .. })
+                | PublicChatKind::Supergroup(PublicChatSupergroup { username, .. }) => {
+                    username.as_deref()
+                }
+                PublicChatKind::Group(_) => None,
+            },
+            ChatKind::Private(this) => this.username.as_deref(),
+        }
+    }
+
+    /// A description, for groups, supergroups and channel chats.
+    pub fn description(&self) -> Option<&str> {
+        match &self.kind {
+            ChatKind::Public(this) => this.description.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// A primary invite link, for groups, supergroups and channel chats.
+    pub fn invite_link(&self) -> Option<&str> {
+        match &self.kind {
+            ChatKind::Public(this) => this.invite_link.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Bio of the other party in a private chat.
+    pub fn bio(&self) -> Option<&str> {
+        match &self.kind {
+            ChatKind::Private(this) => this.bio.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Default chat member permissions, for groups and supergroups.
+    pub fn permissions(&self) -> Option<ChatPermissions> {
+        match &self.kind {
+            ChatKind::Public(this) => match &this.kind {
+                PublicChatKind::Group(PublicChatGroup { permissions })
+                | PublicChatKind::Supergroup(PublicChatSupergroup { permissions, .. }) => {
+                    *permissions
+                }
+                PublicChatKind::Channel(_) => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// The minimum allowed delay between consecutive messages sent by each
+    /// unprivileged user, in seconds, for supergroups.
+    pub fn slow_mode_delay(&self) -> Option<i32> {
+        match &self.kind {
+            ChatKind::Public(ChatPublic {
+                kind: PublicChatKind::Supergroup(this),
+                ..
+            }) => this.slow_mode_delay,
+            _ => None,
+        }
+    }
+
+    /// Unique identifier for the linked chat, i.e. the discussion group
+    /// identifier for a channel and vice versa.
+    pub fn linked_chat_id(&self) -> Option<i64> {
+        match &self.kind {
+            ChatKind::Public(this) => match &this.kind {
+                PublicChatKind::Channel(PublicChatChannel { linked_chat_id, .. })
+                | PublicChatKind::Supergroup(PublicChatSupergroup { linked_chat_id, .. }) => {
+                    *linked_chat_id
+                }
+                PublicChatKind::Group(_) => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::from_str;
@@ -278,8 +382,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn private_chat_ser() {
+        let chat = Chat {
+            id: 0,
+            kind: ChatKind::Private(ChatPrivate {
+                type_: (),
+                username: Some("username".into()),
+                first_name: None,
+                last_name: None,
+                bio: None,
+            }),
+            photo: None,
+            pinned_message: None,
+        };
+
+        let json = serde_json::to_string(&chat).unwrap();
+        assert_eq!(json, r#"{"id":0,"type":"private","username":"username"}"#);
+        assert_eq!(from_str::<Chat>(&json).unwrap(), chat);
+    }
+
     #[test]
     fn private_chat_de_wrong_type_field() {
         assert!(from_str::<Chat>(r#"{"id":0,"type":"WRONG"}"#).is_err());
     }
+
+    #[test]
+    fn supergroup_full_info_de() {
+        let chat: Chat = from_str(
+            r#"{
+                "id": -1001234567890,
+                "type": "supergroup",
+                "title": "Group",
+                "username": "group",
+                "description": "About",
+                "invite_link": "https://t.me/+abc",
+                "permissions": {"can_send_messages": true},
+                "slow_mode_delay": 30,
+                "linked_chat_id": -1009876543210
+            }"#,
+        )
+        .unwrap();
+
+        assert!(chat.is_supergroup());
+        assert_eq!(chat.title(), Some("Group"));
+        assert_eq!(chat.username(), Some("group"));
+        assert_eq!(chat.description(), Some("About"));
+        assert_eq!(chat.invite_link(), Some("https://t.me/+abc"));
+        assert_eq!(chat.slow_mode_delay(), Some(30));
+        assert_eq!(chat.linked_chat_id(), Some(-1009876543210));
+        assert!(chat.permissions().is_some());
+        assert_eq!(chat.bio(), None);
+    }
 }
@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::Administrator;
+
+/// Represents the rights of an administrator in a chat.
+///
+/// The creator of a chat has all rights (see
+/// [`ChatAdministratorRights::all`]).
+///
+/// [The official docs](https://core.telegram.org/bots/api#chatadministratorrights).
+#[serde_with_macros::skip_serializing_none]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct ChatAdministratorRights {
+    /// `true`, if the user's presence in the chat is hidden.
+    pub is_anonymous: bool,
+
+    /// `true`, if the administrator can access the chat event log, chat
+    /// statistics, message statistics in channels, see channel members, see
+    /// anonymous administrators in supergroups and ignore slow mode. Implied by
+    /// any other administrator privilege.
+    pub can_manage_chat: bool,
+
+    /// `true`, if the administrator can delete messages of other users.
+    pub can_delete_messages: bool,
+
+    /// `true`, if the administrator can manage voice chats.
+    pub can_manage_voice_chats: bool,
+
+    /// `true`, if the administrator can restrict, ban or unban chat members.
+    pub can_restrict_members: bool,
+
+    /// `true`, if the administrator can add new administrators with a subset
+    /// of their own privileges or demote administrators that they have
+    /// promoted, directly or indirectly (promoted by administrators that were
+    /// appointed by the user).
+    pub can_promote_members: bool,
+
+    /// `true`, if the user is allowed to change the chat title, photo and
+    /// other settings.
+    pub can_change_info: bool,
+
+    /// `true`, if the user is allowed to invite new users to the chat.
+    pub can_invite_users: bool,
+
+    /// `true`, if the administrator can post in the channel, channels only.
+    pub can_post_messages: Option<bool>,
+
+    /// `true`, if the administrator can edit messages of other users and can
+    /// pin messages, channels only.
+    pub can_edit_messages: Option<bool>,
+
+    /// `true`, if the user is allowed to pin messages, groups and supergroups
+    /// only.
+    pub can_pin_messages: Option<bool>,
+}
+
+impl ChatAdministratorRights {
+    /// Returns the rights of the creator of a chat, i.e. all rights.
+    pub const fn all() -> Self {
+        Self {
+            is_anonymous: false,
+            can_manage_chat: true,
+            can_delete_messages: true,
+            can_manage_voice_chats: true,
+            can_restrict_members: true,
+            can_promote_members: true,
+            can_change_info: true,
+            can_invite_users: true,
+            can_post_messages: Some(true),
+            can_edit_messages: Some(true),
+            can_pin_messages: Some(true),
+        }
+    }
+}
+
+impl From<&Administrator> for ChatAdministratorRights {
+    fn from(admin: &Administrator) -> Self {
+        Self {
+            is_anonymous: admin.is_anonymous,
+            can_manage_chat: admin.can_manage_chat,
+            can_delete_messages: admin.can_delete_messages,
+            can_manage_voice_chats: admin.can_manage_voice_chats,
+            can_restrict_members: admin.can_restrict_members,
+            can_promote_members: admin.can_promote_members,
+            can_change_info: admin.can_change_info,
+            can_invite_users: admin.can_invite_users,
+            can_post_messages: admin.can_post_messages,
+            can_edit_messages: admin.can_edit_messages,
+            can_pin_messages: admin.can_pin_messages,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{ChatMemberKind, Creator};
+
+    use super::*;
+
+    #[test]
+    fn member_rights() {
+        let creator = ChatMemberKind::Creator(Creator {
+            custom_title: None,
+            is_anonymous: true,
+        });
+        let rights = creator.administrator_rights().unwrap();
+        assert!(rights.is_anonymous);
+        assert!(rights.can_delete_messages);
+        assert_eq!(rights.can_pin_messages, Some(true));
+
+        let admin = ChatMemberKind::Administrator(Administrator {
+            custom_title: None,
+            is_anonymous: false,
+            can_be_edited: false,
+            can_manage_chat: true,
+            can_change_info: false,
+            can_post_messages: None,
+            can_edit_messages: None,
+            can_delete_messages: true,
+            can_manage_voice_chats: false,
+            can_invite_users: false,
+            can_restrict_members: false,
+            can_pin_messages: Some(false),
+            can_promote_members: false,
+        });
+        let rights = admin.administrator_rights().unwrap();
+        assert!(rights.can_delete_messages);
+        assert_eq!(rights.can_pin_messages, Some(false));
+
+        assert_eq!(ChatMemberKind::Member.administrator_rights(), None);
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::User;
+use crate::types::{ChatAdministratorRights, User};
 
 /// This object contains information about one member of the chat.
 ///
@@ -141,6 +141,142 @@ impl ChatMember {
             ChatMemberKind::Kicked(_) => ChatMemberStatus::Kicked,
         }
     }
+
+    /// Returns `true` if the member is the creator or an administrator of the
+    /// chat.
+    pub fn is_privileged(&self) -> bool {
+        matches!(
+            self.kind,
+            ChatMemberKind::Creator(_) | ChatMemberKind::Administrator(_)
+        )
+    }
+
+    /// Returns `true` if the member is present in the chat, i.e. is not
+    /// [`ChatMemberKind::Left`] or [`ChatMemberKind::Kicked`].
+    pub fn is_present(&self) -> bool {
+        !matches!(self.kind, ChatMemberKind::Left | ChatMemberKind::Kicked(_))
+    }
+
+    /// Returns `true` if the member is the creator of the chat or an
+    /// administrator who can access the chat event log, chat statistics and so on.
+    pub fn can_manage_chat(&self) -> bool {
+        self.has_right(|rights| rights.can_manage_chat)
+    }
+
+    /// Returns `true` if the member is the creator of the chat or an
+    /// administrator who can change the chat title, photo and other settings.
+    pub fn can_change_info(&self) -> bool {
+        self.has_right(|rights| rights.can_change_info)
+    }
+
+    /// Returns `true` if the member is the creator of the chat or an
+    /// administrator who can delete messages of other users.
+    pub fn can_delete_messages(&self) -> bool {
+        self.has_right(|rights| rights.can_delete_messages)
+    }
+
+    /// Returns `true` if the member is the creator of the chat or an
+    /// administrator who can manage voice chats.
+    pub fn can_manage_voice_chats(&self) -> bool {
+        self.has_right(|rights| rights.can_manage_voice_chats)
+    }
+
+    /// Returns `true` if the member is the creator of the chat or an
+    /// administrator who can invite new users to the chat.
+    pub fn can_invite_users(&self) -> bool {
+        self.has_right(|rights| rights.can_invite_users)
+    }
+
+    /// Returns `true` if the member is the creator of the chat or an
+    /// administrator who can restrict, ban or unban chat members.
+    pub fn can_restrict_members(&self) -> bool {
+        self.has_right(|rights| rights.can_restrict_members)
+    }
+
+    /// Returns `true` if the member is the creator of the chat or an
+    /// administrator who can add new administrators.
+    pub fn can_promote_members(&self) -> bool {
+        self.has_right(|rights| rights.can_promote_members)
+    }
+
+    /// Returns `true` if the member is the creator of the chat or an
+    /// administrator who can post messages in the channel.
+    ///
+    /// This right is only applicable to channels.
+    pub fn can_post_messages(&self) -> bool {
+        self.has_right(|rights| rights.can_post_messages.unwrap_or(false))
+    }
+
+    /// Returns `true` if the member is the creator of the chat or an
+    /// administrator who can edit messages of other users in the channel.
+    ///
+    /// This right is only applicable to channels.
+    pub fn can_edit_messages(&self) -> bool {
+        self.has_right(|rights| rights.can_edit_messages.unwrap_or(false))
+    }
+
+    /// Returns `true` if the member is the creator of the chat or an
+    /// administrator who can pin messages.
+    ///
+    /// In channels, this right is implied by [`can_edit_messages`]. Note that
+    /// regular members may also be able to pin messages, if it's allowed by
+    /// the [permissions of the chat], which are not taken into account.
+    ///
+    /// [`can_edit_messages`]: ChatMember::can_edit_messages
+    /// [permissions of the chat]: crate::types::ChatPermissions
+    pub fn can_pin_messages(&self) -> bool {
+        self.has_right(|rights| {
+            rights
+                .can_pin_messages
+                .or(rights.can_edit_messages)
+                .unwrap_or(false)
+        })
+    }
+
+    /// Returns `true` if the member can send text messages, contacts,
+    /// locations and venues.
+    ///
+    /// This is `false` for members who left the chat or were kicked. Note
+    /// that the [permissions of the chat] are not taken into account.
+    ///
+    /// [permissions of the chat]: crate::types::ChatPermissions
+    pub fn can_send_messages(&self) -> bool {
+        match &self.kind {
+            ChatMemberKind::Creator(_)
+            | ChatMemberKind::Administrator(_)
+            | ChatMemberKind::Member => true,
+            ChatMemberKind::Restricted(Restricted {
+                can_send_messages, ..
+            }) => *can_send_messages,
+            ChatMemberKind::Left | ChatMemberKind::Kicked(_) => false,
+        }
+    }
+
+    /// Returns `true` if the member can send audios, documents, photos,
+    /// videos, video notes and voice notes.
+    ///
+    /// See [`can_send_messages`] for more.
+    ///
+    /// [`can_send_messages`]: ChatMember::can_send_messages
+    pub fn can_send_media_messages(&self) -> bool {
+        match &self.kind {
+            ChatMemberKind::Creator(_)
+            | ChatMemberKind::Administrator(_)
+            | ChatMemberKind::Member => true,
+            ChatMemberKind::Restricted(Restricted {
+                can_send_media_messages,
+                ..
+            }) => *can_send_media_messages,
+            ChatMemberKind::Left | ChatMemberKind::Kicked(_) => false,
+        }
+    }
+
+    fn has_right(&self, right: impl FnOnce(ChatAdministratorRights) -> bool) -> bool {
+        match self.kind.administrator_rights() {
+            Some(rights) => right(rights),
+            None => false,
+        }
+    }
 }
 
 impl ChatMemberKind {
@@ -377,6 +513,19 @@ impl ChatMemberKind {
             | Self::Kicked(_) => None,
         }
     }
+
+    /// Returns the rights of the member, if it's an administrator or the
+    /// creator of the chat.
+    pub fn administrator_rights(&self) -> Option<ChatAdministratorRights> {
+        match self {
+            Self::Creator(creator) => Some(ChatAdministratorRights {
+                is_anonymous: creator.is_anonymous,
+                ..ChatAdministratorRights::all()
+            }),
+            Self::Administrator(admin) => Some(admin.into()),
+            Self::Member | Self::Restricted(_) | Self::Left | Self::Kicked(_) => None,
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
@@ -393,6 +542,7 @@ pub enum ChatMemberStatus {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::UserId;
 
     #[test]
     fn deserialize() {
@@ -419,7 +569,7 @@ mod tests {
         }"#;
         let expected = ChatMember {
             user: User {
-                id: 1029940401,
+                id: UserId(1029940401),
                 is_bot: false,
                 first_name: "First".to_string(),
                 last_name: Some("Last".to_string()),
@@ -445,4 +595,104 @@ mod tests {
         let actual = serde_json::from_str::<ChatMember>(&json).unwrap();
         assert_eq!(actual, expected)
     }
+
+    fn member(kind: ChatMemberKind) -> ChatMember {
+        ChatMember {
+            user: User {
+                id: UserId(1),
+                is_bot: false,
+                first_name: "First".to_string(),
+                last_name: None,
+                username: None,
+                language_code: None,
+            },
+            kind,
+        }
+    }
+
+    #[test]
+    fn creator_permissions() {
+        let creator = member(ChatMemberKind::Creator(Creator {
+            custom_title: None,
+            is_anonymous: false,
+        }));
+
+        assert!(creator.is_privileged());
+        assert!(creator.can_delete_messages());
+        assert!(creator.can_restrict_members());
+        assert!(creator.can_promote_members());
+        assert!(creator.can_pin_messages());
+        assert!(creator.can_send_messages());
+    }
+
+    #[test]
+    fn administrator_permissions() {
+        let admin = member(ChatMemberKind::Administrator(Administrator {
+            custom_title: None,
+            is_anonymous: false,
+            can_be_edited: true,
+            can_manage_chat: true,
+            can_change_info: false,
+            can_post_messages: None,
+            can_edit_messages: None,
+            can_delete_messages: true,
+            can_manage_voice_chats: false,
+            can_invite_users: true,
+            can_restrict_members: false,
+            can_pin_messages: Some(true),
+            can_promote_members: false,
+        }));
+
+        assert!(admin.is_privileged());
+        assert!(admin.can_delete_messages());
+        assert!(admin.can_invite_users());
+        assert!(admin.can_pin_messages());
+        assert!(!admin.can_restrict_members());
+        assert!(!admin.can_change_info());
+        assert!(!admin.can_post_messages());
+        assert!(admin.can_send_media_messages());
+    }
+
+    #[test]
+    fn restricted_permissions() {
+        let restricted = member(ChatMemberKind::Restricted(Restricted {
+            until_date: 0,
+            can_send_messages: true,
+            can_send_media_messages: false,
+            can_send_other_messages: false,
+            can_add_web_page_previews: false,
+        }));
+
+        assert!(!restricted.is_privileged());
+        assert!(restricted.is_present());
+        assert!(!restricted.can_delete_messages());
+        assert!(restricted.can_send_messages());
+        assert!(!restricted.can_send_media_messages());
+    }
+
+    #[test]
+    fn kicked_permissions() {
+        let kicked = member(ChatMemberKind::Kicked(Kicked { until_date: 0 }));
+
+        assert!(!kicked.is_privileged());
+        assert!(!kicked.is_present());
+        assert!(!kicked.can_delete_messages());
+        assert!(!kicked.can_send_messages());
+    }
+
+    #[test]
+    fn until_date() {
+        let kicked = ChatMemberKind::Kicked(Kicked { until_date: 0 });
+        assert_eq!(kicked.until_date(), Some(0));
+
+        let restricted = ChatMemberKind::Restricted(Restricted {
+            until_date: 1650000000,
+            can_send_messages: false,
+            can_send_media_messages: false,
+            can_send_other_messages: false,
+            can_add_web_page_previews: false,
+        });
+        assert_eq!(restricted.until_date(), Some(1650000000));
+        assert_eq!(ChatMemberKind::Member.until_date(), None);
+    }
 }
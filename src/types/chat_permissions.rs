@@ -94,3 +94,38 @@ impl ChatPermissions {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        let permissions = ChatPermissions::new()
+            .can_send_messages(true)
+            .can_send_polls(false)
+            .can_pin_messages(true);
+
+        assert_eq!(
+            serde_json::to_string(&permissions).unwrap(),
+            r#"{"can_send_messages":true,"can_send_polls":false,"can_pin_messages":true}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&ChatPermissions::new()).unwrap(),
+            "{}"
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let permissions: ChatPermissions =
+            serde_json::from_str(r#"{"can_send_messages":true,"can_invite_users":false}"#).unwrap();
+
+        assert_eq!(
+            permissions,
+            ChatPermissions::new()
+                .can_send_messages(true)
+                .can_invite_users(false)
+        );
+    }
+}
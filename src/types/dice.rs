@@ -11,11 +11,15 @@ pub struct Dice {
 
     /// Value of the dice.
     ///
-    /// 1-6 for [`DiceEmoji::Dice`] and [`DiceEmoji::Darts`], 1-5 for
-    /// [`DiceEmoji::Basketball`].
+    /// 1-6 for [`DiceEmoji::Dice`], [`DiceEmoji::Darts`] and
+    /// [`DiceEmoji::Bowling`], 1-5 for [`DiceEmoji::Basketball`] and
+    /// [`DiceEmoji::Football`], 1-64 for [`DiceEmoji::SlotMachine`].
     ///
     /// [`DiceEmoji::Dice`]: crate::types::DiceEmoji::Dice
-    /// [`DiceEmoji::Darts`]:crate::types::DiceEmoji::Darts
-    /// [`DiceEmoji::Basketball`]:crate::types::DiceEmoji::Basketball
+    /// [`DiceEmoji::Darts`]: crate::types::DiceEmoji::Darts
+    /// [`DiceEmoji::Bowling`]: crate::types::DiceEmoji::Bowling
+    /// [`DiceEmoji::Basketball`]: crate::types::DiceEmoji::Basketball
+    /// [`DiceEmoji::Football`]: crate::types::DiceEmoji::Football
+    /// [`DiceEmoji::SlotMachine`]: crate::types::DiceEmoji::SlotMachine
     pub value: i32,
 }
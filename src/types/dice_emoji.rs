@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+/// Emoji on which the dice throw animation is based, see [`Dice`].
+///
+/// [`Dice`]: crate::types::Dice
 #[derive(Copy, Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub enum DiceEmoji {
     /// Values from 1-6. Defaults to this variant.
@@ -14,15 +17,36 @@ pub enum DiceEmoji {
     #[serde(rename = "🏀")]
     Basketball,
 
-    /// Values 1-5
+    /// Values from 1-5.
     #[serde(rename = "⚽")]
     Football,
 
-    /// Values 1-5
+    /// Values from 1-6.
     #[serde(rename = "🎳")]
     Bowling,
 
-    /// Values 1-64
+    /// Values from 1-64.
     #[serde(rename = "🎰")]
     SlotMachine,
 }
+
+impl Default for DiceEmoji {
+    fn default() -> Self {
+        Self::Dice
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        assert_eq!(DiceEmoji::default(), DiceEmoji::Dice);
+        assert_eq!(serde_json::to_string(&DiceEmoji::Dice).unwrap(), r#""🎲""#);
+        assert_eq!(
+            serde_json::from_str::<DiceEmoji>(r#""🎳""#).unwrap(),
+            DiceEmoji::Bowling
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use serde::{Deserialize, Serialize};
 
 /// This object represents a file ready to be downloaded.
@@ -30,3 +32,57 @@ pub struct File {
     /// crate::net::Download::download_file
     pub file_path: String,
 }
+
+impl File {
+    /// Returns `true` if [`file_path`] is a path on the local disk.
+    ///
+    /// A local [Bot API server] running with `--local` returns absolute paths
+    /// to files on its disk, they can be read directly (this is what
+    /// [`Download`] methods do in the [local mode]). Otherwise the path is
+    /// relative and the file should be downloaded via HTTP.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::types::File;
+    ///
+    /// # let file = |file_path: &str| File { file_id: String::new(), file_unique_id: String::new(), file_size: 0, file_path: file_path.to_owned() };
+    /// assert!(!file("photos/file_1.jpg").is_local());
+    /// # #[cfg(unix)]
+    /// assert!(file("/var/lib/telegram-bot-api/TOKEN/photos/file_1.jpg").is_local());
+    /// ```
+    ///
+    /// [`file_path`]: File::file_path
+    /// [Bot API server]: https://github.com/tdlib/telegram-bot-api
+    /// [`Download`]: crate::net::Download
+    /// [local mode]: crate::Bot::local_mode
+    pub fn is_local(&self) -> bool {
+        Self::is_local_path(&self.file_path)
+    }
+
+    pub(crate) fn is_local_path(path: &str) -> bool {
+        Path::new(path).is_absolute()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_local() {
+        let file = |file_path: &str| File {
+            file_id: "id".to_owned(),
+            file_unique_id: "uid".to_owned(),
+            file_size: 0,
+            file_path: file_path.to_owned(),
+        };
+
+        assert!(!file("photos/file_1.jpg").is_local());
+        assert!(!file("documents/file_2").is_local());
+        #[cfg(unix)]
+        assert!(file("/var/lib/telegram-bot-api/TOKEN/photos/file_1.jpg").is_local());
+        #[cfg(windows)]
+        assert!(file(r"C:\telegram-bot-api\TOKEN\photos\file_1.jpg").is_local());
+    }
+}
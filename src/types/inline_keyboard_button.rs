@@ -1,4 +1,4 @@
-use crate::types::{CallbackGame, LoginUrl};
+use crate::types::{CallbackGame, LoginUrl, WebAppInfo};
 use serde::{Deserialize, Serialize};
 
 /// This object represents one button of an inline keyboard.
@@ -56,6 +56,13 @@ pub enum InlineKeyboardButtonKind {
     /// [`CallbackQuery`]: crate::types::CallbackQuery
     CallbackData(String),
 
+    /// Description of the [Web App] that will be launched when the user
+    /// presses the button. Available only in private chats between a user
+    /// and the bot.
+    ///
+    /// [Web App]: https://core.telegram.org/bots/webapps
+    WebApp(WebAppInfo),
+
     /// If set, pressing the button will prompt the user to select one of their
     /// chats, open that chat and insert the bot‘s username and the specified
     /// inline query in the input field. Can be empty, in which case just the
@@ -98,6 +105,28 @@ pub enum InlineKeyboardButtonKind {
     Pay(bool),
 }
 
+impl InlineKeyboardButtonKind {
+    /// Maximum length of [`CallbackData`] in bytes.
+    ///
+    /// [`CallbackData`]: InlineKeyboardButtonKind::CallbackData
+    pub const MAX_CALLBACK_DATA_LEN: usize = 64;
+
+    /// Returns length of [`CallbackData`] in bytes or `None` if this is not a
+    /// callback data button.
+    ///
+    /// Note that Telegram rejects buttons with callback data longer than
+    /// [`MAX_CALLBACK_DATA_LEN`] (with an obscure `BUTTON_DATA_INVALID` error).
+    ///
+    /// [`CallbackData`]: InlineKeyboardButtonKind::CallbackData
+    /// [`MAX_CALLBACK_DATA_LEN`]: InlineKeyboardButtonKind::MAX_CALLBACK_DATA_LEN
+    pub fn callback_data_len(&self) -> Option<usize> {
+        match self {
+            Self::CallbackData(data) => Some(data.len()),
+            _ => None,
+        }
+    }
+}
+
 /// Build buttons.
 ///
 /// # Examples
@@ -107,36 +136,110 @@ pub enum InlineKeyboardButtonKind {
 /// let url_button = InlineKeyboardButton::url("Text".to_string(), "http://url.com".to_string());
 /// ```
 impl InlineKeyboardButton {
-    pub fn url(text: String, url: String) -> InlineKeyboardButton {
+    pub fn url<T, U>(text: T, url: U) -> InlineKeyboardButton
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
         InlineKeyboardButton {
-            text,
-            kind: InlineKeyboardButtonKind::Url(url),
+            text: text.into(),
+            kind: InlineKeyboardButtonKind::Url(url.into()),
         }
     }
 
-    pub fn callback(text: String, callback_data: String) -> InlineKeyboardButton {
+    /// Creates a callback data button.
+    ///
+    /// ## Panics
+    ///
+    /// In debug builds, if `callback_data` is longer than
+    /// [`InlineKeyboardButtonKind::MAX_CALLBACK_DATA_LEN`] bytes, since
+    /// Telegram would reject such button anyway.
+    pub fn callback<T, C>(text: T, callback_data: C) -> InlineKeyboardButton
+    where
+        T: Into<String>,
+        C: Into<String>,
+    {
+        let callback_data = callback_data.into();
+        debug_assert!(
+            callback_data.len() <= InlineKeyboardButtonKind::MAX_CALLBACK_DATA_LEN,
+            "callback data must be at most {} bytes long, got {} bytes",
+            InlineKeyboardButtonKind::MAX_CALLBACK_DATA_LEN,
+            callback_data.len(),
+        );
+
         InlineKeyboardButton {
-            text,
+            text: text.into(),
             kind: InlineKeyboardButtonKind::CallbackData(callback_data),
         }
     }
 
-    pub fn switch_inline_query(text: String, switch_inline_query: String) -> InlineKeyboardButton {
+    pub fn web_app<T>(text: T, web_app: WebAppInfo) -> InlineKeyboardButton
+    where
+        T: Into<String>,
+    {
+        InlineKeyboardButton {
+            text: text.into(),
+            kind: InlineKeyboardButtonKind::WebApp(web_app),
+        }
+    }
+
+    pub fn switch_inline_query<T, Q>(text: T, switch_inline_query: Q) -> InlineKeyboardButton
+    where
+        T: Into<String>,
+        Q: Into<String>,
+    {
         InlineKeyboardButton {
-            text,
-            kind: InlineKeyboardButtonKind::SwitchInlineQuery(switch_inline_query),
+            text: text.into(),
+            kind: InlineKeyboardButtonKind::SwitchInlineQuery(switch_inline_query.into()),
         }
     }
 
-    pub fn switch_inline_query_current_chat(
-        text: String,
-        switch_inline_query_current_chat: String,
-    ) -> InlineKeyboardButton {
+    pub fn switch_inline_query_current_chat<T, Q>(
+        text: T,
+        switch_inline_query_current_chat: Q,
+    ) -> InlineKeyboardButton
+    where
+        T: Into<String>,
+        Q: Into<String>,
+    {
         InlineKeyboardButton {
-            text,
+            text: text.into(),
             kind: InlineKeyboardButtonKind::SwitchInlineQueryCurrentChat(
-                switch_inline_query_current_chat,
+                switch_inline_query_current_chat.into(),
             ),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn callback_data_len() {
+        let button = InlineKeyboardButton::callback("text", "дата");
+        assert_eq!(button.kind.callback_data_len(), Some(8));
+
+        let button = InlineKeyboardButton::url("text", "https://example.com");
+        assert_eq!(button.kind.callback_data_len(), None);
+    }
+
+    #[test]
+    fn web_app() {
+        let button = InlineKeyboardButton::web_app("Open", WebAppInfo::new("https://example.com"));
+        let json = r#"{"text":"Open","web_app":{"url":"https://example.com"}}"#;
+
+        assert_eq!(serde_json::to_string(&button).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<InlineKeyboardButton>(json).unwrap(),
+            button
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "callback data must be at most 64 bytes long")]
+    fn callback_data_too_long() {
+        InlineKeyboardButton::callback("text", "a".repeat(65));
+    }
+}
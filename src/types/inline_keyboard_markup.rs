@@ -72,6 +72,32 @@ impl InlineKeyboardMarkup {
         };
         self
     }
+
+    /// Rearranges all buttons into rows of `n` buttons (the last row may be
+    /// shorter).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+    ///
+    /// let buttons = (1..=5).map(|i| InlineKeyboardButton::callback(i.to_string(), i.to_string()));
+    /// let keyboard = InlineKeyboardMarkup::default().append_row(buttons).columns(2);
+    ///
+    /// let widths: Vec<_> = keyboard.inline_keyboard.iter().map(Vec::len).collect();
+    /// assert_eq!(widths, [2, 2, 1]);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// If `n` is `0`.
+    pub fn columns(mut self, n: usize) -> Self {
+        assert_ne!(n, 0, "number of columns must be positive");
+
+        let buttons: Vec<_> = self.inline_keyboard.drain(..).flatten().collect();
+        self.inline_keyboard = buttons.chunks(n).map(<[_]>::to_vec).collect();
+        self
+    }
 }
 
 #[cfg(test)]
@@ -124,4 +150,22 @@ mod tests {
 
         assert_eq!(markup, expected);
     }
+
+    #[test]
+    fn columns() {
+        let button1 = InlineKeyboardButton::url("text 1", "url 1");
+        let button2 = InlineKeyboardButton::url("text 2", "url 2");
+        let button3 = InlineKeyboardButton::url("text 3", "url 3");
+
+        let markup = InlineKeyboardMarkup::default()
+            .append_row(vec![button1.clone()])
+            .append_row(vec![button2.clone(), button3.clone()])
+            .columns(2);
+
+        let expected = InlineKeyboardMarkup {
+            inline_keyboard: vec![vec![button1, button2], vec![button3]],
+        };
+
+        assert_eq!(markup, expected);
+    }
 }
@@ -56,7 +56,8 @@ pub enum InlineQueryResult {
 mod tests {
     use crate::types::{
         inline_keyboard_markup::InlineKeyboardMarkup, parse_mode::ParseMode, InlineQueryResult,
-        InlineQueryResultCachedAudio, InputMessageContent, InputMessageContentText,
+        InlineQueryResultArticle, InlineQueryResultCachedAudio, InputMessageContent,
+        InputMessageContentText, LinkPreviewOptions,
     };
 
     #[test]
@@ -89,6 +90,7 @@ mod tests {
                 message_text: String::from("message_text"),
                 parse_mode: Some(ParseMode::MarkdownV2),
                 disable_web_page_preview: Some(true),
+                link_preview_options: None,
                 entities: None,
             })),
             caption_entities: None,
@@ -100,5 +102,26 @@ mod tests {
         assert_eq!(expected_json, actual_json);
     }
 
+    #[test]
+    fn article_text_serialize() {
+        let structure = InlineQueryResult::Article(
+            InlineQueryResultArticle::new(
+                "id",
+                "title",
+                InputMessageContent::Text(
+                    InputMessageContentText::new("<b>text</b>")
+                        .parse_mode(ParseMode::Html)
+                        .link_preview_options(LinkPreviewOptions::new().show_above_text(true)),
+                ),
+            )
+            .description("description"),
+        );
+
+        let expected_json = r#"{"type":"article","id":"id","title":"title","input_message_content":{"message_text":"<b>text</b>","parse_mode":"HTML","link_preview_options":{"show_above_text":true}},"description":"description"}"#;
+        let actual_json = serde_json::to_string(&structure).unwrap();
+
+        assert_eq!(expected_json, actual_json);
+    }
+
     // TODO: Add more tests
 }
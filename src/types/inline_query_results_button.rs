@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::WebAppInfo;
+
+/// This object represents a button to be shown above inline query results.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inlinequeryresultsbutton).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct InlineQueryResultsButton {
+    /// Label text on the button.
+    pub text: String,
+
+    #[serde(flatten)]
+    pub kind: InlineQueryResultsButtonKind,
+}
+
+impl InlineQueryResultsButton {
+    pub fn new<S>(text: S, kind: InlineQueryResultsButtonKind) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            text: text.into(),
+            kind,
+        }
+    }
+
+    /// Creates a button which launches a [Web App].
+    ///
+    /// [Web App]: https://core.telegram.org/bots/webapps
+    pub fn web_app<T>(text: T, web_app: WebAppInfo) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(text, InlineQueryResultsButtonKind::WebApp(web_app))
+    }
+
+    /// Creates a button which switches the user to a private chat with the
+    /// bot and sends it a `/start` message with the `start_parameter`.
+    pub fn start_parameter<T, P>(text: T, start_parameter: P) -> Self
+    where
+        T: Into<String>,
+        P: Into<String>,
+    {
+        Self::new(
+            text,
+            InlineQueryResultsButtonKind::StartParameter(start_parameter.into()),
+        )
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InlineQueryResultsButtonKind {
+    /// Description of the [Web App] that will be launched when the user
+    /// presses the button. The Web App will be able to switch back to the
+    /// inline mode using the method `switchInlineQuery` inside the Web App.
+    ///
+    /// [Web App]: https://core.telegram.org/bots/webapps
+    WebApp(WebAppInfo),
+
+    /// [Deep-linking] parameter for the `/start` message sent to the bot when
+    /// a user presses the button. 1-64 characters, only `A-Z`, `a-z`, `0-9`,
+    /// `_` and `-` are allowed.
+    ///
+    /// _Example_: An inline bot that sends YouTube videos can ask the user to
+    /// connect the bot to their YouTube account to adapt search results
+    /// accordingly. To do this, it displays a 'Connect your YouTube account'
+    /// button above the results, or even before showing any. The user presses
+    /// the button, switches to a private chat with the bot and, in doing so,
+    /// passes a start parameter that instructs the bot to return an OAuth
+    /// link. Once done, the bot can offer a [`SwitchInlineQuery`] button so
+    /// that the user can easily return to the chat where they wanted to use
+    /// the bot's inline capabilities.
+    ///
+    /// [Deep-linking]: https://core.telegram.org/bots/features#deep-linking
+    /// [`SwitchInlineQuery`]: crate::types::InlineKeyboardButtonKind::SwitchInlineQuery
+    StartParameter(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        let button = InlineQueryResultsButton::start_parameter("Connect", "connect_youtube");
+        assert_eq!(
+            serde_json::to_string(&button).unwrap(),
+            r#"{"text":"Connect","start_parameter":"connect_youtube"}"#
+        );
+
+        let button =
+            InlineQueryResultsButton::web_app("Open", WebAppInfo::new("https://example.com"));
+        assert_eq!(
+            serde_json::to_string(&button).unwrap(),
+            r#"{"text":"Open","web_app":{"url":"https://example.com"}}"#
+        );
+    }
+}
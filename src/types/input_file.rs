@@ -1,10 +1,46 @@
-use serde::{Deserialize, Serialize};
+use bytes::Bytes;
+use futures::{Stream, TryStreamExt};
+use mime::Mime;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use std::{borrow::Cow, path::PathBuf};
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    fmt,
+    hash::{Hash, Hasher},
+    io,
+    path::PathBuf,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+};
 
 /// This object represents the contents of a file to be uploaded.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#inputfile).
+///
+/// ## File names and content types
+///
+/// Files are uploaded as parts of a `multipart/form-data` request. The file
+/// name of the part is the name of the file ([`InputFile::File`]) or the name
+/// given to [`InputFile::memory`] or [`InputFile::read`]. The content type of
+/// the part can be set with [`InputFile::mime`], otherwise it's not sent.
+///
+/// Telegram detects types of photos, videos, audios, voice messages,
+/// animations and stickers by their contents, so for [`SendPhoto`],
+/// [`SendVideo`], [`SendAudio`], [`SendVoice`], [`SendAnimation`] and sticker
+/// methods neither the file name nor the content type matter much. For
+/// [`SendDocument`] the file name (and its extension) is shown to users, and
+/// it's used alongside with the content type to determine the `mime_type` of
+/// the resulting [`Document`]. So it's recommended to always specify a file
+/// name with a correct extension.
+///
+/// [`SendPhoto`]: crate::payloads::SendPhoto
+/// [`SendVideo`]: crate::payloads::SendVideo
+/// [`SendAudio`]: crate::payloads::SendAudio
+/// [`SendVoice`]: crate::payloads::SendVoice
+/// [`SendAnimation`]: crate::payloads::SendAnimation
+/// [`SendDocument`]: crate::payloads::SendDocument
+/// [`Document`]: crate::types::Document
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum InputFile {
@@ -12,9 +48,12 @@ pub enum InputFile {
     Memory {
         file_name: String,
         data: Cow<'static, [u8]>,
+        #[serde(default, with = "crate::types::non_telegram_types::mime::opt_deser")]
+        mime: Option<Mime>,
     },
     Url(String),
     FileId(String),
+    Read(InputFileStream),
 }
 
 impl InputFile {
@@ -33,7 +72,88 @@ impl InputFile {
         Self::Memory {
             file_name: file_name.into(),
             data: data.into(),
+            mime: None,
+        }
+    }
+
+    /// Creates a file which is read from `stream` while uploading, without
+    /// buffering it in memory.
+    ///
+    /// The stream can be read only once, so a request with this file can be
+    /// sent only once (clones of the file share the same stream).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use bytes::Bytes;
+    /// use futures::stream;
+    /// use teloxide_core::types::InputFile;
+    ///
+    /// let chunks = vec![Ok::<_, std::io::Error>(Bytes::from("Hello, ")), Ok(Bytes::from("world!"))];
+    /// let file = InputFile::read("hello.txt", stream::iter(chunks));
+    /// ```
+    pub fn read<N, S, B, E>(file_name: N, stream: S) -> Self
+    where
+        N: Into<String>,
+        S: Stream<Item = Result<B, E>> + Send + Sync + 'static,
+        B: Into<Bytes> + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync>> + 'static,
+    {
+        Self::Read(InputFileStream::new(file_name.into(), None, stream))
+    }
+
+    /// Same as [`InputFile::read`], but also sets the length of the file, so
+    /// it's sent as the `Content-Length` of the multipart part.
+    pub fn read_with_len<N, S, B, E>(file_name: N, stream: S, len: u64) -> Self
+    where
+        N: Into<String>,
+        S: Stream<Item = Result<B, E>> + Send + Sync + 'static,
+        B: Into<Bytes> + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync>> + 'static,
+    {
+        Self::Read(InputFileStream::new(file_name.into(), Some(len), stream))
+    }
+
+    /// Sets the file name.
+    ///
+    /// Only affects [`InputFile::Memory`] and [`InputFile::Read`], the name of
+    /// [`InputFile::File`] is always the name of the file on the disk.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::types::InputFile;
+    ///
+    /// # let bytes = vec![];
+    /// let file = InputFile::memory("file", bytes)
+    ///     .file_name("a.png")
+    ///     .mime(mime::IMAGE_PNG);
+    /// ```
+    pub fn file_name<S>(mut self, name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        match &mut self {
+            Self::Memory { file_name, .. } => *file_name = name.into(),
+            Self::Read(stream) => stream.meta().file_name = name.into(),
+            Self::File(_) | Self::Url(_) | Self::FileId(_) => {}
+        }
+
+        self
+    }
+
+    /// Sets the content type of the file.
+    ///
+    /// Only affects [`InputFile::Memory`] and [`InputFile::Read`]. See also
+    /// [File names and content types](InputFile#file-names-and-content-types).
+    pub fn mime(mut self, mime_type: Mime) -> Self {
+        match &mut self {
+            Self::Memory { mime, .. } => *mime = Some(mime_type),
+            Self::Read(stream) => stream.meta().mime = Some(mime_type),
+            Self::File(_) | Self::Url(_) | Self::FileId(_) => {}
         }
+
+        self
     }
 
     pub fn url<T>(url: T) -> Self
@@ -72,6 +192,172 @@ impl InputFile {
     }
 }
 
+type ByteStream = Pin<
+    Box<dyn Stream<Item = Result<Bytes, Box<dyn std::error::Error + Send + Sync>>> + Send + Sync>,
+>;
+
+/// A file read from a stream, see [`InputFile::read`].
+///
+/// Note: clones share not only the stream, but also the file name and the
+/// content type.
+#[derive(Clone)]
+pub struct InputFileStream(Arc<StreamInner>);
+
+struct StreamInner {
+    len: Option<u64>,
+    meta: Mutex<StreamMeta>,
+    stream: Mutex<Option<ByteStream>>,
+}
+
+struct StreamMeta {
+    file_name: String,
+    mime: Option<Mime>,
+}
+
+// Multipart forms are built with serde (see `serde_multipart`), but streams
+// can't be serialized. So while a form is built (see `with_streams`), streams
+// are collected here and serialized as their indices, which are then used to
+// find them. Outside of `with_streams` nothing is collected.
+thread_local! {
+    static STREAMS: RefCell<Option<Vec<InputFileStream>>> = RefCell::new(None);
+}
+
+/// Calls `f`, collecting streams serialized by it, so they can be found by
+/// [`InputFileStream::from_index`]. The streams are released when `f`
+/// returns.
+pub(crate) fn with_streams<R>(f: impl FnOnce() -> R) -> R {
+    struct Guard(Option<Vec<InputFileStream>>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            let outer = self.0.take();
+            STREAMS.with(|streams| *streams.borrow_mut() = outer);
+        }
+    }
+
+    let _guard = Guard(STREAMS.with(|streams| streams.borrow_mut().replace(Vec::new())));
+    f()
+}
+
+impl InputFileStream {
+    fn new<S, B, E>(file_name: String, len: Option<u64>, stream: S) -> Self
+    where
+        S: Stream<Item = Result<B, E>> + Send + Sync + 'static,
+        B: Into<Bytes> + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync>> + 'static,
+    {
+        let stream: ByteStream = Box::pin(stream.map_ok(Into::into).map_err(Into::into));
+        Self(Arc::new(StreamInner {
+            len,
+            meta: Mutex::new(StreamMeta {
+                file_name,
+                mime: None,
+            }),
+            stream: Mutex::new(Some(stream)),
+        }))
+    }
+
+    /// Returns the name of the file.
+    pub fn file_name(&self) -> String {
+        self.meta().file_name.clone()
+    }
+
+    /// Returns the content type of the file, if it's set.
+    pub fn mime(&self) -> Option<Mime> {
+        self.meta().mime.clone()
+    }
+
+    /// Returns the length of the file, if it's known.
+    pub fn content_length(&self) -> Option<u64> {
+        self.0.len
+    }
+
+    /// Finds a stream by the index it was serialized as inside of
+    /// [`with_streams`].
+    pub(crate) fn from_index(index: &str) -> Option<Self> {
+        let index: usize = index.parse().ok()?;
+        STREAMS.with(|streams| streams.borrow().as_ref()?.get(index).cloned())
+    }
+
+    fn meta(&self) -> MutexGuard<'_, StreamMeta> {
+        // Metadata is only replaced as a whole, so it's ok to ignore poisoning.
+        self.0.meta.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn take(&self) -> io::Result<ByteStream> {
+        self.0
+            .stream
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .take()
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "the stream of `InputFile::Read` was already read",
+                )
+            })
+    }
+}
+
+impl fmt::Debug for InputFileStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let meta = self.meta();
+        f.debug_struct("InputFileStream")
+            .field("file_name", &meta.file_name)
+            .field("len", &self.0.len)
+            .field("mime", &meta.mime)
+            .finish()
+    }
+}
+
+impl PartialEq for InputFileStream {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for InputFileStream {}
+
+impl Hash for InputFileStream {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (Arc::as_ptr(&self.0) as usize).hash(state)
+    }
+}
+
+impl Serialize for InputFileStream {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let index = STREAMS.with(|streams| {
+            let streams = &mut *streams.borrow_mut();
+            streams.as_mut().map(|streams| {
+                streams.push(self.clone());
+                streams.len() - 1
+            })
+        });
+
+        match index {
+            Some(index) => serializer.serialize_str(&index.to_string()),
+            // Not building a multipart form (e.g. serializing to JSON for logs), so
+            // there is nothing better to serialize than the file name
+            None => serializer.serialize_str(&self.file_name()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for InputFileStream {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let index = String::deserialize(deserializer)?;
+        Self::from_index(&index).ok_or_else(|| {
+            serde::de::Error::custom("streams can only be deserialized while building a form")
+        })
+    }
+}
+
 impl From<InputFile> for Option<PathBuf> {
     fn from(file: InputFile) -> Self {
         match file {
@@ -117,8 +403,55 @@ impl InputFile {
 
                 Ok(Part::stream(Body::wrap_stream(file)).file_name(file_name))
             }
-            Self::Memory { file_name, data } => Ok(Part::bytes(data).file_name(file_name)),
+            Self::Memory {
+                file_name,
+                data,
+                mime,
+            } => with_mime(Part::bytes(data).file_name(file_name), mime),
+            Self::Read(stream) => {
+                let body = Body::wrap_stream(stream.take()?);
+                let part = match stream.content_length() {
+                    Some(len) => Part::stream_with_length(body, len),
+                    None => Part::stream(body),
+                };
+
+                with_mime(part.file_name(stream.file_name()), stream.mime())
+            }
             Self::Url(s) | Self::FileId(s) => Ok(Part::text(s)),
         }
     }
+
+    /// Returns the length, the file name and the content type of the part
+    /// which [`InputFile::into_part`] creates, or `None` if the length is
+    /// unknown.
+    pub(crate) fn part_meta(&self) -> Option<(u64, Option<String>, Option<Mime>)> {
+        match self {
+            Self::File(path) => {
+                let len = std::fs::metadata(path).ok()?.len();
+                let file_name = path.file_name()?.to_string_lossy().into_owned();
+
+                Some((len, Some(file_name), None))
+            }
+            Self::Memory {
+                file_name,
+                data,
+                mime,
+            } => Some((data.len() as u64, Some(file_name.clone()), mime.clone())),
+            Self::Read(stream) => Some((
+                stream.content_length()?,
+                Some(stream.file_name()),
+                stream.mime(),
+            )),
+            Self::Url(s) | Self::FileId(s) => Some((s.len() as u64, None, None)),
+        }
+    }
+}
+
+fn with_mime(part: Part, mime: Option<Mime>) -> std::io::Result<Part> {
+    match mime {
+        Some(mime) => part
+            .mime_str(mime.as_ref())
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err)),
+        None => Ok(part),
+    }
 }
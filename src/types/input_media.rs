@@ -39,6 +39,9 @@ pub struct InputMediaPhoto {
     /// List of special entities that appear in the caption, which can be
     /// specified instead of `parse_mode`.
     pub caption_entities: Option<Vec<MessageEntity>>,
+
+    /// Pass `true` if the photo needs to be covered with a spoiler animation.
+    pub has_spoiler: Option<bool>,
 }
 
 impl InputMediaPhoto {
@@ -48,6 +51,7 @@ impl InputMediaPhoto {
             caption: None,
             parse_mode: None,
             caption_entities: None,
+            has_spoiler: None,
         }
     }
 
@@ -68,6 +72,11 @@ impl InputMediaPhoto {
         self.parse_mode = Some(val);
         self
     }
+
+    pub const fn has_spoiler(mut self, val: bool) -> Self {
+        self.has_spoiler = Some(val);
+        self
+    }
 }
 
 /// Represents a video to be sent.
@@ -112,6 +121,9 @@ pub struct InputMediaVideo {
 
     /// Pass `true`, if the uploaded video is suitable for streaming.
     pub supports_streaming: Option<bool>,
+
+    /// Pass `true` if the video needs to be covered with a spoiler animation.
+    pub has_spoiler: Option<bool>,
 }
 
 impl InputMediaVideo {
@@ -126,6 +138,7 @@ impl InputMediaVideo {
             height: None,
             duration: None,
             supports_streaming: None,
+            has_spoiler: None,
         }
     }
 
@@ -179,6 +192,11 @@ impl InputMediaVideo {
         self.supports_streaming = Some(val);
         self
     }
+
+    pub const fn has_spoiler(mut self, val: bool) -> Self {
+        self.has_spoiler = Some(val);
+        self
+    }
 }
 
 /// Represents an animation file (GIF or H.264/MPEG-4 AVC video without
@@ -221,6 +239,10 @@ pub struct InputMediaAnimation {
 
     /// Animation duration.
     pub duration: Option<u16>,
+
+    /// Pass `true` if the animation needs to be covered with a spoiler
+    /// animation.
+    pub has_spoiler: Option<bool>,
 }
 
 impl InputMediaAnimation {
@@ -234,6 +256,7 @@ impl InputMediaAnimation {
             height: None,
             duration: None,
             caption_entities: None,
+            has_spoiler: None,
         }
     }
 
@@ -282,6 +305,11 @@ impl InputMediaAnimation {
         self.duration = Some(val);
         self
     }
+
+    pub const fn has_spoiler(mut self, val: bool) -> Self {
+        self.has_spoiler = Some(val);
+        self
+    }
 }
 
 /// Represents an audio file to be treated as music to be sent.
@@ -486,6 +514,37 @@ impl From<InputMedia> for InputFile {
 }
 
 impl InputMedia {
+    /// Returns the caption and its parse mode.
+    pub(crate) fn caption(&self) -> (Option<&str>, Option<ParseMode>) {
+        match self {
+            InputMedia::Photo(InputMediaPhoto {
+                caption,
+                parse_mode,
+                ..
+            })
+            | InputMedia::Document(InputMediaDocument {
+                caption,
+                parse_mode,
+                ..
+            })
+            | InputMedia::Audio(InputMediaAudio {
+                caption,
+                parse_mode,
+                ..
+            })
+            | InputMedia::Animation(InputMediaAnimation {
+                caption,
+                parse_mode,
+                ..
+            })
+            | InputMedia::Video(InputMediaVideo {
+                caption,
+                parse_mode,
+                ..
+            }) => (caption.as_deref(), *parse_mode),
+        }
+    }
+
     #[allow(dead_code)]
     pub(crate) fn media(&self) -> &InputFile {
         match self {
@@ -502,6 +561,20 @@ impl InputMedia {
 mod tests {
     use super::*;
 
+    #[test]
+    fn has_spoiler() {
+        let photo = InputMedia::Photo(InputMediaPhoto::new(InputFile::file_id("123456")));
+        assert!(!serde_json::to_string(&photo)
+            .unwrap()
+            .contains("has_spoiler"));
+
+        let video =
+            InputMedia::Video(InputMediaVideo::new(InputFile::file_id("123456")).has_spoiler(true));
+        assert!(serde_json::to_string(&video)
+            .unwrap()
+            .contains(r#""has_spoiler":true"#));
+    }
+
     #[test]
     fn photo_serialize() {
         let expected_json = r#"{"type":"photo","media":{"FileId":"123456"}}"#;
@@ -510,6 +583,7 @@ mod tests {
             caption: None,
             parse_mode: None,
             caption_entities: None,
+            has_spoiler: None,
         });
 
         let actual_json = serde_json::to_string(&photo).unwrap();
@@ -529,6 +603,7 @@ mod tests {
             duration: None,
             supports_streaming: None,
             caption_entities: None,
+            has_spoiler: None,
         });
 
         let actual_json = serde_json::to_string(&video).unwrap();
@@ -547,6 +622,7 @@ mod tests {
             height: None,
             duration: None,
             caption_entities: None,
+            has_spoiler: None,
         });
 
         let actual_json = serde_json::to_string(&video).unwrap();
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::{MessageEntity, ParseMode};
+use crate::types::{LabeledPrice, LinkPreviewOptions, MessageEntity, ParseMode};
 
 /// This object represents the content of a message to be sent as a result of an
 /// inline query.
@@ -13,6 +13,7 @@ pub enum InputMessageContent {
     Location(InputMessageContentLocation),
     Venue(InputMessageContentVenue),
     Contact(InputMessageContentContact),
+    Invoice(InputMessageContentInvoice),
 }
 /// Represents the content of a text message to be sent as the result of an
 /// inline query.
@@ -35,7 +36,20 @@ pub struct InputMessageContentText {
     pub entities: Option<Vec<MessageEntity>>,
 
     /// Disables link previews for links in the sent message.
+    ///
+    /// Can't be used together with [`link_preview_options`], Telegram rejects
+    /// requests which set both.
+    ///
+    /// [`link_preview_options`]: InputMessageContentText::link_preview_options
     pub disable_web_page_preview: Option<bool>,
+
+    /// Link preview generation options for the message.
+    ///
+    /// Can't be used together with [`disable_web_page_preview`], Telegram
+    /// rejects requests which set both.
+    ///
+    /// [`disable_web_page_preview`]: InputMessageContentText::disable_web_page_preview
+    pub link_preview_options: Option<LinkPreviewOptions>,
 }
 
 impl InputMessageContentText {
@@ -47,6 +61,7 @@ impl InputMessageContentText {
             message_text: message_text.into(),
             parse_mode: None,
             disable_web_page_preview: None,
+            link_preview_options: None,
             entities: None,
         }
     }
@@ -76,6 +91,11 @@ impl InputMessageContentText {
         self.disable_web_page_preview = Some(val);
         self
     }
+
+    pub fn link_preview_options(mut self, val: LinkPreviewOptions) -> Self {
+        self.link_preview_options = Some(val);
+        self
+    }
 }
 
 /// Represents the content of a location message to be sent as the result of an
@@ -297,6 +317,259 @@ impl InputMessageContentContact {
     }
 }
 
+/// Represents the content of an invoice message to be sent as the result of an
+/// inline query.
+#[serde_with_macros::skip_serializing_none]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct InputMessageContentInvoice {
+    /// Product name, 1-32 characters.
+    pub title: String,
+
+    /// Product description, 1-255 characters.
+    pub description: String,
+
+    /// Bot-defined invoice payload, 1-128 bytes. This will not be displayed
+    /// to the user, use for your internal processes.
+    pub payload: String,
+
+    /// Payment provider token, obtained via [@Botfather].
+    ///
+    /// [@Botfather]: https://t.me/Botfather
+    pub provider_token: String,
+
+    /// Three-letter ISO 4217 currency code, see [more on currencies].
+    ///
+    /// [more on currencies]: https://core.telegram.org/bots/payments#supported-currencies
+    pub currency: String,
+
+    /// Price breakdown, a list of components (e.g. product price, tax,
+    /// discount, delivery cost, delivery tax, bonus, etc.).
+    pub prices: Vec<LabeledPrice>,
+
+    /// The maximum accepted amount for tips in the smallest units of the
+    /// currency (integer, not float/double). Defaults to 0.
+    pub max_tip_amount: Option<u32>,
+
+    /// An array of suggested amounts of tip in the smallest units of the
+    /// currency (integer, not float/double). At most 4 suggested tip amounts
+    /// can be specified. The suggested tip amounts must be positive, passed
+    /// in a strictly increased order and must not exceed `max_tip_amount`.
+    pub suggested_tip_amounts: Option<Vec<u32>>,
+
+    /// A JSON-serialized object for data about the invoice, which will be
+    /// shared with the payment provider. A detailed description of the
+    /// required fields should be provided by the payment provider.
+    pub provider_data: Option<String>,
+
+    /// URL of the product photo for the invoice. Can be a photo of the goods
+    /// or a marketing image for a service. People like it better when they
+    /// see what they are paying for.
+    pub photo_url: Option<String>,
+
+    /// Photo size.
+    pub photo_size: Option<u32>,
+
+    /// Photo width.
+    pub photo_width: Option<u32>,
+
+    /// Photo height.
+    pub photo_height: Option<u32>,
+
+    /// Pass `true`, if you require the user's full name to complete the
+    /// order.
+    pub need_name: Option<bool>,
+
+    /// Pass `true`, if you require the user's phone number to complete the
+    /// order.
+    pub need_phone_number: Option<bool>,
+
+    /// Pass `true`, if you require the user's email address to complete the
+    /// order.
+    pub need_email: Option<bool>,
+
+    /// Pass `true`, if you require the user's shipping address to complete
+    /// the order.
+    pub need_shipping_address: Option<bool>,
+
+    /// Pass `true`, if user's phone number should be sent to provider.
+    pub send_phone_number_to_provider: Option<bool>,
+
+    /// Pass `true`, if user's email address should be sent to provider.
+    pub send_email_to_provider: Option<bool>,
+
+    /// Pass `true`, if the final price depends on the shipping method.
+    pub is_flexible: Option<bool>,
+}
+
+impl InputMessageContentInvoice {
+    pub fn new<T, D, P, PT, C, Pr>(
+        title: T,
+        description: D,
+        payload: P,
+        provider_token: PT,
+        currency: C,
+        prices: Pr,
+    ) -> Self
+    where
+        T: Into<String>,
+        D: Into<String>,
+        P: Into<String>,
+        PT: Into<String>,
+        C: Into<String>,
+        Pr: IntoIterator<Item = LabeledPrice>,
+    {
+        Self {
+            title: title.into(),
+            description: description.into(),
+            payload: payload.into(),
+            provider_token: provider_token.into(),
+            currency: currency.into(),
+            prices: prices.into_iter().collect(),
+            max_tip_amount: None,
+            suggested_tip_amounts: None,
+            provider_data: None,
+            photo_url: None,
+            photo_size: None,
+            photo_width: None,
+            photo_height: None,
+            need_name: None,
+            need_phone_number: None,
+            need_email: None,
+            need_shipping_address: None,
+            send_phone_number_to_provider: None,
+            send_email_to_provider: None,
+            is_flexible: None,
+        }
+    }
+
+    pub fn title<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.title = val.into();
+        self
+    }
+
+    pub fn description<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.description = val.into();
+        self
+    }
+
+    pub fn payload<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.payload = val.into();
+        self
+    }
+
+    pub fn provider_token<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.provider_token = val.into();
+        self
+    }
+
+    pub fn currency<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.currency = val.into();
+        self
+    }
+
+    pub fn prices<P>(mut self, val: P) -> Self
+    where
+        P: IntoIterator<Item = LabeledPrice>,
+    {
+        self.prices = val.into_iter().collect();
+        self
+    }
+
+    pub fn max_tip_amount(mut self, val: u32) -> Self {
+        self.max_tip_amount = Some(val);
+        self
+    }
+
+    pub fn suggested_tip_amounts<T>(mut self, val: T) -> Self
+    where
+        T: IntoIterator<Item = u32>,
+    {
+        self.suggested_tip_amounts = Some(val.into_iter().collect());
+        self
+    }
+
+    pub fn provider_data<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.provider_data = Some(val.into());
+        self
+    }
+
+    pub fn photo_url<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.photo_url = Some(val.into());
+        self
+    }
+
+    pub fn photo_size(mut self, val: u32) -> Self {
+        self.photo_size = Some(val);
+        self
+    }
+
+    pub fn photo_width(mut self, val: u32) -> Self {
+        self.photo_width = Some(val);
+        self
+    }
+
+    pub fn photo_height(mut self, val: u32) -> Self {
+        self.photo_height = Some(val);
+        self
+    }
+
+    pub fn need_name(mut self, val: bool) -> Self {
+        self.need_name = Some(val);
+        self
+    }
+
+    pub fn need_phone_number(mut self, val: bool) -> Self {
+        self.need_phone_number = Some(val);
+        self
+    }
+
+    pub fn need_email(mut self, val: bool) -> Self {
+        self.need_email = Some(val);
+        self
+    }
+
+    pub fn need_shipping_address(mut self, val: bool) -> Self {
+        self.need_shipping_address = Some(val);
+        self
+    }
+
+    pub fn send_phone_number_to_provider(mut self, val: bool) -> Self {
+        self.send_phone_number_to_provider = Some(val);
+        self
+    }
+
+    pub fn send_email_to_provider(mut self, val: bool) -> Self {
+        self.send_email_to_provider = Some(val);
+        self
+    }
+
+    pub fn is_flexible(mut self, val: bool) -> Self {
+        self.is_flexible = Some(val);
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -308,6 +581,7 @@ mod tests {
             message_text: String::from("text"),
             parse_mode: None,
             disable_web_page_preview: None,
+            link_preview_options: None,
             entities: None,
         });
 
@@ -362,4 +636,52 @@ mod tests {
         let actual_json = serde_json::to_string(&contact_content).unwrap();
         assert_eq!(expected_json, actual_json);
     }
+
+    #[test]
+    fn text_link_preview_options_serialize() {
+        let text_content = InputMessageContent::Text(
+            InputMessageContentText::new("text")
+                .link_preview_options(LinkPreviewOptions::new().is_disabled(true)),
+        );
+
+        assert_eq!(
+            serde_json::to_string(&text_content).unwrap(),
+            r#"{"message_text":"text","link_preview_options":{"is_disabled":true}}"#
+        );
+    }
+
+    #[test]
+    fn text_link_preview_conflict() {
+        // Telegram reports the conflict, so both fields are sent as is
+        let text_content = InputMessageContentText::new("text")
+            .disable_web_page_preview(true)
+            .link_preview_options(LinkPreviewOptions::new());
+
+        assert_eq!(
+            serde_json::to_string(&text_content).unwrap(),
+            r#"{"message_text":"text","disable_web_page_preview":true,"link_preview_options":{}}"#
+        );
+    }
+
+    #[test]
+    fn invoice_serialize() {
+        let invoice_content = InputMessageContent::Invoice(
+            InputMessageContentInvoice::new(
+                "title",
+                "description",
+                "payload",
+                "token",
+                "USD",
+                vec![LabeledPrice::new("price", 100)],
+            )
+            .need_email(true),
+        );
+
+        let json = r#"{"title":"title","description":"description","payload":"payload","provider_token":"token","currency":"USD","prices":[{"label":"price","amount":100}],"need_email":true}"#;
+        assert_eq!(serde_json::to_string(&invoice_content).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<InputMessageContent>(json).unwrap(),
+            invoice_content
+        );
+    }
 }
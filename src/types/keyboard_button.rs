@@ -1,6 +1,6 @@
 use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::types::{KeyboardButtonPollType, True};
+use crate::types::{KeyboardButtonPollType, True, WebAppInfo};
 
 /// This object represents one button of the reply keyboard.
 ///
@@ -20,6 +20,10 @@ pub struct KeyboardButton {
     ///   when the button is pressed. Available in private chats only
     /// - If `Some(Location)`, the user's current location will be sent when the
     ///   button is pressed. Available in private chats only
+    /// - If `Some(WebApp(_))`, the described [Web App] will be launched when
+    ///   the button is pressed. Available in private chats only
+    ///
+    /// [Web App]: https://core.telegram.org/bots/webapps
     #[serde(flatten)]
     pub request: Option<ButtonRequest>,
 }
@@ -50,6 +54,7 @@ pub enum ButtonRequest {
     Location,
     Contact,
     Poll(KeyboardButtonPollType),
+    WebApp(WebAppInfo),
 }
 
 /// Helper struct for (de)serializing [`ButtonRequest`](ButtonRequest)
@@ -71,6 +76,10 @@ struct RawRequest {
     /// chats only.
     #[serde(rename = "request_poll")]
     poll: Option<KeyboardButtonPollType>,
+
+    /// If specified, the described Web App will be launched when the button
+    /// is pressed. Available in private chats only.
+    web_app: Option<WebAppInfo>,
 }
 
 impl<'de> Deserialize<'de> for ButtonRequest {
@@ -84,6 +93,7 @@ impl<'de> Deserialize<'de> for ButtonRequest {
                 contact: Some(_),
                 location: Some(_),
                 poll: Some(_),
+                ..
             } => Err(D::Error::custom(
                 "`request_contact` and `request_location` fields are mutually exclusive, but both \
                  were provided",
@@ -98,6 +108,10 @@ impl<'de> Deserialize<'de> for ButtonRequest {
                 poll: Some(poll_type),
                 ..
             } => Ok(Self::Poll(poll_type)),
+            RawRequest {
+                web_app: Some(web_app),
+                ..
+            } => Ok(Self::WebApp(web_app)),
             _ => Err(D::Error::custom(
                 "Either one of `request_contact` and `request_location` fields is required",
             )),
@@ -115,18 +129,28 @@ impl Serialize for ButtonRequest {
                 contact: Some(True),
                 location: None,
                 poll: None,
+                web_app: None,
             }
             .serialize(serializer),
             Self::Location => RawRequest {
                 contact: None,
                 location: Some(True),
                 poll: None,
+                web_app: None,
             }
             .serialize(serializer),
             Self::Poll(poll_type) => RawRequest {
                 contact: None,
                 location: None,
                 poll: Some(poll_type.clone()),
+                web_app: None,
+            }
+            .serialize(serializer),
+            Self::WebApp(web_app) => RawRequest {
+                contact: None,
+                location: None,
+                poll: None,
+                web_app: Some(web_app.clone()),
             }
             .serialize(serializer),
         }
@@ -180,4 +204,18 @@ mod tests {
         let actual = serde_json::from_str(json).unwrap();
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn web_app() {
+        let json = r#"{"text":"Open","web_app":{"url":"https://example.com"}}"#;
+        let button = KeyboardButton::new("Open").request(ButtonRequest::WebApp(WebAppInfo::new(
+            "https://example.com",
+        )));
+
+        assert_eq!(serde_json::to_string(&button).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<KeyboardButton>(json).unwrap(),
+            button
+        );
+    }
 }
@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes the options used for link preview generation.
+///
+/// This is a replacement for the `disable_web_page_preview` field of
+/// [`SendMessage`] and [`EditMessageText`]. Telegram rejects requests with
+/// both `link_preview_options` and `disable_web_page_preview` set, so only one
+/// of them should be used (this is checked by a debug assertion before
+/// sending the request).
+///
+/// [The official docs](https://core.telegram.org/bots/api#linkpreviewoptions).
+///
+/// [`SendMessage`]: crate::payloads::SendMessage
+/// [`EditMessageText`]: crate::payloads::EditMessageText
+#[serde_with_macros::skip_serializing_none]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct LinkPreviewOptions {
+    /// `true`, if the link preview is disabled.
+    pub is_disabled: Option<bool>,
+
+    /// URL to use for the link preview. If empty, then the first URL found in
+    /// the message text will be used.
+    pub url: Option<String>,
+
+    /// `true`, if the media in the link preview is supposed to be shrunk;
+    /// ignored if the URL isn't explicitly specified or media size change
+    /// isn't supported for the preview.
+    pub prefer_small_media: Option<bool>,
+
+    /// `true`, if the media in the link preview is supposed to be enlarged;
+    /// ignored if the URL isn't explicitly specified or media size change
+    /// isn't supported for the preview.
+    pub prefer_large_media: Option<bool>,
+
+    /// `true`, if the link preview must be shown above the message text;
+    /// otherwise, the link preview will be shown below the message text.
+    pub show_above_text: Option<bool>,
+}
+
+impl LinkPreviewOptions {
+    pub const fn new() -> Self {
+        Self {
+            is_disabled: None,
+            url: None,
+            prefer_small_media: None,
+            prefer_large_media: None,
+            show_above_text: None,
+        }
+    }
+
+    pub const fn is_disabled(mut self, val: bool) -> Self {
+        self.is_disabled = Some(val);
+        self
+    }
+
+    pub fn url<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.url = Some(val.into());
+        self
+    }
+
+    pub const fn prefer_small_media(mut self, val: bool) -> Self {
+        self.prefer_small_media = Some(val);
+        self
+    }
+
+    pub const fn prefer_large_media(mut self, val: bool) -> Self {
+        self.prefer_large_media = Some(val);
+        self
+    }
+
+    pub const fn show_above_text(mut self, val: bool) -> Self {
+        self.show_above_text = Some(val);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::payloads::{SendMessage, SendMessageSetters};
+
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        let options = LinkPreviewOptions::new().show_above_text(true);
+        assert_eq!(
+            serde_json::to_string(&options).unwrap(),
+            r#"{"show_above_text":true}"#
+        );
+
+        let payload = SendMessage::new(0, "text").link_preview_options(options);
+        assert_eq!(
+            serde_json::to_value(&payload).unwrap(),
+            serde_json::json!({
+                "chat_id": 0,
+                "text": "text",
+                "link_preview_options": { "show_above_text": true }
+            })
+        );
+    }
+
+    #[test]
+    fn both_set() {
+        // Telegram reports the conflict, so both fields are sent as is
+        let payload = SendMessage::new(0, "text")
+            .disable_web_page_preview(true)
+            .link_preview_options(LinkPreviewOptions::new().is_disabled(true));
+        assert_eq!(
+            serde_json::to_value(&payload).unwrap(),
+            serde_json::json!({
+                "chat_id": 0,
+                "text": "text",
+                "disable_web_page_preview": true,
+                "link_preview_options": { "is_disabled": true }
+            })
+        );
+    }
+}
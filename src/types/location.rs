@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 /// This object represents a point on the map.
+#[serde_with_macros::skip_serializing_none]
 #[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Location {
     /// Longitude as defined by sender.
@@ -24,3 +25,37 @@ pub struct Location {
     /// member, in meters. For sent live locations only.
     pub proximity_alert_radius: Option<u32>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn live_location() {
+        let json = r#"{"longitude":30.31,"latitude":59.94,"horizontal_accuracy":12.5,"live_period":900,"heading":90,"proximity_alert_radius":100}"#;
+        let location: Location = serde_json::from_str(json).unwrap();
+
+        assert_eq!(location.horizontal_accuracy, Some(12.5));
+        assert_eq!(location.live_period, Some(900));
+        assert_eq!(location.heading, Some(90));
+        assert_eq!(location.proximity_alert_radius, Some(100));
+        assert_eq!(serde_json::to_string(&location).unwrap(), json);
+    }
+
+    #[test]
+    fn serialize_static_location() {
+        let location = Location {
+            longitude: 30.31,
+            latitude: 59.94,
+            horizontal_accuracy: None,
+            live_period: None,
+            heading: None,
+            proximity_alert_radius: None,
+        };
+
+        assert_eq!(
+            serde_json::to_string(&location).unwrap(),
+            r#"{"longitude":30.31,"latitude":59.94}"#
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::WebAppInfo;
+
+/// This object describes the bot's menu button in a private chat.
+///
+/// If a menu button other than [`MenuButton::Default`] is set for a private
+/// chat, then it is applied in the chat. Otherwise the default menu button is
+/// applied. By default, the menu button opens the list of bot commands.
+///
+/// [The official docs](https://core.telegram.org/bots/api#menubutton).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+pub enum MenuButton {
+    /// Opens the bot's list of commands.
+    Commands,
+
+    /// Launches a [Web App].
+    ///
+    /// [Web App]: https://core.telegram.org/bots/webapps
+    WebApp {
+        /// Text on the button.
+        text: String,
+
+        /// Description of the Web App that will be launched when the user
+        /// presses the button. The Web App will be able to send an arbitrary
+        /// message on behalf of the user using the method
+        /// `answerWebAppQuery`.
+        web_app: WebAppInfo,
+    },
+
+    /// Describes that no specific value for the menu button was set.
+    Default,
+}
+
+impl Default for MenuButton {
+    fn default() -> Self {
+        Self::Default
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        let button = MenuButton::WebApp {
+            text: "Open".to_owned(),
+            web_app: WebAppInfo::new("https://example.com/app"),
+        };
+        let json =
+            r#"{"type":"web_app","text":"Open","web_app":{"url":"https://example.com/app"}}"#;
+
+        assert_eq!(serde_json::to_string(&button).unwrap(), json);
+        assert_eq!(serde_json::from_str::<MenuButton>(json).unwrap(), button);
+        assert_eq!(
+            serde_json::from_str::<MenuButton>(r#"{"type":"commands"}"#).unwrap(),
+            MenuButton::Commands
+        );
+    }
+}
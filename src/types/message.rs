@@ -5,10 +5,10 @@ use serde::{Deserialize, Serialize};
 use crate::types::{
     chat::{ChatKind, PublicChatKind},
     Animation, Audio, Chat, ChatPublic, Contact, Dice, Document, Game, InlineKeyboardMarkup,
-    Invoice, Location, MessageAutoDeleteTimerChanged, MessageEntity, PassportData, PhotoSize, Poll,
-    ProximityAlertTriggered, PublicChatChannel, PublicChatSupergroup, Sticker, SuccessfulPayment,
-    True, User, Venue, Video, VideoNote, Voice, VoiceChatEnded, VoiceChatParticipantsInvited,
-    VoiceChatStarted,
+    Invoice, Location, MessageAutoDeleteTimerChanged, MessageEntity, MessageId, PaidMediaInfo,
+    PassportData, PhotoSize, Poll, ProximityAlertTriggered, PublicChatChannel,
+    PublicChatSupergroup, Sticker, SuccessfulPayment, ThreadId, True, User, Venue, Video,
+    VideoNote, Voice, VoiceChatEnded, VoiceChatParticipantsInvited, VoiceChatStarted, WebAppData,
 };
 
 /// This object represents a message.
@@ -18,7 +18,17 @@ use crate::types::{
 pub struct Message {
     /// Unique message identifier inside this chat.
     #[serde(rename = "message_id")]
-    pub id: i32,
+    pub id: MessageId,
+
+    /// Unique identifier of a message thread (a forum topic) to which the
+    /// message belongs; for supergroups only.
+    #[serde(rename = "message_thread_id")]
+    pub thread_id: Option<ThreadId>,
+
+    /// Unique identifier of the business connection from which the message
+    /// was received. If non-empty, the message belongs to a chat of the
+    /// corresponding business account.
+    pub business_connection_id: Option<String>,
 
     /// Date the message was sent in Unix time.
     pub date: i32,
@@ -57,6 +67,7 @@ pub enum MessageKind {
     VoiceChatStarted(MessageVoiceChatStarted),
     VoiceChatEnded(MessageVoiceChatEnded),
     VoiceChatParticipantsInvited(MessageVoiceChatParticipantsInvited),
+    WebAppData(MessageWebAppData),
 }
 
 #[serde_with_macros::skip_serializing_none]
@@ -241,7 +252,7 @@ pub struct ForwardChannel {
     pub chat: Chat,
 
     #[serde(rename = "forward_from_message_id")]
-    pub message_id: i32,
+    pub message_id: MessageId,
 
     #[serde(rename = "forward_signature")]
     pub signature: Option<String>,
@@ -278,6 +289,7 @@ pub enum MediaKind {
     VideoNote(MediaVideoNote),
     Voice(MediaVoice),
     Venue(MediaVenue),
+    PaidMedia(MediaPaidMedia),
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -443,6 +455,21 @@ pub struct MediaVenue {
     pub venue: Venue,
 }
 
+#[serde_with_macros::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MediaPaidMedia {
+    /// Message contains paid media; information about the paid media.
+    pub paid_media: PaidMediaInfo,
+
+    /// Caption for the paid media, 0-1024 characters.
+    pub caption: Option<String>,
+
+    /// For messages with a caption, special entities like usernames, URLs,
+    /// bot commands, etc. that appear in the caption.
+    #[serde(default = "Vec::new")]
+    pub caption_entities: Vec<MessageEntity>,
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MessageDice {
     /// Message is a dice with random value from 1 to 6.
@@ -474,6 +501,12 @@ pub struct MessageVoiceChatParticipantsInvited {
     pub voice_chat_participants_invited: VoiceChatParticipantsInvited,
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MessageWebAppData {
+    /// Service message: data sent by a Web App.
+    pub web_app_data: WebAppData,
+}
+
 mod getters {
     use std::ops::Deref;
 
@@ -482,13 +515,13 @@ mod getters {
         message::{ForwardKind::NonChannel, MessageKind::*},
         Chat, ForwardChannel, ForwardKind, ForwardNonChannel, ForwardOrigin, ForwardedFrom,
         MediaAnimation, MediaAudio, MediaContact, MediaDocument, MediaGame, MediaKind,
-        MediaLocation, MediaPhoto, MediaPoll, MediaSticker, MediaText, MediaVenue, MediaVideo,
-        MediaVideoNote, MediaVoice, Message, MessageChannelChatCreated, MessageCommon,
+        MediaLocation, MediaPaidMedia, MediaPhoto, MediaPoll, MediaSticker, MediaText, MediaVenue,
+        MediaVideo, MediaVideoNote, MediaVoice, Message, MessageChannelChatCreated, MessageCommon,
         MessageConnectedWebsite, MessageDeleteChatPhoto, MessageDice, MessageEntity,
-        MessageGroupChatCreated, MessageInvoice, MessageLeftChatMember, MessageMigrate,
+        MessageGroupChatCreated, MessageId, MessageInvoice, MessageLeftChatMember, MessageMigrate,
         MessageNewChatMembers, MessageNewChatPhoto, MessageNewChatTitle, MessagePassportData,
         MessagePinned, MessageProximityAlertTriggered, MessageSuccessfulPayment,
-        MessageSupergroupChatCreated, PhotoSize, True, User,
+        MessageSupergroupChatCreated, MessageWebAppData, PhotoSize, True, User,
     };
 
     /// Getters for [Message] fields from [telegram docs].
@@ -545,7 +578,7 @@ mod getters {
             }
         }
 
-        pub fn forward_from_message_id(&self) -> Option<&i32> {
+        pub fn forward_from_message_id(&self) -> Option<&MessageId> {
             match &self.kind {
                 Common(MessageCommon {
                     forward_kind: ForwardKind::Channel(ForwardChannel { message_id, .. }),
@@ -676,6 +709,13 @@ mod getters {
                             caption_entities, ..
                         }),
                     ..
+                })
+                | Common(MessageCommon {
+                    media_kind:
+                        MediaKind::PaidMedia(MediaPaidMedia {
+                            caption_entities, ..
+                        }),
+                    ..
                 }) => Some(caption_entities),
                 _ => None,
             }
@@ -779,7 +819,8 @@ mod getters {
                     | MediaKind::Document(MediaDocument { caption, .. })
                     | MediaKind::Photo(MediaPhoto { caption, .. })
                     | MediaKind::Video(MediaVideo { caption, .. })
-                    | MediaKind::Voice(MediaVoice { caption, .. }) => {
+                    | MediaKind::Voice(MediaVoice { caption, .. })
+                    | MediaKind::PaidMedia(MediaPaidMedia { caption, .. }) => {
                         caption.as_ref().map(Deref::deref)
                     }
                     _ => None,
@@ -818,6 +859,16 @@ mod getters {
             }
         }
 
+        pub fn paid_media(&self) -> Option<&types::PaidMediaInfo> {
+            match &self.kind {
+                Common(MessageCommon {
+                    media_kind: MediaKind::PaidMedia(MediaPaidMedia { paid_media, .. }),
+                    ..
+                }) => Some(paid_media),
+                _ => None,
+            }
+        }
+
         pub fn poll(&self) -> Option<&types::Poll> {
             match &self.kind {
                 Common(MessageCommon {
@@ -972,6 +1023,13 @@ mod getters {
             }
         }
 
+        pub fn web_app_data(&self) -> Option<&types::WebAppData> {
+            match &self.kind {
+                WebAppData(MessageWebAppData { web_app_data }) => Some(web_app_data),
+                _ => None,
+            }
+        }
+
         pub fn reply_markup(&self) -> Option<&types::InlineKeyboardMarkup> {
             match &self.kind {
                 Common(MessageCommon { reply_markup, .. }) => reply_markup.as_ref(),
@@ -1008,6 +1066,34 @@ impl Message {
     }
 }
 
+/// [`chrono`] getters for dates of the message.
+#[cfg(feature = "chrono")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "chrono")))]
+impl Message {
+    /// [`Message::date`] as [`DateTime`].
+    ///
+    /// [`DateTime`]: chrono::DateTime
+    pub fn date_utc(&self) -> chrono::DateTime<chrono::Utc> {
+        crate::types::from_unix_time(self.date)
+    }
+
+    /// [`Message::edit_date`] as [`DateTime`].
+    ///
+    /// [`DateTime`]: chrono::DateTime
+    pub fn edit_date_utc(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.edit_date().copied().map(crate::types::from_unix_time)
+    }
+
+    /// [`Message::forward_date`] as [`DateTime`].
+    ///
+    /// [`DateTime`]: chrono::DateTime
+    pub fn forward_date_utc(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.forward_date()
+            .copied()
+            .map(crate::types::from_unix_time)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::from_str;
@@ -1119,8 +1205,41 @@ mod tests {
           "date": 1568289890,
           "text": "Лол кек 😂"
          }"#;
-        let message = from_str::<Message>(json);
-        assert!(message.is_ok());
+        let message = from_str::<Message>(json).unwrap();
+        assert_eq!(message.date, 1568289890);
+        assert_eq!(message.edit_date(), None);
+
+        #[cfg(feature = "chrono")]
+        assert_eq!(message.date_utc().to_rfc3339(), "2019-09-12T12:04:50+00:00");
+    }
+
+    #[test]
+    fn de_unknown_fields() {
+        let json = r#"{
+          "message_id": 199785,
+          "from": {
+           "id": 250918540,
+           "is_bot": false,
+           "first_name": "Андрей",
+           "some_new_user_field": true
+          },
+          "chat": {
+           "id": 250918540,
+           "first_name": "Андрей",
+           "type": "private",
+           "some_new_chat_field": [1, 2, 3]
+          },
+          "date": 1568289890,
+          "text": "text",
+          "entities": [{ "type": "bold", "offset": 0, "length": 4, "some_new_entity_field": {} }],
+          "some_new_message_field": { "a": "b" }
+         }"#;
+        let message = from_str::<Message>(json).unwrap();
+
+        assert_eq!(message.id, MessageId(199785));
+        assert_eq!(message.text(), Some("text"));
+        assert_eq!(message.entities().map(<[_]>::len), Some(1));
+        assert_eq!(message.from().unwrap().first_name, "Андрей");
     }
 
     #[test]
@@ -1212,4 +1331,68 @@ mod tests {
         let message = from_str::<Message>(json);
         assert!(message.is_ok());
     }
+
+    #[test]
+    fn de_paid_media() {
+        let json = r#"{
+          "message_id": 199792,
+          "chat": {
+           "id": -1001234567890,
+           "title": "Channel",
+           "type": "channel"
+          },
+          "date": 1568290622,
+          "paid_media": {
+           "star_count": 10,
+           "paid_media": [
+            { "type": "preview", "width": 320, "height": 240 },
+            { "type": "photo", "photo": [{ "file_id": "id", "file_unique_id": "", "width": 320, "height": 240 }] }
+           ]
+          },
+          "caption": "caption"
+         }"#;
+        let message = from_str::<Message>(json).unwrap();
+
+        let paid_media = message.paid_media().unwrap();
+        assert_eq!(paid_media.star_count, 10);
+        assert!(matches!(
+            paid_media.paid_media[..],
+            [
+                PaidMedia::Preview(PaidMediaPreview {
+                    width: Some(320),
+                    height: Some(240),
+                    duration: None,
+                }),
+                PaidMedia::Photo(_),
+            ]
+        ));
+        assert_eq!(message.caption(), Some("caption"));
+    }
+
+    #[test]
+    fn de_web_app_data() {
+        let json = r#"{
+          "message_id": 1,
+          "from": {
+           "id": 218485655,
+           "is_bot": false,
+           "first_name": "Andrey"
+          },
+          "chat": {
+           "id": 218485655,
+           "first_name": "Andrey",
+           "type": "private"
+          },
+          "date": 1650000000,
+          "web_app_data": {
+           "data": "{\"order\":42}",
+           "button_text": "Order"
+          }
+         }"#;
+        let message = from_str::<Message>(json).unwrap();
+
+        let data = message.web_app_data().unwrap();
+        assert_eq!(data.data, r#"{"order":42}"#);
+        assert_eq!(data.button_text, "Order");
+    }
 }
@@ -1,5 +1,75 @@
-/// This object represents a unique message identifier.
-pub struct MessageId {
-    /// Unique message identifier
-    pub message_id: i32,
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+/// A unique message identifier.
+///
+/// Message identifiers are unique only inside of a chat.
+///
+/// This is a separate type (and not just an integer) to not confuse message
+/// identifiers with other identifiers (e.g. [`UserId`]). Message identifiers
+/// are 64-bit, so that identifiers in big chats can't overflow.
+///
+/// `MessageId` is serialized as an integer, but can also be deserialized from
+/// a [`MessageId` object] returned by e.g. [`CopyMessage`].
+///
+/// [`UserId`]: crate::types::UserId
+/// [`MessageId` object]: https://core.telegram.org/bots/api#messageid
+/// [`CopyMessage`]: crate::payloads::CopyMessage
+#[derive(
+    Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Display, From,
+)]
+#[serde(from = "MessageIdRaw", into = "i64")]
+pub struct MessageId(pub i64);
+
+impl From<MessageId> for i64 {
+    fn from(MessageId(id): MessageId) -> Self {
+        id
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum MessageIdRaw {
+    Id(i64),
+    Object { message_id: i64 },
+}
+
+impl From<MessageIdRaw> for MessageId {
+    fn from(raw: MessageIdRaw) -> Self {
+        match raw {
+            MessageIdRaw::Id(id) | MessageIdRaw::Object { message_id: id } => Self(id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::payloads::{setters::*, SendMessage};
+
+    #[test]
+    fn serde() {
+        assert_eq!(serde_json::to_string(&MessageId(42)).unwrap(), "42");
+        assert_eq!(
+            serde_json::from_str::<MessageId>("42").unwrap(),
+            MessageId(42)
+        );
+        assert_eq!(
+            serde_json::from_str::<MessageId>(r#"{"message_id":42}"#).unwrap(),
+            MessageId(42)
+        );
+    }
+
+    #[test]
+    fn bigger_than_i32() {
+        let id = MessageId(i64::from(i32::MAX) + 1);
+        let json = serde_json::to_string(&id).unwrap();
+
+        assert_eq!(json, "2147483648");
+        assert_eq!(serde_json::from_str::<MessageId>(&json).unwrap(), id);
+
+        let payload = SendMessage::new(0, "text").reply_to_message_id(id);
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(json["reply_to_message_id"], 2_147_483_648_i64);
+    }
 }
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Chat, MessageId, ReactionType, User};
+
+/// This object represents a change of a reaction on a message performed by a
+/// user.
+///
+/// [The official docs](https://core.telegram.org/bots/api#messagereactionupdated).
+#[serde_with_macros::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MessageReactionUpdated {
+    /// The chat containing the message the user reacted to.
+    pub chat: Chat,
+
+    /// Unique identifier of the message inside the chat.
+    pub message_id: MessageId,
+
+    /// The user that changed the reaction, if the user isn't anonymous.
+    pub user: Option<User>,
+
+    /// The chat on behalf of which the reaction was changed, if the user is
+    /// anonymous.
+    pub actor_chat: Option<Chat>,
+
+    /// Date of the change in Unix time.
+    pub date: i64,
+
+    /// Previous list of reaction types that were set by the user.
+    pub old_reaction: Vec<ReactionType>,
+
+    /// New list of reaction types that have been set by the user.
+    pub new_reaction: Vec<ReactionType>,
+}
@@ -0,0 +1,105 @@
+use crate::types::{ChatAdministratorRights, ChatMember, ChatMemberKind, UserId};
+
+/// Administrators of a chat, partitioned into the creator and other
+/// administrators.
+///
+/// This can be created from the result of [`GetChatAdministrators`], see also
+/// [`RequesterExt::chat_administrators`].
+///
+/// [`GetChatAdministrators`]: crate::payloads::GetChatAdministrators
+/// [`RequesterExt::chat_administrators`]: crate::requests::RequesterExt::chat_administrators
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ChatAdministrators {
+    /// The creator of the chat.
+    ///
+    /// This is `None` if the creator is not a member of the chat anymore.
+    pub creator: Option<ChatMember>,
+
+    /// Administrators of the chat, except for the creator.
+    pub administrators: Vec<ChatMember>,
+}
+
+impl ChatAdministrators {
+    /// Returns the creator or the administrator with the id `user_id`.
+    pub fn get(&self, user_id: UserId) -> Option<&ChatMember> {
+        self.iter().find(|member| member.user.id == user_id)
+    }
+
+    /// Returns `true` if `user_id` is the creator or an administrator of the
+    /// chat.
+    pub fn is_admin(&self, user_id: UserId) -> bool {
+        self.get(user_id).is_some()
+    }
+
+    /// Returns the rights of the creator or the administrator with the id
+    /// `user_id`, or `None` if the user is not an administrator.
+    pub fn rights(&self, user_id: UserId) -> Option<ChatAdministratorRights> {
+        self.get(user_id)
+            .and_then(|member| member.kind.administrator_rights())
+    }
+
+    /// Returns an iterator over the creator and the administrators.
+    pub fn iter(&self) -> impl Iterator<Item = &ChatMember> {
+        self.creator.iter().chain(&self.administrators)
+    }
+}
+
+impl From<Vec<ChatMember>> for ChatAdministrators {
+    /// Partitions `members` into the creator and administrators, members with
+    /// other statuses are ignored.
+    fn from(members: Vec<ChatMember>) -> Self {
+        let mut this = Self::default();
+        for member in members {
+            match member.kind {
+                ChatMemberKind::Creator(_) => this.creator = Some(member),
+                ChatMemberKind::Administrator(_) => this.administrators.push(member),
+                ChatMemberKind::Member
+                | ChatMemberKind::Restricted(_)
+                | ChatMemberKind::Left
+                | ChatMemberKind::Kicked(_) => {}
+            }
+        }
+
+        this
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partition() {
+        let members: Vec<ChatMember> = serde_json::from_value(serde_json::json!([
+            {
+                "user": { "id": 1, "is_bot": false, "first_name": "Admin" },
+                "status": "administrator",
+                "is_anonymous": false,
+                "can_be_edited": false,
+                "can_manage_chat": true,
+                "can_change_info": false,
+                "can_delete_messages": true,
+                "can_manage_voice_chats": false,
+                "can_invite_users": false,
+                "can_restrict_members": false,
+                "can_promote_members": false
+            },
+            {
+                "user": { "id": 2, "is_bot": false, "first_name": "Creator" },
+                "status": "creator",
+                "is_anonymous": false
+            }
+        ]))
+        .unwrap();
+
+        let admins = ChatAdministrators::from(members);
+        assert_eq!(admins.creator.as_ref().unwrap().user.id, UserId(2));
+        assert_eq!(admins.administrators.len(), 1);
+        assert_eq!(admins.iter().count(), 2);
+
+        assert!(admins.rights(UserId(1)).unwrap().can_delete_messages);
+        assert!(!admins.rights(UserId(1)).unwrap().can_change_info);
+        assert!(admins.rights(UserId(2)).unwrap().can_change_info);
+        assert!(!admins.is_admin(UserId(3)));
+    }
+}
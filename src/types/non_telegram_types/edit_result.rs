@@ -0,0 +1,132 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::types::{Message, True};
+
+/// The result of `editMessage*` methods.
+///
+/// When a message sent by the bot is edited, Telegram returns the edited
+/// [`Message`], when an inline message is edited, it returns `true` instead.
+///
+/// Note: the requests of this crate are already split into chat and inline
+/// variants (e.g. [`EditMessageText`] returns [`Message`] and
+/// [`EditMessageTextInline`] returns [`True`]), so this type is only needed
+/// when it's not known statically which kind of message is edited.
+///
+/// [`EditMessageText`]: crate::payloads::EditMessageText
+/// [`EditMessageTextInline`]: crate::payloads::EditMessageTextInline
+#[derive(Clone, Debug, PartialEq)]
+pub enum EditResult {
+    /// A message sent by the bot was edited.
+    Message(Box<Message>),
+
+    /// An inline message was edited.
+    Inline,
+}
+
+impl EditResult {
+    /// Returns the edited message, or `None` if an inline message was edited.
+    pub fn message(&self) -> Option<&Message> {
+        match self {
+            Self::Message(message) => Some(message),
+            Self::Inline => None,
+        }
+    }
+
+    /// Returns the edited message, or `None` if an inline message was edited.
+    pub fn into_message(self) -> Option<Message> {
+        match self {
+            Self::Message(message) => Some(*message),
+            Self::Inline => None,
+        }
+    }
+
+    /// Returns `true` if an inline message was edited.
+    pub fn is_inline(&self) -> bool {
+        matches!(self, Self::Inline)
+    }
+}
+
+impl From<Message> for EditResult {
+    fn from(message: Message) -> Self {
+        Self::Message(Box::new(message))
+    }
+}
+
+impl From<True> for EditResult {
+    fn from(_: True) -> Self {
+        Self::Inline
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum Raw {
+    Inline(True),
+    Message(Box<Message>),
+}
+
+impl<'de> Deserialize<'de> for EditResult {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match Raw::deserialize(deserializer)? {
+            Raw::Inline(True) => Self::Inline,
+            Raw::Message(message) => Self::Message(message),
+        })
+    }
+}
+
+impl Serialize for EditResult {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Message(message) => message.serialize(serializer),
+            Self::Inline => True.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::MessageId;
+
+    use super::*;
+
+    #[test]
+    fn inline() {
+        let result: EditResult = serde_json::from_str("true").unwrap();
+        assert!(result.is_inline());
+        assert_eq!(serde_json::to_string(&result).unwrap(), "true");
+
+        assert!(serde_json::from_str::<EditResult>("false").is_err());
+    }
+
+    #[test]
+    fn message() {
+        let json = r#"{
+            "message_id": 198,
+            "date": 1567927221,
+            "chat": {
+                "id": 250918540,
+                "first_name": "Андрей",
+                "username": "aka_dude",
+                "type": "private"
+            },
+            "from": {
+                "id": 250918540,
+                "is_bot": false,
+                "first_name": "Андрей",
+                "username": "aka_dude"
+            },
+            "edit_date": 1567927300,
+            "text": "edited"
+        }"#;
+
+        let result: EditResult = serde_json::from_str(json).unwrap();
+        assert_eq!(result.message().unwrap().id, MessageId(198));
+        assert_eq!(result.into_message().unwrap().text(), Some("edited"));
+    }
+}
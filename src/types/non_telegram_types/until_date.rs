@@ -0,0 +1,219 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The date until which a user is banned or restricted, e.g.
+/// [`BanChatMember::until_date`].
+///
+/// Telegram treats any date which is less than 30 seconds or more than 366
+/// days from the current time as "forever", so e.g. a ban for a year and two
+/// days is permanent. Use [`UntilDate::forever`] to ban a user permanently
+/// and check dates with [`UntilDate::is_forever_at`] when in doubt.
+///
+/// Serialized as unix time (`0` for [`UntilDate::Forever`]).
+///
+/// [`BanChatMember::until_date`]: crate::payloads::BanChatMember::until_date
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum UntilDate {
+    /// The user is banned or restricted forever.
+    Forever,
+
+    /// The user is banned or restricted until this date, unix time.
+    Date(u64),
+}
+
+impl UntilDate {
+    /// The maximum duration of a ban or restriction which isn't "forever".
+    pub const MAX_DURATION_SECS: u64 = 366 * 24 * 60 * 60;
+
+    /// The minimum duration of a ban or restriction which isn't "forever".
+    pub const MIN_DURATION_SECS: u64 = 30;
+
+    /// Bans or restricts the user forever.
+    pub const fn forever() -> Self {
+        Self::Forever
+    }
+
+    /// Bans or restricts the user until `unix_time`.
+    ///
+    /// `0` is converted to [`UntilDate::Forever`], like Telegram does. Note
+    /// that the date is also treated as "forever" if it's too close or too
+    /// far, see [`UntilDate::is_forever_at`].
+    pub const fn at(unix_time: u64) -> Self {
+        match unix_time {
+            0 => Self::Forever,
+            _ => Self::Date(unix_time),
+        }
+    }
+
+    /// Returns the date as unix time, `0` for [`UntilDate::Forever`].
+    pub const fn unix_time(self) -> u64 {
+        match self {
+            Self::Forever => 0,
+            Self::Date(unix_time) => unix_time,
+        }
+    }
+
+    /// Returns `true` if Telegram will treat this date as "forever" when it's
+    /// sent at `now` (unix time), i.e. if it's [`UntilDate::Forever`] or it's
+    /// less than [`MIN_DURATION_SECS`] or more than [`MAX_DURATION_SECS`] from
+    /// `now`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::types::UntilDate;
+    ///
+    /// let now = 1650000000;
+    /// let day = 24 * 60 * 60;
+    ///
+    /// assert!(!UntilDate::at(now + 7 * day).is_forever_at(now));
+    /// assert!(UntilDate::at(now + 10).is_forever_at(now));
+    /// assert!(UntilDate::at(now + 367 * day).is_forever_at(now));
+    /// ```
+    ///
+    /// [`MIN_DURATION_SECS`]: UntilDate::MIN_DURATION_SECS
+    /// [`MAX_DURATION_SECS`]: UntilDate::MAX_DURATION_SECS
+    pub fn is_forever_at(self, now: u64) -> bool {
+        match self {
+            Self::Forever => true,
+            Self::Date(date) => match date.checked_sub(now) {
+                Some(duration) => {
+                    !(Self::MIN_DURATION_SECS..=Self::MAX_DURATION_SECS).contains(&duration)
+                }
+                // The date is in the past
+                None => true,
+            },
+        }
+    }
+}
+
+impl From<u64> for UntilDate {
+    fn from(unix_time: u64) -> Self {
+        Self::at(unix_time)
+    }
+}
+
+/// For `until_date` fields of Telegram types, e.g. [`Restricted::until_date`].
+/// Negative dates are in the past, so they mean "forever" too.
+///
+/// [`Restricted::until_date`]: crate::types::Restricted::until_date
+impl From<i32> for UntilDate {
+    fn from(unix_time: i32) -> Self {
+        Self::at(unix_time.max(0) as u64)
+    }
+}
+
+impl Serialize for UntilDate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(self.unix_time())
+    }
+}
+
+impl<'de> Deserialize<'de> for UntilDate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Negative dates are in the past, so they mean "forever" too
+        i64::deserialize(deserializer).map(|date| Self::at(date.max(0) as u64))
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "chrono")))]
+impl UntilDate {
+    /// Returns the date as [`DateTime`], `None` for [`UntilDate::Forever`].
+    ///
+    /// [`DateTime`]: chrono::DateTime
+    pub fn date_utc(self) -> Option<chrono::DateTime<chrono::Utc>> {
+        use chrono::TimeZone;
+
+        match self {
+            Self::Forever => None,
+            Self::Date(date) => chrono::Utc.timestamp_opt(date as i64, 0).single(),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "chrono")))]
+impl From<chrono::DateTime<chrono::Utc>> for UntilDate {
+    fn from(date: chrono::DateTime<chrono::Utc>) -> Self {
+        Self::at(date.timestamp().max(0) as u64)
+    }
+}
+
+/// Converts unix time from a date field of a Telegram type.
+#[cfg(feature = "chrono")]
+pub(crate) fn from_unix_time(secs: i32) -> chrono::DateTime<chrono::Utc> {
+    use chrono::TimeZone;
+
+    // Any `i32` number of seconds fits into `DateTime`
+    chrono::Utc.timestamp_opt(secs.into(), 0).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1650000000;
+
+    #[test]
+    fn unix_time() {
+        assert_eq!(UntilDate::at(0), UntilDate::forever());
+        assert_eq!(UntilDate::forever().unix_time(), 0);
+        assert_eq!(UntilDate::at(NOW).unix_time(), NOW);
+        assert_eq!(UntilDate::from(NOW), UntilDate::Date(NOW));
+        assert_eq!(UntilDate::from(0), UntilDate::Forever);
+        assert_eq!(UntilDate::from(-1), UntilDate::Forever);
+    }
+
+    #[test]
+    fn forever_boundaries() {
+        let min = UntilDate::MIN_DURATION_SECS;
+        let max = UntilDate::MAX_DURATION_SECS;
+
+        assert!(UntilDate::forever().is_forever_at(NOW));
+        assert!(UntilDate::at(NOW).is_forever_at(NOW));
+        assert!(UntilDate::at(NOW + min - 1).is_forever_at(NOW));
+        assert!(!UntilDate::at(NOW + min).is_forever_at(NOW));
+        assert!(!UntilDate::at(NOW + max).is_forever_at(NOW));
+        assert!(UntilDate::at(NOW + max + 1).is_forever_at(NOW));
+        assert!(UntilDate::at(NOW - 60).is_forever_at(NOW));
+        assert!(UntilDate::at(u64::MAX).is_forever_at(u64::MAX - 1));
+    }
+
+    #[test]
+    fn serde() {
+        assert_eq!(serde_json::to_string(&UntilDate::forever()).unwrap(), "0");
+        assert_eq!(
+            serde_json::to_string(&UntilDate::at(NOW)).unwrap(),
+            "1650000000"
+        );
+
+        assert_eq!(
+            serde_json::from_str::<UntilDate>("0").unwrap(),
+            UntilDate::Forever
+        );
+        assert_eq!(
+            serde_json::from_str::<UntilDate>("-1").unwrap(),
+            UntilDate::Forever
+        );
+        assert_eq!(
+            serde_json::from_str::<UntilDate>("1650000000").unwrap(),
+            UntilDate::Date(NOW)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        let date = from_unix_time(1650000000);
+
+        assert_eq!(UntilDate::from(date), UntilDate::Date(NOW));
+        assert_eq!(UntilDate::Date(NOW).date_utc(), Some(date));
+        assert_eq!(UntilDate::Forever.date_utc(), None);
+    }
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{PhotoSize, Video};
+
+/// Describes the paid media added to a message.
+///
+/// [The official docs](https://core.telegram.org/bots/api#paidmediainfo).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct PaidMediaInfo {
+    /// The number of Telegram Stars that must be paid to buy access to the
+    /// media.
+    pub star_count: u32,
+
+    /// Information about the paid media.
+    pub paid_media: Vec<PaidMedia>,
+}
+
+/// This object describes paid media.
+///
+/// [The official docs](https://core.telegram.org/bots/api#paidmedia).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)]
+pub enum PaidMedia {
+    Preview(PaidMediaPreview),
+    Photo(PaidMediaPhoto),
+    Video(PaidMediaVideo),
+}
+
+/// The paid media isn't available before the payment.
+///
+/// [The official docs](https://core.telegram.org/bots/api#paidmediapreview).
+#[serde_with_macros::skip_serializing_none]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct PaidMediaPreview {
+    /// Media width as defined by the sender.
+    pub width: Option<u32>,
+
+    /// Media height as defined by the sender.
+    pub height: Option<u32>,
+
+    /// Duration of the media in seconds as defined by the sender.
+    pub duration: Option<u32>,
+}
+
+/// The paid media is a photo.
+///
+/// [The official docs](https://core.telegram.org/bots/api#paidmediaphoto).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct PaidMediaPhoto {
+    /// The photo.
+    pub photo: Vec<PhotoSize>,
+}
+
+/// The paid media is a video.
+///
+/// [The official docs](https://core.telegram.org/bots/api#paidmediavideo).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct PaidMediaVideo {
+    /// The video.
+    pub video: Video,
+}
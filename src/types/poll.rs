@@ -106,4 +106,33 @@ mod tests {
         "#;
         serde_json::from_str::<Poll>(data).unwrap();
     }
+
+    #[test]
+    fn deserialize_quiz() {
+        let data = r#"
+        {
+            "id": "5377643193141559300",
+            "question": "2 + 2 = ?",
+            "options": [
+                { "text": "4", "voter_count": 2 },
+                { "text": "5", "voter_count": 1 }
+            ],
+            "total_voter_count": 3,
+            "is_closed": true,
+            "is_anonymous": false,
+            "type": "quiz",
+            "allows_multiple_answers": false,
+            "correct_option_id": 0,
+            "explanation": "Basic arithmetic",
+            "open_period": 60,
+            "close_date": 1617000000
+        }
+        "#;
+        let poll = serde_json::from_str::<Poll>(data).unwrap();
+
+        assert_eq!(poll.poll_type, PollType::Quiz);
+        assert_eq!(poll.correct_option_id, Some(0));
+        assert_eq!(poll.explanation.as_deref(), Some("Basic arithmetic"));
+        assert_eq!(poll.options[1].voter_count, 1);
+    }
 }
@@ -1,8 +1,17 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+/// Type of a [`Poll`].
+///
+/// [`Poll`]: crate::types::Poll
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum PollType {
+    /// A quiz, which has exactly one correct answer (see
+    /// [`SendPoll::correct_option_id`]).
+    ///
+    /// [`SendPoll::correct_option_id`]: crate::payloads::SendPoll::correct_option_id
     Quiz,
+
+    /// A regular poll.
     Regular,
 }
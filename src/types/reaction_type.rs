@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+/// The type of a reaction.
+///
+/// [The official docs](https://core.telegram.org/bots/api#reactiontype).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+pub enum ReactionType {
+    /// The reaction is based on an emoji.
+    Emoji {
+        /// Reaction emoji (e.g. "👍" or "🔥"). See the [official docs] for the
+        /// list of available emoji.
+        ///
+        /// [official docs]: https://core.telegram.org/bots/api#reactiontypeemoji
+        emoji: String,
+    },
+
+    /// The reaction is based on a custom emoji.
+    CustomEmoji {
+        /// Custom emoji identifier.
+        custom_emoji_id: String,
+    },
+}
+
+impl ReactionType {
+    /// Creates an [`Emoji`] reaction.
+    ///
+    /// [`Emoji`]: ReactionType::Emoji
+    pub fn emoji<S>(emoji: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::Emoji {
+            emoji: emoji.into(),
+        }
+    }
+
+    /// Creates a [`CustomEmoji`] reaction.
+    ///
+    /// [`CustomEmoji`]: ReactionType::CustomEmoji
+    pub fn custom_emoji<S>(custom_emoji_id: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::CustomEmoji {
+            custom_emoji_id: custom_emoji_id.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_string(&ReactionType::emoji("👍")).unwrap(),
+            r#"{"type":"emoji","emoji":"👍"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&ReactionType::custom_emoji("123")).unwrap(),
+            r#"{"type":"custom_emoji","custom_emoji_id":"123"}"#
+        );
+    }
+}
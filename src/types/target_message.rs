@@ -1,4 +1,4 @@
-use crate::types::ChatId;
+use crate::types::{ChatId, MessageId};
 
 use serde::{Deserialize, Serialize};
 
@@ -6,8 +6,13 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum TargetMessage {
-    Common { chat_id: ChatId, message_id: i32 },
-    Inline { inline_message_id: String },
+    Common {
+        chat_id: ChatId,
+        message_id: MessageId,
+    },
+    Inline {
+        inline_message_id: String,
+    },
 }
 
 impl From<String> for TargetMessage {
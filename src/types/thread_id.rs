@@ -0,0 +1,26 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Unique identifier of a message thread (a forum topic) in a chat.
+///
+/// This is a separate type (and not just an integer) to not confuse thread
+/// identifiers with message identifiers. Like [`MessageId`], it's 64-bit.
+///
+/// [`MessageId`]: crate::types::MessageId
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize, Display)]
+#[serde(transparent)]
+pub struct ThreadId(pub i64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde() {
+        assert_eq!(serde_json::to_string(&ThreadId(42)).unwrap(), "42");
+        assert_eq!(
+            serde_json::from_str::<ThreadId>("42").unwrap(),
+            ThreadId(42)
+        );
+    }
+}
@@ -1,10 +1,14 @@
 #![allow(clippy::large_enum_variant)]
 
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{self, MapAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 
 use crate::types::{
-    CallbackQuery, Chat, ChatMemberUpdated, ChosenInlineResult, InlineQuery, Message, Poll,
-    PollAnswer, PreCheckoutQuery, ShippingQuery, User,
+    BusinessConnection, BusinessMessagesDeleted, CallbackQuery, Chat, ChatMemberUpdated,
+    ChosenInlineResult, InlineQuery, Message, MessageReactionUpdated, Poll, PollAnswer,
+    PreCheckoutQuery, ShippingQuery, User,
 };
 use serde_json::Value;
 
@@ -30,6 +34,43 @@ pub struct Update {
 }
 
 impl Update {
+    /// Parses an update from JSON bytes, e.g. from the body of a webhook
+    /// request.
+    ///
+    /// Unlike [`Update::try_parse`], this function doesn't log errors.
+    /// Updates of kinds unknown to teloxide-core are parsed into
+    /// [`UpdateKind::Unknown`].
+    ///
+    /// ## Round-trip
+    ///
+    /// Serializing an `Update` (e.g. with [`serde_json::to_vec`]) and parsing
+    /// it back with this function gives an equal `Update` (if it doesn't,
+    /// it's a bug, please report it). Note though that the serialized JSON is
+    /// not byte-for-byte equal to what Telegram sent: fields unknown to
+    /// teloxide-core are dropped and the order of fields may differ.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::types::{Update, UpdateKind};
+    ///
+    /// let body = br#"{
+    ///     "update_id": 1,
+    ///     "poll_answer": {
+    ///         "poll_id": "1",
+    ///         "user": { "id": 1, "is_bot": false, "first_name": "Name" },
+    ///         "option_ids": [0]
+    ///     }
+    /// }"#;
+    ///
+    /// let update = Update::from_json(body).unwrap();
+    /// assert_eq!(update.id, 1);
+    /// assert!(matches!(update.kind, UpdateKind::PollAnswer(_)));
+    /// ```
+    pub fn from_json(json: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(json)
+    }
+
     /// Tries to parse `value` into `Update`, logging an error on failure.
     ///
     /// It is used to implement update listeners.
@@ -50,8 +91,7 @@ impl Update {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, PartialEq)]
 pub enum UpdateKind {
     /// New incoming message of any kind — text, photo, sticker, etc.
     Message(Message),
@@ -108,31 +148,232 @@ pub enum UpdateKind {
     ///
     /// [`AllowedUpdate::ChatMember`]: crate::types::AllowedUpdate::ChatMember
     ChatMember(ChatMemberUpdated),
+
+    /// A reaction to a message was changed by a user. The bot must be an
+    /// administrator in the chat and must explicitly specify
+    /// [`AllowedUpdate::MessageReaction`] in the list of `allowed_updates` to
+    /// receive these updates. The update isn't received for reactions set by
+    /// bots.
+    ///
+    /// [`AllowedUpdate::MessageReaction`]: crate::types::AllowedUpdate::MessageReaction
+    MessageReaction(MessageReactionUpdated),
+
+    /// The bot was connected to or disconnected from a business account, or
+    /// a user edited an existing connection with the bot.
+    BusinessConnection(BusinessConnection),
+
+    /// New message from a connected business account.
+    BusinessMessage(Message),
+
+    /// New version of a message from a connected business account.
+    EditedBusinessMessage(Message),
+
+    /// Messages were deleted from a connected business account.
+    DeletedBusinessMessages(BusinessMessagesDeleted),
+
+    /// An update of a kind unknown to teloxide-core (e.g. added in a newer
+    /// version of the Bot API).
+    ///
+    /// Contains the raw JSON of the update (without `update_id`), e.g.
+    /// `{"new_kind": { ... }}`.
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for UpdateKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct UpdateKindVisitor;
+
+        impl<'de> Visitor<'de> for UpdateKindVisitor {
+            type Value = UpdateKind;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a map with an update")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut kind = None;
+                let mut unknown = serde_json::Map::new();
+
+                // Like derived impls, the first known key determines the kind, other
+                // keys are ignored. Malformed updates of known kinds are still errors.
+                while let Some(key) = map.next_key::<String>()? {
+                    if kind.is_some() {
+                        map.next_value::<de::IgnoredAny>()?;
+                        continue;
+                    }
+
+                    kind = Some(match key.as_str() {
+                        "message" => UpdateKind::Message(map.next_value()?),
+                        "edited_message" => UpdateKind::EditedMessage(map.next_value()?),
+                        "channel_post" => UpdateKind::ChannelPost(map.next_value()?),
+                        "edited_channel_post" => UpdateKind::EditedChannelPost(map.next_value()?),
+                        "inline_query" => UpdateKind::InlineQuery(map.next_value()?),
+                        "chosen_inline_result" => UpdateKind::ChosenInlineResult(map.next_value()?),
+                        "callback_query" => UpdateKind::CallbackQuery(map.next_value()?),
+                        "shipping_query" => UpdateKind::ShippingQuery(map.next_value()?),
+                        "pre_checkout_query" => UpdateKind::PreCheckoutQuery(map.next_value()?),
+                        "poll" => UpdateKind::Poll(map.next_value()?),
+                        "poll_answer" => UpdateKind::PollAnswer(map.next_value()?),
+                        "my_chat_member" => UpdateKind::MyChatMember(map.next_value()?),
+                        "chat_member" => UpdateKind::ChatMember(map.next_value()?),
+                        "message_reaction" => UpdateKind::MessageReaction(map.next_value()?),
+                        "business_connection" => UpdateKind::BusinessConnection(map.next_value()?),
+                        "business_message" => UpdateKind::BusinessMessage(map.next_value()?),
+                        "edited_business_message" => {
+                            UpdateKind::EditedBusinessMessage(map.next_value()?)
+                        }
+                        "deleted_business_messages" => {
+                            UpdateKind::DeletedBusinessMessages(map.next_value()?)
+                        }
+                        _ => {
+                            unknown.insert(key, map.next_value()?);
+                            continue;
+                        }
+                    });
+                }
+
+                match kind {
+                    Some(kind) => Ok(kind),
+                    None if !unknown.is_empty() => Ok(UpdateKind::Unknown(Value::Object(unknown))),
+                    None => Err(de::Error::custom("update doesn't contain any kind")),
+                }
+            }
+        }
+
+        deserializer.deserialize_map(UpdateKindVisitor)
+    }
+}
+
+impl Serialize for UpdateKind {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        const NAME: &str = "UpdateKind";
+
+        match self {
+            UpdateKind::Message(v) => s.serialize_newtype_variant(NAME, 0, "message", v),
+            UpdateKind::EditedMessage(v) => {
+                s.serialize_newtype_variant(NAME, 1, "edited_message", v)
+            }
+            UpdateKind::ChannelPost(v) => s.serialize_newtype_variant(NAME, 2, "channel_post", v),
+            UpdateKind::EditedChannelPost(v) => {
+                s.serialize_newtype_variant(NAME, 3, "edited_channel_post", v)
+            }
+            UpdateKind::InlineQuery(v) => s.serialize_newtype_variant(NAME, 4, "inline_query", v),
+            UpdateKind::ChosenInlineResult(v) => {
+                s.serialize_newtype_variant(NAME, 5, "chosen_inline_result", v)
+            }
+            UpdateKind::CallbackQuery(v) => {
+                s.serialize_newtype_variant(NAME, 6, "callback_query", v)
+            }
+            UpdateKind::ShippingQuery(v) => {
+                s.serialize_newtype_variant(NAME, 7, "shipping_query", v)
+            }
+            UpdateKind::PreCheckoutQuery(v) => {
+                s.serialize_newtype_variant(NAME, 8, "pre_checkout_query", v)
+            }
+            UpdateKind::Poll(v) => s.serialize_newtype_variant(NAME, 9, "poll", v),
+            UpdateKind::PollAnswer(v) => s.serialize_newtype_variant(NAME, 10, "poll_answer", v),
+            UpdateKind::MyChatMember(v) => {
+                s.serialize_newtype_variant(NAME, 11, "my_chat_member", v)
+            }
+            UpdateKind::ChatMember(v) => s.serialize_newtype_variant(NAME, 12, "chat_member", v),
+            UpdateKind::MessageReaction(v) => {
+                s.serialize_newtype_variant(NAME, 13, "message_reaction", v)
+            }
+            UpdateKind::BusinessConnection(v) => {
+                s.serialize_newtype_variant(NAME, 14, "business_connection", v)
+            }
+            UpdateKind::BusinessMessage(v) => {
+                s.serialize_newtype_variant(NAME, 15, "business_message", v)
+            }
+            UpdateKind::EditedBusinessMessage(v) => {
+                s.serialize_newtype_variant(NAME, 16, "edited_business_message", v)
+            }
+            UpdateKind::DeletedBusinessMessages(v) => {
+                s.serialize_newtype_variant(NAME, 17, "deleted_business_messages", v)
+            }
+            // The raw JSON already contains the key
+            UpdateKind::Unknown(v) => v.serialize(s),
+        }
+    }
 }
 
 impl Update {
-    pub fn user(&self) -> Option<&User> {
+    /// Returns the user that triggered this update, if any.
+    ///
+    /// For messages this is [`Message::from`], which is `None` for channel
+    /// posts and messages sent on behalf of chats. For reactions this is
+    /// `None` if the reaction was changed anonymously. Updates of kind
+    /// [`UpdateKind::Poll`], [`UpdateKind::DeletedBusinessMessages`] and
+    /// [`UpdateKind::Unknown`] don't have a user.
+    pub fn from_user(&self) -> Option<&User> {
         match &self.kind {
-            UpdateKind::Message(m) => m.from(),
-            UpdateKind::EditedMessage(m) => m.from(),
-            UpdateKind::CallbackQuery(query) => Some(&query.from),
-            UpdateKind::ChosenInlineResult(chosen) => Some(&chosen.from),
+            UpdateKind::Message(m)
+            | UpdateKind::EditedMessage(m)
+            | UpdateKind::ChannelPost(m)
+            | UpdateKind::EditedChannelPost(m)
+            | UpdateKind::BusinessMessage(m)
+            | UpdateKind::EditedBusinessMessage(m) => m.from(),
             UpdateKind::InlineQuery(query) => Some(&query.from),
+            UpdateKind::ChosenInlineResult(chosen) => Some(&chosen.from),
+            UpdateKind::CallbackQuery(query) => Some(&query.from),
             UpdateKind::ShippingQuery(query) => Some(&query.from),
             UpdateKind::PreCheckoutQuery(query) => Some(&query.from),
+            UpdateKind::Poll(_)
+            | UpdateKind::DeletedBusinessMessages(_)
+            | UpdateKind::Unknown(_) => None,
             UpdateKind::PollAnswer(answer) => Some(&answer.user),
-            _ => None,
+            UpdateKind::MyChatMember(updated) | UpdateKind::ChatMember(updated) => {
+                Some(&updated.from)
+            }
+            UpdateKind::MessageReaction(reaction) => reaction.user.as_ref(),
+            UpdateKind::BusinessConnection(connection) => Some(&connection.user),
         }
     }
 
+    // FIXME: remove this method in the next minor version bump (0.3.0)
+    #[deprecated = "This method doesn't handle all update kinds. Use `Update::from_user` instead."]
+    pub fn user(&self) -> Option<&User> {
+        self.from_user()
+    }
+
+    /// Returns the chat this update came from, if any.
+    ///
+    /// For callback queries this is the chat of the message with the callback
+    /// button, which is `None` if the message was sent via inline mode.
+    /// Inline queries, chosen inline results, shipping and pre-checkout
+    /// queries, polls, poll answers, business connections and unknown updates
+    /// don't have a chat.
     pub fn chat(&self) -> Option<&Chat> {
         match &self.kind {
-            UpdateKind::Message(m) => Some(&m.chat),
-            UpdateKind::EditedMessage(m) => Some(&m.chat),
-            UpdateKind::ChannelPost(p) => Some(&p.chat),
-            UpdateKind::EditedChannelPost(p) => Some(&p.chat),
-            UpdateKind::CallbackQuery(q) => Some(&q.message.as_ref()?.chat),
-            _ => None,
+            UpdateKind::Message(m)
+            | UpdateKind::EditedMessage(m)
+            | UpdateKind::ChannelPost(m)
+            | UpdateKind::EditedChannelPost(m)
+            | UpdateKind::BusinessMessage(m)
+            | UpdateKind::EditedBusinessMessage(m) => Some(&m.chat),
+            UpdateKind::CallbackQuery(query) => Some(&query.message.as_ref()?.chat),
+            UpdateKind::MyChatMember(updated) | UpdateKind::ChatMember(updated) => {
+                Some(&updated.chat)
+            }
+            UpdateKind::MessageReaction(reaction) => Some(&reaction.chat),
+            UpdateKind::DeletedBusinessMessages(deleted) => Some(&deleted.chat),
+            UpdateKind::InlineQuery(_)
+            | UpdateKind::ChosenInlineResult(_)
+            | UpdateKind::ShippingQuery(_)
+            | UpdateKind::PreCheckoutQuery(_)
+            | UpdateKind::Poll(_)
+            | UpdateKind::PollAnswer(_)
+            | UpdateKind::BusinessConnection(_)
+            | UpdateKind::Unknown(_) => None,
         }
     }
 }
@@ -141,7 +382,7 @@ impl Update {
 mod test {
     use crate::types::{
         Chat, ChatKind, ChatPrivate, ForwardKind, ForwardOrigin, MediaKind, MediaText, Message,
-        MessageCommon, MessageKind, Update, UpdateKind, User,
+        MessageCommon, MessageId, MessageKind, ReactionType, Update, UpdateKind, User, UserId,
     };
 
     // TODO: more tests for deserialization
@@ -173,7 +414,9 @@ mod test {
             id: 892_252_934,
             kind: UpdateKind::Message(Message {
                 via_bot: None,
-                id: 6557,
+                id: MessageId(6557),
+                thread_id: None,
+                business_connection_id: None,
                 date: 1_569_518_342,
                 chat: Chat {
                     id: 218_485_655,
@@ -189,7 +432,7 @@ mod test {
                 },
                 kind: MessageKind::Common(MessageCommon {
                     from: Some(User {
-                        id: 218_485_655,
+                        id: UserId(218_485_655),
                         is_bot: false,
                         first_name: String::from("Waffle"),
                         last_name: None,
@@ -215,6 +458,227 @@ mod test {
         assert_eq!(expected, actual);
     }
 
+    const USER: &str = r#"{"id":1,"is_bot":false,"first_name":"User"}"#;
+    const CHAT: &str = r#"{"id":-2,"type":"group","title":"Chat"}"#;
+
+    fn update(kind: &str, value: &str) -> Update {
+        let json = format!(r#"{{"update_id":1,"{}":{}}}"#, kind, value);
+        Update::from_json(json.as_bytes()).unwrap()
+    }
+
+    fn message_json() -> String {
+        format!(
+            r#"{{"message_id":1,"date":0,"from":{},"chat":{},"text":"text"}}"#,
+            USER, CHAT
+        )
+    }
+
+    fn ids(update: &Update) -> (Option<u64>, Option<i64>) {
+        (
+            update.from_user().map(|user| user.id.0),
+            update.chat().map(|chat| chat.id),
+        )
+    }
+
+    #[test]
+    fn from_user_and_chat() {
+        let chat_member_updated = format!(
+            r#"{{"chat":{chat},"from":{user},"date":0,
+                "old_chat_member":{{"user":{user},"status":"left"}},
+                "new_chat_member":{{"user":{user},"status":"member"}}}}"#,
+            chat = CHAT,
+            user = USER
+        );
+
+        let cases = vec![
+            ("message", message_json(), (Some(1), Some(-2))),
+            ("edited_message", message_json(), (Some(1), Some(-2))),
+            ("channel_post", message_json(), (Some(1), Some(-2))),
+            ("edited_channel_post", message_json(), (Some(1), Some(-2))),
+            (
+                "inline_query",
+                format!(r#"{{"id":"1","from":{},"query":"","offset":""}}"#, USER),
+                (Some(1), None),
+            ),
+            (
+                "chosen_inline_result",
+                format!(r#"{{"result_id":"1","from":{},"query":""}}"#, USER),
+                (Some(1), None),
+            ),
+            (
+                "callback_query",
+                format!(
+                    r#"{{"id":"1","from":{},"chat_instance":"1","message":{}}}"#,
+                    USER,
+                    message_json()
+                ),
+                (Some(1), Some(-2)),
+            ),
+            (
+                "callback_query",
+                format!(
+                    r#"{{"id":"1","from":{},"chat_instance":"1","inline_message_id":"1"}}"#,
+                    USER
+                ),
+                (Some(1), None),
+            ),
+            (
+                "shipping_query",
+                format!(
+                    r#"{{"id":"1","from":{},"invoice_payload":"",
+                        "shipping_address":{{"country_code":"US","state":"","city":"",
+                        "street_line1":"","street_line2":"","post_code":""}}}}"#,
+                    USER
+                ),
+                (Some(1), None),
+            ),
+            (
+                "pre_checkout_query",
+                format!(
+                    r#"{{"id":"1","from":{},"currency":"USD","total_amount":1,"invoice_payload":""}}"#,
+                    USER
+                ),
+                (Some(1), None),
+            ),
+            (
+                "poll",
+                r#"{"id":"1","question":"?","options":[],"is_closed":false,
+                    "total_voter_count":0,"is_anonymous":true,"type":"regular",
+                    "allows_multiple_answers":false}"#
+                    .to_owned(),
+                (None, None),
+            ),
+            (
+                "poll_answer",
+                format!(r#"{{"poll_id":"1","user":{},"option_ids":[]}}"#, USER),
+                (Some(1), None),
+            ),
+            (
+                "my_chat_member",
+                chat_member_updated.clone(),
+                (Some(1), Some(-2)),
+            ),
+            ("chat_member", chat_member_updated, (Some(1), Some(-2))),
+            (
+                "message_reaction",
+                format!(
+                    r#"{{"chat":{},"message_id":1,"user":{},"date":0,
+                        "old_reaction":[],"new_reaction":[{{"type":"emoji","emoji":"👍"}}]}}"#,
+                    CHAT, USER
+                ),
+                (Some(1), Some(-2)),
+            ),
+            (
+                "message_reaction",
+                format!(
+                    r#"{{"chat":{},"message_id":1,"actor_chat":{},"date":0,
+                        "old_reaction":[],"new_reaction":[]}}"#,
+                    CHAT, CHAT
+                ),
+                (None, Some(-2)),
+            ),
+            (
+                "business_connection",
+                format!(
+                    r#"{{"id":"1","user":{},"user_chat_id":1,"date":0,"can_reply":true,
+                        "is_enabled":true}}"#,
+                    USER
+                ),
+                (Some(1), None),
+            ),
+            ("business_message", message_json(), (Some(1), Some(-2))),
+            (
+                "edited_business_message",
+                message_json(),
+                (Some(1), Some(-2)),
+            ),
+            (
+                "deleted_business_messages",
+                format!(
+                    r#"{{"business_connection_id":"1","chat":{},"message_ids":[1,2]}}"#,
+                    CHAT
+                ),
+                (None, Some(-2)),
+            ),
+        ];
+
+        for (kind, value, expected) in cases {
+            assert_eq!(ids(&update(kind, &value)), expected, "{}", kind);
+        }
+    }
+
+    #[test]
+    fn from_json_round_trip() {
+        let json = br#"{
+            "update_id":892252934,
+            "message":{
+                "message_id":6557,
+                "from":{
+                    "id":218485655,
+                    "is_bot": false,
+                    "first_name":"Waffle",
+                    "username":"WaffleLapkin",
+                    "language_code":"en"
+                },
+                "chat":{
+                    "id":218485655,
+                    "first_name":"Waffle",
+                    "username":"WaffleLapkin",
+                    "type":"private"
+                },
+               "date":1569518342,
+               "text":"hello there"
+            }
+        }"#;
+
+        let update = Update::from_json(json).unwrap();
+        let serialized = serde_json::to_vec(&update).unwrap();
+        assert_eq!(Update::from_json(&serialized).unwrap(), update);
+
+        assert!(Update::from_json(b"{}").is_err());
+    }
+
+    #[test]
+    fn message_reaction() {
+        let json = format!(
+            r#"{{"update_id":1,"message_reaction":{{"chat":{},"message_id":7,"user":{},"date":0,
+                "old_reaction":[{{"type":"custom_emoji","custom_emoji_id":"42"}}],
+                "new_reaction":[{{"type":"emoji","emoji":"🔥"}}]}}}}"#,
+            CHAT, USER
+        );
+
+        let update = Update::from_json(json.as_bytes()).unwrap();
+        let reaction = match &update.kind {
+            UpdateKind::MessageReaction(reaction) => reaction,
+            _ => panic!("Expected `MessageReaction`"),
+        };
+        assert_eq!(reaction.message_id, MessageId(7));
+        assert_eq!(reaction.old_reaction, [ReactionType::custom_emoji("42")]);
+        assert_eq!(reaction.new_reaction, [ReactionType::emoji("🔥")]);
+
+        let ser = serde_json::to_vec(&update).unwrap();
+        assert_eq!(Update::from_json(&ser).unwrap(), update);
+    }
+
+    #[test]
+    fn unknown_kind() {
+        let json = br#"{"update_id":1,"future_update":{"field":[1,2]}}"#;
+
+        let update = Update::from_json(json).unwrap();
+        assert_eq!(
+            update.kind,
+            UpdateKind::Unknown(serde_json::json!({ "future_update": { "field": [1, 2] } }))
+        );
+        assert_eq!(update.from_user(), None);
+        assert_eq!(update.chat(), None);
+
+        let ser = serde_json::to_vec(&update).unwrap();
+        assert_eq!(Update::from_json(&ser).unwrap(), update);
+
+        // Known kinds still must be valid
+        assert!(Update::from_json(br#"{"update_id":1,"message":{}}"#).is_err());
+    }
+
     #[test]
     fn de_private_chat_text_message() {
         let text = r#"
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::types::UserId;
+
 /// This object represents a Telegram user or bot.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#user).
@@ -7,7 +9,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct User {
     /// Unique identifier for this user or bot.
-    pub id: i64,
+    pub id: UserId,
 
     /// `true`, if this user is a bot.
     pub is_bot: bool,
@@ -40,7 +42,7 @@ impl User {
     }
 
     pub fn url(&self) -> reqwest::Url {
-        reqwest::Url::parse(format!("tg://user/?id={}", self.id).as_str()).unwrap()
+        self.id.url()
     }
 }
 
@@ -59,7 +61,7 @@ mod tests {
             "language_code":"ru"
         }"#;
         let expected = User {
-            id: 12345,
+            id: UserId(12345),
             is_bot: false,
             first_name: "firstName".to_string(),
             last_name: Some("lastName".to_string()),
@@ -0,0 +1,57 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// A unique user (or bot) identifier.
+///
+/// This is a separate type (and not just an integer) to not confuse user
+/// identifiers with other identifiers (e.g. [`MessageId`]). User identifiers
+/// are always positive and may have up to 52 significant bits.
+///
+/// Since the identifier of a private chat with a user is equal to the user
+/// identifier, `UserId` can be converted into [`ChatId`].
+///
+/// [`MessageId`]: crate::types::MessageId
+#[derive(
+    Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Display, From,
+)]
+#[serde(transparent)]
+pub struct UserId(pub u64);
+
+impl UserId {
+    /// Returns an URL that links to the user with this id in the form of
+    /// `tg://user/?id=<...>`.
+    pub fn url(self) -> reqwest::Url {
+        reqwest::Url::parse(&format!("tg://user/?id={}", self)).unwrap()
+    }
+}
+
+impl From<UserId> for u64 {
+    fn from(UserId(id): UserId) -> Self {
+        id
+    }
+}
+
+impl From<UserId> for ChatId {
+    fn from(UserId(id): UserId) -> Self {
+        // User ids have at most 52 significant bits, so the conversion is lossless
+        ChatId::Id(id as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde() {
+        assert_eq!(serde_json::to_string(&UserId(42)).unwrap(), "42");
+        assert_eq!(serde_json::from_str::<UserId>("42").unwrap(), UserId(42));
+    }
+
+    #[test]
+    fn into_chat_id() {
+        assert_eq!(ChatId::from(UserId(42)), ChatId::Id(42));
+    }
+}
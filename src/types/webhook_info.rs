@@ -35,3 +35,39 @@ pub struct WebhookInfo {
     /// types.
     pub allowed_updates: Option<Vec<String>>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{
+            "url": "https://example.com/webhook",
+            "has_custom_certificate": false,
+            "pending_update_count": 3,
+            "last_error_date": 1618070000,
+            "last_error_message": "Connection timed out",
+            "max_connections": 40,
+            "allowed_updates": ["message", "callback_query"]
+        }"#;
+
+        let info: WebhookInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(info.url, "https://example.com/webhook");
+        assert_eq!(info.pending_update_count, 3);
+        assert_eq!(info.ip_address, None);
+        assert_eq!(info.last_error_date, Some(1618070000));
+        assert_eq!(info.max_connections, Some(40));
+        assert_eq!(
+            info.allowed_updates.as_deref(),
+            Some(&["message".to_owned(), "callback_query".to_owned()][..])
+        );
+
+        // An empty `url` means that the webhook is not set up
+        let info: WebhookInfo = serde_json::from_str(
+            r#"{"url":"","has_custom_certificate":false,"pending_update_count":0}"#,
+        )
+        .unwrap();
+        assert!(info.url.is_empty());
+    }
+}
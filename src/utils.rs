@@ -0,0 +1,215 @@
+//! Some useful utilities.
+
+use crate::{types::ParseMode, RequestError};
+
+pub use self::split::split_text;
+
+pub mod html;
+#[cfg(feature = "login_widget")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "login_widget")))]
+pub mod login_widget;
+pub mod markdown;
+#[cfg(feature = "web_app")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "web_app")))]
+pub mod web_app;
+
+#[cfg(any(feature = "web_app", feature = "login_widget"))]
+mod signed;
+mod split;
+
+/// The maximum length of a message text (e.g. [`SendMessage::text`]), in
+/// UTF-16 code units.
+///
+/// [`SendMessage::text`]: crate::payloads::SendMessage::text
+pub const MESSAGE_TEXT_MAX_LEN: usize = 4096;
+
+/// The maximum length of a media caption (e.g. [`SendPhoto::caption`]), in
+/// UTF-16 code units.
+///
+/// [`SendPhoto::caption`]: crate::payloads::SendPhoto::caption
+pub const CAPTION_MAX_LEN: usize = 1024;
+
+/// The maximum length of a chat title (see [`SetChatTitle::title`]), in
+/// UTF-16 code units.
+///
+/// [`SetChatTitle::title`]: crate::payloads::SetChatTitle::title
+pub const CHAT_TITLE_MAX_LEN: usize = 128;
+
+/// Returns the length of `s` in UTF-16 code units.
+///
+/// Telegram measures lengths of texts (and offsets of [`MessageEntity`]) in
+/// UTF-16 code units, which differs from both the number of bytes and the
+/// number of `char`s: e.g. most emoji take 2 code units.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::utils::utf16_len;
+///
+/// assert_eq!(utf16_len("hi"), 2);
+/// assert_eq!(utf16_len("привет"), 6);
+/// assert_eq!(utf16_len("👋"), 2);
+/// ```
+///
+/// [`MessageEntity`]: crate::types::MessageEntity
+pub fn utf16_len(s: &str) -> usize {
+    s.encode_utf16().count()
+}
+
+/// Returns [`RequestError::MessageTooLong`] if `text` is longer than `limit`
+/// UTF-16 code units.
+pub(crate) fn check_len(text: &str, limit: usize) -> Result<(), RequestError> {
+    // UTF-16 length never exceeds UTF-8 length, so short texts can be skipped
+    // without counting
+    if text.len() <= limit {
+        return Ok(());
+    }
+
+    match utf16_len(text) {
+        actual if actual > limit => Err(RequestError::MessageTooLong { limit, actual }),
+        _ => Ok(()),
+    }
+}
+
+/// Same as [`check_len`], but only checks texts without `parse_mode`.
+///
+/// Telegram applies limits after parsing entities, so markup (e.g. HTML tags)
+/// isn't counted. Since parsing the markup the way Telegram does is not
+/// trivial, texts with markup are left to Telegram to check.
+pub(crate) fn check_len_unless_parsed(
+    text: &str,
+    parse_mode: Option<ParseMode>,
+    limit: usize,
+) -> Result<(), RequestError> {
+    match parse_mode {
+        Some(_) => Ok(()),
+        None => check_len(text, limit),
+    }
+}
+
+/// Checks a caption with [`check_len_unless_parsed`].
+pub(crate) fn check_caption(
+    caption: Option<&str>,
+    parse_mode: Option<ParseMode>,
+) -> Result<(), RequestError> {
+    match caption {
+        Some(caption) => check_len_unless_parsed(caption, parse_mode, CAPTION_MAX_LEN),
+        None => Ok(()),
+    }
+}
+
+/// Same as [`check_len`], but also returns [`RequestError::EmptyText`] if
+/// `text` is empty.
+pub(crate) fn check_non_empty_len(text: &str, limit: usize) -> Result<(), RequestError> {
+    match text.is_empty() {
+        true => Err(RequestError::EmptyText),
+        false => check_len(text, limit),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utf16() {
+        assert_eq!(utf16_len(""), 0);
+        assert_eq!(utf16_len("abc"), 3);
+        // 2 bytes in UTF-8, 1 code unit in UTF-16
+        assert_eq!(utf16_len("é"), 1);
+        // 4 bytes in UTF-8, 2 code units in UTF-16
+        assert_eq!(utf16_len("🦀"), 2);
+        // 👨‍👩‍👧 is 3 emoji joined with 2 zero width joiners
+        assert_eq!(utf16_len("👨‍👩‍👧"), 8);
+    }
+
+    #[test]
+    fn limit() {
+        assert!(check_len(&"a".repeat(4096), MESSAGE_TEXT_MAX_LEN).is_ok());
+        // 2048 emoji take 8192 bytes, but only 4096 code units
+        assert!(check_len(&"🦀".repeat(2048), MESSAGE_TEXT_MAX_LEN).is_ok());
+
+        match check_len(&"🦀".repeat(2049), MESSAGE_TEXT_MAX_LEN) {
+            Err(RequestError::MessageTooLong { limit, actual }) => {
+                assert_eq!(limit, 4096);
+                assert_eq!(actual, 4098);
+            }
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_mode() {
+        use crate::{
+            payloads::{SendMessage, SendMessageSetters},
+            requests::Payload,
+        };
+
+        // 4096 characters of text, but 4103 with markup
+        let text = format!("<b>{}</b>", "a".repeat(4096));
+        assert!(SendMessage::new(0, text.clone())
+            .parse_mode(ParseMode::Html)
+            .check_lengths()
+            .is_ok());
+        assert!(matches!(
+            SendMessage::new(0, text).check_lengths(),
+            Err(RequestError::MessageTooLong {
+                limit: 4096,
+                actual: 4103
+            })
+        ));
+    }
+
+    #[test]
+    fn media_group() {
+        use crate::{
+            payloads::SendMediaGroup,
+            requests::Payload,
+            types::{InputFile, InputMedia, InputMediaDocument, InputMediaPhoto},
+        };
+
+        let photo = |caption: String| {
+            InputMedia::Photo(InputMediaPhoto::new(InputFile::file_id("id")).caption(caption))
+        };
+        let document = InputMedia::Document(InputMediaDocument::new(InputFile::file_id("id")));
+
+        let media = vec![document.clone(), photo("a".repeat(1024))];
+        assert!(SendMediaGroup::new(0, media).check_lengths().is_ok());
+
+        let mut parsed = InputMediaPhoto::new(InputFile::file_id("id")).caption("a".repeat(1025));
+        parsed.parse_mode = Some(ParseMode::Html);
+        let media = vec![document.clone(), InputMedia::Photo(parsed)];
+        assert!(SendMediaGroup::new(0, media).check_lengths().is_ok());
+
+        let media = vec![document, photo("a".repeat(1025))];
+        assert!(matches!(
+            SendMediaGroup::new(0, media).check_lengths(),
+            Err(RequestError::MessageTooLong {
+                limit: 1024,
+                actual: 1025
+            })
+        ));
+    }
+
+    #[test]
+    fn chat_title() {
+        use crate::{payloads::SetChatTitle, requests::Payload};
+
+        assert!(SetChatTitle::new(0, "t").check_lengths().is_ok());
+        assert!(SetChatTitle::new(0, "я".repeat(128))
+            .check_lengths()
+            .is_ok());
+
+        assert!(matches!(
+            SetChatTitle::new(0, "").check_lengths(),
+            Err(RequestError::EmptyText)
+        ));
+        assert!(matches!(
+            SetChatTitle::new(0, "🦀".repeat(65)).check_lengths(),
+            Err(RequestError::MessageTooLong {
+                limit: 128,
+                actual: 130
+            })
+        ));
+    }
+}
@@ -0,0 +1,188 @@
+//! Helpers for the [Telegram Login Widget].
+//!
+//! [Telegram Login Widget]: https://core.telegram.org/widgets/login
+
+use std::time::Duration;
+
+use sha2::{Digest, Sha256};
+
+use crate::types::UserId;
+
+use super::signed::{self, Fields};
+
+pub use super::signed::ValidationError;
+
+/// The default maximum age of authorization data accepted by [`check`].
+pub const AUTH_DATA_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Authorization data of a user, sent by the Login Widget.
+///
+/// [The official docs](https://core.telegram.org/widgets/login#receiving-authorization-data).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LoginData {
+    /// Unique identifier of the user.
+    pub id: UserId,
+
+    /// User‘s first name.
+    pub first_name: String,
+
+    /// User‘s last name.
+    pub last_name: Option<String>,
+
+    /// User‘s username.
+    pub username: Option<String>,
+
+    /// URL of the user’s profile photo.
+    pub photo_url: Option<String>,
+
+    /// Unix time when the user was authorized.
+    pub auth_date: i32,
+
+    /// A hash of all the other fields, which the bot server can use to check
+    /// their validity.
+    pub hash: String,
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "chrono")))]
+impl LoginData {
+    /// [`LoginData::auth_date`] as [`DateTime`].
+    ///
+    /// [`DateTime`]: chrono::DateTime
+    pub fn auth_date_utc(&self) -> chrono::DateTime<chrono::Utc> {
+        crate::types::from_unix_time(self.auth_date)
+    }
+}
+
+/// Checks authorization data sent by the Login Widget and parses it, using
+/// [`AUTH_DATA_MAX_AGE`] as the maximum age of the data.
+///
+/// See [`check_with_max_age`] for details.
+pub fn check(data: &str, token: &str) -> Result<LoginData, ValidationError> {
+    check_with_max_age(data, token, AUTH_DATA_MAX_AGE)
+}
+
+/// Checks authorization data sent by the Login Widget and parses it.
+///
+/// `data` is a query string with the fields of the user, e.g. the query of the
+/// `data-auth-url` redirect. The data is accepted if it was signed by
+/// Telegram for the bot with the given `token` (the signature is compared in
+/// constant time) and its `auth_date` isn't older than `max_age`.
+///
+/// Per [Checking authorization], the secret key is SHA-256 of the bot token
+/// and `hash` is HMAC-SHA256 of the other fields, sorted and joined as
+/// `key=value` lines, with the secret key.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::utils::login_widget::{self, ValidationError};
+///
+/// // e.g. `request.uri().query()`
+/// let query = "id=218485655&first_name=Andrey&auth_date=1650000000&hash=00";
+///
+/// assert_eq!(
+///     login_widget::check(query, "123456:ABC"),
+///     Err(ValidationError::InvalidSignature)
+/// );
+/// ```
+///
+/// [Checking authorization]: https://core.telegram.org/widgets/login#checking-authorization
+pub fn check_with_max_age(
+    data: &str,
+    token: &str,
+    max_age: Duration,
+) -> Result<LoginData, ValidationError> {
+    check_at(data, token, max_age, signed::now())
+}
+
+fn check_at(
+    data: &str,
+    token: &str,
+    max_age: Duration,
+    now: u64,
+) -> Result<LoginData, ValidationError> {
+    let fields = Fields::parse(data);
+    fields.verify(&Sha256::digest(token.as_bytes()))?;
+    let auth_date = fields.auth_date(max_age, now)?;
+
+    Ok(LoginData {
+        id: UserId(
+            fields
+                .parsed("id")?
+                .ok_or(ValidationError::MissingField("id"))?,
+        ),
+        first_name: fields.required("first_name")?.to_owned(),
+        last_name: fields.owned("last_name"),
+        username: fields.owned("username"),
+        photo_url: fields.owned("photo_url"),
+        auth_date,
+        hash: fields.owned("hash").unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKEN: &str = "123456:ABC-DEF1234ghIkl-zyx57W2v1u123ew11";
+
+    // Telegram doesn't publish test vectors, these were generated with the
+    // algorithm from the docs using `TOKEN`
+    const FULL: &str = "id=218485655&first_name=Andrey&last_name=Ivanov&username=andrey\
+        &photo_url=https%3A%2F%2Ft.me%2Fi%2Fuserpic%2F320%2Fandrey.jpg&auth_date=1650000000\
+        &hash=c66439d8e402162f3d0031a0c96e82ce2f35a65f232e7ea6963c405408c01de2";
+    const MINIMAL: &str = "id=218485655&first_name=Andrey&auth_date=1650000000\
+        &hash=5bdbc9954314c058ac20a1a95e047d59bc9b3ccf2132e4946f1fc5e5e96735ce";
+
+    const AUTH_DATE: u64 = 1650000000;
+
+    #[test]
+    fn check() {
+        assert_eq!(
+            check_at(FULL, TOKEN, AUTH_DATA_MAX_AGE, AUTH_DATE + 60),
+            Ok(LoginData {
+                id: UserId(218485655),
+                first_name: "Andrey".to_owned(),
+                last_name: Some("Ivanov".to_owned()),
+                username: Some("andrey".to_owned()),
+                photo_url: Some("https://t.me/i/userpic/320/andrey.jpg".to_owned()),
+                auth_date: 1650000000,
+                hash: "c66439d8e402162f3d0031a0c96e82ce2f35a65f232e7ea6963c405408c01de2".to_owned(),
+            })
+        );
+
+        let data = check_at(MINIMAL, TOKEN, AUTH_DATA_MAX_AGE, AUTH_DATE).unwrap();
+        assert_eq!(data.last_name, None);
+        assert_eq!(data.photo_url, None);
+
+        #[cfg(feature = "chrono")]
+        assert_eq!(data.auth_date_utc().timestamp(), 1650000000);
+    }
+
+    #[test]
+    fn check_errors() {
+        let err = |data: &str, token: &str, now: u64| {
+            check_at(data, token, AUTH_DATA_MAX_AGE, now).unwrap_err()
+        };
+
+        assert_eq!(
+            err(FULL, "654321:another-token", AUTH_DATE),
+            ValidationError::InvalidSignature
+        );
+        assert_eq!(
+            err(&MINIMAL.replace("218485655", "1"), TOKEN, AUTH_DATE),
+            ValidationError::InvalidSignature
+        );
+        assert_eq!(
+            err("id=1&first_name=A&auth_date=1650000000", TOKEN, AUTH_DATE),
+            ValidationError::MissingField("hash")
+        );
+        assert_eq!(
+            err(MINIMAL, TOKEN, AUTH_DATE + AUTH_DATA_MAX_AGE.as_secs() + 1),
+            ValidationError::Expired {
+                auth_date: 1650000000
+            }
+        );
+    }
+}
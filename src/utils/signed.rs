@@ -0,0 +1,154 @@
+//! Checks of data signed by Telegram with a key derived from the bot token,
+//! shared by [`web_app`] and [`login_widget`].
+//!
+//! [`web_app`]: super::web_app
+//! [`login_widget`]: super::login_widget
+
+use std::{
+    borrow::Cow,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+pub(super) type HmacSha256 = Hmac<Sha256>;
+
+/// An error returned when validating data signed by Telegram, e.g. by
+/// [`validate_init_data`] or [`login_widget::check`].
+///
+/// [`validate_init_data`]: super::web_app::validate_init_data
+/// [`login_widget::check`]: super::login_widget::check
+#[derive(Debug, Clone, Eq, PartialEq, thiserror::Error)]
+pub enum ValidationError {
+    /// A required field (e.g. `hash` or `auth_date`) is missing.
+    #[error("The `{0}` field is missing")]
+    MissingField(&'static str),
+
+    /// A field couldn't be parsed.
+    #[error("The `{0}` field is invalid")]
+    InvalidField(&'static str),
+
+    /// The data wasn't signed with the bot token, or it was modified.
+    #[error("The signature doesn't match the data")]
+    InvalidSignature,
+
+    /// The signature is valid, but the data is older than allowed.
+    #[error("The data is too old, it was signed at {auth_date}")]
+    Expired {
+        /// Unix time when the data was signed.
+        auth_date: i32,
+    },
+}
+
+/// Fields of signed data, parsed from a query string.
+pub(super) struct Fields<'a>(Vec<(Cow<'a, str>, Cow<'a, str>)>);
+
+impl<'a> Fields<'a> {
+    pub(super) fn parse(query: &'a str) -> Self {
+        Self(form_urlencoded::parse(query.as_bytes()).collect())
+    }
+
+    pub(super) fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_ref())
+    }
+
+    pub(super) fn owned(&self, name: &str) -> Option<String> {
+        self.get(name).map(ToOwned::to_owned)
+    }
+
+    pub(super) fn required(&self, name: &'static str) -> Result<&str, ValidationError> {
+        self.get(name).ok_or(ValidationError::MissingField(name))
+    }
+
+    pub(super) fn parsed<T: FromStr>(
+        &self,
+        name: &'static str,
+    ) -> Result<Option<T>, ValidationError> {
+        self.get(name)
+            .map(|s| s.parse().map_err(|_| ValidationError::InvalidField(name)))
+            .transpose()
+    }
+
+    /// Checks that `hash` is HMAC-SHA256 of the other fields (sorted and
+    /// joined as `key=value` lines) with the `secret` key. The comparison
+    /// takes constant time.
+    pub(super) fn verify(&self, secret: &[u8]) -> Result<(), ValidationError> {
+        let hash = decode_hex(self.required("hash")?).ok_or(ValidationError::InvalidSignature)?;
+
+        let mut lines: Vec<_> = self
+            .0
+            .iter()
+            .filter(|(key, _)| key != "hash")
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect();
+        lines.sort_unstable();
+        let data_check_string = lines.join("\n");
+
+        let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC accepts keys of any size");
+        mac.update(data_check_string.as_bytes());
+        mac.verify_slice(&hash)
+            .map_err(|_| ValidationError::InvalidSignature)
+    }
+
+    /// Returns `auth_date`, checking that it isn't older than `max_age` at
+    /// `now` (Unix time).
+    pub(super) fn auth_date(&self, max_age: Duration, now: u64) -> Result<i32, ValidationError> {
+        let auth_date: i32 = self
+            .required("auth_date")?
+            .parse()
+            .map_err(|_| ValidationError::InvalidField("auth_date"))?;
+
+        // Data signed "in the future" (e.g. due to clock skew) is accepted
+        let age = now.saturating_sub(auth_date.max(0) as u64);
+        if age > max_age.as_secs() {
+            return Err(ValidationError::Expired { auth_date });
+        }
+
+        Ok(auth_date)
+    }
+}
+
+/// Returns the current Unix time.
+pub(super) fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    fn digit(b: u8) -> Option<u8> {
+        match b {
+            b'0'..=b'9' => Some(b - b'0'),
+            b'a'..=b'f' => Some(b - b'a' + 10),
+            b'A'..=b'F' => Some(b - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    let pairs = s.as_bytes().chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return None;
+    }
+
+    pairs
+        .map(|pair| Some(digit(pair[0])? << 4 | digit(pair[1])?))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex() {
+        assert_eq!(decode_hex("00ff1A"), Some(vec![0x00, 0xff, 0x1a]));
+        assert_eq!(decode_hex("abc"), None);
+        assert_eq!(decode_hex("zz"), None);
+    }
+}
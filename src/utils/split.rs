@@ -0,0 +1,426 @@
+//! Splitting of long texts into multiple messages.
+
+use crate::{types::ParseMode, utils::utf16_len};
+
+/// Splits `text` into parts which are at most `limit` UTF-16 code units long,
+/// so that each part can be sent as a separate message.
+///
+/// Parts are split at newlines if possible, then at spaces, and only then in
+/// the middle of a word. The newline or the space at which the text is split
+/// is not included in either part.
+///
+/// If `parse_mode` is set, formatting entities are not broken: an entity
+/// which spans several parts is closed at the end of one part and reopened at
+/// the start of the next one, and escape sequences, HTML tags and character
+/// references, links and custom emoji are never split. The length of a part
+/// includes its markup, so parts are never longer than `limit` after parsing
+/// either. A link (or a custom emoji) which is longer than `limit` by itself
+/// is put into a separate part as-is.
+///
+/// See also [`RequesterExt::send_long_message`].
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{types::ParseMode, utils::split_text};
+///
+/// assert_eq!(
+///     split_text("first line\nsecond line", 15, None),
+///     ["first line", "second line"]
+/// );
+///
+/// assert_eq!(
+///     split_text("<b>bold text</b>", 12, Some(ParseMode::Html)),
+///     ["<b>bold</b>", "<b>text</b>"]
+/// );
+/// ```
+///
+/// [`RequesterExt::send_long_message`]: crate::requests::RequesterExt::send_long_message
+pub fn split_text(text: &str, limit: usize, parse_mode: Option<ParseMode>) -> Vec<String> {
+    #[allow(deprecated)]
+    let tokens = match parse_mode {
+        None => plain_tokens(text),
+        Some(ParseMode::Html) => html_tokens(text),
+        Some(ParseMode::MarkdownV2) => markdown_tokens(text, true),
+        Some(ParseMode::Markdown) => markdown_tokens(text, false),
+    };
+
+    split_tokens(&tokens, limit)
+}
+
+/// A piece of text which can't be split.
+struct Token<'a> {
+    text: &'a str,
+    kind: Kind,
+}
+
+enum Kind {
+    /// A single character.
+    Char,
+
+    /// An escape sequence, a character reference, a link or a custom emoji.
+    Atom,
+
+    /// Start of an entity, which is ended by `close`.
+    Open { close: String },
+
+    /// End of an entity, which was started by an [`Kind::Open`] with the same
+    /// `close`.
+    Close { close: String },
+}
+
+impl Token<'_> {
+    fn is_separator(&self, sep: &str) -> bool {
+        matches!(self.kind, Kind::Char) && self.text == sep
+    }
+
+    fn is_content(&self) -> bool {
+        match self.kind {
+            Kind::Char => !self.text.trim().is_empty(),
+            Kind::Atom => true,
+            Kind::Open { .. } | Kind::Close { .. } => false,
+        }
+    }
+}
+
+/// Entities which are open at some point: `(start, end)` markup pairs.
+type Stack<'a> = Vec<(&'a str, &'a str)>;
+
+fn apply<'a>(stack: &mut Stack<'a>, token: &'a Token<'a>) {
+    match &token.kind {
+        Kind::Open { close } => stack.push((token.text, close)),
+        Kind::Close { close } => {
+            if let Some(i) = stack.iter().rposition(|(_, c)| c == close) {
+                stack.remove(i);
+            }
+        }
+        Kind::Char | Kind::Atom => {}
+    }
+}
+
+fn closing_len(stack: &Stack<'_>) -> usize {
+    stack.iter().map(|(_, close)| utf16_len(close)).sum()
+}
+
+fn split_tokens(tokens: &[Token<'_>], limit: usize) -> Vec<String> {
+    let mut parts = Vec::new();
+
+    let mut start = 0;
+    let mut start_stack = Stack::new();
+    while start < tokens.len() {
+        let mut stack = start_stack.clone();
+        let mut len: usize = start_stack.iter().map(|(open, _)| utf16_len(open)).sum();
+        let mut newline = None;
+        let mut space = None;
+
+        let mut end = start;
+        while end < tokens.len() {
+            let token = &tokens[end];
+            let mut next_stack = stack.clone();
+            apply(&mut next_stack, token);
+
+            let next_len = len + utf16_len(token.text);
+            if next_len + closing_len(&next_stack) > limit {
+                break;
+            }
+
+            if token.is_separator("\n") {
+                newline = Some((end, stack.clone()));
+            } else if token.is_separator(" ") {
+                space = Some((end, stack.clone()));
+            }
+
+            stack = next_stack;
+            len = next_len;
+            end += 1;
+        }
+
+        let (end, next_start, end_stack) = if end == tokens.len() {
+            (end, end, stack)
+        } else {
+            let (end, stack) = match newline.or(space) {
+                Some((sep, stack)) => (sep, stack),
+                // A single token which doesn't fit at all
+                None if end == start => {
+                    let mut stack = start_stack.clone();
+                    apply(&mut stack, &tokens[start]);
+                    (start + 1, stack)
+                }
+                None => (end, stack),
+            };
+
+            // Don't start the next part with the separator
+            match tokens.get(end) {
+                Some(t) if t.is_separator("\n") || t.is_separator(" ") => (end, end + 1, stack),
+                _ => (end, end, stack),
+            }
+        };
+
+        if tokens[start..end].iter().any(Token::is_content) {
+            let mut part = String::new();
+            start_stack.iter().for_each(|(open, _)| part.push_str(open));
+            tokens[start..end]
+                .iter()
+                .for_each(|t| part.push_str(t.text));
+            end_stack
+                .iter()
+                .rev()
+                .for_each(|(_, close)| part.push_str(close));
+
+            parts.push(part);
+        }
+
+        start = next_start;
+        start_stack = end_stack;
+    }
+
+    parts
+}
+
+fn plain_tokens(text: &str) -> Vec<Token<'_>> {
+    text.char_indices()
+        .map(|(i, c)| Token {
+            text: &text[i..i + c.len_utf8()],
+            kind: Kind::Char,
+        })
+        .collect()
+}
+
+fn html_tokens(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let len = match c {
+            '<' => rest.find('>').map(|end| end + 1),
+            // Character references are at most a few characters long
+            '&' => rest
+                .char_indices()
+                .skip(1)
+                .take(10)
+                .find(|&(_, c)| !(c.is_ascii_alphanumeric() || c == '#'))
+                .filter(|&(_, c)| c == ';')
+                .map(|(end, _)| end + 1),
+            _ => None,
+        };
+
+        let (token, tail) = rest.split_at(len.unwrap_or_else(|| c.len_utf8()));
+        let kind = match len {
+            Some(_) if token.starts_with('<') => {
+                let name = token
+                    .trim_start_matches(&['<', '/'][..])
+                    .split(|c: char| c.is_whitespace() || c == '>')
+                    .next()
+                    .unwrap_or_default();
+                let close = format!("</{}>", name);
+
+                match token.starts_with("</") {
+                    true => Kind::Close { close },
+                    false => Kind::Open { close },
+                }
+            }
+            Some(_) => Kind::Atom,
+            None => Kind::Char,
+        };
+
+        tokens.push(Token { text: token, kind });
+        rest = tail;
+    }
+
+    tokens
+}
+
+fn markdown_tokens(text: &str, v2: bool) -> Vec<Token<'_>> {
+    let markers: &[&str] = if v2 {
+        &["```", "`", "||", "__", "*", "_", "~"]
+    } else {
+        &["```", "`", "*", "_"]
+    };
+
+    let mut tokens = Vec::new();
+    // Markers of entities which are currently open
+    let mut open: Vec<&str> = Vec::new();
+
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let code = matches!(open.last(), Some(&"```") | Some(&"`"));
+
+        let (len, kind) = if c == '\\' && rest.len() > 1 {
+            let next = rest[1..].chars().next().map_or(0, char::len_utf8);
+            (1 + next, Kind::Atom)
+        } else if let Some(&marker) = markers
+            .iter()
+            .find(|&&m| rest.starts_with(m) && (!code || Some(&m) == open.last()))
+        {
+            if open.contains(&marker) {
+                let i = open.iter().rposition(|&m| m == marker).unwrap();
+                open.remove(i);
+                (
+                    marker.len(),
+                    Kind::Close {
+                        close: marker.to_owned(),
+                    },
+                )
+            } else if marker == "```" {
+                open.push(marker);
+                // The language of the code block is a part of the marker
+                let len = rest.find('\n').map_or(rest.len(), |i| i + 1);
+                (
+                    len,
+                    Kind::Open {
+                        close: marker.to_owned(),
+                    },
+                )
+            } else {
+                open.push(marker);
+                (
+                    marker.len(),
+                    Kind::Open {
+                        close: marker.to_owned(),
+                    },
+                )
+            }
+        } else if let Some(len) = Some(rest)
+            .filter(|_| !code)
+            .and_then(|rest| link_len(rest, v2))
+        {
+            (len, Kind::Atom)
+        } else {
+            (c.len_utf8(), Kind::Char)
+        };
+
+        let (token, tail) = rest.split_at(len);
+        tokens.push(Token { text: token, kind });
+        rest = tail;
+    }
+
+    tokens
+}
+
+/// Returns the length of a link (`[text](url)`) or a custom emoji
+/// (`![👍](tg://emoji?id=...)`) at the start of `text`.
+fn link_len(text: &str, escapes: bool) -> Option<usize> {
+    let start = match text.strip_prefix('!') {
+        Some(rest) if rest.starts_with('[') => 1,
+        _ if text.starts_with('[') => 0,
+        _ => return None,
+    };
+
+    let end = |text: &str, from: usize, delim: char| {
+        let mut chars = text[from..].char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' if escapes => {
+                    chars.next();
+                }
+                c if c == delim => return Some(from + i + 1),
+                _ => {}
+            }
+        }
+        None
+    };
+
+    let text_end = end(text, start + 1, ']')?;
+    if !text[text_end..].starts_with('(') {
+        return None;
+    }
+
+    end(text, text_end + 1, ')')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(parts: &[String], limit: usize) {
+        for part in parts {
+            assert!(utf16_len(part) <= limit, "{:?} is too long", part);
+        }
+    }
+
+    #[test]
+    fn plain() {
+        assert_eq!(split_text("short", 10, None), ["short"]);
+        assert_eq!(split_text("", 10, None), Vec::<String>::new());
+
+        // Newlines are preferred to spaces
+        assert_eq!(split_text("a b\nc d e f", 8, None), ["a b", "c d e f"]);
+        assert_eq!(split_text("aaa bbb ccc", 8, None), ["aaa bbb", "ccc"]);
+        assert_eq!(split_text("aaaaabbbbb", 5, None), ["aaaaa", "bbbbb"]);
+    }
+
+    #[test]
+    fn utf16() {
+        // Each emoji is 2 UTF-16 code units, so only 2 of them fit into 5
+        let parts = split_text(&"🦀".repeat(5), 5, None);
+        assert_eq!(parts, ["🦀🦀", "🦀🦀", "🦀"]);
+
+        let text = vec!["привет 🦀"; 1000].join(" ");
+        let parts = split_text(&text, 4096, None);
+        check(&parts, 4096);
+        assert_eq!(parts.join(" "), text);
+    }
+
+    #[test]
+    fn html() {
+        let parts = split_text("<b>aaa <i>bbb</i> ccc</b>", 17, Some(ParseMode::Html));
+        assert_eq!(parts, ["<b>aaa</b>", "<b><i>bbb</i></b>", "<b>ccc</b>"]);
+
+        // Tags and character references are not split
+        let parts = split_text(
+            r#"<a href="https://example.com">a&amp;b</a>"#,
+            40,
+            Some(ParseMode::Html),
+        );
+        assert_eq!(
+            parts,
+            [
+                r#"<a href="https://example.com">a&amp;</a>"#,
+                r#"<a href="https://example.com">b</a>"#
+            ]
+        );
+
+        let text = "<pre>code\n</pre>".repeat(1000);
+        check(&split_text(&text, 4096, Some(ParseMode::Html)), 4096);
+    }
+
+    #[test]
+    fn markdown_v2() {
+        let mode = Some(ParseMode::MarkdownV2);
+
+        let parts = split_text(r"*aaa _bbb_ \*ccc*", 10, mode);
+        assert_eq!(parts, ["*aaa*", "*_bbb_*", r"*\*ccc*"]);
+
+        // `_` is not a marker inside of code
+        let parts = split_text("`a_b c_d`", 6, mode);
+        assert_eq!(parts, ["`a_b`", "`c_d`"]);
+
+        let parts = split_text("```rust\nlet a;\nlet b;```", 18, mode);
+        assert_eq!(parts, ["```rust\nlet a;```", "```rust\nlet b;```"]);
+
+        // Links are not split
+        let parts = split_text("see [the docs](https://example.com)", 30, mode);
+        assert_eq!(parts, ["see", "[the docs](https://example.com)"]);
+
+        let parts = split_text("||spoiler|| __a b__", 11, mode);
+        assert_eq!(parts, ["||spoiler||", "__a b__"]);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn markdown() {
+        // `~` is not a marker in the legacy mode
+        let parts = split_text("*a ~b*", 5, Some(ParseMode::Markdown));
+        assert_eq!(parts, ["*a*", "*~b*"]);
+    }
+
+    #[test]
+    fn too_long_atom() {
+        let parts = split_text(
+            "a [link](https://example.com) b",
+            10,
+            Some(ParseMode::MarkdownV2),
+        );
+        assert_eq!(parts, ["a", "[link](https://example.com)", "b"]);
+    }
+}
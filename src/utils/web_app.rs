@@ -0,0 +1,333 @@
+//! Helpers for [Web Apps] (also known as Mini Apps).
+//!
+//! [Web Apps]: https://core.telegram.org/bots/webapps
+
+use std::time::Duration;
+
+use hmac::Mac;
+use serde::{Deserialize, Serialize};
+
+use crate::types::UserId;
+
+use super::signed::{self, Fields, HmacSha256};
+
+pub use super::signed::ValidationError;
+
+/// The default maximum age of `initData` accepted by [`validate_init_data`].
+pub const INIT_DATA_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Data passed to a Web App by Telegram when it's opened, i.e.
+/// `Telegram.WebApp.initData`, returned by [`validate_init_data`].
+///
+/// [The official docs](https://core.telegram.org/bots/webapps#webappinitdata).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct WebAppInitData {
+    /// A unique identifier for the Web App session, required for sending
+    /// messages via [`AnswerWebAppQuery`].
+    ///
+    /// [`AnswerWebAppQuery`]: https://core.telegram.org/bots/api#answerwebappquery
+    pub query_id: Option<String>,
+
+    /// The user who opened the Web App.
+    pub user: Option<WebAppUser>,
+
+    /// The chat partner of the current user in the chat where the bot was
+    /// launched via the attachment menu. Returned only for private chats.
+    pub receiver: Option<WebAppUser>,
+
+    /// Type of the chat from which the Web App was opened: `sender`,
+    /// `private`, `group`, `supergroup` or `channel`.
+    pub chat_type: Option<String>,
+
+    /// Global identifier, uniquely corresponding to the chat from which the
+    /// Web App was opened.
+    pub chat_instance: Option<String>,
+
+    /// The value of the `startattach` or `startapp` parameter, passed via
+    /// link.
+    pub start_param: Option<String>,
+
+    /// Time in seconds, after which a message can be sent via
+    /// [`AnswerWebAppQuery`].
+    ///
+    /// [`AnswerWebAppQuery`]: https://core.telegram.org/bots/api#answerwebappquery
+    pub can_send_after: Option<u32>,
+
+    /// Unix time when the form was opened.
+    pub auth_date: i32,
+
+    /// A hash of all passed parameters, which the bot server can use to
+    /// check their validity.
+    pub hash: String,
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "chrono")))]
+impl WebAppInitData {
+    /// [`WebAppInitData::auth_date`] as [`DateTime`].
+    ///
+    /// [`DateTime`]: chrono::DateTime
+    pub fn auth_date_utc(&self) -> chrono::DateTime<chrono::Utc> {
+        crate::types::from_unix_time(self.auth_date)
+    }
+}
+
+/// A user in [`WebAppInitData`].
+///
+/// [The official docs](https://core.telegram.org/bots/webapps#webappuser).
+#[serde_with_macros::skip_serializing_none]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct WebAppUser {
+    /// Unique identifier for this user or bot.
+    pub id: UserId,
+
+    /// `true`, if this user is a bot. Returned only in the `receiver` field.
+    pub is_bot: Option<bool>,
+
+    /// User‘s or bot’s first name.
+    pub first_name: String,
+
+    /// User‘s or bot’s last name.
+    pub last_name: Option<String>,
+
+    /// User‘s or bot’s username.
+    pub username: Option<String>,
+
+    /// [IETF language tag] of the user's language. Returned only in the
+    /// `user` field.
+    ///
+    /// [IETF language tag]: https://en.wikipedia.org/wiki/IETF_language_tag
+    pub language_code: Option<String>,
+
+    /// `true`, if this user is a Telegram Premium user.
+    pub is_premium: Option<bool>,
+
+    /// URL of the user’s profile photo.
+    pub photo_url: Option<String>,
+}
+
+/// Validates the `initData` of a Web App and parses it, using
+/// [`INIT_DATA_MAX_AGE`] as the maximum age of the data.
+///
+/// See [`validate_init_data_with_max_age`] for details.
+pub fn validate_init_data(init_data: &str, token: &str) -> Result<WebAppInitData, ValidationError> {
+    validate_init_data_with_max_age(init_data, token, INIT_DATA_MAX_AGE)
+}
+
+/// Validates the `initData` of a Web App and parses it.
+///
+/// `init_data` is the raw query string which the Web App got in
+/// `Telegram.WebApp.initData` and passed to the bot's backend. The data is
+/// accepted if it was signed by Telegram for the bot with the given `token`
+/// (the signature is compared in constant time) and its `auth_date` isn't
+/// older than `max_age`.
+///
+/// Never trust the `Telegram.WebApp.initDataUnsafe` object instead: a bad
+/// client can put anything there.
+///
+/// Per [Validating data received via the Web App], the secret key is
+/// HMAC-SHA256 of the bot token with `"WebAppData"` as the key, and `hash` is
+/// HMAC-SHA256 of the other fields, sorted and joined as `key=value` lines,
+/// with the secret key.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::utils::web_app::{validate_init_data, ValidationError};
+///
+/// fn user_name(init_data: &str, token: &str) -> Result<String, ValidationError> {
+///     let data = validate_init_data(init_data, token)?;
+///     Ok(data.user.map(|u| u.first_name).unwrap_or_default())
+/// }
+///
+/// assert_eq!(
+///     user_name("auth_date=1650000000&hash=00", "123456:ABC"),
+///     Err(ValidationError::InvalidSignature)
+/// );
+/// ```
+///
+/// [Validating data received via the Web App]: https://core.telegram.org/bots/webapps#validating-data-received-via-the-mini-app
+pub fn validate_init_data_with_max_age(
+    init_data: &str,
+    token: &str,
+    max_age: Duration,
+) -> Result<WebAppInitData, ValidationError> {
+    validate_at(init_data, token, max_age, signed::now())
+}
+
+fn validate_at(
+    init_data: &str,
+    token: &str,
+    max_age: Duration,
+    now: u64,
+) -> Result<WebAppInitData, ValidationError> {
+    let fields = Fields::parse(init_data);
+    fields.verify(secret(token).as_ref())?;
+    let auth_date = fields.auth_date(max_age, now)?;
+
+    let user = |name: &'static str| {
+        fields
+            .get(name)
+            .map(|json| serde_json::from_str(json).map_err(|_| ValidationError::InvalidField(name)))
+            .transpose()
+    };
+
+    Ok(WebAppInitData {
+        query_id: fields.owned("query_id"),
+        user: user("user")?,
+        receiver: user("receiver")?,
+        chat_type: fields.owned("chat_type"),
+        chat_instance: fields.owned("chat_instance"),
+        start_param: fields.owned("start_param"),
+        can_send_after: fields.parsed("can_send_after")?,
+        auth_date,
+        hash: fields.owned("hash").unwrap_or_default(),
+    })
+}
+
+/// HMAC-SHA256 of the bot token with `"WebAppData"` as the key.
+fn secret(token: &str) -> impl AsRef<[u8]> {
+    let mut secret =
+        HmacSha256::new_from_slice(b"WebAppData").expect("HMAC accepts keys of any size");
+    secret.update(token.as_bytes());
+    secret.finalize().into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKEN: &str = "123456:ABC-DEF1234ghIkl-zyx57W2v1u123ew11";
+    const USER: &str = "user=%7B%22id%22%3A218485655%2C%22first_name%22%3A%22Andrey%22%2C%22language_code%22%3A%22en%22%7D";
+    const HASH: &str = "hash=9b3e91ffc6c55b44f51bcd09caff2a757f6a1ec0d41725791de0eef90171a7a0";
+
+    fn check(init_data: &str) -> Result<WebAppInitData, ValidationError> {
+        validate_at(init_data, TOKEN, INIT_DATA_MAX_AGE, AUTH_DATE)
+    }
+
+    #[test]
+    fn field_order_does_not_matter() {
+        let init_data = format!(
+            "{}&{}&query_id=AAHdF6IQAAAAAN0XohDhrOrc&auth_date=1650000000",
+            HASH, USER
+        );
+        assert!(check(&init_data).is_ok());
+    }
+
+    #[test]
+    fn tampered() {
+        let init_data = format!(
+            "auth_date=1650000001&query_id=AAHdF6IQAAAAAN0XohDhrOrc&{}&{}",
+            USER, HASH
+        );
+        assert_eq!(check(&init_data), Err(ValidationError::InvalidSignature));
+
+        let init_data = format!(
+            "auth_date=1650000000&query_id=AAHdF6IQAAAAAN0XohDhrOrc&{}",
+            USER
+        );
+        assert_eq!(
+            check(&init_data),
+            Err(ValidationError::MissingField("hash"))
+        );
+
+        let init_data = format!(
+            "auth_date=1650000000&query_id=AAHdF6IQAAAAAN0XohDhrOrc&{}&hash=xyz",
+            USER
+        );
+        assert_eq!(check(&init_data), Err(ValidationError::InvalidSignature));
+
+        // The token is checked too
+        let init_data = format!(
+            "auth_date=1650000000&query_id=AAHdF6IQAAAAAN0XohDhrOrc&{}&{}",
+            USER, HASH
+        );
+        assert!(check(&init_data).is_ok());
+        assert_eq!(
+            validate_at(
+                &init_data,
+                "654321:another-token",
+                INIT_DATA_MAX_AGE,
+                AUTH_DATE
+            ),
+            Err(ValidationError::InvalidSignature)
+        );
+    }
+
+    // Generated with the algorithm from the Telegram docs using `TOKEN`
+    const FULL: &str = "auth_date=1650000000&chat_instance=-4273927342872&chat_type=private\
+        &receiver=%7B%22id%22%3A42%2C%22is_bot%22%3Atrue%2C%22first_name%22%3A%22Bot%22%2C%22username%22%3A%22some_bot%22%7D\
+        &start_param=promo&can_send_after=5\
+        &user=%7B%22id%22%3A218485655%2C%22first_name%22%3A%22Andrey%22%2C%22username%22%3A%22andrey%22%2C%22language_code%22%3A%22en%22%2C%22is_premium%22%3Atrue%7D\
+        &hash=cef244a64df0b68bf53d11e994d053449a2793341b79ad33fb9c9d0c17c7f8ca";
+    const NO_AUTH_DATE: &str = "query_id=AAHdF6IQAAAAAN0XohDhrOrc\
+        &hash=6facc5f9f0d3b00a53b652884f6262f5c83bf26647839b7264894d8b5d4ad8b2";
+
+    const AUTH_DATE: u64 = 1650000000;
+
+    #[test]
+    fn validate() {
+        let data = validate_at(FULL, TOKEN, INIT_DATA_MAX_AGE, AUTH_DATE + 60).unwrap();
+
+        assert_eq!(data.auth_date, 1650000000);
+        assert_eq!(data.query_id, None);
+        assert_eq!(data.chat_type.as_deref(), Some("private"));
+        assert_eq!(data.chat_instance.as_deref(), Some("-4273927342872"));
+        assert_eq!(data.start_param.as_deref(), Some("promo"));
+        assert_eq!(data.can_send_after, Some(5));
+        assert_eq!(
+            data.user,
+            Some(WebAppUser {
+                id: UserId(218485655),
+                is_bot: None,
+                first_name: "Andrey".to_owned(),
+                last_name: None,
+                username: Some("andrey".to_owned()),
+                language_code: Some("en".to_owned()),
+                is_premium: Some(true),
+                photo_url: None,
+            })
+        );
+        assert_eq!(
+            data.receiver.as_ref().map(|r| (r.id, r.is_bot)),
+            Some((UserId(42), Some(true)))
+        );
+        assert!(data.hash.starts_with("cef244a6"));
+
+        #[cfg(feature = "chrono")]
+        assert_eq!(data.auth_date_utc().timestamp(), 1650000000);
+    }
+
+    #[test]
+    fn validate_errors() {
+        let err = |init_data: &str, token: &str, now: u64| {
+            validate_at(init_data, token, INIT_DATA_MAX_AGE, now).unwrap_err()
+        };
+
+        assert_eq!(
+            err(FULL, "654321:another-token", AUTH_DATE),
+            ValidationError::InvalidSignature
+        );
+        assert_eq!(
+            err(&FULL.replace("promo", "other"), TOKEN, AUTH_DATE),
+            ValidationError::InvalidSignature
+        );
+        assert_eq!(
+            err("auth_date=1650000000", TOKEN, AUTH_DATE),
+            ValidationError::MissingField("hash")
+        );
+        assert_eq!(
+            err(NO_AUTH_DATE, TOKEN, AUTH_DATE),
+            ValidationError::MissingField("auth_date")
+        );
+        assert_eq!(
+            err(FULL, TOKEN, AUTH_DATE + INIT_DATA_MAX_AGE.as_secs() + 1),
+            ValidationError::Expired {
+                auth_date: 1650000000
+            }
+        );
+
+        // Clock skew
+        assert!(validate_at(FULL, TOKEN, INIT_DATA_MAX_AGE, AUTH_DATE - 10).is_ok());
+    }
+}